@@ -0,0 +1,86 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAbs;
+
+macro_rules! make_abs {
+    ($t:ty) => {
+        impl<const N: usize> ArgminAbs for [$t; N] {
+            #[inline]
+            fn abs(&self) -> [$t; N] {
+                std::array::from_fn(|i| <$t>::abs(self[i]))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminAbs for [[$t; N]; M] {
+            #[inline]
+            fn abs(&self) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminAbs>::abs(&self[i]))
+            }
+        }
+    };
+}
+
+make_abs!(isize);
+make_abs!(i8);
+make_abs!(i16);
+make_abs!(i32);
+make_abs!(i64);
+make_abs!(f32);
+make_abs!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_abs_vec_ $t>]() {
+                    let a = [-1 as $t, 4 as $t, -8 as $t];
+                    let target = [1 as $t, 4 as $t, 8 as $t];
+                    let res = <[$t; 3] as ArgminAbs>::abs(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_abs_mat_ $t>]() {
+                    let a = [
+                        [-1 as $t, 4 as $t, -8 as $t],
+                        [2 as $t, -5 as $t, 9 as $t],
+                    ];
+                    let target = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t],
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminAbs>::abs(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,158 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAdd;
+
+macro_rules! make_add {
+    ($t:ty) => {
+        impl<const N: usize> ArgminAdd<$t, [$t; N]> for [$t; N] {
+            #[inline]
+            fn add(&self, other: &$t) -> [$t; N] {
+                std::array::from_fn(|i| self[i] + other)
+            }
+        }
+
+        impl<const N: usize> ArgminAdd<[$t; N], [$t; N]> for $t {
+            #[inline]
+            fn add(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self + other[i])
+            }
+        }
+
+        impl<const N: usize> ArgminAdd<[$t; N], [$t; N]> for [$t; N] {
+            #[inline]
+            fn add(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self[i] + other[i])
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminAdd<[[$t; N]; M], [[$t; N]; M]>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn add(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| {
+                    <[$t; N] as ArgminAdd<[$t; N], [$t; N]>>::add(&self[i], &other[i])
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminAdd<$t, [[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn add(&self, other: &$t) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminAdd<$t, [$t; N]>>::add(&self[i], other))
+            }
+        }
+    };
+}
+
+make_add!(isize);
+make_add!(usize);
+make_add!(i8);
+make_add!(i16);
+make_add!(i32);
+make_add!(i64);
+make_add!(u8);
+make_add!(u16);
+make_add!(u32);
+make_add!(u64);
+make_add!(f32);
+make_add!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_add_vec_scalar_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 1 as $t;
+                    let target = [2 as $t, 5 as $t, 9 as $t];
+                    let res = <[$t; 3] as ArgminAdd<$t, [$t; 3]>>::add(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_scalar_vec_ $t>]() {
+                    let a = [41 as $t, 38 as $t, 34 as $t];
+                    let b = 1 as $t;
+                    let target = [42 as $t, 39 as $t, 35 as $t];
+                    let res = <$t as ArgminAdd<[$t; 3], [$t; 3]>>::add(&b, &a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_vec_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = [41 as $t, 38 as $t, 34 as $t];
+                    let target = [42 as $t, 42 as $t, 42 as $t];
+                    let res = <[$t; 3] as ArgminAdd<[$t; 3], [$t; 3]>>::add(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_mat_mat_ $t>]() {
+                    let a = [[1 as $t, 4 as $t], [4 as $t, 8 as $t]];
+                    let b = [[41 as $t, 38 as $t], [38 as $t, 34 as $t]];
+                    let target = [[42 as $t, 42 as $t], [42 as $t, 42 as $t]];
+                    let res = <[[$t; 2]; 2] as ArgminAdd<[[$t; 2]; 2], [[$t; 2]; 2]>>::add(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_mat_scalar_ $t>]() {
+                    let a = [[1 as $t, 4 as $t, 8 as $t], [4 as $t, 8 as $t, 1 as $t]];
+                    let b = 1 as $t;
+                    let target = [[2 as $t, 5 as $t, 9 as $t], [5 as $t, 9 as $t, 2 as $t]];
+                    let res = <[[$t; 3]; 2] as ArgminAdd<$t, [[$t; 3]; 2]>>::add(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
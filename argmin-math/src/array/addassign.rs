@@ -0,0 +1,157 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAddAssign;
+
+macro_rules! make_addassign {
+    ($t:ty) => {
+        impl<const N: usize> ArgminAddAssign<$t> for [$t; N] {
+            #[inline]
+            fn add_assign(&mut self, other: &$t) {
+                for a in self.iter_mut() {
+                    *a += *other;
+                }
+            }
+        }
+
+        impl<const N: usize> ArgminAddAssign<[$t; N]> for [$t; N] {
+            #[inline]
+            fn add_assign(&mut self, other: &[$t; N]) {
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    *a += *b;
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminAddAssign<$t> for [[$t; N]; M] {
+            #[inline]
+            fn add_assign(&mut self, other: &$t) {
+                for row in self.iter_mut() {
+                    <[$t; N] as ArgminAddAssign<$t>>::add_assign(row, other);
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminAddAssign<[[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn add_assign(&mut self, other: &[[$t; N]; M]) {
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    <[$t; N] as ArgminAddAssign<[$t; N]>>::add_assign(a, b);
+                }
+            }
+        }
+    };
+}
+
+make_addassign!(isize);
+make_addassign!(usize);
+make_addassign!(i8);
+make_addassign!(i16);
+make_addassign!(i32);
+make_addassign!(i64);
+make_addassign!(u8);
+make_addassign!(u16);
+make_addassign!(u32);
+make_addassign!(u64);
+make_addassign!(f32);
+make_addassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_add_assign_vec_scalar_ $t>]() {
+                    let mut a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 34 as $t;
+                    <[$t; 3] as ArgminAddAssign<$t>>::add_assign(&mut a, &b);
+                    let target = [35 as $t, 38 as $t, 42 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_assign_vec_vec_ $t>]() {
+                    let mut a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = [5 as $t, 6 as $t, 7 as $t];
+                    <[$t; 3] as ArgminAddAssign<[$t; 3]>>::add_assign(&mut a, &b);
+                    let target = [6 as $t, 10 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_assign_mat_scalar_ $t>]() {
+                    let mut a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 1 as $t],
+                    ];
+                    let b = 34 as $t;
+                    <[[$t; 3]; 2] as ArgminAddAssign<$t>>::add_assign(&mut a, &b);
+                    let target = [
+                        [35 as $t, 38 as $t, 42 as $t],
+                        [38 as $t, 42 as $t, 35 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_assign_mat_mat_ $t>]() {
+                    let mut a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 1 as $t],
+                    ];
+                    let b = [
+                        [5 as $t, 6 as $t, 7 as $t],
+                        [6 as $t, 7 as $t, 5 as $t],
+                    ];
+                    <[[$t; 3]; 2] as ArgminAddAssign<[[$t; 3]; 2]>>::add_assign(&mut a, &b);
+                    let target = [
+                        [6 as $t, 10 as $t, 15 as $t],
+                        [10 as $t, 15 as $t, 6 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
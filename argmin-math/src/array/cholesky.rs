@@ -0,0 +1,99 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminCholesky, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct CholeskyError;
+
+impl fmt::Display for CholeskyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is not positive definite")
+    }
+}
+
+macro_rules! make_cholesky {
+    ($t:ty) => {
+        impl<const N: usize> ArgminCholesky<[[$t; N]; N]> for [[$t; N]; N] {
+            fn cholesky(&self) -> Result<[[$t; N]; N], Error> {
+                let mut l = [[0.0; N]; N];
+                for j in 0..N {
+                    let s: $t = (0..j).map(|k| l[j][k] * l[j][k]).sum();
+                    let d = self[j][j] - s;
+                    if d <= 0.0 || !d.is_finite() {
+                        return Err(CholeskyError {}.into());
+                    }
+                    l[j][j] = d.sqrt();
+                    for i in (j + 1)..N {
+                        let s: $t = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+                        l[i][j] = (self[i][j] - s) / l[j][j];
+                    }
+                }
+                Ok(l)
+            }
+        }
+    };
+}
+
+make_cholesky!(f32);
+make_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArgminDot, ArgminTranspose};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = [
+                        [4 as $t, 12 as $t, -16 as $t],
+                        [12 as $t, 37 as $t, -43 as $t],
+                        [-16 as $t, -43 as $t, 98 as $t],
+                    ];
+                    let target = [
+                        [2 as $t, 0 as $t, 0 as $t],
+                        [6 as $t, 1 as $t, 0 as $t],
+                        [-8 as $t, 5 as $t, 3 as $t],
+                    ];
+                    let l = <[[$t; 3]; 3] as ArgminCholesky<[[$t; 3]; 3]>>::cholesky(&a).unwrap();
+                    let lt: [[$t; 3]; 3] = l.t();
+                    let llt: [[$t; 3]; 3] = l.dot(&lt);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((l[i][j] - target[i][j]) as f64).abs()) < 0.0001);
+                            assert!((((llt[i][j] - a[i][j]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let err = <[[$t; 2]; 2] as ArgminCholesky<[[$t; 2]; 2]>>::cholesky(&a)
+                        .unwrap_err()
+                        .downcast::<CholeskyError>()
+                        .unwrap();
+                    assert_eq!(err, CholeskyError {});
+                    assert_eq!(format!("{}", err), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
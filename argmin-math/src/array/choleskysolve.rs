@@ -0,0 +1,78 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::choleskysolve::{cholesky_substitute, CholeskySolveError};
+use crate::{ArgminCholeskySolve, Error};
+
+macro_rules! make_cholesky_solve {
+    ($t:ty) => {
+        impl<const N: usize> ArgminCholeskySolve<[$t; N], [$t; N]> for [[$t; N]; N] {
+            fn cholesky_solve(&self, b: &[$t; N]) -> Result<[$t; N], Error> {
+                let mut x = *b;
+                if !cholesky_substitute(N, |i, j| self[i][j], &mut x) {
+                    return Err(CholeskySolveError {}.into());
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_cholesky_solve!(f32);
+make_cholesky_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    // Cholesky factor of [[4, 12, -16], [12, 37, -43], [-16, -43, 98]]; the upper
+                    // triangle must not be accessed
+                    let l = [
+                        [2 as $t, 100 as $t, 100 as $t],
+                        [6 as $t, 1 as $t, 100 as $t],
+                        [-8 as $t, 5 as $t, 3 as $t],
+                    ];
+                    let b = [0 as $t, 6 as $t, 39 as $t];
+                    let x = <[[$t; 3]; 3] as ArgminCholeskySolve<[$t; 3], [$t; 3]>>::cholesky_solve(
+                        &l, &b
+                    ).unwrap();
+                    for i in 0..3 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_singular_ $t>]() {
+                    let l = [
+                        [1 as $t, 0 as $t],
+                        [2 as $t, 0 as $t],
+                    ];
+                    let b = [1 as $t, 1 as $t];
+                    let err = <[[$t; 2]; 2] as ArgminCholeskySolve<[$t; 2], [$t; 2]>>::cholesky_solve(
+                        &l, &b
+                    )
+                    .unwrap_err()
+                    .downcast::<CholeskySolveError>()
+                    .unwrap();
+                    assert_eq!(err, CholeskySolveError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,105 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminClamp;
+
+macro_rules! make_clamp {
+    ($t:ty) => {
+        impl<const N: usize> ArgminClamp<$t> for [$t; N] {
+            #[inline]
+            fn clamp(&self, min: &$t, max: &$t) -> [$t; N] {
+                std::array::from_fn(|i| {
+                    let a = self[i];
+                    if a < *min {
+                        *min
+                    } else if a > *max {
+                        *max
+                    } else {
+                        a
+                    }
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminClamp<$t> for [[$t; N]; M] {
+            #[inline]
+            fn clamp(&self, min: &$t, max: &$t) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminClamp<$t>>::clamp(&self[i], min, max))
+            }
+        }
+    };
+}
+
+make_clamp!(isize);
+make_clamp!(usize);
+make_clamp!(i8);
+make_clamp!(i16);
+make_clamp!(i32);
+make_clamp!(i64);
+make_clamp!(u8);
+make_clamp!(u16);
+make_clamp!(u32);
+make_clamp!(u64);
+make_clamp!(f32);
+make_clamp!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_clamp_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let target = [2 as $t, 4 as $t, 6 as $t];
+                    let res = <[$t; 3] as ArgminClamp<$t>>::clamp(&a, &(2 as $t), &(6 as $t));
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_clamp_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [9 as $t, 5 as $t, 0 as $t],
+                    ];
+                    let target = [
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [6 as $t, 5 as $t, 2 as $t],
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminClamp<$t>>::clamp(&a, &(2 as $t), &(6 as $t));
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
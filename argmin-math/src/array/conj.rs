@@ -0,0 +1,116 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminConj;
+use num_complex::Complex;
+
+macro_rules! make_conj {
+    ($t:ty) => {
+        impl<const N: usize> ArgminConj for [$t; N] {
+            #[inline]
+            fn conj(&self) -> [$t; N] {
+                std::array::from_fn(|i| <$t as ArgminConj>::conj(&self[i]))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminConj for [[$t; N]; M] {
+            #[inline]
+            fn conj(&self) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminConj>::conj(&self[i]))
+            }
+        }
+    };
+}
+
+make_conj!(isize);
+make_conj!(i8);
+make_conj!(i16);
+make_conj!(i32);
+make_conj!(i64);
+make_conj!(f32);
+make_conj!(f64);
+make_conj!(Complex<isize>);
+make_conj!(Complex<i8>);
+make_conj!(Complex<i16>);
+make_conj!(Complex<i32>);
+make_conj!(Complex<i64>);
+make_conj!(Complex<f32>);
+make_conj!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_conj_complex_vec_ $t>]() {
+                    let a = [
+                        Complex::new(1 as $t, 2 as $t),
+                        Complex::new(4 as $t, -3 as $t),
+                        Complex::new(8 as $t, 0 as $t),
+                    ];
+                    let b = [
+                        Complex::new(1 as $t, -2 as $t),
+                        Complex::new(4 as $t, 3 as $t),
+                        Complex::new(8 as $t, 0 as $t),
+                    ];
+                    let res = <[Complex<$t>; 3] as ArgminConj>::conj(&a);
+                    for i in 0..3 {
+                        let tmp = b[i] - res[i];
+                        let norm = ((tmp.re * tmp.re + tmp.im * tmp.im) as f64).sqrt();
+                        assert!(norm  < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_conj_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let res = <[$t; 3] as ArgminConj>::conj(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_conj_complex_mat_ $t>]() {
+                    let a = [
+                        [Complex::new(1 as $t, 2 as $t), Complex::new(4 as $t, -3 as $t)],
+                        [Complex::new(8 as $t, 0 as $t), Complex::new(-1 as $t, -1 as $t)],
+                    ];
+                    let b = [
+                        [Complex::new(1 as $t, -2 as $t), Complex::new(4 as $t, 3 as $t)],
+                        [Complex::new(8 as $t, 0 as $t), Complex::new(-1 as $t, 1 as $t)],
+                    ];
+                    let res = <[[Complex<$t>; 2]; 2] as ArgminConj>::conj(&a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let tmp = b[i][j] - res[i][j];
+                            let norm = ((tmp.re * tmp.re + tmp.im * tmp.im) as f64).sqrt();
+                            assert!(norm  < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
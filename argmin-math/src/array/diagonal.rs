@@ -0,0 +1,56 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiagonal;
+
+impl<T: Clone, const N: usize> ArgminDiagonal<[T; N]> for [[T; N]; N] {
+    #[inline]
+    fn diagonal(&self) -> [T; N] {
+        std::array::from_fn(|i| self[i][i].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diagonal_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 9 as $t],
+                    ];
+                    let res = a.diagonal();
+                    let target = [1 as $t, 5 as $t, 9 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
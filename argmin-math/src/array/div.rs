@@ -0,0 +1,149 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+use num_complex::Complex;
+
+macro_rules! make_div {
+    ($t:ty) => {
+        impl<const N: usize> ArgminDiv<$t, [$t; N]> for [$t; N] {
+            #[inline]
+            fn div(&self, other: &$t) -> [$t; N] {
+                std::array::from_fn(|i| self[i] / other)
+            }
+        }
+
+        impl<const N: usize> ArgminDiv<[$t; N], [$t; N]> for $t {
+            #[inline]
+            fn div(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self / other[i])
+            }
+        }
+
+        impl<const N: usize> ArgminDiv<[$t; N], [$t; N]> for [$t; N] {
+            #[inline]
+            fn div(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self[i] / other[i])
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDiv<[[$t; N]; M], [[$t; N]; M]>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn div(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| {
+                    <[$t; N] as ArgminDiv<[$t; N], [$t; N]>>::div(&self[i], &other[i])
+                })
+            }
+        }
+    };
+}
+
+make_div!(isize);
+make_div!(usize);
+make_div!(i8);
+make_div!(i16);
+make_div!(i32);
+make_div!(i64);
+make_div!(u8);
+make_div!(u16);
+make_div!(u32);
+make_div!(u64);
+make_div!(f32);
+make_div!(f64);
+make_div!(Complex<isize>);
+make_div!(Complex<usize>);
+make_div!(Complex<i8>);
+make_div!(Complex<i16>);
+make_div!(Complex<i32>);
+make_div!(Complex<i64>);
+make_div!(Complex<u8>);
+make_div!(Complex<u16>);
+make_div!(Complex<u32>);
+make_div!(Complex<u64>);
+make_div!(Complex<f32>);
+make_div!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_div_vec_scalar_ $t>]() {
+                    let a = [4 as $t, 16 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = [2 as $t, 8 as $t, 4 as $t];
+                    let res = <[$t; 3] as ArgminDiv<$t, [$t; 3]>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_vec_ $t>]() {
+                    let a = [2 as $t, 4 as $t, 8 as $t];
+                    let b = 24 as $t;
+                    let target = [12 as $t, 6 as $t, 3 as $t];
+                    let res = <$t as ArgminDiv<[$t; 3], [$t; 3]>>::div(&b, &a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_vec_vec_ $t>]() {
+                    let a = [4 as $t, 16 as $t, 8 as $t];
+                    let b = [2 as $t, 4 as $t, 8 as $t];
+                    let target = [2 as $t, 4 as $t, 1 as $t];
+                    let res = <[$t; 3] as ArgminDiv<[$t; 3], [$t; 3]>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_mat_mat_ $t>]() {
+                    let a = [[4 as $t, 16 as $t], [16 as $t, 8 as $t]];
+                    let b = [[2 as $t, 4 as $t], [4 as $t, 8 as $t]];
+                    let target = [[2 as $t, 4 as $t], [4 as $t, 1 as $t]];
+                    let res = <[[$t; 2]; 2] as ArgminDiv<[[$t; 2]; 2], [[$t; 2]; 2]>>::div(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
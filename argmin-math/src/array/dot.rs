@@ -0,0 +1,261 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use num_complex::Complex;
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl<const N: usize> ArgminDot<[$t; N], $t> for [$t; N] {
+            #[inline]
+            fn dot(&self, other: &[$t; N]) -> $t {
+                self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
+            }
+        }
+
+        impl<const N: usize> ArgminDot<$t, [$t; N]> for [$t; N] {
+            #[inline]
+            fn dot(&self, other: &$t) -> [$t; N] {
+                std::array::from_fn(|i| self[i] * other)
+            }
+        }
+
+        impl<const N: usize> ArgminDot<[$t; N], [$t; N]> for $t {
+            #[inline]
+            fn dot(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| other[i] * self)
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<[$t; N], [[$t; N]; M]> for [$t; M] {
+            #[inline]
+            fn dot(&self, other: &[$t; N]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| std::array::from_fn(|j| other[j] * self[i]))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<[$t; N], [$t; M]> for [[$t; N]; M] {
+            #[inline]
+            fn dot(&self, other: &[$t; N]) -> [$t; M] {
+                std::array::from_fn(|i| self[i].dot(other))
+            }
+        }
+
+        impl<const N: usize, const M: usize, const K: usize> ArgminDot<[[$t; K]; N], [[$t; K]; M]>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn dot(&self, other: &[[$t; K]; N]) -> [[$t; K]; M] {
+                std::array::from_fn(|i| {
+                    std::array::from_fn(|j| (0..N).map(|k| self[i][k] * other[k][j]).sum())
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<$t, [[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn dot(&self, other: &$t) -> [[$t; N]; M] {
+                std::array::from_fn(|i| std::array::from_fn(|j| self[i][j] * other))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminDot<[[$t; N]; M], [[$t; N]; M]> for $t {
+            #[inline]
+            fn dot(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| std::array::from_fn(|j| other[i][j] * self))
+            }
+        }
+    };
+}
+
+make_dot!(isize);
+make_dot!(usize);
+make_dot!(i8);
+make_dot!(i16);
+make_dot!(i32);
+make_dot!(i64);
+make_dot!(u8);
+make_dot!(u16);
+make_dot!(u32);
+make_dot!(u64);
+make_dot!(f32);
+make_dot!(f64);
+make_dot!(Complex<isize>);
+make_dot!(Complex<usize>);
+make_dot!(Complex<i8>);
+make_dot!(Complex<i16>);
+make_dot!(Complex<i32>);
+make_dot!(Complex<i64>);
+make_dot!(Complex<u8>);
+make_dot!(Complex<u16>);
+make_dot!(Complex<u32>);
+make_dot!(Complex<u64>);
+make_dot!(Complex<f32>);
+make_dot!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_vec_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = [4 as $t, 5 as $t, 6 as $t];
+                    let res: $t = a.dot(&b);
+                    assert_relative_eq!(res as f64, 32 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_scalar_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product: [$t; 3] = a.dot(&b);
+                    let res = [2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(product[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scalar_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product: [$t; 3] = b.dot(&a);
+                    let res = [2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(product[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let b = [4 as $t, 5 as $t];
+                    let res = [
+                        [4 as $t, 5 as $t],
+                        [8 as $t, 10 as $t],
+                        [12 as $t, 15 as $t],
+                    ];
+                    let product: [[$t; 2]; 3] = a.dot(&b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(product[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_2_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let b = [1 as $t, 2 as $t, 3 as $t];
+                    let res = [14 as $t, 32 as $t];
+                    let product: [$t; 2] = a.dot(&b);
+                    for i in 0..2 {
+                        assert_relative_eq!(product[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let b = [
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                        [5 as $t, 6 as $t],
+                    ];
+                    let res = [
+                        [22 as $t, 28 as $t],
+                        [49 as $t, 64 as $t],
+                    ];
+                    let product: [[$t; 2]; 2] = a.dot(&b);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(product[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_scalar_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let b = 2 as $t;
+                    let res = [
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [8 as $t, 10 as $t, 12 as $t],
+                    ];
+                    let product: [[$t; 3]; 2] = a.dot(&b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(product[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scalar_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let b = 2 as $t;
+                    let res = [
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [8 as $t, 10 as $t, 12 as $t],
+                    ];
+                    let product: [[$t; 3]; 2] = b.dot(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(product[i][j] as f64, res[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
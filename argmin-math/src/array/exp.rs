@@ -0,0 +1,117 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminExp;
+use num_complex::Complex;
+
+macro_rules! make_exp {
+    ($t:ty) => {
+        impl<const N: usize> ArgminExp for [$t; N] {
+            #[inline]
+            fn exp(&self) -> [$t; N] {
+                std::array::from_fn(|i| <$t>::exp(self[i]))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminExp for [[$t; N]; M] {
+            #[inline]
+            fn exp(&self) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminExp>::exp(&self[i]))
+            }
+        }
+    };
+}
+
+macro_rules! make_exp_complex {
+    ($t:ty) => {
+        impl<const N: usize> ArgminExp for [$t; N] {
+            #[inline]
+            fn exp(&self) -> [$t; N] {
+                std::array::from_fn(|i| self[i].exp())
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminExp for [[$t; N]; M] {
+            #[inline]
+            fn exp(&self) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminExp>::exp(&self[i]))
+            }
+        }
+    };
+}
+
+make_exp!(f32);
+make_exp!(f64);
+make_exp_complex!(Complex<f32>);
+make_exp_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_exp_vec_ $t>]() {
+                    let a = [0 as $t, 1 as $t, 2 as $t];
+                    let target = [1 as $t, std::f64::consts::E as $t, (std::f64::consts::E * std::f64::consts::E) as $t];
+                    let res = <[$t; 3] as ArgminExp>::exp(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_exp_mat_ $t>]() {
+                    let a = [
+                        [0 as $t, 1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t, 0 as $t],
+                    ];
+                    let target = [
+                        [1 as $t, std::f64::consts::E as $t, (std::f64::consts::E * std::f64::consts::E) as $t],
+                        [(std::f64::consts::E * std::f64::consts::E) as $t, std::f64::consts::E as $t, 1 as $t],
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminExp>::exp(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = 1e-5);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_exp_complex_vec_ $t>]() {
+                    let a = [Complex::new(0 as $t, std::f64::consts::PI as $t)];
+                    let target = [Complex::new(-1 as $t, 0 as $t)];
+                    let res = <[Complex<$t>; 1] as ArgminExp>::exp(&a);
+                    for i in 0..1 {
+                        assert_relative_eq!(res[i].re as f64, target[i].re as f64, epsilon = 1e-6);
+                        assert_relative_eq!(res[i].im as f64, target[i].im as f64, epsilon = 1e-6);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
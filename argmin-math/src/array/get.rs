@@ -0,0 +1,77 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminGet;
+
+impl<T: Clone, const N: usize> ArgminGet<usize, T> for [T; N] {
+    #[inline]
+    fn get_elem(&self, idx: usize) -> T {
+        self[idx].clone()
+    }
+}
+
+impl<T: Clone, const N: usize, const M: usize> ArgminGet<(usize, usize), T> for [[T; N]; M] {
+    #[inline]
+    fn get_elem(&self, (i, j): (usize, usize)) -> T {
+        self[i][j].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_get_vec_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let res: $t = a.get_elem(1);
+                    assert_relative_eq!(res as f64, 2 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_get_mat_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let res: $t = a.get_elem((1, 2));
+                    assert_relative_eq!(res as f64, 6 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_get_vec_panic_ $t>]() {
+                    let a = [1 as $t, 2 as $t, 3 as $t];
+                    let idx = a.len();
+                    let _: $t = a.get_elem(idx);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,123 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::lufactor::{lu_factorize_in_place, lu_is_singular, lu_solve_into};
+use crate::{ArgminInv, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct InvError;
+
+impl fmt::Display for InvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+macro_rules! make_inv {
+    ($t:ty) => {
+        impl<const N: usize> ArgminInv<[[$t; N]; N]> for [[$t; N]; N] {
+            fn inv(&self) -> Result<[[$t; N]; N], Error> {
+                let mut lu = *self;
+                let mut perm = [0; N];
+                lu_factorize_in_place(&mut lu, &mut perm);
+                if lu_is_singular(self, &lu) {
+                    return Err(InvError {}.into());
+                }
+                // Solve for one column of the inverse at a time with the same factorization and
+                // assemble the result row-wise
+                let mut res = [[0.0; N]; N];
+                let mut e = [0.0; N];
+                let mut col = [0.0; N];
+                for j in 0..N {
+                    e[j] = 1.0;
+                    lu_solve_into(&lu, &perm, &e, &mut col);
+                    e[j] = 0.0;
+                    for (i, c) in col.iter().enumerate() {
+                        res[i][j] = *c;
+                    }
+                }
+                Ok(res)
+            }
+        }
+    };
+}
+
+make_inv!(f32);
+make_inv!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_inv_ $t>]() {
+                    let a = [
+                        [2 as $t, 5 as $t],
+                        [1 as $t, 3 as $t],
+                    ];
+                    let target = [
+                        [3 as $t, -5 as $t],
+                        [-1 as $t, 2 as $t],
+                    ];
+                    let res = <[[$t; 2]; 2] as ArgminInv<[[$t; 2]; 2]>>::inv(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[i][j] - target[i][j]) as f64).abs()) < 0.000001);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_singular_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t],
+                    ];
+                    let err = <[[$t; 2]; 2] as ArgminInv<[[$t; 2]; 2]>>::inv(&a)
+                        .unwrap_err()
+                        .downcast::<InvError>()
+                        .unwrap();
+                    assert_eq!(err, InvError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_pivoting_ $t>]() {
+                    // Zero in the top left corner requires a row exchange
+                    let a = [
+                        [0 as $t, 1 as $t, 2 as $t],
+                        [1 as $t, 0 as $t, 3 as $t],
+                        [4 as $t, -3 as $t, 8 as $t],
+                    ];
+                    let target = [
+                        [-4.5 as $t, 7 as $t, -1.5 as $t],
+                        [-2 as $t, 4 as $t, -1 as $t],
+                        [1.5 as $t, -2 as $t, 0.5 as $t],
+                    ];
+                    let res = <[[$t; 3]; 3] as ArgminInv<[[$t; 3]; 3]>>::inv(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[i][j] - target[i][j]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,27 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+
+impl<T, const N: usize> ArgminLen for [T; N] {
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        assert_eq!(ArgminLen::len(&[1.0f64, 2.0, 3.0]), 3);
+        assert_eq!(ArgminLen::len(&[[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0]]), 2);
+        assert!(ArgminLen::is_empty(&[0.0f64; 0]));
+    }
+}
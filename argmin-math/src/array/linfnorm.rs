@@ -0,0 +1,188 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLInfNorm;
+use num_complex::Complex;
+
+macro_rules! make_linfnorm {
+    ($t:ty) => {
+        impl<const N: usize> ArgminLInfNorm<$t> for [$t; N] {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().fold(0 as $t, |acc, a| {
+                    let a = <$t>::abs(*a);
+                    if a > acc {
+                        a
+                    } else {
+                        acc
+                    }
+                })
+            }
+        }
+    };
+}
+
+// NaN entries propagate to the result
+macro_rules! make_linfnorm_float {
+    ($t:ty) => {
+        impl<const N: usize> ArgminLInfNorm<$t> for [$t; N] {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().fold(0 as $t, |acc, a| {
+                    let a = <$t>::abs(*a);
+                    if a.is_nan() || a > acc {
+                        a
+                    } else {
+                        acc
+                    }
+                })
+            }
+        }
+    };
+}
+
+macro_rules! make_linfnorm_unsigned {
+    ($t:ty) => {
+        impl<const N: usize> ArgminLInfNorm<$t> for [$t; N] {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().fold(0, |acc, &a| if a > acc { a } else { acc })
+            }
+        }
+    };
+}
+
+macro_rules! make_linfnorm_complex {
+    ($t:ty) => {
+        impl<const N: usize> ArgminLInfNorm<$t> for [Complex<$t>; N] {
+            #[inline]
+            fn linf_norm(&self) -> $t {
+                self.iter().fold(0.0, |acc, a| {
+                    let a = a.norm();
+                    if a.is_nan() || a > acc {
+                        a
+                    } else {
+                        acc
+                    }
+                })
+            }
+        }
+    };
+}
+
+make_linfnorm_unsigned!(usize);
+make_linfnorm_unsigned!(u8);
+make_linfnorm_unsigned!(u16);
+make_linfnorm_unsigned!(u32);
+make_linfnorm_unsigned!(u64);
+make_linfnorm!(isize);
+make_linfnorm!(i8);
+make_linfnorm!(i16);
+make_linfnorm!(i32);
+make_linfnorm!(i64);
+make_linfnorm_float!(f32);
+make_linfnorm_float!(f64);
+make_linfnorm_complex!(f32);
+make_linfnorm_complex!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let res = <[$t; 3] as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(res as f64, 8 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_signed {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_signed_ $t>]() {
+                    let a = [1 as $t, -9 as $t, 8 as $t];
+                    let res = <[$t; 3] as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(res as f64, 9 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_complex_ $t>]() {
+                    let a = [Complex::new(1 as $t, 1 as $t), Complex::new(-3 as $t, 4 as $t)];
+                    let res = <[Complex<$t>; 2] as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(res as f64, 5 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_nan {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_nan_ $t>]() {
+                    let a = [1 as $t, <$t>::NAN, 8 as $t];
+                    let res = <[$t; 3] as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert!(res.is_nan());
+                    let a = [<$t>::NAN, 1 as $t, 8 as $t];
+                    let res = <[$t; 3] as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert!(res.is_nan());
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_linfnorm_complex_nan_ $t>]() {
+                    let a = [Complex::new(1 as $t, 1 as $t), Complex::new(<$t>::NAN, 4 as $t)];
+                    let res = <[Complex<$t>; 2] as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert!(res.is_nan());
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_signed!(isize);
+    make_test_signed!(i8);
+    make_test_signed!(i16);
+    make_test_signed!(i32);
+    make_test_signed!(i64);
+    make_test_signed!(f32);
+    make_test_signed!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+
+    make_test_nan!(f32);
+    make_test_nan!(f64);
+}
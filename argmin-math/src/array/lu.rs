@@ -0,0 +1,96 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::lufactor::lu_factorize_in_place;
+use crate::ArgminLU;
+use crate::Error;
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl<const N: usize> ArgminLU<[[$t; N]; N]> for [[$t; N]; N] {
+            fn lu(&self) -> Result<([[$t; N]; N], [[$t; N]; N], [[$t; N]; N]), Error> {
+                let mut lu = *self;
+                let mut perm = [0; N];
+                lu_factorize_in_place(&mut lu, &mut perm);
+                let mut p = [[0.0; N]; N];
+                let mut l = [[0.0; N]; N];
+                let mut u = [[0.0; N]; N];
+                for i in 0..N {
+                    p[i][perm[i]] = 1.0;
+                    l[i][..i].copy_from_slice(&lu[i][..i]);
+                    l[i][i] = 1.0;
+                    u[i][i..].copy_from_slice(&lu[i][i..]);
+                }
+                Ok((p, l, u))
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArgminDot;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 10 as $t],
+                    ];
+                    let (p, l, u) = <[[$t; 3]; 3] as ArgminLU<[[$t; 3]; 3]>>::lu(&a).unwrap();
+                    let pa: [[$t; 3]; 3] = p.dot(&a);
+                    let lu: [[$t; 3]; 3] = l.dot(&u);
+                    for i in 0..3 {
+                        assert!((((l[i][i] - 1 as $t) as f64).abs()) < 0.000001);
+                        for j in 0..3 {
+                            if j > i {
+                                assert!(((l[i][j]) as f64).abs() < 0.000001);
+                            }
+                            if j < i {
+                                assert!(((u[i][j]) as f64).abs() < 0.000001);
+                            }
+                            assert!((((pa[i][j] - lu[i][j]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    // Partial pivoting selects the row with the largest element first
+                    assert!((((p[0][2] - 1 as $t) as f64).abs()) < 0.000001);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_singular_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t],
+                    ];
+                    let (p, l, u) = <[[$t; 2]; 2] as ArgminLU<[[$t; 2]; 2]>>::lu(&a).unwrap();
+                    let pa: [[$t; 2]; 2] = p.dot(&a);
+                    let lu: [[$t; 2]; 2] = l.dot(&u);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((pa[i][j] - lu[i][j]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    assert!(((u[1][1]) as f64).abs() < 0.000001);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,84 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod abs;
+mod add;
+mod addassign;
+mod cholesky;
+mod choleskysolve;
+mod clamp;
+mod conj;
+mod diagonal;
+mod div;
+mod dot;
+mod exp;
+mod eye;
+mod get;
+mod inv;
+mod l1norm;
+mod l2norm;
+mod len;
+mod linfnorm;
+mod lu;
+mod minmax;
+mod modifiedcholesky;
+mod mul;
+mod mulassign;
+mod powi;
+mod random;
+mod scaledadd;
+mod scaledaddassign;
+mod scaledsub;
+mod scaledsubassign;
+mod set;
+mod signum;
+mod solve;
+mod sqrt;
+mod sub;
+mod subassign;
+mod sum;
+mod transpose;
+mod zero;
+
+pub use abs::*;
+pub use add::*;
+pub use addassign::*;
+pub use cholesky::*;
+pub use choleskysolve::*;
+pub use clamp::*;
+pub use conj::*;
+pub use diagonal::*;
+pub use div::*;
+pub use dot::*;
+pub use exp::*;
+pub use eye::*;
+pub use get::*;
+pub use inv::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use len::*;
+pub use linfnorm::*;
+pub use lu::*;
+pub use minmax::*;
+pub use modifiedcholesky::*;
+pub use mul::*;
+pub use mulassign::*;
+pub use powi::*;
+pub use random::*;
+pub use scaledadd::*;
+pub use scaledaddassign::*;
+pub use scaledsub::*;
+pub use scaledsubassign::*;
+pub use set::*;
+pub use signum::*;
+pub use solve::*;
+pub use sqrt::*;
+pub use sub::*;
+pub use subassign::*;
+pub use sum::*;
+pub use transpose::*;
+pub use zero::*;
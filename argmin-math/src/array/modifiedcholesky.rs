@@ -0,0 +1,62 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::modifiedcholesky::gill_murray;
+use crate::{ArgminModifiedCholesky, Error};
+
+macro_rules! make_modified_cholesky {
+    ($t:ty) => {
+        impl<const N: usize> ArgminModifiedCholesky<[[$t; N]; N], $t> for [[$t; N]; N] {
+            fn modified_cholesky(&self) -> Result<([[$t; N]; N], $t), Error> {
+                let (l, max_e) = gill_murray(N, |i, j| self[i][j]);
+                Ok((
+                    std::array::from_fn(|i| std::array::from_fn(|j| l[i][j])),
+                    max_e,
+                ))
+            }
+        }
+    };
+}
+
+make_modified_cholesky!(f32);
+make_modified_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArgminDot, ArgminTranspose};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_modified_cholesky_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let (l, max_e) =
+                        <[[$t; 2]; 2] as ArgminModifiedCholesky<[[$t; 2]; 2], $t>>::modified_cholesky(&a)
+                            .unwrap();
+                    assert!(max_e > 0 as $t);
+                    let lt: [[$t; 2]; 2] = l.t();
+                    let llt: [[$t; 2]; 2] = l.dot(&lt);
+                    assert!((((llt[0][1] - a[0][1]) as f64).abs()) < 0.0001);
+                    assert!((((llt[1][0] - a[1][0]) as f64).abs()) < 0.0001);
+                    for i in 0..2 {
+                        assert!(llt[i][i] >= a[i][i]);
+                        assert!(((llt[i][i] - a[i][i]) as f64) <= (max_e as f64) + 0.0001);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
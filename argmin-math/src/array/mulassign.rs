@@ -0,0 +1,157 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMulAssign;
+
+macro_rules! make_mulassign {
+    ($t:ty) => {
+        impl<const N: usize> ArgminMulAssign<$t> for [$t; N] {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                for a in self.iter_mut() {
+                    *a *= *other;
+                }
+            }
+        }
+
+        impl<const N: usize> ArgminMulAssign<[$t; N]> for [$t; N] {
+            #[inline]
+            fn mul_assign(&mut self, other: &[$t; N]) {
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    *a *= *b;
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMulAssign<$t> for [[$t; N]; M] {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                for row in self.iter_mut() {
+                    <[$t; N] as ArgminMulAssign<$t>>::mul_assign(row, other);
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminMulAssign<[[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn mul_assign(&mut self, other: &[[$t; N]; M]) {
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    <[$t; N] as ArgminMulAssign<[$t; N]>>::mul_assign(a, b);
+                }
+            }
+        }
+    };
+}
+
+make_mulassign!(isize);
+make_mulassign!(usize);
+make_mulassign!(i8);
+make_mulassign!(i16);
+make_mulassign!(i32);
+make_mulassign!(i64);
+make_mulassign!(u8);
+make_mulassign!(u16);
+make_mulassign!(u32);
+make_mulassign!(u64);
+make_mulassign!(f32);
+make_mulassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_assign_vec_scalar_ $t>]() {
+                    let mut a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    <[$t; 3] as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = [2 as $t, 8 as $t, 16 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_assign_vec_vec_ $t>]() {
+                    let mut a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = [5 as $t, 6 as $t, 7 as $t];
+                    <[$t; 3] as ArgminMulAssign<[$t; 3]>>::mul_assign(&mut a, &b);
+                    let target = [5 as $t, 24 as $t, 56 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_assign_mat_scalar_ $t>]() {
+                    let mut a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 1 as $t],
+                    ];
+                    let b = 2 as $t;
+                    <[[$t; 3]; 2] as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = [
+                        [2 as $t, 8 as $t, 16 as $t],
+                        [8 as $t, 16 as $t, 2 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_assign_mat_mat_ $t>]() {
+                    let mut a = [
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [4 as $t, 8 as $t, 1 as $t],
+                    ];
+                    let b = [
+                        [5 as $t, 6 as $t, 7 as $t],
+                        [6 as $t, 7 as $t, 5 as $t],
+                    ];
+                    <[[$t; 3]; 2] as ArgminMulAssign<[[$t; 3]; 2]>>::mul_assign(&mut a, &b);
+                    let target = [
+                        [5 as $t, 24 as $t, 56 as $t],
+                        [24 as $t, 56 as $t, 5 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
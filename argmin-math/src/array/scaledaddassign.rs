@@ -0,0 +1,111 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledAddAssign;
+
+macro_rules! make_scaledaddassign {
+    ($t:ty) => {
+        impl<const N: usize> ArgminScaledAddAssign<[$t; N], $t> for [$t; N] {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &[$t; N]) {
+                for (a, b) in self.iter_mut().zip(vec.iter()) {
+                    *a += *factor * *b;
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminScaledAddAssign<[[$t; N]; M], $t>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &[[$t; N]; M]) {
+                for (a, b) in self.iter_mut().zip(vec.iter()) {
+                    <[$t; N] as ArgminScaledAddAssign<[$t; N], $t>>::scaled_add_assign(
+                        a, factor, b,
+                    );
+                }
+            }
+        }
+    };
+}
+
+make_scaledaddassign!(isize);
+make_scaledaddassign!(usize);
+make_scaledaddassign!(i8);
+make_scaledaddassign!(i16);
+make_scaledaddassign!(i32);
+make_scaledaddassign!(i64);
+make_scaledaddassign!(u8);
+make_scaledaddassign!(u16);
+make_scaledaddassign!(u32);
+make_scaledaddassign!(u64);
+make_scaledaddassign!(f32);
+make_scaledaddassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaled_add_assign_vec_ $t>]() {
+                    let mut a = [41 as $t, 38 as $t, 34 as $t];
+                    let b = 2 as $t;
+                    let c = [5 as $t, 6 as $t, 7 as $t];
+                    <[$t; 3] as ArgminScaledAddAssign<[$t; 3], $t>>::scaled_add_assign(&mut a, &b, &c);
+                    let target = [51 as $t, 50 as $t, 48 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaled_add_assign_mat_ $t>]() {
+                    let mut a = [
+                        [41 as $t, 38 as $t, 34 as $t],
+                        [38 as $t, 34 as $t, 41 as $t],
+                    ];
+                    let b = 2 as $t;
+                    let c = [
+                        [5 as $t, 6 as $t, 7 as $t],
+                        [6 as $t, 7 as $t, 5 as $t],
+                    ];
+                    <[[$t; 3]; 2] as ArgminScaledAddAssign<[[$t; 3]; 2], $t>>::scaled_add_assign(&mut a, &b, &c);
+                    let target = [
+                        [51 as $t, 50 as $t, 48 as $t],
+                        [50 as $t, 48 as $t, 51 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
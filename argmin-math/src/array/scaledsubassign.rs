@@ -0,0 +1,111 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledSubAssign;
+
+macro_rules! make_scaledsubassign {
+    ($t:ty) => {
+        impl<const N: usize> ArgminScaledSubAssign<[$t; N], $t> for [$t; N] {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &[$t; N]) {
+                for (a, b) in self.iter_mut().zip(vec.iter()) {
+                    *a -= *factor * *b;
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminScaledSubAssign<[[$t; N]; M], $t>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &[[$t; N]; M]) {
+                for (a, b) in self.iter_mut().zip(vec.iter()) {
+                    <[$t; N] as ArgminScaledSubAssign<[$t; N], $t>>::scaled_sub_assign(
+                        a, factor, b,
+                    );
+                }
+            }
+        }
+    };
+}
+
+make_scaledsubassign!(isize);
+make_scaledsubassign!(usize);
+make_scaledsubassign!(i8);
+make_scaledsubassign!(i16);
+make_scaledsubassign!(i32);
+make_scaledsubassign!(i64);
+make_scaledsubassign!(u8);
+make_scaledsubassign!(u16);
+make_scaledsubassign!(u32);
+make_scaledsubassign!(u64);
+make_scaledsubassign!(f32);
+make_scaledsubassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaled_sub_assign_vec_ $t>]() {
+                    let mut a = [41 as $t, 38 as $t, 34 as $t];
+                    let b = 2 as $t;
+                    let c = [5 as $t, 6 as $t, 7 as $t];
+                    <[$t; 3] as ArgminScaledSubAssign<[$t; 3], $t>>::scaled_sub_assign(&mut a, &b, &c);
+                    let target = [31 as $t, 26 as $t, 20 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaled_sub_assign_mat_ $t>]() {
+                    let mut a = [
+                        [41 as $t, 38 as $t, 34 as $t],
+                        [38 as $t, 34 as $t, 41 as $t],
+                    ];
+                    let b = 2 as $t;
+                    let c = [
+                        [5 as $t, 6 as $t, 7 as $t],
+                        [6 as $t, 7 as $t, 5 as $t],
+                    ];
+                    <[[$t; 3]; 2] as ArgminScaledSubAssign<[[$t; 3]; 2], $t>>::scaled_sub_assign(&mut a, &b, &c);
+                    let target = [
+                        [31 as $t, 26 as $t, 20 as $t],
+                        [26 as $t, 20 as $t, 31 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
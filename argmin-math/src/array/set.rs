@@ -0,0 +1,79 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSet;
+
+impl<T, const N: usize> ArgminSet<usize, T> for [T; N] {
+    #[inline]
+    fn set_elem(&mut self, idx: usize, value: T) {
+        self[idx] = value;
+    }
+}
+
+impl<T, const N: usize, const M: usize> ArgminSet<(usize, usize), T> for [[T; N]; M] {
+    #[inline]
+    fn set_elem(&mut self, (i, j): (usize, usize), value: T) {
+        self[i][j] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_set_vec_ $t>]() {
+                    let mut a = [1 as $t, 2 as $t, 3 as $t];
+                    a.set_elem(1, 9 as $t);
+                    let target = [1 as $t, 9 as $t, 3 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_set_mat_ $t>]() {
+                    let mut a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    a.set_elem((1, 2), 9 as $t);
+                    let target = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 9 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
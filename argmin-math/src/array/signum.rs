@@ -0,0 +1,103 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSignum;
+use num_complex::Complex;
+
+macro_rules! make_signum {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSignum for [$t; N] {
+            #[inline]
+            fn signum(mut self) -> Self {
+                for x in &mut self {
+                    *x = x.signum();
+                }
+                self
+            }
+        }
+    };
+}
+
+macro_rules! make_signum_complex {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSignum for [$t; N] {
+            #[inline]
+            fn signum(mut self) -> Self {
+                for x in &mut self {
+                    x.re = x.re.signum();
+                    x.im = x.im.signum();
+                }
+                self
+            }
+        }
+    };
+}
+
+make_signum!(isize);
+make_signum!(i8);
+make_signum!(i16);
+make_signum!(i32);
+make_signum!(i64);
+make_signum!(f32);
+make_signum!(f64);
+make_signum_complex!(Complex<isize>);
+make_signum_complex!(Complex<i8>);
+make_signum_complex!(Complex<i16>);
+make_signum_complex!(Complex<i32>);
+make_signum_complex!(Complex<i64>);
+make_signum_complex!(Complex<f32>);
+make_signum_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_signum_ $t>]() {
+                    let a = [3 as $t, -4 as $t, -8 as $t];
+                    let b = [1 as $t, -1 as $t, -1 as $t];
+                    let res = <[$t; 3] as ArgminSignum>::signum(a);
+                    for i in 0..3 {
+                        assert_relative_eq!(b[i] as f64, res[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_signum_complex_ $t>]() {
+                    let a = [
+                        Complex::new(3 as $t, -4 as $t),
+                        Complex::new(-8 as $t, 2 as $t),
+                    ];
+                    let b = [
+                        Complex::new(1 as $t, -1 as $t),
+                        Complex::new(-1 as $t, 1 as $t),
+                    ];
+                    let res = <[Complex<$t>; 2] as ArgminSignum>::signum(a);
+                    for i in 0..2 {
+                        assert_relative_eq!(b[i].re as f64, res[i].re as f64, epsilon = f64::EPSILON);
+                        assert_relative_eq!(b[i].im as f64, res[i].im as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
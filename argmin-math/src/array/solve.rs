@@ -0,0 +1,104 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::lufactor::{lu_factorize_in_place, lu_is_singular, lu_solve_into};
+use crate::{ArgminSolve, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveError;
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSolve<[$t; N], [$t; N]> for [[$t; N]; N] {
+            fn solve(&self, b: &[$t; N]) -> Result<[$t; N], Error> {
+                let mut lu = *self;
+                let mut perm = [0; N];
+                lu_factorize_in_place(&mut lu, &mut perm);
+                if lu_is_singular(self, &lu) {
+                    return Err(SolveError {}.into());
+                }
+                let mut x = [0.0; N];
+                lu_solve_into(&lu, &perm, b, &mut x);
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 10 as $t],
+                    ];
+                    let b = [6 as $t, 15 as $t, 25 as $t];
+                    let x = <[[$t; 3]; 3] as ArgminSolve<[$t; 3], [$t; 3]>>::solve(&a, &b).unwrap();
+                    for i in 0..3 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let a = [
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t],
+                    ];
+                    let b = [1 as $t, 2 as $t];
+                    let err = <[[$t; 2]; 2] as ArgminSolve<[$t; 2], [$t; 2]>>::solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_nearly_singular_ $t>]() {
+                    // Singular in exact arithmetic, but the rounded pivot is not exactly zero
+                    let a = [
+                        [0.1 as $t, 0.2 as $t, 0.3 as $t],
+                        [0.4 as $t, 0.5 as $t, 0.6 as $t],
+                        [0.7 as $t, 0.8 as $t, 0.9 as $t],
+                    ];
+                    let b = [1 as $t, 2 as $t, 3 as $t];
+                    let err = <[[$t; 3]; 3] as ArgminSolve<[$t; 3], [$t; 3]>>::solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
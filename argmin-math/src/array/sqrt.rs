@@ -0,0 +1,117 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSqrt;
+use num_complex::Complex;
+
+macro_rules! make_sqrt {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSqrt for [$t; N] {
+            #[inline]
+            fn sqrt(&self) -> [$t; N] {
+                std::array::from_fn(|i| <$t>::sqrt(self[i]))
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminSqrt for [[$t; N]; M] {
+            #[inline]
+            fn sqrt(&self) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminSqrt>::sqrt(&self[i]))
+            }
+        }
+    };
+}
+
+macro_rules! make_sqrt_complex {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSqrt for [$t; N] {
+            #[inline]
+            fn sqrt(&self) -> [$t; N] {
+                std::array::from_fn(|i| self[i].sqrt())
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminSqrt for [[$t; N]; M] {
+            #[inline]
+            fn sqrt(&self) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminSqrt>::sqrt(&self[i]))
+            }
+        }
+    };
+}
+
+make_sqrt!(f32);
+make_sqrt!(f64);
+make_sqrt_complex!(Complex<f32>);
+make_sqrt_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sqrt_vec_ $t>]() {
+                    let a = [4 as $t, 9 as $t, 16 as $t];
+                    let target = [2 as $t, 3 as $t, 4 as $t];
+                    let res = <[$t; 3] as ArgminSqrt>::sqrt(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sqrt_mat_ $t>]() {
+                    let a = [
+                        [4 as $t, 9 as $t, 16 as $t],
+                        [1 as $t, 25 as $t, 36 as $t],
+                    ];
+                    let target = [
+                        [2 as $t, 3 as $t, 4 as $t],
+                        [1 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let res = <[[$t; 3]; 2] as ArgminSqrt>::sqrt(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sqrt_complex_vec_ $t>]() {
+                    let a = [Complex::new(3 as $t, 4 as $t), Complex::new(-4 as $t, 0 as $t)];
+                    let target = [Complex::new(2 as $t, 1 as $t), Complex::new(0 as $t, 2 as $t)];
+                    let res = <[Complex<$t>; 2] as ArgminSqrt>::sqrt(&a);
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i].re as f64, target[i].re as f64, epsilon = 1e-6);
+                        assert_relative_eq!(res[i].im as f64, target[i].im as f64, epsilon = 1e-6);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
@@ -0,0 +1,171 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+use num_complex::Complex;
+
+macro_rules! make_sub {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSub<$t, [$t; N]> for [$t; N] {
+            #[inline]
+            fn sub(&self, other: &$t) -> [$t; N] {
+                std::array::from_fn(|i| self[i] - other)
+            }
+        }
+
+        impl<const N: usize> ArgminSub<[$t; N], [$t; N]> for $t {
+            #[inline]
+            fn sub(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self - other[i])
+            }
+        }
+
+        impl<const N: usize> ArgminSub<[$t; N], [$t; N]> for [$t; N] {
+            #[inline]
+            fn sub(&self, other: &[$t; N]) -> [$t; N] {
+                std::array::from_fn(|i| self[i] - other[i])
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminSub<[[$t; N]; M], [[$t; N]; M]>
+            for [[$t; N]; M]
+        {
+            #[inline]
+            fn sub(&self, other: &[[$t; N]; M]) -> [[$t; N]; M] {
+                std::array::from_fn(|i| {
+                    <[$t; N] as ArgminSub<[$t; N], [$t; N]>>::sub(&self[i], &other[i])
+                })
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminSub<$t, [[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn sub(&self, other: &$t) -> [[$t; N]; M] {
+                std::array::from_fn(|i| <[$t; N] as ArgminSub<$t, [$t; N]>>::sub(&self[i], other))
+            }
+        }
+    };
+}
+
+make_sub!(isize);
+make_sub!(usize);
+make_sub!(i8);
+make_sub!(i16);
+make_sub!(i32);
+make_sub!(i64);
+make_sub!(u8);
+make_sub!(u16);
+make_sub!(u32);
+make_sub!(u64);
+make_sub!(f32);
+make_sub!(f64);
+make_sub!(Complex<isize>);
+make_sub!(Complex<usize>);
+make_sub!(Complex<i8>);
+make_sub!(Complex<i16>);
+make_sub!(Complex<i32>);
+make_sub!(Complex<i64>);
+make_sub!(Complex<u8>);
+make_sub!(Complex<u16>);
+make_sub!(Complex<u32>);
+make_sub!(Complex<u64>);
+make_sub!(Complex<f32>);
+make_sub!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sub_vec_scalar_ $t>]() {
+                    let a = [41 as $t, 38 as $t, 34 as $t];
+                    let b = 1 as $t;
+                    let target = [40 as $t, 37 as $t, 33 as $t];
+                    let res = <[$t; 3] as ArgminSub<$t, [$t; 3]>>::sub(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_scalar_vec_ $t>]() {
+                    let a = [1 as $t, 4 as $t, 8 as $t];
+                    let b = 50 as $t;
+                    let target = [49 as $t, 46 as $t, 42 as $t];
+                    let res = <$t as ArgminSub<[$t; 3], [$t; 3]>>::sub(&b, &a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_vec_vec_ $t>]() {
+                    let a = [41 as $t, 38 as $t, 34 as $t];
+                    let b = [1 as $t, 4 as $t, 8 as $t];
+                    let target = [40 as $t, 34 as $t, 26 as $t];
+                    let res = <[$t; 3] as ArgminSub<[$t; 3], [$t; 3]>>::sub(&a, &b);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_mat_mat_ $t>]() {
+                    let a = [[41 as $t, 38 as $t], [38 as $t, 34 as $t]];
+                    let b = [[1 as $t, 4 as $t], [4 as $t, 8 as $t]];
+                    let target = [[40 as $t, 34 as $t], [34 as $t, 26 as $t]];
+                    let res = <[[$t; 2]; 2] as ArgminSub<[[$t; 2]; 2], [[$t; 2]; 2]>>::sub(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_mat_scalar_ $t>]() {
+                    let a = [[41 as $t, 38 as $t, 34 as $t], [38 as $t, 34 as $t, 41 as $t]];
+                    let b = 1 as $t;
+                    let target = [[40 as $t, 37 as $t, 33 as $t], [37 as $t, 33 as $t, 40 as $t]];
+                    let res = <[[$t; 3]; 2] as ArgminSub<$t, [[$t; 3]; 2]>>::sub(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
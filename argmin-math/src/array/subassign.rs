@@ -0,0 +1,157 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSubAssign;
+
+macro_rules! make_subassign {
+    ($t:ty) => {
+        impl<const N: usize> ArgminSubAssign<$t> for [$t; N] {
+            #[inline]
+            fn sub_assign(&mut self, other: &$t) {
+                for a in self.iter_mut() {
+                    *a -= *other;
+                }
+            }
+        }
+
+        impl<const N: usize> ArgminSubAssign<[$t; N]> for [$t; N] {
+            #[inline]
+            fn sub_assign(&mut self, other: &[$t; N]) {
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    *a -= *b;
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminSubAssign<$t> for [[$t; N]; M] {
+            #[inline]
+            fn sub_assign(&mut self, other: &$t) {
+                for row in self.iter_mut() {
+                    <[$t; N] as ArgminSubAssign<$t>>::sub_assign(row, other);
+                }
+            }
+        }
+
+        impl<const N: usize, const M: usize> ArgminSubAssign<[[$t; N]; M]> for [[$t; N]; M] {
+            #[inline]
+            fn sub_assign(&mut self, other: &[[$t; N]; M]) {
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    <[$t; N] as ArgminSubAssign<[$t; N]>>::sub_assign(a, b);
+                }
+            }
+        }
+    };
+}
+
+make_subassign!(isize);
+make_subassign!(usize);
+make_subassign!(i8);
+make_subassign!(i16);
+make_subassign!(i32);
+make_subassign!(i64);
+make_subassign!(u8);
+make_subassign!(u16);
+make_subassign!(u32);
+make_subassign!(u64);
+make_subassign!(f32);
+make_subassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sub_assign_vec_scalar_ $t>]() {
+                    let mut a = [41 as $t, 38 as $t, 34 as $t];
+                    let b = 1 as $t;
+                    <[$t; 3] as ArgminSubAssign<$t>>::sub_assign(&mut a, &b);
+                    let target = [40 as $t, 37 as $t, 33 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_assign_vec_vec_ $t>]() {
+                    let mut a = [41 as $t, 38 as $t, 34 as $t];
+                    let b = [5 as $t, 6 as $t, 7 as $t];
+                    <[$t; 3] as ArgminSubAssign<[$t; 3]>>::sub_assign(&mut a, &b);
+                    let target = [36 as $t, 32 as $t, 27 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(a[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_assign_mat_scalar_ $t>]() {
+                    let mut a = [
+                        [41 as $t, 38 as $t, 34 as $t],
+                        [38 as $t, 34 as $t, 41 as $t],
+                    ];
+                    let b = 1 as $t;
+                    <[[$t; 3]; 2] as ArgminSubAssign<$t>>::sub_assign(&mut a, &b);
+                    let target = [
+                        [40 as $t, 37 as $t, 33 as $t],
+                        [37 as $t, 33 as $t, 40 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_assign_mat_mat_ $t>]() {
+                    let mut a = [
+                        [41 as $t, 38 as $t, 34 as $t],
+                        [38 as $t, 34 as $t, 41 as $t],
+                    ];
+                    let b = [
+                        [5 as $t, 6 as $t, 7 as $t],
+                        [6 as $t, 7 as $t, 5 as $t],
+                    ];
+                    <[[$t; 3]; 2] as ArgminSubAssign<[[$t; 3]; 2]>>::sub_assign(&mut a, &b);
+                    let target = [
+                        [36 as $t, 32 as $t, 27 as $t],
+                        [32 as $t, 27 as $t, 36 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(a[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
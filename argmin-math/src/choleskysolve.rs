@@ -0,0 +1,88 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Forward and backward substitution with a Cholesky factor shared by the dense backends which
+//! implement [`ArgminCholeskySolve`](crate::ArgminCholeskySolve) in pure Rust.
+
+use num_traits::Float;
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub(crate) struct CholeskySolveError;
+
+impl fmt::Display for CholeskySolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+#[cfg(any(feature = "vec", feature = "ndarray_all", feature = "faer_all"))]
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub(crate) struct CholeskySolveDimensionError;
+
+#[cfg(any(feature = "vec", feature = "ndarray_all", feature = "faer_all"))]
+impl fmt::Display for CholeskySolveDimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Matrix must be square with as many rows as the right-hand side has elements"
+        )
+    }
+}
+
+/// Overwrites `x` with the solution of `L L^T x = b`, where `x` initially holds `b` and `L` is the
+/// lower triangular `n x n` matrix with elements `l(i, j)` (only `j <= i` is accessed).
+///
+/// Returns `false` and leaves `x` untouched if a diagonal element of `L` is zero.
+pub(crate) fn cholesky_substitute<F: Float>(
+    n: usize,
+    l: impl Fn(usize, usize) -> F,
+    x: &mut [F],
+) -> bool {
+    if (0..n).any(|i| l(i, i) == F::zero()) {
+        return false;
+    }
+    // L y = b
+    for i in 0..n {
+        let s = (0..i).fold(F::zero(), |acc, k| acc + l(i, k) * x[k]);
+        x[i] = (x[i] - s) / l(i, i);
+    }
+    // L^T x = y
+    for i in (0..n).rev() {
+        let s = ((i + 1)..n).fold(F::zero(), |acc, k| acc + l(k, i) * x[k]);
+        x[i] = (x[i] - s) / l(i, i);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cholesky_substitute() {
+        // Cholesky factor of [[4, 12, -16], [12, 37, -43], [-16, -43, 98]]; the upper triangle
+        // must not be accessed
+        let l = [[2.0, 100.0, 100.0], [6.0, 1.0, 100.0], [-8.0, 5.0, 3.0]];
+        let mut x = [0.0, 6.0, 39.0];
+        assert!(cholesky_substitute(3, |i, j| l[i][j], &mut x));
+        for xi in x {
+            assert_relative_eq!(xi, 1.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cholesky_substitute_singular() {
+        let l = [[1.0, 0.0], [2.0, 0.0]];
+        let mut x = [1.0, 1.0];
+        assert!(!cholesky_substitute(2, |i, j| l[i][j], &mut x));
+        for xi in x {
+            assert_relative_eq!(xi, 1.0);
+        }
+    }
+}
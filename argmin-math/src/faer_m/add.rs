@@ -0,0 +1,223 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAdd;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_add {
+    ($t:ty) => {
+        impl ArgminAdd<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn add(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] + *other)
+            }
+        }
+
+        impl ArgminAdd<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn add(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self + other[i])
+            }
+        }
+
+        impl ArgminAdd<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn add(&self, other: &Col<$t>) -> Col<$t> {
+                self + other
+            }
+        }
+
+        impl ArgminAdd<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn add(&self, other: &Mat<$t>) -> Mat<$t> {
+                self + other
+            }
+        }
+
+        impl ArgminAdd<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn add(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] + *other)
+            }
+        }
+    };
+}
+
+make_add!(f32);
+make_add!(f64);
+make_add!(c32);
+make_add!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_add_vec_scalar_ $t>]() {
+                    let a = col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 34 as $t;
+                    let target = col![35 as $t, 38 as $t, 42 as $t];
+                    let res = <Col<$t> as ArgminAdd<$t, Col<$t>>>::add(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_scalar_vec_ $t>]() {
+                    let a = col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 34 as $t;
+                    let target = col![35 as $t, 38 as $t, 42 as $t];
+                    let res = <$t as ArgminAdd<Col<$t>, Col<$t>>>::add(&b, &a);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_vec_vec_ $t>]() {
+                    let a = col![1 as $t, 4 as $t, 8 as $t];
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    let target = col![42 as $t, 42 as $t, 42 as $t];
+                    let res = <Col<$t> as ArgminAdd<Col<$t>, Col<$t>>>::add(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_vec_vec_panic_ $t>]() {
+                    let a = col![1 as $t, 4 as $t];
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminAdd<Col<$t>, Col<$t>>>::add(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_vec_vec_panic_2_ $t>]() {
+                    let a = Col::<$t>::zeros(0);
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminAdd<Col<$t>, Col<$t>>>::add(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_vec_vec_panic_3_ $t>]() {
+                    let a = col![41 as $t, 38 as $t, 34 as $t];
+                    let b = Col::<$t>::zeros(0);
+                    <Col<$t> as ArgminAdd<Col<$t>, Col<$t>>>::add(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_mat_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = mat![
+                        [41 as $t, 38 as $t, 34 as $t],
+                        [40 as $t, 37 as $t, 33 as $t]
+                    ];
+                    let target = mat![
+                        [42 as $t, 42 as $t, 42 as $t],
+                        [42 as $t, 42 as $t, 42 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminAdd<Mat<$t>, Mat<$t>>>::add(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((target[(j, i)] - res[(j, i)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_add_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let target = mat![
+                        [3 as $t, 6 as $t, 10 as $t],
+                        [4 as $t, 7 as $t, 11 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminAdd<$t, Mat<$t>>>::add(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((target[(j, i)] - res[(j, i)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_mat_mat_panic_2_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = mat![
+                        [41 as $t, 38 as $t],
+                    ];
+                    <Mat<$t> as ArgminAdd<Mat<$t>, Mat<$t>>>::add(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_mat_mat_panic_3_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = Mat::<$t>::zeros(0, 0);
+                    <Mat<$t> as ArgminAdd<Mat<$t>, Mat<$t>>>::add(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_add_complex() {
+        let a = col![c64::new(1.0, 2.0), c64::new(3.0, -1.0)];
+        let b = col![c64::new(2.0, -2.0), c64::new(-3.0, 4.0)];
+        let res = <Col<c64> as ArgminAdd<Col<c64>, Col<c64>>>::add(&a, &b);
+        assert!((res[0] - c64::new(3.0, 0.0)).norm() < std::f64::EPSILON);
+        assert!((res[1] - c64::new(0.0, 3.0)).norm() < std::f64::EPSILON);
+        let res = <Mat<c32> as ArgminAdd<c32, Mat<c32>>>::add(
+            &mat![[c32::new(1.0, 2.0)]],
+            &c32::new(1.0, -2.0),
+        );
+        assert!((res[(0, 0)] - c32::new(2.0, 0.0)).norm() < std::f32::EPSILON);
+    }
+}
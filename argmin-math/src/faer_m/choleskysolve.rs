@@ -0,0 +1,101 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::choleskysolve::{cholesky_substitute, CholeskySolveDimensionError, CholeskySolveError};
+use crate::{ArgminCholeskySolve, Error};
+use faer::{Col, Mat};
+
+macro_rules! make_cholesky_solve {
+    ($t:ty) => {
+        impl ArgminCholeskySolve<Col<$t>, Col<$t>> for Mat<$t> {
+            fn cholesky_solve(&self, b: &Col<$t>) -> Result<Col<$t>, Error> {
+                let n = self.nrows();
+                if self.ncols() != n || b.nrows() != n {
+                    return Err(CholeskySolveDimensionError {}.into());
+                }
+                let mut x: Vec<$t> = b.iter().copied().collect();
+                if !cholesky_substitute(n, |i, j| self[(i, j)], &mut x) {
+                    return Err(CholeskySolveError {}.into());
+                }
+                Ok(Col::from_fn(n, |i| x[i]))
+            }
+        }
+    };
+}
+
+make_cholesky_solve!(f32);
+make_cholesky_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    // Cholesky factor of [[4, 12, -16], [12, 37, -43], [-16, -43, 98]]; the upper
+                    // triangle must not be accessed
+                    let l = mat![
+                        [2 as $t, 100 as $t, 100 as $t],
+                        [6 as $t, 1 as $t, 100 as $t],
+                        [-8 as $t, 5 as $t, 3 as $t]
+                    ];
+                    let b = col![0 as $t, 6 as $t, 39 as $t];
+                    let x = <Mat<$t> as ArgminCholeskySolve<Col<$t>, Col<$t>>>::cholesky_solve(
+                        &l, &b
+                    ).unwrap();
+                    for i in 0..3 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_singular_ $t>]() {
+                    let l = mat![
+                        [1 as $t, 0 as $t],
+                        [2 as $t, 0 as $t]
+                    ];
+                    let b = col![1 as $t, 1 as $t];
+                    let err = <Mat<$t> as ArgminCholeskySolve<Col<$t>, Col<$t>>>::cholesky_solve(
+                        &l, &b
+                    )
+                    .unwrap_err()
+                    .downcast::<CholeskySolveError>()
+                    .unwrap();
+                    assert_eq!(err, CholeskySolveError {});
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_dimension_ $t>]() {
+                    let l = mat![
+                        [1 as $t, 0 as $t],
+                        [2 as $t, 1 as $t]
+                    ];
+                    let b = col![1 as $t, 1 as $t, 1 as $t];
+                    let err = <Mat<$t> as ArgminCholeskySolve<Col<$t>, Col<$t>>>::cholesky_solve(
+                        &l, &b
+                    )
+                    .unwrap_err()
+                    .downcast::<CholeskySolveDimensionError>()
+                    .unwrap();
+                    assert_eq!(err, CholeskySolveDimensionError {});
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
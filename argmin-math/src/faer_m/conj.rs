@@ -0,0 +1,142 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminConj;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_conj {
+    ($t:ty) => {
+        impl ArgminConj for Col<$t> {
+            #[inline]
+            fn conj(&self) -> Col<$t> {
+                self.clone()
+            }
+        }
+
+        impl ArgminConj for Mat<$t> {
+            #[inline]
+            fn conj(&self) -> Mat<$t> {
+                self.clone()
+            }
+        }
+    };
+}
+
+macro_rules! make_conj_complex {
+    ($t:ty) => {
+        impl ArgminConj for Col<$t> {
+            #[inline]
+            fn conj(&self) -> Col<$t> {
+                self.conjugate().to_owned()
+            }
+        }
+
+        impl ArgminConj for Mat<$t> {
+            #[inline]
+            fn conj(&self) -> Mat<$t> {
+                self.conjugate().to_owned()
+            }
+        }
+    };
+}
+
+make_conj!(f32);
+make_conj!(f64);
+make_conj_complex!(c32);
+make_conj_complex!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_conj_complex_faer_ $t>]() {
+                    let a = col![
+                        $t::new(1.0, 2.0),
+                        $t::new(4.0, -3.0),
+                        $t::new(8.0, 0.0)
+                    ];
+                    let b = col![
+                        $t::new(1.0, -2.0),
+                        $t::new(4.0, 3.0),
+                        $t::new(8.0, 0.0)
+                    ];
+                    let res = <Col<$t> as ArgminConj>::conj(&a);
+                    for i in 0..3 {
+                        let tmp = b[i] - res[i];
+                        let norm = ((tmp.re * tmp.re + tmp.im * tmp.im) as f64).sqrt();
+                        assert!(norm  < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_conj_complex_faer_mat_ $t>]() {
+                    let a = mat![
+                        [$t::new(1.0, 2.0), $t::new(4.0, -3.0)],
+                        [$t::new(8.0, 0.0), $t::new(0.0, -1.0)]
+                    ];
+                    let b = mat![
+                        [$t::new(1.0, -2.0), $t::new(4.0, 3.0)],
+                        [$t::new(8.0, 0.0), $t::new(0.0, 1.0)]
+                    ];
+                    let res = <Mat<$t> as ArgminConj>::conj(&a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let tmp = b[(i, j)] - res[(i, j)];
+                            let norm = ((tmp.re * tmp.re + tmp.im * tmp.im) as f64).sqrt();
+                            assert!(norm  < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_real {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_conj_faer_ $t>]() {
+                    let a = col![1 as $t, -4 as $t, 8 as $t];
+                    let res = <Col<$t> as ArgminConj>::conj(&a);
+                    for i in 0..3 {
+                        assert!(((a[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_conj_faer_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, -4 as $t],
+                        [8 as $t, 2 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminConj>::conj(&a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((a[(i, j)] - res[(i, j)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(c32);
+    make_test!(c64);
+
+    make_test_real!(f32);
+    make_test_real!(f64);
+}
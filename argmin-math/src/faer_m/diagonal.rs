@@ -0,0 +1,60 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiagonal;
+use faer::{Col, Mat};
+
+impl<T: Clone> ArgminDiagonal<Col<T>> for Mat<T> {
+    #[inline]
+    fn diagonal(&self) -> Col<T> {
+        assert_eq!(self.nrows(), self.ncols());
+        Col::from_fn(self.nrows(), |i| self[(i, i)].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diagonal_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 9 as $t]
+                    ];
+                    let d = <Mat<$t> as ArgminDiagonal<Col<$t>>>::diagonal(&a);
+                    let target = [1.0, 5.0, 9.0];
+                    assert_eq!(d.nrows(), 3);
+                    for i in 0..3 {
+                        assert!(((d[i] as f64) - target[i]).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_diagonal_non_square_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    <Mat<$t> as ArgminDiagonal<Col<$t>>>::diagonal(&a);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
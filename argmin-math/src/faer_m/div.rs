@@ -0,0 +1,199 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_div {
+    ($t:ty) => {
+        impl ArgminDiv<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn div(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] / *other)
+            }
+        }
+
+        impl ArgminDiv<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn div(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self / other[i])
+            }
+        }
+
+        impl ArgminDiv<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn div(&self, other: &Col<$t>) -> Col<$t> {
+                assert_eq!(self.nrows(), other.nrows());
+                Col::from_fn(self.nrows(), |i| self[i] / other[i])
+            }
+        }
+
+        impl ArgminDiv<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn div(&self, other: &Mat<$t>) -> Mat<$t> {
+                assert_eq!(self.shape(), other.shape());
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| {
+                    self[(i, j)] / other[(i, j)]
+                })
+            }
+        }
+    };
+}
+
+make_div!(f32);
+make_div!(f64);
+make_div!(c32);
+make_div!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_div_vec_scalar_ $t>]() {
+                    let a = col![4 as $t, 16 as $t, 8 as $t];
+                    let b = 2 as $t;
+                    let target = col![2 as $t, 8 as $t, 4 as $t];
+                    let res = <Col<$t> as ArgminDiv<$t, Col<$t>>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_scalar_vec_ $t>]() {
+                    let a = col![2 as $t, 4 as $t, 8 as $t];
+                    let b = 32 as $t;
+                    let target = col![16 as $t, 8 as $t, 4 as $t];
+                    let res = <$t as ArgminDiv<Col<$t>, Col<$t>>>::div(&b, &a);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_vec_vec_ $t>]() {
+                    let a = col![4 as $t, 9 as $t, 8 as $t];
+                    let b = col![2 as $t, 3 as $t, 4 as $t];
+                    let target = col![2 as $t, 3 as $t, 2 as $t];
+                    let res = <Col<$t> as ArgminDiv<Col<$t>, Col<$t>>>::div(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_vec_vec_panic_ $t>]() {
+                    let a = col![1 as $t, 4 as $t];
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminDiv<Col<$t>, Col<$t>>>::div(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_vec_vec_panic_2_ $t>]() {
+                    let a = Col::<$t>::zeros(0);
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminDiv<Col<$t>, Col<$t>>>::div(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_vec_vec_panic_3_ $t>]() {
+                    let a = col![41 as $t, 38 as $t, 34 as $t];
+                    let b = Col::<$t>::zeros(0);
+                    <Col<$t> as ArgminDiv<Col<$t>, Col<$t>>>::div(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_div_mat_mat_ $t>]() {
+                    let a = mat![
+                        [4 as $t, 12 as $t, 8 as $t],
+                        [9 as $t, 20 as $t, 45 as $t]
+                    ];
+                    let b = mat![
+                        [2 as $t, 3 as $t, 4 as $t],
+                        [3 as $t, 4 as $t, 5 as $t]
+                    ];
+                    let target = mat![
+                        [2 as $t, 4 as $t, 2 as $t],
+                        [3 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminDiv<Mat<$t>, Mat<$t>>>::div(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((target[(j, i)] - res[(j, i)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_mat_mat_panic_2_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = mat![
+                        [41 as $t, 38 as $t],
+                    ];
+                    <Mat<$t> as ArgminDiv<Mat<$t>, Mat<$t>>>::div(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_mat_mat_panic_3_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = Mat::<$t>::zeros(0, 0);
+                    <Mat<$t> as ArgminDiv<Mat<$t>, Mat<$t>>>::div(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_div_complex() {
+        let a = col![c64::new(6.0, 2.0), c64::new(-5.0, 15.0)];
+        let b = col![c64::new(2.0, -2.0), c64::new(-3.0, 4.0)];
+        let res = <Col<c64> as ArgminDiv<Col<c64>, Col<c64>>>::div(&a, &b);
+        assert!((res[0] - c64::new(1.0, 2.0)).norm() < 1e-12);
+        assert!((res[1] - c64::new(3.0, -1.0)).norm() < 1e-12);
+        let res = <Col<c32> as ArgminDiv<c32, Col<c32>>>::div(
+            &col![c32::new(2.0, 4.0)],
+            &c32::new(2.0, 0.0),
+        );
+        assert!((res[0] - c32::new(1.0, 2.0)).norm() < std::f32::EPSILON);
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_dot_faer {
+    ($t:ty) => {
+        impl ArgminDot<Col<$t>, $t> for Col<$t> {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> $t {
+                assert_eq!(self.nrows(), other.nrows());
+                self.iter().zip(other.iter()).map(|(a, b)| *a * *b).sum()
+            }
+        }
+
+        impl ArgminDot<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn dot(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] * *other)
+            }
+        }
+
+        impl ArgminDot<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self * other[i])
+            }
+        }
+
+        impl ArgminDot<Col<$t>, Mat<$t>> for Col<$t> {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), other.nrows(), |i, j| self[i] * other[j])
+            }
+        }
+
+        impl ArgminDot<Col<$t>, Col<$t>> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &Col<$t>) -> Col<$t> {
+                self * other
+            }
+        }
+
+        impl ArgminDot<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &Mat<$t>) -> Mat<$t> {
+                self * other
+            }
+        }
+
+        impl ArgminDot<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] * *other)
+            }
+        }
+
+        impl ArgminDot<Mat<$t>, Mat<$t>> for $t {
+            #[inline]
+            fn dot(&self, other: &Mat<$t>) -> Mat<$t> {
+                Mat::from_fn(other.nrows(), other.ncols(), |i, j| *self * other[(i, j)])
+            }
+        }
+    };
+}
+
+make_dot_faer!(f32);
+make_dot_faer!(f64);
+make_dot_faer!(c32);
+make_dot_faer!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_vec_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    let res: $t = <Col<$t> as ArgminDot<Col<$t>, $t>>::dot(&a, &b);
+                    assert!((((res - 32 as $t) as f64).abs()) < std::f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_vec_vec_panic_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![4 as $t, 5 as $t];
+                    <Col<$t> as ArgminDot<Col<$t>, $t>>::dot(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_scalar_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product: Col<$t> = <Col<$t> as ArgminDot<$t, Col<$t>>>::dot(&a, &b);
+                    let res = col![2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert!((((res[i] - product[i]) as f64).abs()) < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scalar_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let product: Col<$t> = <$t as ArgminDot<Col<$t>, Col<$t>>>::dot(&b, &a);
+                    let res = col![2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert!((((res[i] - product[i]) as f64).abs()) < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![4 as $t, 5 as $t, 6 as $t];
+                    let res = mat![
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [8 as $t, 10 as $t, 12 as $t],
+                        [12 as $t, 15 as $t, 18 as $t]
+                    ];
+                    let product: Mat<$t> = <Col<$t> as ArgminDot<Col<$t>, Mat<$t>>>::dot(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[(i, j)] - product[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_2_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 9 as $t]
+                    ];
+                    let b = col![1 as $t, 2 as $t, 3 as $t];
+                    let res = col![14 as $t, 32 as $t, 50 as $t];
+                    let product: Col<$t> = <Mat<$t> as ArgminDot<Col<$t>, Col<$t>>>::dot(&a, &b);
+                    for i in 0..3 {
+                        assert!((((res[i] - product[i]) as f64).abs()) < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mat_vec_2_panic_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = col![1 as $t, 2 as $t];
+                    <Mat<$t> as ArgminDot<Col<$t>, Col<$t>>>::dot(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [3 as $t, 2 as $t, 1 as $t]
+                    ];
+                    let b = mat![
+                        [3 as $t, 2 as $t, 1 as $t],
+                        [6 as $t, 5 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 3 as $t]
+                    ];
+                    let res = mat![
+                        [21 as $t, 24 as $t, 18 as $t],
+                        [54 as $t, 57 as $t, 42 as $t],
+                        [23 as $t, 20 as $t, 14 as $t]
+                    ];
+                    let product: Mat<$t> = <Mat<$t> as ArgminDot<Mat<$t>, Mat<$t>>>::dot(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[(i, j)] - product[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_primitive_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [3 as $t, 2 as $t, 1 as $t]
+                    ];
+                    let res = mat![
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [8 as $t, 10 as $t, 12 as $t],
+                        [6 as $t, 4 as $t, 2 as $t]
+                    ];
+                    let product: Mat<$t> = <Mat<$t> as ArgminDot<$t, Mat<$t>>>::dot(&a, &(2 as $t));
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[(i, j)] - product[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_primitive_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [3 as $t, 2 as $t, 1 as $t]
+                    ];
+                    let res = mat![
+                        [2 as $t, 4 as $t, 6 as $t],
+                        [8 as $t, 10 as $t, 12 as $t],
+                        [6 as $t, 4 as $t, 2 as $t]
+                    ];
+                    let product: Mat<$t> = <$t as ArgminDot<Mat<$t>, Mat<$t>>>::dot(&(2 as $t), &a);
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[(i, j)] - product[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_vec_vec_complex() {
+        let a = col![c64::new(1.0, 2.0), c64::new(3.0, -1.0)];
+        let b = col![c64::new(2.0, -2.0), c64::new(-3.0, 4.0)];
+        let res: c64 = <Col<c64> as ArgminDot<Col<c64>, c64>>::dot(&a, &b);
+        // (1 + 2i)(2 - 2i) + (3 - i)(-3 + 4i) = (6 + 2i) + (-5 + 15i)
+        assert!((res - c64::new(1.0, 17.0)).norm() < std::f64::EPSILON);
+    }
+
+    #[test]
+    fn test_mat_vec_complex() {
+        let a = mat![
+            [c32::new(1.0, 1.0), c32::new(0.0, 0.0)],
+            [c32::new(0.0, 0.0), c32::new(2.0, 0.0)]
+        ];
+        let b = col![c32::new(1.0, -1.0), c32::new(0.0, 3.0)];
+        let res: Col<c32> = <Mat<c32> as ArgminDot<Col<c32>, Col<c32>>>::dot(&a, &b);
+        assert!((res[0] - c32::new(2.0, 0.0)).norm() < std::f32::EPSILON);
+        assert!((res[1] - c32::new(0.0, 6.0)).norm() < std::f32::EPSILON);
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminEigh, Error};
+use faer::{Col, Mat, Side};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct EighError;
+
+impl fmt::Display for EighError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Eigendecomposition failed to converge")
+    }
+}
+
+macro_rules! make_eigh {
+    ($t:ty) => {
+        impl ArgminEigh<Col<$t>, Mat<$t>> for Mat<$t> {
+            fn eigh(&self) -> Result<(Col<$t>, Mat<$t>), Error> {
+                assert_eq!(self.nrows(), self.ncols());
+                match self.self_adjoint_eigen(Side::Lower) {
+                    Ok(evd) => Ok((evd.S().column_vector().to_owned(), evd.U().to_owned())),
+                    Err(_) => Err(EighError {}.into()),
+                }
+            }
+        }
+    };
+}
+
+make_eigh!(f32);
+make_eigh!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eigh_ $t>]() {
+                    let a = mat![
+                        [2 as $t, 1 as $t],
+                        [1 as $t, 2 as $t]
+                    ];
+                    let (values, vectors) = <Mat<$t> as ArgminEigh<Col<$t>, Mat<$t>>>::eigh(&a).unwrap();
+                    assert!((((values[0] - 1 as $t) as f64).abs()) < 0.000001);
+                    assert!((((values[1] - 3 as $t) as f64).abs()) < 0.000001);
+                    for j in 0..2 {
+                        let v = vectors.col(j).to_owned();
+                        let av = &a * &v;
+                        for i in 0..2 {
+                            assert!((((av[i] - values[j] * v[i]) as f64).abs()) < 0.000001);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,97 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminEye;
+use faer::{c32, c64, Mat};
+
+macro_rules! make_eye {
+    ($t:ty) => {
+        impl ArgminEye for Mat<$t> {
+            #[inline]
+            fn eye_like(&self) -> Mat<$t> {
+                // TODO: Should return an error!
+                assert_eq!(self.nrows(), self.ncols());
+                Mat::identity(self.nrows(), self.ncols())
+            }
+
+            #[inline]
+            fn eye(n: usize) -> Mat<$t> {
+                Mat::identity(n, n)
+            }
+        }
+    };
+}
+
+make_eye!(f32);
+make_eye!(f64);
+make_eye!(c32);
+make_eye!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eye_ $t>]() {
+                    let e: Mat<$t> = <Mat<$t> as ArgminEye>::eye(3);
+                    let res = mat![
+                        [1 as $t, 0 as $t, 0 as $t],
+                        [0 as $t, 1 as $t, 0 as $t],
+                        [0 as $t, 0 as $t, 1 as $t]
+                    ];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[(i, j)] - e[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_eye_like_ $t>]() {
+                    let a = mat![
+                        [0 as $t, 2 as $t, 6 as $t],
+                        [3 as $t, 2 as $t, 7 as $t],
+                        [9 as $t, 8 as $t, 1 as $t]
+                    ];
+                    let e: Mat<$t> = a.eye_like();
+                    let res = mat![
+                        [1 as $t, 0 as $t, 0 as $t],
+                        [0 as $t, 1 as $t, 0 as $t],
+                        [0 as $t, 0 as $t, 1 as $t]
+                    ];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[(i, j)] - e[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_eye_like_panic_ $t>]() {
+                    let a = mat![
+                        [0 as $t, 2 as $t, 6 as $t],
+                        [3 as $t, 2 as $t, 7 as $t]
+                    ];
+                    let _: Mat<$t> = a.eye_like();
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,405 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{
+    ArgminAdd, ArgminAddAssign, ArgminCholeskySolve, ArgminConj, ArgminDiagonal, ArgminDiv,
+    ArgminDot, ArgminEigh, ArgminGet, ArgminL1Norm, ArgminL2Norm, ArgminLen, ArgminMinMax,
+    ArgminMul, ArgminMulAssign, ArgminRandom, ArgminScaledAddAssign, ArgminScaledSubAssign,
+    ArgminSet, ArgminSignum, ArgminSolve, ArgminSub, ArgminSubAssign, ArgminTranspose,
+    ArgminZeroLike, Error,
+};
+use faer::{Col, Mat};
+use rand::Rng;
+use std::ops::{Deref, DerefMut};
+
+/// Column vector which can be serialized
+///
+/// faer only implements `serde` for `Mat` and these implementations cannot be added to `Col`
+/// outside of faer. `FaerCol` is a thin wrapper around `Col` which is serialized as a sequence of
+/// its elements when the `faer_latest-serde` feature is enabled, which makes it usable as
+/// parameter vector and gradient of solvers which need to serialize their state. It derefs to the
+/// wrapped `Col` and implements the same `Argmin*` traits for `f32` and `f64`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FaerCol<T>(pub Col<T>);
+
+impl<T> Deref for FaerCol<T> {
+    type Target = Col<T>;
+
+    fn deref(&self) -> &Col<T> {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for FaerCol<T> {
+    fn deref_mut(&mut self) -> &mut Col<T> {
+        &mut self.0
+    }
+}
+
+impl<T> From<Col<T>> for FaerCol<T> {
+    fn from(col: Col<T>) -> Self {
+        FaerCol(col)
+    }
+}
+
+impl<T> From<FaerCol<T>> for Col<T> {
+    fn from(col: FaerCol<T>) -> Self {
+        col.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for FaerCol<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Clone> serde::Deserialize<'de> for FaerCol<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = Vec::<T>::deserialize(deserializer)?;
+        Ok(FaerCol(Col::from_fn(elements.len(), |i| {
+            elements[i].clone()
+        })))
+    }
+}
+
+macro_rules! make_binop {
+    ($trait:ident, $method:ident, $t:ty) => {
+        impl $trait<FaerCol<$t>, FaerCol<$t>> for FaerCol<$t> {
+            #[inline]
+            fn $method(&self, other: &FaerCol<$t>) -> FaerCol<$t> {
+                FaerCol(<Col<$t> as $trait<Col<$t>, Col<$t>>>::$method(
+                    &self.0, &other.0,
+                ))
+            }
+        }
+
+        impl $trait<$t, FaerCol<$t>> for FaerCol<$t> {
+            #[inline]
+            fn $method(&self, other: &$t) -> FaerCol<$t> {
+                FaerCol(<Col<$t> as $trait<$t, Col<$t>>>::$method(&self.0, other))
+            }
+        }
+
+        impl $trait<FaerCol<$t>, FaerCol<$t>> for $t {
+            #[inline]
+            fn $method(&self, other: &FaerCol<$t>) -> FaerCol<$t> {
+                FaerCol(<$t as $trait<Col<$t>, Col<$t>>>::$method(self, &other.0))
+            }
+        }
+    };
+}
+
+macro_rules! make_assignop {
+    ($trait:ident, $method:ident, $t:ty) => {
+        impl $trait<FaerCol<$t>> for FaerCol<$t> {
+            #[inline]
+            fn $method(&mut self, other: &FaerCol<$t>) {
+                <Col<$t> as $trait<Col<$t>>>::$method(&mut self.0, &other.0)
+            }
+        }
+
+        impl $trait<$t> for FaerCol<$t> {
+            #[inline]
+            fn $method(&mut self, other: &$t) {
+                <Col<$t> as $trait<$t>>::$method(&mut self.0, other)
+            }
+        }
+    };
+}
+
+macro_rules! make_faercol {
+    ($t:ty) => {
+        make_binop!(ArgminAdd, add, $t);
+        make_binop!(ArgminSub, sub, $t);
+        make_binop!(ArgminMul, mul, $t);
+        make_binop!(ArgminDiv, div, $t);
+        make_assignop!(ArgminAddAssign, add_assign, $t);
+        make_assignop!(ArgminSubAssign, sub_assign, $t);
+        make_assignop!(ArgminMulAssign, mul_assign, $t);
+
+        impl ArgminScaledAddAssign<FaerCol<$t>, $t> for FaerCol<$t> {
+            #[inline]
+            fn scaled_add_assign(&mut self, factor: &$t, vec: &FaerCol<$t>) {
+                self.0.scaled_add_assign(factor, &vec.0)
+            }
+        }
+
+        impl ArgminScaledSubAssign<FaerCol<$t>, $t> for FaerCol<$t> {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &FaerCol<$t>) {
+                self.0.scaled_sub_assign(factor, &vec.0)
+            }
+        }
+
+        impl ArgminDot<FaerCol<$t>, $t> for FaerCol<$t> {
+            #[inline]
+            fn dot(&self, other: &FaerCol<$t>) -> $t {
+                <Col<$t> as ArgminDot<Col<$t>, $t>>::dot(&self.0, &other.0)
+            }
+        }
+
+        impl ArgminDot<$t, FaerCol<$t>> for FaerCol<$t> {
+            #[inline]
+            fn dot(&self, other: &$t) -> FaerCol<$t> {
+                FaerCol(<Col<$t> as ArgminDot<$t, Col<$t>>>::dot(&self.0, other))
+            }
+        }
+
+        impl ArgminDot<FaerCol<$t>, FaerCol<$t>> for $t {
+            #[inline]
+            fn dot(&self, other: &FaerCol<$t>) -> FaerCol<$t> {
+                FaerCol(<$t as ArgminDot<Col<$t>, Col<$t>>>::dot(self, &other.0))
+            }
+        }
+
+        impl ArgminDot<FaerCol<$t>, Mat<$t>> for FaerCol<$t> {
+            #[inline]
+            fn dot(&self, other: &FaerCol<$t>) -> Mat<$t> {
+                <Col<$t> as ArgminDot<Col<$t>, Mat<$t>>>::dot(&self.0, &other.0)
+            }
+        }
+
+        impl ArgminDot<FaerCol<$t>, FaerCol<$t>> for Mat<$t> {
+            #[inline]
+            fn dot(&self, other: &FaerCol<$t>) -> FaerCol<$t> {
+                FaerCol(<Mat<$t> as ArgminDot<Col<$t>, Col<$t>>>::dot(
+                    self, &other.0,
+                ))
+            }
+        }
+
+        impl ArgminSolve<FaerCol<$t>, FaerCol<$t>> for Mat<$t> {
+            #[inline]
+            fn solve(&self, b: &FaerCol<$t>) -> Result<FaerCol<$t>, Error> {
+                <Mat<$t> as ArgminSolve<Col<$t>, Col<$t>>>::solve(self, &b.0).map(FaerCol)
+            }
+        }
+
+        impl ArgminCholeskySolve<FaerCol<$t>, FaerCol<$t>> for Mat<$t> {
+            #[inline]
+            fn cholesky_solve(&self, b: &FaerCol<$t>) -> Result<FaerCol<$t>, Error> {
+                <Mat<$t> as ArgminCholeskySolve<Col<$t>, Col<$t>>>::cholesky_solve(self, &b.0)
+                    .map(FaerCol)
+            }
+        }
+
+        impl ArgminEigh<FaerCol<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn eigh(&self) -> Result<(FaerCol<$t>, Mat<$t>), Error> {
+                let (values, vectors) = <Mat<$t> as ArgminEigh<Col<$t>, Mat<$t>>>::eigh(self)?;
+                Ok((FaerCol(values), vectors))
+            }
+        }
+
+        impl ArgminDiagonal<FaerCol<$t>> for Mat<$t> {
+            #[inline]
+            fn diagonal(&self) -> FaerCol<$t> {
+                FaerCol(<Mat<$t> as ArgminDiagonal<Col<$t>>>::diagonal(self))
+            }
+        }
+
+        impl ArgminL1Norm<$t> for FaerCol<$t> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                self.0.l1_norm()
+            }
+        }
+
+        impl ArgminL2Norm<$t> for FaerCol<$t> {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                self.0.l2_norm()
+            }
+        }
+
+        impl ArgminMinMax for FaerCol<$t> {
+            #[inline]
+            fn min(x: &Self, y: &Self) -> Self {
+                FaerCol(<Col<$t> as ArgminMinMax>::min(&x.0, &y.0))
+            }
+
+            #[inline]
+            fn max(x: &Self, y: &Self) -> Self {
+                FaerCol(<Col<$t> as ArgminMinMax>::max(&x.0, &y.0))
+            }
+        }
+
+        impl ArgminSignum for FaerCol<$t> {
+            #[inline]
+            fn signum(self) -> Self {
+                FaerCol(self.0.signum())
+            }
+        }
+
+        impl ArgminConj for FaerCol<$t> {
+            #[inline]
+            fn conj(&self) -> Self {
+                FaerCol(<Col<$t> as ArgminConj>::conj(&self.0))
+            }
+        }
+
+        impl ArgminRandom for FaerCol<$t> {
+            #[inline]
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> Self {
+                FaerCol(Col::rand_from_range(&min.0, &max.0, rng))
+            }
+        }
+
+        impl ArgminZeroLike for FaerCol<$t> {
+            #[inline]
+            fn zero_like(&self) -> Self {
+                FaerCol(self.0.zero_like())
+            }
+        }
+
+        impl ArgminGet<usize, $t> for FaerCol<$t> {
+            #[inline]
+            fn get_elem(&self, idx: usize) -> $t {
+                self.0.get_elem(idx)
+            }
+        }
+
+        impl ArgminSet<usize, $t> for FaerCol<$t> {
+            #[inline]
+            fn set_elem(&mut self, idx: usize, value: $t) {
+                self.0.set_elem(idx, value)
+            }
+        }
+
+        impl ArgminTranspose<FaerCol<$t>> for FaerCol<$t> {
+            #[inline]
+            fn t(self) -> FaerCol<$t> {
+                FaerCol(<Col<$t> as ArgminTranspose<Col<$t>>>::t(self.0))
+            }
+        }
+    };
+}
+
+make_faercol!(f32);
+make_faercol!(f64);
+
+impl<T> ArgminLen for FaerCol<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        <Col<T> as ArgminLen>::len(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_faercol_add_ $t>]() {
+                    let a = FaerCol(col![1 as $t, 2 as $t, 3 as $t]);
+                    let b = FaerCol(col![4 as $t, 5 as $t, 6 as $t]);
+                    let res = <FaerCol<$t> as ArgminAdd<FaerCol<$t>, FaerCol<$t>>>::add(&a, &b);
+                    let target = [5 as $t, 7 as $t, 9 as $t];
+                    for i in 0..3 {
+                        assert!((((res[i] - target[i]) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_faercol_scaled_add_assign_ $t>]() {
+                    let mut a = FaerCol(col![1 as $t, 2 as $t, 3 as $t]);
+                    let b = FaerCol(col![4 as $t, 5 as $t, 6 as $t]);
+                    a.scaled_add_assign(&(2 as $t), &b);
+                    let target = [9 as $t, 12 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert!((((a[i] - target[i]) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_faercol_dot_ $t>]() {
+                    let a = FaerCol(col![1 as $t, 2 as $t]);
+                    let b = FaerCol(col![3 as $t, 4 as $t]);
+                    let res = <FaerCol<$t> as ArgminDot<FaerCol<$t>, $t>>::dot(&a, &b);
+                    assert!(((res - 11 as $t) as f64).abs() < f64::EPSILON);
+                    let outer = <FaerCol<$t> as ArgminDot<FaerCol<$t>, Mat<$t>>>::dot(&a, &b);
+                    let target = mat![[3 as $t, 4 as $t], [6 as $t, 8 as $t]];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((outer[(i, j)] - target[(i, j)]) as f64).abs()) < f64::EPSILON);
+                        }
+                    }
+                    let m = mat![[1 as $t, 2 as $t], [3 as $t, 4 as $t]];
+                    let res = <Mat<$t> as ArgminDot<FaerCol<$t>, FaerCol<$t>>>::dot(&m, &a);
+                    let target = [5 as $t, 11 as $t];
+                    for i in 0..2 {
+                        assert!((((res[i] - target[i]) as f64).abs()) < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_faercol_solve_ $t>]() {
+                    let m = mat![[2 as $t, 1 as $t], [1 as $t, 3 as $t]];
+                    let b = FaerCol(col![1 as $t, 2 as $t]);
+                    let x = <Mat<$t> as ArgminSolve<FaerCol<$t>, FaerCol<$t>>>::solve(&m, &b).unwrap();
+                    let target = [0.2 as $t, 0.6 as $t];
+                    for i in 0..2 {
+                        assert!((((x[i] - target[i]) as f64).abs()) < 0.00001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_faercol_cholesky_solve_ $t>]() {
+                    let l = mat![[2 as $t, 0 as $t], [1 as $t, 2 as $t]];
+                    let b = FaerCol(col![6 as $t, 7 as $t]);
+                    let x = <Mat<$t> as ArgminCholeskySolve<FaerCol<$t>, FaerCol<$t>>>::cholesky_solve(
+                        &l, &b
+                    ).unwrap();
+                    for i in 0..2 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.00001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_faercol_norm_ $t>]() {
+                    let a = FaerCol(col![4 as $t, -3 as $t]);
+                    assert!(((a.l2_norm() - 5 as $t) as f64).abs() < f64::EPSILON);
+                    assert!(((a.l1_norm() - 7 as $t) as f64).abs() < f64::EPSILON);
+                    assert_eq!(a.len(), 2);
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            item! {
+                #[test]
+                fn [<test_faercol_serde_ $t>]() {
+                    let a = FaerCol(col![1 as $t, 2.5 as $t, -3 as $t]);
+                    let serialized = serde_json::to_string(&a).unwrap();
+                    assert_eq!(serialized, "[1.0,2.5,-3.0]");
+                    let b: FaerCol<$t> = serde_json::from_str(&serialized).unwrap();
+                    assert_eq!(a, b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
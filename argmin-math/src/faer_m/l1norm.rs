@@ -0,0 +1,97 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL1Norm;
+use faer::{c32, c64, Col};
+
+macro_rules! make_l1norm {
+    ($t:ty) => {
+        impl ArgminL1Norm<$t> for Col<$t> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                self.iter().map(|a| a.abs()).sum()
+            }
+        }
+    };
+}
+
+macro_rules! make_l1norm_complex {
+    ($i:ty, $t:ty) => {
+        impl ArgminL1Norm<$t> for Col<$i> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                self.iter().map(|a| a.l1_norm()).sum()
+            }
+        }
+    };
+}
+
+make_l1norm!(f32);
+make_l1norm!(f64);
+make_l1norm_complex!(c32, f32);
+make_l1norm_complex!(c64, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::col;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_ $t>]() {
+                    let a = col![4 as $t, 3 as $t];
+                    let res = <Col<$t> as ArgminL1Norm<$t>>::l1_norm(&a);
+                    let target = 7 as $t;
+                    assert!(((target - res) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_norm_signed_ $t>]() {
+                    let a = col![-4 as $t, -3 as $t];
+                    let res = <Col<$t> as ArgminL1Norm<$t>>::l1_norm(&a);
+                    let target = 7 as $t;
+                    assert!(((target - res) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($i:ty, $t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_complex_ $t>]() {
+                    let a = col![$i::new(4 as $t, 2 as $t), $i::new(3 as $t, 4 as $t)];
+                    let res = <Col<$i> as ArgminL1Norm<$t>>::l1_norm(&a);
+                    let target = a[0].l1_norm() + a[1].l1_norm();
+                    assert!(((target - res) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_norm_complex_signed_ $t>]() {
+                    let a = col![$i::new(-4 as $t, -2 as $t), $i::new(-3 as $t, -4 as $t)];
+                    let res = <Col<$i> as ArgminL1Norm<$t>>::l1_norm(&a);
+                    let target = a[0].l1_norm() + a[1].l1_norm();
+                    assert!(((target - res) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(c32, f32);
+    make_test_complex!(c64, f64);
+}
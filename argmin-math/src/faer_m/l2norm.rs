@@ -0,0 +1,76 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL2Norm;
+use faer::{c32, c64, Col};
+
+macro_rules! make_norm {
+    ($i:ty, $t:ty) => {
+        impl ArgminL2Norm<$t> for Col<$i> {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                self.norm_l2()
+            }
+        }
+    };
+}
+
+make_norm!(f32, f32);
+make_norm!(f64, f64);
+make_norm!(c32, f32);
+make_norm!(c64, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::col;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_ $t>]() {
+                    let a = col![4 as $t, 3 as $t];
+                    let res = <Col<$t> as ArgminL2Norm<$t>>::l2_norm(&a);
+                    let target = 5 as $t;
+                    assert!(((target - res) as f64).abs() < std::f32::EPSILON as f64);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_norm_signed_ $t>]() {
+                    let a = col![-4 as $t, -3 as $t];
+                    let res = <Col<$t> as ArgminL2Norm<$t>>::l2_norm(&a);
+                    let target = 5 as $t;
+                    assert!(((target - res) as f64).abs() < std::f32::EPSILON as f64);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($i:ty, $t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_complex_ $t>]() {
+                    let a = col![$i::new(4 as $t, 2 as $t), $i::new(3 as $t, 4 as $t)];
+                    let res = <Col<$i> as ArgminL2Norm<$t>>::l2_norm(&a);
+                    let target = (a[0].norm_sqr() + a[1].norm_sqr()).sqrt();
+                    assert!(((target - res) as f64).abs() < std::f32::EPSILON as f64);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(c32, f32);
+    make_test_complex!(c64, f64);
+}
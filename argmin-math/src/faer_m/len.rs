@@ -0,0 +1,39 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+use faer::{Col, Mat};
+
+impl<T> ArgminLen for Col<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.nrows()
+    }
+}
+
+impl<T> ArgminLen for Mat<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.nrows() * self.ncols()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+
+    #[test]
+    fn test_len() {
+        let a = col![1.0f64, 4.0, 8.0];
+        assert_eq!(<Col<f64> as ArgminLen>::len(&a), 3);
+        let b = mat![[1.0f64, 4.0, 8.0], [2.0, 3.0, 4.0]];
+        assert_eq!(<Mat<f64> as ArgminLen>::len(&b), 6);
+        assert!(!<Mat<f64> as ArgminLen>::is_empty(&b));
+        assert!(<Col<f64> as ArgminLen>::is_empty(&Col::zeros(0)));
+    }
+}
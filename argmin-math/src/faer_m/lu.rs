@@ -0,0 +1,95 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminLU, Error};
+use faer::Mat;
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct LUError;
+
+impl fmt::Display for LUError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LU decomposition requires a square matrix")
+    }
+}
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl ArgminLU<Mat<$t>> for Mat<$t> {
+            fn lu(&self) -> Result<(Mat<$t>, Mat<$t>, Mat<$t>), Error> {
+                if self.nrows() != self.ncols() {
+                    return Err(LUError {}.into());
+                }
+                let n = self.nrows();
+                let lu = self.partial_piv_lu();
+                let (fwd, _) = lu.P().arrays();
+                let p = Mat::from_fn(n, n, |i, j| if fwd[i] == j { 1.0 } else { 0.0 });
+                Ok((p, lu.L().to_owned(), lu.U().to_owned()))
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 10 as $t]
+                    ];
+                    let (p, l, u) = <Mat<$t> as ArgminLU<Mat<$t>>>::lu(&a).unwrap();
+                    let pa = &p * &a;
+                    let lu = &l * &u;
+                    for i in 0..3 {
+                        assert!((((l[(i, i)] - 1 as $t) as f64).abs()) < 0.0001);
+                        for j in 0..3 {
+                            if j > i {
+                                assert!((((l[(i, j)]) as f64).abs()) < 0.0001);
+                            }
+                            if j < i {
+                                assert!((((u[(i, j)]) as f64).abs()) < 0.0001);
+                            }
+                            assert!((((pa[(i, j)] - lu[(i, j)]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_not_square_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let err = <Mat<$t> as ArgminLU<Mat<$t>>>::lu(&a)
+                        .unwrap_err()
+                        .downcast::<LUError>()
+                        .unwrap();
+                    assert_eq!(err, LUError {});
+                    assert_eq!(format!("{}", err), "LU decomposition requires a square matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
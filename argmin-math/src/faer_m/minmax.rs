@@ -0,0 +1,146 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMinMax;
+use faer::{Col, Mat};
+
+macro_rules! make_minmax {
+    ($t:ty) => {
+        impl ArgminMinMax for Col<$t> {
+            #[inline]
+            fn min(x: &Self, y: &Self) -> Col<$t> {
+                assert_eq!(x.nrows(), y.nrows());
+                Col::from_fn(x.nrows(), |i| if x[i] < y[i] { x[i] } else { y[i] })
+            }
+
+            #[inline]
+            fn max(x: &Self, y: &Self) -> Col<$t> {
+                assert_eq!(x.nrows(), y.nrows());
+                Col::from_fn(x.nrows(), |i| if x[i] > y[i] { x[i] } else { y[i] })
+            }
+        }
+
+        impl ArgminMinMax for Mat<$t> {
+            #[inline]
+            fn min(x: &Self, y: &Self) -> Mat<$t> {
+                assert_eq!(x.shape(), y.shape());
+                Mat::from_fn(x.nrows(), x.ncols(), |i, j| {
+                    let a = x[(i, j)];
+                    let b = y[(i, j)];
+                    if a < b {
+                        a
+                    } else {
+                        b
+                    }
+                })
+            }
+
+            #[inline]
+            fn max(x: &Self, y: &Self) -> Mat<$t> {
+                assert_eq!(x.shape(), y.shape());
+                Mat::from_fn(x.nrows(), x.ncols(), |i, j| {
+                    let a = x[(i, j)];
+                    let b = y[(i, j)];
+                    if a > b {
+                        a
+                    } else {
+                        b
+                    }
+                })
+            }
+        }
+    };
+}
+
+make_minmax!(f32);
+make_minmax!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_minmax_vec_vec_ $t>]() {
+                    let a = col![1 as $t, 4 as $t, 8 as $t];
+                    let b = col![2 as $t, 3 as $t, 4 as $t];
+                    let target_max = col![2 as $t, 4 as $t, 8 as $t];
+                    let target_min = col![1 as $t, 3 as $t, 4 as $t];
+                    let res_max = <Col<$t> as ArgminMinMax>::max(&a, &b);
+                    let res_min = <Col<$t> as ArgminMinMax>::min(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target_max[i] - res_max[i]) as f64).abs() < std::f64::EPSILON);
+                        assert!(((target_min[i] - res_min[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_minmax_mat_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = mat![
+                        [2 as $t, 3 as $t, 4 as $t],
+                        [3 as $t, 4 as $t, 5 as $t]
+                    ];
+                    let target_max = mat![
+                        [2 as $t, 4 as $t, 8 as $t],
+                        [3 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let target_min = mat![
+                        [1 as $t, 3 as $t, 4 as $t],
+                        [2 as $t, 4 as $t, 5 as $t]
+                    ];
+                    let res_max = <Mat<$t> as ArgminMinMax>::max(&a, &b);
+                    let res_min = <Mat<$t> as ArgminMinMax>::min(&a, &b);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target_max[(i, j)] - res_max[(i, j)]) as f64).abs() < std::f64::EPSILON);
+                            assert!(((target_min[(i, j)] - res_min[(i, j)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_minmax_vec_vec_panic_ $t>]() {
+                    let a = col![1 as $t, 4 as $t];
+                    let b = col![2 as $t, 3 as $t, 4 as $t];
+                    <Col<$t> as ArgminMinMax>::max(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_minmax_mat_mat_panic_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = mat![
+                        [2 as $t, 3 as $t],
+                        [3 as $t, 4 as $t]
+                    ];
+                    <Mat<$t> as ArgminMinMax>::min(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,78 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::modifiedcholesky::{gill_murray, ModifiedCholeskyError};
+use crate::{ArgminModifiedCholesky, Error};
+use faer::Mat;
+
+macro_rules! make_modified_cholesky {
+    ($t:ty) => {
+        impl ArgminModifiedCholesky<Mat<$t>, $t> for Mat<$t> {
+            fn modified_cholesky(&self) -> Result<(Mat<$t>, $t), Error> {
+                if self.nrows() != self.ncols() {
+                    return Err(ModifiedCholeskyError {}.into());
+                }
+                let n = self.nrows();
+                let (l, max_e) = gill_murray(n, |i, j| self[(i, j)]);
+                Ok((Mat::from_fn(n, n, |i, j| l[i][j]), max_e))
+            }
+        }
+    };
+}
+
+make_modified_cholesky!(f32);
+make_modified_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::mat;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_modified_cholesky_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t]
+                    ];
+                    let (l, max_e) =
+                        <Mat<$t> as ArgminModifiedCholesky<Mat<$t>, $t>>::modified_cholesky(&a)
+                            .unwrap();
+                    assert!(max_e > 0 as $t);
+                    let llt = &l * l.transpose();
+                    assert!((((llt[(0, 1)] - a[(0, 1)]) as f64).abs()) < 0.0001);
+                    assert!((((llt[(1, 0)] - a[(1, 0)]) as f64).abs()) < 0.0001);
+                    for i in 0..2 {
+                        assert!(llt[(i, i)] >= a[(i, i)]);
+                        assert!(((llt[(i, i)] - a[(i, i)]) as f64) <= (max_e as f64) + 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_modified_cholesky_non_square_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let err = <Mat<$t> as ArgminModifiedCholesky<Mat<$t>, $t>>::modified_cholesky(&a)
+                        .unwrap_err()
+                        .downcast::<ModifiedCholeskyError>()
+                        .unwrap();
+                    assert_eq!(err, ModifiedCholeskyError {});
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,124 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use rand::Rng;
+
+use crate::ArgminRandom;
+use faer::{Col, Mat};
+
+macro_rules! make_random {
+    ($t:ty) => {
+        impl ArgminRandom for Col<$t> {
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> Col<$t> {
+                assert!(min.nrows() > 0);
+                assert_eq!(min.nrows(), max.nrows());
+                Col::from_fn(min.nrows(), |i| {
+                    let a = min[i];
+                    let b = max[i];
+                    // Do not require a < b:
+                    // We do want to know if a and b are *exactly* the same.
+                    #[allow(clippy::float_cmp)]
+                    if a == b {
+                        a
+                    } else if a < b {
+                        rng.gen_range(a..b)
+                    } else {
+                        rng.gen_range(b..a)
+                    }
+                })
+            }
+        }
+
+        impl ArgminRandom for Mat<$t> {
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> Mat<$t> {
+                assert!(min.nrows() > 0 && min.ncols() > 0);
+                assert_eq!(min.shape(), max.shape());
+                Mat::from_fn(min.nrows(), min.ncols(), |i, j| {
+                    let a = min[(i, j)];
+                    let b = max[(i, j)];
+                    // We do want to know if a and b are *exactly* the same.
+                    #[allow(clippy::float_cmp)]
+                    if a == b {
+                        a
+                    } else if a < b {
+                        rng.gen_range(a..b)
+                    } else {
+                        rng.gen_range(b..a)
+                    }
+                })
+            }
+        }
+    };
+}
+
+make_random!(f32);
+make_random!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_random_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 4 as $t];
+                    let b = col![2 as $t, 3 as $t, 5 as $t];
+                    let mut rng = rand::thread_rng();
+                    let random = Col::<$t>::rand_from_range(&a, &b, &mut rng);
+                    for i in 0..3usize {
+                        assert!(random[i] >= a[i]);
+                        assert!(random[i] <= b[i]);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_random_vec_reversed_equal_ $t>]() {
+                    let a = col![2 as $t, 2 as $t, 4 as $t];
+                    let b = col![1 as $t, 2 as $t, 5 as $t];
+                    let mut rng = rand::thread_rng();
+                    let random = Col::<$t>::rand_from_range(&a, &b, &mut rng);
+                    assert!(random[0] >= b[0]);
+                    assert!(random[0] <= a[0]);
+                    assert!(((random[1] - a[1]) as f64).abs() < std::f64::EPSILON);
+                    assert!(random[2] >= a[2]);
+                    assert!(random[2] <= b[2]);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_random_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 4 as $t],
+                        [2 as $t, 3 as $t, 5 as $t]
+                    ];
+                    let b = mat![
+                        [2 as $t, 3 as $t, 5 as $t],
+                        [3 as $t, 4 as $t, 6 as $t]
+                    ];
+                    let mut rng = rand::thread_rng();
+                    let random = Mat::<$t>::rand_from_range(&a, &b, &mut rng);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(random[(j, i)] >= a[(j, i)]);
+                            assert!(random[(j, i)] <= b[(j, i)]);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,156 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(test)]
+mod tests {
+    use crate::ArgminScaledAdd;
+    use faer::{col, mat, Col, Mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledadd_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let c = col![4 as $t, 5 as $t, 6 as $t];
+                    let res = <Col<$t> as ArgminScaledAdd<Col<$t>, $t, Col<$t>>>::scaled_add(&a, &b, &c);
+                    let target = col![9 as $t, 12 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert!((((res[i] - target[i]) as f64).abs()) < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledadd_vec_panic_1_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    let c = col![4 as $t, 5 as $t];
+                    <Col<$t> as ArgminScaledAdd<Col<$t>, $t, Col<$t>>>::scaled_add(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledadd_vec_panic_2_ $t>]() {
+                    let a = col![1 as $t, 2 as $t];
+                    let b = 2 as $t;
+                    let c = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledAdd<Col<$t>, $t, Col<$t>>>::scaled_add(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledadd_vec_vec_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![3 as $t, 2 as $t, 1 as $t];
+                    let c = col![4 as $t, 5 as $t, 6 as $t];
+                    let res = <Col<$t> as ArgminScaledAdd<Col<$t>, Col<$t>, Col<$t>>>::scaled_add(&a, &b, &c);
+                    let target = col![13 as $t, 12 as $t, 9 as $t];
+                    for i in 0..3 {
+                        assert!((((res[i] - target[i]) as f64).abs()) < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledadd_vec_vec_panic_1_ $t>]() {
+                    let a = col![1 as $t, 2 as $t];
+                    let b = col![3 as $t, 2 as $t, 1 as $t];
+                    let c = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledAdd<Col<$t>, Col<$t>, Col<$t>>>::scaled_add(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledadd_vec_vec_panic_2_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![3 as $t, 2 as $t];
+                    let c = col![4 as $t, 5 as $t, 6 as $t];
+                    <Col<$t> as ArgminScaledAdd<Col<$t>, Col<$t>, Col<$t>>>::scaled_add(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledadd_vec_vec_panic_3_ $t>]() {
+                    let a = col![1 as $t, 2 as $t, 3 as $t];
+                    let b = col![3 as $t, 2 as $t, 1 as $t];
+                    let c = col![4 as $t, 5 as $t];
+                    <Col<$t> as ArgminScaledAdd<Col<$t>, Col<$t>, Col<$t>>>::scaled_add(&a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledadd_mat_mat_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                    ];
+                    let b = mat![
+                        [4 as $t, 3 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let c = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let res = <Mat<$t> as ArgminScaledAdd<Mat<$t>, Mat<$t>, Mat<$t>>>::scaled_add(&a, &b, &c);
+                    let target = mat![
+                        [5 as $t, 8 as $t],
+                        [7 as $t, 5 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[(i, j)] - target[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaledadd_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                    ];
+                    let b = 2 as $t;
+                    let c = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let res = <Mat<$t> as ArgminScaledAdd<Mat<$t>, $t, Mat<$t>>>::scaled_add(&a, &b, &c);
+                    let target = mat![
+                        [3 as $t, 6 as $t],
+                        [7 as $t, 6 as $t],
+                    ];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[(i, j)] - target[(i, j)]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,116 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledSubAssign;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_scaledsubassign {
+    ($t:ty) => {
+        impl ArgminScaledSubAssign<Col<$t>, $t> for Col<$t> {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &Col<$t>) {
+                assert_eq!(self.nrows(), vec.nrows());
+                for (a, b) in self.iter_mut().zip(vec.iter()) {
+                    *a -= *factor * *b;
+                }
+            }
+        }
+
+        impl ArgminScaledSubAssign<Mat<$t>, $t> for Mat<$t> {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &Mat<$t>) {
+                assert_eq!(self.shape(), vec.shape());
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        self[(i, j)] -= *factor * vec[(i, j)];
+                    }
+                }
+            }
+        }
+    };
+}
+
+make_scaledsubassign!(f32);
+make_scaledsubassign!(f64);
+make_scaledsubassign!(c32);
+make_scaledsubassign!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaled_sub_assign_vec_ $t>]() {
+                    let mut a = col![11 as $t, 16 as $t, 22 as $t];
+                    let b = 2 as $t;
+                    let c = col![5 as $t, 6 as $t, 7 as $t];
+                    <Col<$t> as ArgminScaledSubAssign<Col<$t>, $t>>::scaled_sub_assign(&mut a, &b, &c);
+                    let target = col![1 as $t, 4 as $t, 8 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaled_sub_assign_vec_panic_ $t>]() {
+                    let mut a = col![1 as $t, 4 as $t];
+                    let b = 2 as $t;
+                    let c = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminScaledSubAssign<Col<$t>, $t>>::scaled_sub_assign(&mut a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaled_sub_assign_mat_ $t>]() {
+                    let mut a = mat![
+                        [11 as $t, 16 as $t, 22 as $t],
+                        [4 as $t, 9 as $t, 15 as $t]
+                    ];
+                    let b = 2 as $t;
+                    let c = mat![
+                        [5 as $t, 6 as $t, 7 as $t],
+                        [1 as $t, 2 as $t, 3 as $t]
+                    ];
+                    <Mat<$t> as ArgminScaledSubAssign<Mat<$t>, $t>>::scaled_sub_assign(&mut a, &b, &c);
+                    let target = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[(j, i)] as f64, a[(j, i)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaled_sub_assign_mat_panic_ $t>]() {
+                    let mut a = mat![[1 as $t, 4 as $t, 8 as $t], [2 as $t, 5 as $t, 9 as $t]];
+                    let b = 2 as $t;
+                    let c = mat![[41 as $t, 38 as $t], [40 as $t, 37 as $t]];
+                    <Mat<$t> as ArgminScaledSubAssign<Mat<$t>, $t>>::scaled_sub_assign(&mut a, &b, &c);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
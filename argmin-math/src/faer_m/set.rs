@@ -0,0 +1,57 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSet;
+use faer::{Col, Mat};
+
+impl<T> ArgminSet<usize, T> for Col<T> {
+    #[inline]
+    fn set_elem(&mut self, idx: usize, value: T) {
+        self[idx] = value;
+    }
+}
+
+impl<T> ArgminSet<(usize, usize), T> for Mat<T> {
+    #[inline]
+    fn set_elem(&mut self, idx: (usize, usize), value: T) {
+        self[idx] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_set_vec_ $t>]() {
+                    let mut a = col![1 as $t, 4 as $t, 8 as $t];
+                    a.set_elem(1, 2 as $t);
+                    assert!((((a[1] as f64) - 2.0).abs()) < f64::EPSILON);
+                    assert!((((a[0] as f64) - 1.0).abs()) < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_set_mat_ $t>]() {
+                    let mut a = mat![[1 as $t, 4 as $t], [8 as $t, 3 as $t]];
+                    a.set_elem((1, 0), 2 as $t);
+                    assert!((((a[(1, 0)] as f64) - 2.0).abs()) < f64::EPSILON);
+                    assert!((((a[(0, 1)] as f64) - 4.0).abs()) < f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
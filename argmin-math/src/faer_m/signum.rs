@@ -0,0 +1,167 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSignum;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_signum {
+    ($t:ty) => {
+        impl ArgminSignum for Col<$t> {
+            #[inline]
+            fn signum(mut self) -> Col<$t> {
+                for a in self.iter_mut() {
+                    *a = a.signum();
+                }
+                self
+            }
+        }
+
+        impl ArgminSignum for Mat<$t> {
+            #[inline]
+            fn signum(mut self) -> Mat<$t> {
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        self[(i, j)] = self[(i, j)].signum();
+                    }
+                }
+                self
+            }
+        }
+    };
+}
+
+macro_rules! make_signum_complex {
+    ($t:ty) => {
+        impl ArgminSignum for Col<$t> {
+            #[inline]
+            fn signum(mut self) -> Col<$t> {
+                for a in self.iter_mut() {
+                    a.re = a.re.signum();
+                    a.im = a.im.signum();
+                }
+                self
+            }
+        }
+
+        impl ArgminSignum for Mat<$t> {
+            #[inline]
+            fn signum(mut self) -> Mat<$t> {
+                for j in 0..self.ncols() {
+                    for i in 0..self.nrows() {
+                        self[(i, j)].re = self[(i, j)].re.signum();
+                        self[(i, j)].im = self[(i, j)].im.signum();
+                    }
+                }
+                self
+            }
+        }
+    };
+}
+
+make_signum!(f32);
+make_signum!(f64);
+make_signum_complex!(c32);
+make_signum_complex!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_signum_complex_faer_ $t>]() {
+                    let x = col![
+                        $t::new(1.0, 2.3),
+                        $t::new(-1.0, 2.3),
+                        $t::new(-1.0, -2.3),
+                        $t::new(1.0, -2.3)
+                    ];
+                    let y = col![
+                        $t::new(1.0, 1.0),
+                        $t::new(-1.0, 1.0),
+                        $t::new(-1.0, -1.0),
+                        $t::new(1.0, -1.0)
+                    ];
+                    let res = <Col<$t> as ArgminSignum>::signum(x);
+                    for i in 0..4 {
+                        let tmp = y[i] - res[i];
+                        let norm = ((tmp.re * tmp.re + tmp.im * tmp.im) as f64).sqrt();
+                        assert!(norm  < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_signum_complex_faer_mat_ $t>]() {
+                    let x = mat![
+                        [$t::new(1.0, 2.3), $t::new(-1.0, 2.3)],
+                        [$t::new(-1.0, -2.3), $t::new(1.0, -2.3)]
+                    ];
+                    let y = mat![
+                        [$t::new(1.0, 1.0), $t::new(-1.0, 1.0)],
+                        [$t::new(-1.0, -1.0), $t::new(1.0, -1.0)]
+                    ];
+                    let res = <Mat<$t> as ArgminSignum>::signum(x);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let tmp = y[(i, j)] - res[(i, j)];
+                            let norm = ((tmp.re * tmp.re + tmp.im * tmp.im) as f64).sqrt();
+                            assert!(norm  < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_real {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_signum_faer_ $t>]() {
+                    let x = col![1 as $t, -4 as $t, 8 as $t];
+                    let y = col![1 as $t, -1 as $t, 1 as $t];
+                    let res = <Col<$t> as ArgminSignum>::signum(x);
+                    for i in 0..3 {
+                        assert!(((y[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_signum_faer_mat_ $t>]() {
+                    let x = mat![
+                        [1 as $t, -4 as $t],
+                        [-8 as $t, 2 as $t]
+                    ];
+                    let y = mat![
+                        [1 as $t, -1 as $t],
+                        [-1 as $t, 1 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminSignum>::signum(x);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((y[(i, j)] - res[(i, j)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(c32);
+    make_test!(c64);
+
+    make_test_real!(f32);
+    make_test_real!(f64);
+}
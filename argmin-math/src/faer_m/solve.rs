@@ -0,0 +1,87 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminSolve, Error};
+use faer::linalg::solvers::Solve;
+use faer::{Col, Mat};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveError;
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<Col<$t>, Col<$t>> for Mat<$t> {
+            fn solve(&self, b: &Col<$t>) -> Result<Col<$t>, Error> {
+                assert_eq!(self.nrows(), self.ncols());
+                assert_eq!(b.nrows(), self.nrows());
+                let lu = self.partial_piv_lu();
+                let u = lu.U();
+                if (0..self.nrows()).any(|i| u[(i, i)] == 0.0) {
+                    return Err(SolveError {}.into());
+                }
+                Ok(lu.solve(b))
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 10 as $t]
+                    ];
+                    let b = col![6 as $t, 15 as $t, 25 as $t];
+                    let x = <Mat<$t> as ArgminSolve<Col<$t>, Col<$t>>>::solve(&a, &b).unwrap();
+                    for i in 0..3 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 4 as $t]
+                    ];
+                    let b = col![1 as $t, 2 as $t];
+                    let err = <Mat<$t> as ArgminSolve<Col<$t>, Col<$t>>>::solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
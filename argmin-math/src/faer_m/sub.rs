@@ -0,0 +1,223 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_sub {
+    ($t:ty) => {
+        impl ArgminSub<$t, Col<$t>> for Col<$t> {
+            #[inline]
+            fn sub(&self, other: &$t) -> Col<$t> {
+                Col::from_fn(self.nrows(), |i| self[i] - *other)
+            }
+        }
+
+        impl ArgminSub<Col<$t>, Col<$t>> for $t {
+            #[inline]
+            fn sub(&self, other: &Col<$t>) -> Col<$t> {
+                Col::from_fn(other.nrows(), |i| *self - other[i])
+            }
+        }
+
+        impl ArgminSub<Col<$t>, Col<$t>> for Col<$t> {
+            #[inline]
+            fn sub(&self, other: &Col<$t>) -> Col<$t> {
+                self - other
+            }
+        }
+
+        impl ArgminSub<Mat<$t>, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn sub(&self, other: &Mat<$t>) -> Mat<$t> {
+                self - other
+            }
+        }
+
+        impl ArgminSub<$t, Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn sub(&self, other: &$t) -> Mat<$t> {
+                Mat::from_fn(self.nrows(), self.ncols(), |i, j| self[(i, j)] - *other)
+            }
+        }
+    };
+}
+
+make_sub!(f32);
+make_sub!(f64);
+make_sub!(c32);
+make_sub!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sub_vec_scalar_ $t>]() {
+                    let a = col![36 as $t, 39 as $t, 43 as $t];
+                    let b = 1 as $t;
+                    let target = col![35 as $t, 38 as $t, 42 as $t];
+                    let res = <Col<$t> as ArgminSub<$t, Col<$t>>>::sub(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_scalar_vec_ $t>]() {
+                    let a = col![1 as $t, 4 as $t, 8 as $t];
+                    let b = 34 as $t;
+                    let target = col![33 as $t, 30 as $t, 26 as $t];
+                    let res = <$t as ArgminSub<Col<$t>, Col<$t>>>::sub(&b, &a);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_vec_vec_ $t>]() {
+                    let a = col![41 as $t, 38 as $t, 34 as $t];
+                    let b = col![1 as $t, 4 as $t, 8 as $t];
+                    let target = col![40 as $t, 34 as $t, 26 as $t];
+                    let res = <Col<$t> as ArgminSub<Col<$t>, Col<$t>>>::sub(&a, &b);
+                    for i in 0..3 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_ $t>]() {
+                    let a = col![1 as $t, 4 as $t];
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminSub<Col<$t>, Col<$t>>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_2_ $t>]() {
+                    let a = Col::<$t>::zeros(0);
+                    let b = col![41 as $t, 38 as $t, 34 as $t];
+                    <Col<$t> as ArgminSub<Col<$t>, Col<$t>>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_3_ $t>]() {
+                    let a = col![41 as $t, 38 as $t, 34 as $t];
+                    let b = Col::<$t>::zeros(0);
+                    <Col<$t> as ArgminSub<Col<$t>, Col<$t>>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_mat_mat_ $t>]() {
+                    let a = mat![
+                        [43 as $t, 46 as $t, 50 as $t],
+                        [44 as $t, 47 as $t, 51 as $t]
+                    ];
+                    let b = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let target = mat![
+                        [42 as $t, 42 as $t, 42 as $t],
+                        [42 as $t, 42 as $t, 42 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminSub<Mat<$t>, Mat<$t>>>::sub(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((target[(j, i)] - res[(j, i)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_mat_scalar_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = 1 as $t;
+                    let target = mat![
+                        [0 as $t, 3 as $t, 7 as $t],
+                        [1 as $t, 4 as $t, 8 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminSub<$t, Mat<$t>>>::sub(&a, &b);
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!(((target[(j, i)] - res[(j, i)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_mat_mat_panic_2_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = mat![
+                        [41 as $t, 38 as $t],
+                    ];
+                    <Mat<$t> as ArgminSub<Mat<$t>, Mat<$t>>>::sub(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_mat_mat_panic_3_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t]
+                    ];
+                    let b = Mat::<$t>::zeros(0, 0);
+                    <Mat<$t> as ArgminSub<Mat<$t>, Mat<$t>>>::sub(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_sub_complex() {
+        let a = col![c64::new(1.0, 2.0), c64::new(3.0, -1.0)];
+        let b = col![c64::new(2.0, -2.0), c64::new(-3.0, 4.0)];
+        let res = <Col<c64> as ArgminSub<Col<c64>, Col<c64>>>::sub(&a, &b);
+        assert!((res[0] - c64::new(-1.0, 4.0)).norm() < std::f64::EPSILON);
+        assert!((res[1] - c64::new(6.0, -5.0)).norm() < std::f64::EPSILON);
+        let res = <Mat<c32> as ArgminSub<c32, Mat<c32>>>::sub(
+            &mat![[c32::new(1.0, 2.0)]],
+            &c32::new(1.0, -2.0),
+        );
+        assert!((res[(0, 0)] - c32::new(0.0, 4.0)).norm() < std::f32::EPSILON);
+    }
+}
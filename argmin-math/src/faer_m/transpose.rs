@@ -0,0 +1,111 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminTranspose;
+use faer::{c32, c64, Col, Mat};
+
+macro_rules! make_transpose {
+    ($t:ty) => {
+        // Like ndarray's one-dimensional arrays, a column is its own transpose
+        impl ArgminTranspose<Col<$t>> for Col<$t> {
+            #[inline]
+            fn t(self) -> Col<$t> {
+                self
+            }
+        }
+
+        impl ArgminTranspose<Mat<$t>> for Mat<$t> {
+            #[inline]
+            fn t(self) -> Mat<$t> {
+                self.transpose().to_owned()
+            }
+        }
+    };
+}
+
+make_transpose!(f32);
+make_transpose!(f64);
+make_transpose!(c32);
+make_transpose!(c64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_transpose_ $t>]() {
+                    let a = col![1 as $t, 4 as $t];
+                    let target = col![1 as $t, 4 as $t];
+                    let res = <Col<$t> as ArgminTranspose<Col<$t>>>::t(a);
+                    for i in 0..2 {
+                        assert!(((target[i] - res[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_transpose_2d_1_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t],
+                        [8 as $t, 7 as $t]
+                    ];
+                    let target = mat![
+                        [1 as $t, 8 as $t],
+                        [4 as $t, 7 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminTranspose<Mat<$t>>>::t(a);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_transpose_2d_2_ $t>]() {
+                    let a = mat![
+                        [1 as $t, 4 as $t],
+                        [8 as $t, 7 as $t],
+                        [3 as $t, 6 as $t]
+                    ];
+                    let target = mat![
+                        [1 as $t, 8 as $t, 3 as $t],
+                        [4 as $t, 7 as $t, 6 as $t]
+                    ];
+                    let res = <Mat<$t> as ArgminTranspose<Mat<$t>>>::t(a);
+                    assert_eq!(res.shape(), (2, 3));
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((target[(i, j)] - res[(i, j)]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_transpose_complex() {
+        // Transposition does not conjugate the elements
+        let a = mat![[c64::new(1.0, 2.0), c64::new(3.0, -1.0)]];
+        let res = <Mat<c64> as ArgminTranspose<Mat<c64>>>::t(a);
+        assert_eq!(res.shape(), (2, 1));
+        assert!((res[(0, 0)] - c64::new(1.0, 2.0)).norm() < std::f64::EPSILON);
+        assert!((res[(1, 0)] - c64::new(3.0, -1.0)).norm() < std::f64::EPSILON);
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminZero, ArgminZeroLike};
+use faer::{Col, Mat};
+
+impl<T> ArgminZeroLike for Col<T>
+where
+    T: ArgminZero,
+{
+    #[inline]
+    fn zero_like(&self) -> Col<T> {
+        Col::from_fn(self.nrows(), |_| T::zero())
+    }
+}
+
+impl<T> ArgminZeroLike for Mat<T>
+where
+    T: ArgminZero,
+{
+    #[inline]
+    fn zero_like(&self) -> Mat<T> {
+        Mat::from_fn(self.nrows(), self.ncols(), |_, _| T::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_like_ $t>]() {
+                    let t = col![1 as $t, 2 as $t, 3 as $t];
+                    let a = t.zero_like();
+                    assert_eq!(a.nrows(), 3);
+                    for i in 0..3 {
+                        assert!(((a[i] - 0 as $t) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_zero_like_empty_ $t>]() {
+                    let t = Col::<$t>::zeros(0);
+                    let a = t.zero_like();
+                    assert_eq!(a.nrows(), 0);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_2d_zero_like_ $t>]() {
+                    let t = mat![
+                        [42 as $t, 42 as $t, 42 as $t],
+                        [42 as $t, 42 as $t, 42 as $t]
+                    ];
+                    let a = t.zero_like();
+                    assert_eq!(a.nrows(), 2);
+                    assert_eq!(a.ncols(), 3);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert!(((a[(i, j)] - 0 as $t) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
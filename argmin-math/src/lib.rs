@@ -6,9 +6,10 @@
 // copied, modified, or distributed except according to those terms.
 
 //! argmin-math provides mathematics related abstractions needed in argmin. It supports
-//! implementations of these abstractions for basic `Vec`s and for `ndarray` and `nalgebra`.
-//! The traits can of course also be implemented for your own types to make them compatible with
-//! argmin.
+//! implementations of these abstractions for basic `Vec`s, fixed-size arrays and for `ndarray`,
+//! `nalgebra`, `faer` and `sprs` (sparse matrices). The traits can of course also be implemented
+//! for your own types to make them compatible with argmin. Such implementations can be checked
+//! with the conformance tests provided by the `testing` feature.
 //!
 //! For an introduction on how to use argmin, please also have a look at the
 //! [book](https://www.argmin-rs.org/book/).
@@ -37,6 +38,10 @@
 //! | `primitives`           | yes     | basic integer and floating point types                |
 //! | `vec`                  | yes     | `Vec`s (basic functionality)                          |
 //!
+//! The `vec` backend implements `ArgminInv`, `ArgminSolve`, `ArgminLU` and `ArgminCholesky` for
+//! `Vec<Vec<f32>>` and `Vec<Vec<f64>>` in pure Rust. Solvers which require these operations
+//! therefore also work without any BLAS/LAPACK dependency (for instance in WASM builds).
+//!
 //! ### `ndarray`
 //!
 //! | Feature                         | Default | Comment                                                            |
@@ -88,6 +93,66 @@
 //! | `nalgebra_v0_29`       | no      | version 0.29                             |
 //! | `nalgebra_v0_29-serde` | no      | version 0.29 + serde support             |
 //!
+//! ### `faer`
+//!
+//! | Feature                | Default | Comment                                  |
+//! |------------------------|---------|------------------------------------------|
+//! | `faer_latest`          | no      | latest supported version                 |
+//! | `faer_latest-serde`    | no      | latest supported version + serde support |
+//! | `faer_v0_23`           | no      | version 0.23                             |
+//! | `faer_v0_23-serde`     | no      | version 0.23 + serde support             |
+//!
+//! The traits are implemented for `faer::Col` and `faer::Mat` with `f32`, `f64`, `c32` and `c64`
+//! elements. The matrix decompositions (`ArgminInv`, `ArgminSolve`, `ArgminCholesky`, ...) as well
+//! as `ArgminMinMax` and `ArgminRandom` are only available for `f32` and `f64`. The decompositions
+//! are implemented in pure Rust by `faer` and do not require a BLAS library. `faer` only
+//! implements `serde`'s traits for `faer::Mat`. Column vectors which need to be serialized (for
+//! instance parameter vectors and gradients when `argmin`'s `serde1` feature is enabled) can be
+//! wrapped in `FaerCol`, which implements the same traits as `faer::Col` for `f32` and `f64`. The
+//! elementwise and reduction traits (`ArgminAbs`, `ArgminSqrt`, `ArgminExp`, `ArgminPowi`,
+//! `ArgminClamp`, `ArgminSum` and `ArgminLInfNorm`) are not implemented for `faer` yet.
+//!
+//! ### `sprs`
+//!
+//! | Feature                | Default | Comment                                  |
+//! |------------------------|---------|------------------------------------------|
+//! | `sprs_latest`          | no      | latest supported version                 |
+//! | `sprs_latest-serde`    | no      | latest supported version + serde support |
+//! | `sprs_v0_11`           | no      | version 0.11                             |
+//! | `sprs_v0_11-serde`     | no      | version 0.11 + serde support             |
+//!
+//! The sparse matrix `sprs::CsMat<f32>` and `sprs::CsMat<f64>` (CSR or CSC) is paired with `Vec`s
+//! as dense vectors (therefore these features also turn on `vec`). This covers what `GaussNewton`
+//! (for the Jacobian), `NewtonCG` (for the Hessian) and `ConjugateGradient` (in the operator)
+//! need, for instance `ArgminDot` with `Vec`, `ArgminTranspose` and `ArgminSolve`. None of these
+//! operations densify the matrix; `ArgminSolve` uses a sparse Gaussian elimination.
+//!
+//! ### `array`
+//!
+//! | Feature                | Default | Comment                                      |
+//! |------------------------|---------|----------------------------------------------|
+//! | `array`                | no      | fixed-size arrays `[T; N]` and `[[T; N]; M]` |
+//!
+//! Intended for small problems where the number of parameters is known at compile time: `[f64; N]`
+//! can be used as parameter vector and gradient and `[[f64; N]; N]` as Hessian, which means that
+//! solvers such as `BFGS`, `NelderMead` or `Newton` run without heap allocations in the math
+//! operations. Matrices are stored row-major (`[[T; N]; M]` has `M` rows). The decompositions
+//! (`ArgminInv`, `ArgminSolve`, `ArgminLU` and `ArgminCholesky`) are implemented in pure Rust for
+//! `f32` and `f64`. Note that `serde` only supports arrays with up to 32 elements, therefore
+//! `argmin`'s `serde1` feature limits `N` to at most 32.
+//!
+//! ### `testing`
+//!
+//! | Feature                | Default | Comment                                      |
+//! |------------------------|---------|----------------------------------------------|
+//! | `testing`              | no      | conformance tests for backends               |
+//!
+//! Not a backend: the `testing` module provides property-based checks of algebraic laws (such as
+//! `(x + y) - y == x`, `x . y == y . x`, `eye * x == x` or `inv(A) * A * x == x`) for any type
+//! implementing the traits of this crate, and the `conformance_tests!` macro generates a test per
+//! check. This is intended for verifying custom backends and is therefore usually enabled in
+//! `[dev-dependencies]`. Only real-valued scalars are covered.
+//!
 //!
 //! ## Choosing a backend
 //!
@@ -179,6 +244,18 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "faer_0_23")] {
+        extern crate faer_0_23 as faer;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "sprs_0_11")] {
+        extern crate sprs_0_11 as sprs;
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "ndarray_0_15")] {
         extern crate ndarray_0_15 as ndarray;
@@ -224,12 +301,51 @@ mod nalgebra_m;
 #[cfg(feature = "nalgebra_all")]
 pub use crate::nalgebra_m::*;
 
+#[cfg(feature = "faer_all")]
+mod faer_m;
+#[cfg(feature = "faer_all")]
+pub use crate::faer_m::*;
+
+#[cfg(feature = "sprs_all")]
+mod sprs_m;
+#[cfg(feature = "sprs_all")]
+pub use crate::sprs_m::*;
+
 #[cfg(feature = "vec")]
 mod vec;
 #[cfg(feature = "vec")]
 pub use crate::vec::*;
 
+#[cfg(feature = "array")]
+mod array;
+#[cfg(feature = "array")]
+pub use crate::array::*;
+
+#[cfg(any(
+    feature = "vec",
+    feature = "array",
+    feature = "ndarray_all",
+    feature = "faer_all"
+))]
+mod choleskysolve;
+
+#[cfg(any(feature = "vec", feature = "array"))]
+mod lufactor;
+
+#[cfg(any(
+    feature = "vec",
+    feature = "array",
+    feature = "ndarray_all",
+    feature = "nalgebra_all",
+    feature = "faer_all"
+))]
+mod modifiedcholesky;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 use anyhow::Error;
+use rand::Rng;
 
 /// Dot/scalar product of `T` and `self`
 pub trait ArgminDot<T, U> {
@@ -308,6 +424,36 @@ pub trait ArgminScaledSub<T, U, V> {
     fn scaled_sub(&self, factor: &U, vec: &T) -> V;
 }
 
+/// Add a `T` to `self` in place
+pub trait ArgminAddAssign<T> {
+    /// Add a `T` to `self` in place
+    fn add_assign(&mut self, other: &T);
+}
+
+/// Subtract a `T` from `self` in place
+pub trait ArgminSubAssign<T> {
+    /// Subtract a `T` from `self` in place
+    fn sub_assign(&mut self, other: &T);
+}
+
+/// (Pointwise) Multiply `self` by a `T` in place
+pub trait ArgminMulAssign<T> {
+    /// (Pointwise) Multiply `self` by a `T` in place
+    fn mul_assign(&mut self, other: &T);
+}
+
+/// Add a `T` scaled by an `U` to `self` in place
+pub trait ArgminScaledAddAssign<T, U> {
+    /// Add a `T` scaled by an `U` to `self` in place
+    fn scaled_add_assign(&mut self, factor: &U, vec: &T);
+}
+
+/// Subtract a `T` scaled by an `U` from `self` in place
+pub trait ArgminScaledSubAssign<T, U> {
+    /// Subtract a `T` scaled by an `U` from `self` in place
+    fn scaled_sub_assign(&mut self, factor: &U, vec: &T);
+}
+
 /// Compute the l1-norm (`U`) of `self`
 pub trait ArgminL1Norm<U> {
     /// Compute the l1-norm (`U`) of `self`
@@ -320,6 +466,12 @@ pub trait ArgminL2Norm<U> {
     fn l2_norm(&self) -> U;
 }
 
+/// Compute the l-infinity-norm (`U`) of `self`, i.e. the largest absolute value of its elements
+pub trait ArgminLInfNorm<U> {
+    /// Compute the l-infinity-norm (`U`) of `self`
+    fn linf_norm(&self) -> U;
+}
+
 // Sub-optimal: self is moved. ndarray however offers array views...
 /// Return the transpose (`U`) of `self`
 pub trait ArgminTranspose<U> {
@@ -333,10 +485,63 @@ pub trait ArgminInv<T> {
     fn inv(&self) -> Result<T, Error>;
 }
 
+/// Solve the linear system `self * x = b` for `x` (`U`)
+///
+/// Preferable to forming the inverse via [`ArgminInv`], which is both slower and numerically less
+/// accurate.
+pub trait ArgminSolve<T, U> {
+    /// Solve `self * x = b`
+    fn solve(&self, b: &T) -> Result<U, Error>;
+}
+
+/// Cholesky decomposition of a symmetric (Hermitian) positive definite matrix
+pub trait ArgminCholesky<T> {
+    /// Returns the lower triangular factor `L` with `self = L * L^H`
+    fn cholesky(&self) -> Result<T, Error>;
+}
+
+/// Solve the linear system `L * L^H * x = b` for `x` (`U`), where `self` is the lower triangular
+/// factor `L` of a Cholesky decomposition (see [`ArgminCholesky`] and [`ArgminModifiedCholesky`])
+///
+/// Only the lower triangle of `self` is accessed. The system is solved by one forward and one
+/// backward substitution, which is considerably cheaper than a general [`ArgminSolve`].
+pub trait ArgminCholeskySolve<T, U> {
+    /// Solve `self * self^H * x = b`
+    fn cholesky_solve(&self, b: &T) -> Result<U, Error>;
+}
+
+/// Modified Cholesky decomposition of a symmetric, possibly indefinite matrix
+pub trait ArgminModifiedCholesky<T, F> {
+    /// Returns the lower triangular factor `L` of the Gill-Murray modified Cholesky decomposition
+    /// `self + E = L * L^T` with a nonnegative diagonal matrix `E`, as well as the largest diagonal
+    /// element of `E`. `E` vanishes if `self` is sufficiently positive definite.
+    fn modified_cholesky(&self) -> Result<(T, F), Error>;
+}
+
+/// QR decomposition
+pub trait ArgminQR<Q, R> {
+    /// Returns `Q` with orthonormal columns and upper triangular `R` with `self = Q * R`
+    fn qr(&self) -> Result<(Q, R), Error>;
+}
+
+/// LU decomposition with partial pivoting
+pub trait ArgminLU<T> {
+    /// Returns the permutation matrix `P`, the unit lower triangular matrix `L` and the upper
+    /// triangular matrix `U` with `P * self = L * U`
+    fn lu(&self) -> Result<(T, T, T), Error>;
+}
+
+/// Eigendecomposition of a symmetric (Hermitian) matrix
+pub trait ArgminEigh<V, T> {
+    /// Returns the eigenvalues (`V`) in ascending order and the corresponding eigenvectors as
+    /// columns of `T`
+    fn eigh(&self) -> Result<(V, T), Error>;
+}
+
 /// Create a random number
 pub trait ArgminRandom {
-    /// Get a random element between min and max,
-    fn rand_from_range(min: &Self, max: &Self) -> Self;
+    /// Get a random element between min and max, drawn from the random number generator `rng`
+    fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> Self;
 }
 
 /// Minimum and Maximum of type `T`
@@ -352,3 +557,68 @@ pub trait ArgminSignum {
     /// Returns a number that represents the sign of `self`.
     fn signum(self) -> Self;
 }
+
+/// Elementwise absolute value of `self`
+pub trait ArgminAbs {
+    /// Returns the absolute value of each element of `self`
+    fn abs(&self) -> Self;
+}
+
+/// Elementwise square root of `self`
+pub trait ArgminSqrt {
+    /// Returns the square root of each element of `self`
+    fn sqrt(&self) -> Self;
+}
+
+/// Elementwise exponential function of `self`
+pub trait ArgminExp {
+    /// Returns `e^x` for each element `x` of `self`
+    fn exp(&self) -> Self;
+}
+
+/// Elementwise integer power of `self`
+pub trait ArgminPowi {
+    /// Raises each element of `self` to the power `n`
+    fn powi(&self, n: i32) -> Self;
+}
+
+/// Elementwise clamping of `self` to an interval with scalar (`T`) bounds
+pub trait ArgminClamp<T> {
+    /// Restricts each element of `self` to the interval `[min, max]`
+    fn clamp(&self, min: &T, max: &T) -> Self;
+}
+
+/// Sum (`U`) of all elements of `self`
+pub trait ArgminSum<U> {
+    /// Returns the sum of all elements
+    fn sum(&self) -> U;
+}
+
+/// Get an element (`U`) of `self` at index `I`
+pub trait ArgminGet<I, U> {
+    /// Returns the element at index `idx`
+    fn get_elem(&self, idx: I) -> U;
+}
+
+/// Set an element (`U`) of `self` at index `I`
+pub trait ArgminSet<I, U> {
+    /// Sets the element at index `idx` to `value`
+    fn set_elem(&mut self, idx: I, value: U);
+}
+
+/// Number of elements of `self`
+pub trait ArgminLen {
+    /// Returns the number of elements
+    fn len(&self) -> usize;
+
+    /// Returns `true` if `self` has no elements
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Diagonal (`V`) of a square matrix
+pub trait ArgminDiagonal<V> {
+    /// Returns the diagonal of `self` as a vector
+    fn diagonal(&self) -> V;
+}
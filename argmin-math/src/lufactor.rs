@@ -0,0 +1,121 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Compact LU decomposition shared by `ArgminLU`, `ArgminSolve` and `ArgminInv` of the `Vec` and
+//! array backends
+//!
+//! The helpers work on slices of rows, where a row is anything which derefs to a slice of
+//! elements (`Vec<F>` or `[F; N]`). The `*_in_place` and `*_into` variants do not allocate and
+//! are used by the array backend, which keeps all intermediate results on the stack.
+
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// Compact LU decomposition with partial pivoting of the square matrix `a`
+///
+/// The strictly lower triangle of the returned matrix holds `L` (whose unit diagonal is not
+/// stored) and the upper triangle holds `U`. Row `i` of `P * a` is row `perm[i]` of `a`.
+#[cfg(feature = "vec")]
+pub(crate) fn lu_factorize<F, R>(a: &[R]) -> (Vec<R>, Vec<usize>)
+where
+    F: Float,
+    R: Clone + AsRef<[F]> + AsMut<[F]>,
+{
+    let mut lu = a.to_vec();
+    let mut perm = vec![0; a.len()];
+    lu_factorize_in_place(&mut lu, &mut perm);
+    (lu, perm)
+}
+
+/// Overwrites the square matrix `lu` with its compact LU decomposition with partial pivoting
+///
+/// Same as [`lu_factorize`], but without allocating. `perm` must have as many elements as `lu`
+/// has rows.
+pub(crate) fn lu_factorize_in_place<F, R>(lu: &mut [R], perm: &mut [usize])
+where
+    F: Float,
+    R: AsRef<[F]> + AsMut<[F]>,
+{
+    let n = lu.len();
+    for (i, p) in perm.iter_mut().enumerate() {
+        *p = i;
+    }
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| {
+                lu[i].as_ref()[k]
+                    .abs()
+                    .partial_cmp(&lu[j].as_ref()[k].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        if pivot != k {
+            lu.swap(k, pivot);
+            perm.swap(k, pivot);
+        }
+        if lu[k].as_ref()[k] == F::zero() {
+            // Singular column: nothing to eliminate
+            continue;
+        }
+        let (upper, lower) = lu.split_at_mut(k + 1);
+        let pivot_row = upper[k].as_ref();
+        for row in lower.iter_mut() {
+            let row = row.as_mut();
+            let factor = row[k] / pivot_row[k];
+            row[k] = factor;
+            for (rj, pj) in row[(k + 1)..].iter_mut().zip(pivot_row[(k + 1)..].iter()) {
+                *rj = *rj - factor * *pj;
+            }
+        }
+    }
+}
+
+/// Returns `true` if a pivot of the compact LU decomposition `lu` of `a` is negligible compared to
+/// the largest element of `a`
+pub(crate) fn lu_is_singular<F: Float, R: AsRef<[F]>>(a: &[R], lu: &[R]) -> bool {
+    let n = a.len();
+    let scale = a
+        .iter()
+        .flat_map(|row| row.as_ref().iter())
+        .fold(F::zero(), |acc, aij| acc.max(aij.abs()));
+    let tol = F::from(n).unwrap() * F::epsilon() * scale;
+    (0..n).any(|i| {
+        let d = lu[i].as_ref()[i];
+        d.abs() <= tol || d.is_nan()
+    })
+}
+
+/// Solves `a * x = b` given the compact LU decomposition `(lu, perm)` of `a`
+#[cfg(feature = "vec")]
+pub(crate) fn lu_solve<F: Float, R: AsRef<[F]>>(lu: &[R], perm: &[usize], b: &[F]) -> Vec<F> {
+    let mut x = vec![F::zero(); b.len()];
+    lu_solve_into(lu, perm, b, &mut x);
+    x
+}
+
+/// Solves `a * x = b` given the compact LU decomposition `(lu, perm)` of `a` and writes the
+/// solution into `x`
+pub(crate) fn lu_solve_into<F: Float, R: AsRef<[F]>>(
+    lu: &[R],
+    perm: &[usize],
+    b: &[F],
+    x: &mut [F],
+) {
+    let n = b.len();
+    // Forward substitution `L * y = P * b`
+    for i in 0..n {
+        let row = lu[i].as_ref();
+        let s = (0..i).fold(F::zero(), |acc, j| acc + row[j] * x[j]);
+        x[i] = b[perm[i]] - s;
+    }
+    // Back substitution `U * x = y`
+    for i in (0..n).rev() {
+        let row = lu[i].as_ref();
+        let s = ((i + 1)..n).fold(F::zero(), |acc, j| acc + row[j] * x[j]);
+        x[i] = (x[i] - s) / row[i];
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Gill-Murray modified Cholesky decomposition shared by the dense backends
+//!
+//! None of the supported linear algebra libraries provides a modified Cholesky decomposition,
+//! therefore the backends implement [`ArgminModifiedCholesky`](crate::ArgminModifiedCholesky) by
+//! passing their elements to [`gill_murray`] and converting the factor back.
+
+use num_traits::Float;
+use std::fmt;
+
+#[cfg(any(feature = "vec", feature = "ndarray_all", feature = "faer_all"))]
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub(crate) struct ModifiedCholeskyError;
+
+#[cfg(any(feature = "vec", feature = "ndarray_all", feature = "faer_all"))]
+impl fmt::Display for ModifiedCholeskyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Modified Cholesky decomposition requires a square matrix"
+        )
+    }
+}
+
+/// Gill-Murray modified Cholesky decomposition `A + E = L L^T` of the symmetric `n x n` matrix
+/// `A` with elements `a(i, j)`
+///
+/// Returns the rows of `L` and the largest diagonal element of `E`.
+///
+/// Reference: Philip E. Gill, Walter Murray and Margaret H. Wright (1981). Practical
+/// Optimization. Academic Press. ISBN 0-12-283952-8.
+pub(crate) fn gill_murray<F: Float>(n: usize, a: impl Fn(usize, usize) -> F) -> (Vec<Vec<F>>, F) {
+    let mut gamma = F::zero();
+    let mut xi = F::zero();
+    for i in 0..n {
+        for j in 0..n {
+            if i == j {
+                gamma = gamma.max(a(i, j).abs());
+            } else {
+                xi = xi.max(a(i, j).abs());
+            }
+        }
+    }
+    let nu = F::from(1.0f64.max(((n * n) as f64 - 1.0).sqrt())).unwrap();
+    let beta_sq = gamma.max(xi / nu).max(F::epsilon());
+    let delta = F::epsilon() * (gamma + xi).max(F::one());
+
+    // `L D L^T` decomposition with unit lower triangular `L`
+    let mut l = vec![vec![F::zero(); n]; n];
+    let mut d = vec![F::zero(); n];
+    let mut max_e = F::zero();
+    for j in 0..n {
+        let c_jj = a(j, j) - (0..j).fold(F::zero(), |acc, s| acc + d[s] * l[j][s].powi(2));
+        let c: Vec<F> = ((j + 1)..n)
+            .map(|i| a(i, j) - (0..j).fold(F::zero(), |acc, s| acc + d[s] * l[i][s] * l[j][s]))
+            .collect();
+        let theta = c.iter().fold(F::zero(), |acc, c_ij| acc.max(c_ij.abs()));
+        d[j] = c_jj.abs().max(theta.powi(2) / beta_sq).max(delta);
+        max_e = max_e.max(d[j] - c_jj);
+        l[j][j] = F::one();
+        for (i, c_ij) in ((j + 1)..n).zip(c) {
+            l[i][j] = c_ij / d[j];
+        }
+    }
+
+    // `L D L^T = (L D^(1/2)) (L D^(1/2))^T`
+    for (j, dj) in d.iter().enumerate() {
+        let s = dj.sqrt();
+        for row in l.iter_mut().skip(j) {
+            row[j] = row[j] * s;
+        }
+    }
+    (l, max_e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn multiply_transposed(l: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        let n = l.len();
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| l[i].iter().zip(l[j].iter()).map(|(a, b)| a * b).sum())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_gill_murray_positive_definite() {
+        // Positive definite matrices are not modified
+        let a = [[4.0, 2.0, 0.4], [2.0, 5.0, 1.0], [0.4, 1.0, 3.0]];
+        let (l, max_e) = gill_murray(3, |i, j| a[i][j]);
+        assert_relative_eq!(max_e, 0.0);
+        let llt = multiply_transposed(&l);
+        for i in 0..3 {
+            for j in 0..3 {
+                if j > i {
+                    assert_relative_eq!(l[i][j], 0.0);
+                }
+                assert_relative_eq!(llt[i][j], a[i][j], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gill_murray_indefinite() {
+        // Indefinite matrices are modified on the diagonal only
+        let a = [[1.0, 2.0, 0.0], [2.0, 1.0, 0.5], [0.0, 0.5, -3.0]];
+        let (l, max_e) = gill_murray(3, |i, j| a[i][j]);
+        assert!(max_e > 0.0);
+        let llt = multiply_transposed(&l);
+        for i in 0..3 {
+            assert!(l[i][i] > 0.0);
+            assert!(llt[i][i] >= a[i][i]);
+            assert!(llt[i][i] - a[i][i] <= max_e * (1.0 + 1e-12));
+            for j in 0..3 {
+                if i != j {
+                    assert_relative_eq!(llt[i][j], a[i][j], epsilon = 1e-12);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminCholesky, Error};
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, storage::Storage},
+    ComplexField, DefaultAllocator, OMatrix, SquareMatrix,
+};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct CholeskyError;
+
+impl fmt::Display for CholeskyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Matrix is not positive definite")
+    }
+}
+
+impl<N, D, S> ArgminCholesky<OMatrix<N, D, D>> for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: Dim,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    #[inline]
+    fn cholesky(&self) -> Result<OMatrix<N, D, D>, Error> {
+        match self.clone_owned().cholesky() {
+            Some(c) => Ok(c.unpack()),
+            None => Err(CholeskyError {}.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Matrix2;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = Matrix2::new(
+                        4 as $t, 2 as $t,
+                        2 as $t, 5 as $t,
+                    );
+                    let target = Matrix2::new(
+                        2 as $t, 0 as $t,
+                        1 as $t, 2 as $t,
+                    );
+                    let res = <Matrix2<$t> as ArgminCholesky<Matrix2<$t>>>::cholesky(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[(i, j)] - target[(i, j)]) as f64).abs()) < 0.000001);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = Matrix2::new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let err = <Matrix2<$t> as ArgminCholesky<Matrix2<$t>>>::cholesky(&a)
+                        .unwrap_err()
+                        .downcast::<CholeskyError>()
+                        .unwrap();
+                    assert_eq!(err, CholeskyError {});
+                    assert_eq!(format!("{}", err), "Matrix is not positive definite");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
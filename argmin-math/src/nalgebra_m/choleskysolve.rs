@@ -0,0 +1,117 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminCholeskySolve, Error};
+use nalgebra::{
+    base::{
+        allocator::Allocator,
+        constraint::{SameNumberOfRows, ShapeConstraint},
+        dimension::Dim,
+        storage::Storage,
+    },
+    ComplexField, DefaultAllocator, Matrix, OMatrix, SquareMatrix,
+};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct CholeskySolveError;
+
+impl fmt::Display for CholeskySolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+impl<N, D, S, R2, C2, S2> ArgminCholeskySolve<Matrix<N, R2, C2, S2>, OMatrix<N, R2, C2>>
+    for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: Dim,
+    S: Storage<N, D, D>,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<N, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+    DefaultAllocator: Allocator<N, R2, C2>,
+{
+    #[inline]
+    fn cholesky_solve(&self, b: &Matrix<N, R2, C2, S2>) -> Result<OMatrix<N, R2, C2>, Error> {
+        let mut x = b.clone_owned();
+        if self.solve_lower_triangular_mut(&mut x) && self.ad_solve_lower_triangular_mut(&mut x) {
+            Ok(x)
+        } else {
+            Err(CholeskySolveError {}.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DMatrix, DVector, Matrix2, Matrix3, Vector2, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    // Cholesky factor of [[4, 12, -16], [12, 37, -43], [-16, -43, 98]]; the upper
+                    // triangle must not be accessed
+                    let l = Matrix3::new(
+                        2 as $t, 100 as $t, 100 as $t,
+                        6 as $t, 1 as $t, 100 as $t,
+                        -8 as $t, 5 as $t, 3 as $t,
+                    );
+                    let b = Vector3::new(0 as $t, 6 as $t, 39 as $t);
+                    let x = <Matrix3<$t> as ArgminCholeskySolve<Vector3<$t>, Vector3<$t>>>::cholesky_solve(
+                        &l, &b
+                    ).unwrap();
+                    for i in 0..3 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_dynamic_ $t>]() {
+                    let l = DMatrix::from_row_slice(2, 2, &[2 as $t, 0 as $t, 1 as $t, 2 as $t]);
+                    let b = DVector::from_vec(vec![6 as $t, 7 as $t]);
+                    let x = <DMatrix<$t> as ArgminCholeskySolve<DVector<$t>, DVector<$t>>>::cholesky_solve(
+                        &l, &b
+                    ).unwrap();
+                    for i in 0..2 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_singular_ $t>]() {
+                    let l = Matrix2::new(
+                        1 as $t, 0 as $t,
+                        2 as $t, 0 as $t,
+                    );
+                    let b = Vector2::new(1 as $t, 1 as $t);
+                    let err = <Matrix2<$t> as ArgminCholeskySolve<Vector2<$t>, Vector2<$t>>>::cholesky_solve(
+                        &l, &b
+                    )
+                    .unwrap_err()
+                    .downcast::<CholeskySolveError>()
+                    .unwrap();
+                    assert_eq!(err, CholeskySolveError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,92 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminClamp;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix,
+};
+
+impl<N, R, C> ArgminClamp<N> for OMatrix<N, R, C>
+where
+    N: Scalar + Copy + PartialOrd,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn clamp(&self, min: &N, max: &N) -> OMatrix<N, R, C> {
+        self.map(|v| {
+            if v < *min {
+                *min
+            } else if v > *max {
+                *max
+            } else {
+                v
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_clamp_ $t>]() {
+                    let a = Vector3::new(1 as $t, 4 as $t, 8 as $t);
+                    let target = Vector3::new(2 as $t, 4 as $t, 6 as $t);
+                    let res = <Vector3<$t> as ArgminClamp<$t>>::clamp(&a, &(2 as $t), &(6 as $t));
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_clamp_mat_ $t>]() {
+                    let a = Matrix2x3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        9 as $t, 5 as $t, 0 as $t
+                    );
+                    let target = Matrix2x3::new(
+                        2 as $t, 4 as $t, 6 as $t,
+                        6 as $t, 5 as $t, 2 as $t
+                    );
+                    let res = <Matrix2x3<$t> as ArgminClamp<$t>>::clamp(&a, &(2 as $t), &(6 as $t));
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
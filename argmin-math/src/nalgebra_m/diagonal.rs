@@ -0,0 +1,84 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiagonal;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix, OVector,
+};
+
+impl<N, D> ArgminDiagonal<OVector<N, D>> for OMatrix<N, D, D>
+where
+    N: Scalar,
+    D: Dim,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    #[inline]
+    fn diagonal(&self) -> OVector<N, D> {
+        assert!(self.is_square());
+        self.map_diagonal(|x| x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DMatrix, Matrix3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diagonal_ $t>]() {
+                    let a = Matrix3::new(
+                        1 as $t, 2 as $t, 3 as $t,
+                        4 as $t, 5 as $t, 6 as $t,
+                        7 as $t, 8 as $t, 9 as $t
+                    );
+                    let d = <Matrix3<$t> as ArgminDiagonal<_>>::diagonal(&a);
+                    let target = [1.0, 5.0, 9.0];
+                    for i in 0..3 {
+                        assert!(((d[i] as f64) - target[i]).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_diagonal_dynamic_ $t>]() {
+                    let a = DMatrix::from_row_slice(2, 2, &[1 as $t, 2 as $t, 3 as $t, 4 as $t]);
+                    let d = <DMatrix<$t> as ArgminDiagonal<_>>::diagonal(&a);
+                    assert_eq!(d.len(), 2);
+                    assert!(((d[0] as f64) - 1.0).abs() < f64::EPSILON);
+                    assert!(((d[1] as f64) - 4.0).abs() < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_diagonal_non_square_ $t>]() {
+                    let a = DMatrix::from_row_slice(2, 3, &[1 as $t; 6]);
+                    <DMatrix<$t> as ArgminDiagonal<_>>::diagonal(&a);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,80 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminEigh, Error};
+use nalgebra::{
+    base::{
+        allocator::Allocator,
+        dimension::{Const, DimDiff, DimSub},
+        storage::Storage,
+    },
+    ComplexField, DefaultAllocator, OMatrix, OVector, SquareMatrix,
+};
+
+impl<N, D, S> ArgminEigh<OVector<N::RealField, D>, OMatrix<N, D, D>> for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: DimSub<Const<1>>,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D>
+        + Allocator<N, DimDiff<D, Const<1>>>
+        + Allocator<N::RealField, D>
+        + Allocator<N::RealField, DimDiff<D, Const<1>>>,
+{
+    #[inline]
+    fn eigh(&self) -> Result<(OVector<N::RealField, D>, OMatrix<N, D, D>), Error> {
+        let (nrows, ncols) = self.shape_generic();
+        let eigen = self.clone_owned().symmetric_eigen();
+        // nalgebra does not sort the eigenvalues
+        let mut idx: Vec<usize> = (0..nrows.value()).collect();
+        idx.sort_by(|&i, &j| {
+            eigen.eigenvalues[i]
+                .partial_cmp(&eigen.eigenvalues[j])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let values =
+            OVector::from_fn_generic(nrows, Const::<1>, |i, _| eigen.eigenvalues[idx[i]].clone());
+        let vectors =
+            OMatrix::from_fn_generic(nrows, ncols, |i, j| eigen.eigenvectors[(i, idx[j])].clone());
+        Ok((values, vectors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2, Vector2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eigh_ $t>]() {
+                    let a = Matrix2::new(
+                        2 as $t, 1 as $t,
+                        1 as $t, 2 as $t,
+                    );
+                    let (values, vectors) =
+                        <Matrix2<$t> as ArgminEigh<Vector2<$t>, Matrix2<$t>>>::eigh(&a).unwrap();
+                    assert!((((values[0] - 1 as $t) as f64).abs()) < 0.000001);
+                    assert!((((values[1] - 3 as $t) as f64).abs()) < 0.000001);
+                    for j in 0..2 {
+                        let v = vectors.column(j);
+                        let av = a * v;
+                        for i in 0..2 {
+                            assert!((((av[i] - values[j] * v[i]) as f64).abs()) < 0.000001);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,74 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminExp;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim},
+    DefaultAllocator, OMatrix, SimdComplexField,
+};
+
+impl<N, R, C> ArgminExp for OMatrix<N, R, C>
+where
+    N: SimdComplexField,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn exp(&self) -> OMatrix<N, R, C> {
+        self.map(|v| v.simd_exp())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_exp_ $t>]() {
+                    let a = Vector3::new(0 as $t, 1 as $t, 2 as $t);
+                    let target = Vector3::new(1 as $t, std::f64::consts::E as $t, (std::f64::consts::E * std::f64::consts::E) as $t);
+                    let res = <Vector3<$t> as ArgminExp>::exp(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_exp_mat_ $t>]() {
+                    let a = Matrix2x3::new(
+                        0 as $t, 1 as $t, 2 as $t,
+                        2 as $t, 1 as $t, 0 as $t
+                    );
+                    let target = Matrix2x3::new(
+                        1 as $t, std::f64::consts::E as $t, (std::f64::consts::E * std::f64::consts::E) as $t,
+                        (std::f64::consts::E * std::f64::consts::E) as $t, std::f64::consts::E as $t, 1 as $t
+                    );
+                    let res = <Matrix2x3<$t> as ArgminExp>::exp(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = 1e-5);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
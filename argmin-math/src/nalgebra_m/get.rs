@@ -0,0 +1,75 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminGet;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix,
+};
+
+impl<N, R, C> ArgminGet<usize, N> for OMatrix<N, R, C>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn get_elem(&self, idx: usize) -> N {
+        self[idx].clone()
+    }
+}
+
+impl<N, R, C> ArgminGet<(usize, usize), N> for OMatrix<N, R, C>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn get_elem(&self, idx: (usize, usize)) -> N {
+        self[idx].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_get_vec_ $t>]() {
+                    let a = Vector3::new(1 as $t, 4 as $t, 8 as $t);
+                    assert!((((a.get_elem(1) as f64) - 4.0).abs()) < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_get_mat_ $t>]() {
+                    let a = Matrix2x3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        2 as $t, 5 as $t, 9 as $t
+                    );
+                    assert!((((a.get_elem((1, 0)) as f64) - 2.0).abs()) < f64::EPSILON);
+                    assert!((((a.get_elem((0, 2)) as f64) - 8.0).abs()) < f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(f32);
+    make_test!(f64);
+}
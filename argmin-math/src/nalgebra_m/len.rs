@@ -0,0 +1,41 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix,
+};
+
+impl<N, R, C> ArgminLen for OMatrix<N, R, C>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.nrows() * self.ncols()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2x3, Vector3};
+
+    #[test]
+    fn test_len() {
+        let a = Vector3::new(1.0f64, 4.0, 8.0);
+        assert_eq!(<Vector3<f64> as ArgminLen>::len(&a), 3);
+        let b = Matrix2x3::new(1.0f64, 4.0, 8.0, 2.0, 5.0, 9.0);
+        assert_eq!(<Matrix2x3<f64> as ArgminLen>::len(&b), 6);
+        assert!(!<Matrix2x3<f64> as ArgminLen>::is_empty(&b));
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLInfNorm;
+
+use nalgebra::{
+    base::{dimension::Dim, storage::Storage},
+    Matrix, SimdComplexField, UniformNorm,
+};
+
+impl<N, R, C, S> ArgminLInfNorm<N::SimdRealField> for Matrix<N, R, C, S>
+where
+    N: SimdComplexField,
+    R: Dim,
+    C: Dim,
+    S: Storage<N, R, C>,
+{
+    #[inline]
+    fn linf_norm(&self) -> N::SimdRealField {
+        self.apply_norm(&UniformNorm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::Vector3;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_linfnorm_ $t>]() {
+                    let a = Vector3::new(1 as $t, 4 as $t, 8 as $t);
+                    let res = <Vector3<$t> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(res as f64, 8 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_linfnorm_signed_ $t>]() {
+                    let a = Vector3::new(1 as $t, -9 as $t, 8 as $t);
+                    let res = <Vector3<$t> as ArgminLInfNorm<$t>>::linf_norm(&a);
+                    assert_relative_eq!(res as f64, 9 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,71 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminLU, Error};
+use nalgebra::{
+    base::{allocator::Allocator, dimension::DimMin, storage::Storage},
+    ComplexField, DefaultAllocator, OMatrix, SquareMatrix,
+};
+
+impl<N, D, S> ArgminLU<OMatrix<N, D, D>> for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D>,
+{
+    #[inline]
+    fn lu(&self) -> Result<(OMatrix<N, D, D>, OMatrix<N, D, D>, OMatrix<N, D, D>), Error> {
+        let (nrows, ncols) = self.shape_generic();
+        let lu = self.clone_owned().lu();
+        let mut p = OMatrix::identity_generic(nrows, ncols);
+        lu.p().permute_rows(&mut p);
+        Ok((p, lu.l(), lu.u()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Matrix3;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = Matrix3::new(
+                        1 as $t, 2 as $t, 3 as $t,
+                        4 as $t, 5 as $t, 6 as $t,
+                        7 as $t, 8 as $t, 10 as $t,
+                    );
+                    let (p, l, u) = <Matrix3<$t> as ArgminLU<Matrix3<$t>>>::lu(&a).unwrap();
+                    let pa = p * a;
+                    let lu = l * u;
+                    for i in 0..3 {
+                        assert!((((l[(i, i)] - 1 as $t) as f64).abs()) < 0.000001);
+                        for j in 0..3 {
+                            if j > i {
+                                assert!(((l[(i, j)]) as f64).abs() < 0.000001);
+                            }
+                            if j < i {
+                                assert!(((u[(i, j)]) as f64).abs() < 0.000001);
+                            }
+                            assert!((((pa[(i, j)] - lu[(i, j)]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    // Partial pivoting selects the row with the largest element first
+                    assert!((((p[(0, 2)] - 1 as $t) as f64).abs()) < 0.000001);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
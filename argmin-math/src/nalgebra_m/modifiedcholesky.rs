@@ -0,0 +1,67 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::modifiedcholesky::gill_murray;
+use crate::{ArgminModifiedCholesky, Error};
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, storage::Storage},
+    DefaultAllocator, OMatrix, Scalar, SquareMatrix,
+};
+use num_traits::Float;
+
+impl<N, D, S> ArgminModifiedCholesky<OMatrix<N, D, D>, N> for SquareMatrix<N, D, S>
+where
+    N: Scalar + Float,
+    D: Dim,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D>,
+{
+    #[inline]
+    fn modified_cholesky(&self) -> Result<(OMatrix<N, D, D>, N), Error> {
+        let (nrows, ncols) = self.shape_generic();
+        let (l, max_e) = gill_murray(self.nrows(), |i, j| self[(i, j)]);
+        Ok((
+            OMatrix::from_fn_generic(nrows, ncols, |i, j| l[i][j]),
+            max_e,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::Matrix2;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_modified_cholesky_ $t>]() {
+                    let a = Matrix2::new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 1 as $t,
+                    );
+                    let (l, max_e) =
+                        <Matrix2<$t> as ArgminModifiedCholesky<Matrix2<$t>, $t>>::modified_cholesky(&a)
+                            .unwrap();
+                    assert!(max_e > 0 as $t);
+                    let llt = l * l.transpose();
+                    assert!((((llt[(0, 1)] - a[(0, 1)]) as f64).abs()) < 0.0001);
+                    assert!((((llt[(1, 0)] - a[(1, 0)]) as f64).abs()) < 0.0001);
+                    for i in 0..2 {
+                        assert!(llt[(i, i)] >= a[(i, i)]);
+                        assert!(((llt[(i, i)] - a[(i, i)]) as f64) <= (max_e as f64) + 0.0001);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
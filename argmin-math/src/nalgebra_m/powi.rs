@@ -0,0 +1,86 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminPowi;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim},
+    DefaultAllocator, OMatrix, SimdComplexField,
+};
+
+impl<N, R, C> ArgminPowi for OMatrix<N, R, C>
+where
+    N: SimdComplexField,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn powi(&self, n: i32) -> OMatrix<N, R, C> {
+        self.map(|v| v.simd_powi(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_powi_ $t>]() {
+                    let a = Vector3::new(1 as $t, -2 as $t, 3 as $t);
+                    let target = Vector3::new(1 as $t, 4 as $t, 9 as $t);
+                    let res = <Vector3<$t> as ArgminPowi>::powi(&a, 2);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_powi_negative_ $t>]() {
+                    let a = Vector3::new(2 as $t, 4 as $t, 8 as $t);
+                    let target = Vector3::new(0.5 as $t, 0.25 as $t, 0.125 as $t);
+                    let res = <Vector3<$t> as ArgminPowi>::powi(&a, -1);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_powi_mat_ $t>]() {
+                    let a = Matrix2x3::new(
+                        1 as $t, -2 as $t, 3 as $t,
+                        4 as $t, 5 as $t, -6 as $t
+                    );
+                    let target = Matrix2x3::new(
+                        1 as $t, 4 as $t, 9 as $t,
+                        16 as $t, 25 as $t, 36 as $t
+                    );
+                    let res = <Matrix2x3<$t> as ArgminPowi>::powi(&a, 2);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,88 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminQR, Error};
+use nalgebra::{
+    base::{
+        allocator::Allocator,
+        dimension::{Dim, DimMin, DimMinimum},
+        storage::Storage,
+    },
+    ComplexField, DefaultAllocator, Matrix, OMatrix,
+};
+
+impl<N, R, C, S> ArgminQR<OMatrix<N, R, DimMinimum<R, C>>, OMatrix<N, DimMinimum<R, C>, C>>
+    for Matrix<N, R, C, S>
+where
+    N: ComplexField,
+    R: DimMin<C>,
+    C: Dim,
+    S: Storage<N, R, C>,
+    DefaultAllocator: Allocator<N, R, C>
+        + Allocator<N, R>
+        + Allocator<N, DimMinimum<R, C>>
+        + Allocator<N, R, DimMinimum<R, C>>
+        + Allocator<N, DimMinimum<R, C>, C>,
+{
+    #[inline]
+    fn qr(
+        &self,
+    ) -> Result<
+        (
+            OMatrix<N, R, DimMinimum<R, C>>,
+            OMatrix<N, DimMinimum<R, C>, C>,
+        ),
+        Error,
+    > {
+        let qr = self.clone_owned().qr();
+        Ok((qr.q(), qr.r()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2, Matrix3x2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_ $t>]() {
+                    let a = Matrix3x2::new(
+                        1 as $t, 2 as $t,
+                        3 as $t, 4 as $t,
+                        5 as $t, 6 as $t,
+                    );
+                    let (q, r) = <Matrix3x2<$t> as ArgminQR<Matrix3x2<$t>, Matrix2<$t>>>::qr(&a)
+                        .unwrap();
+                    // `R` is upper triangular
+                    assert!(((r[(1, 0)]) as f64).abs() < 0.000001);
+                    // `Q` has orthonormal columns
+                    let qtq = q.transpose() * q;
+                    let eye = Matrix2::<$t>::identity();
+                    // `Q * R` reproduces `A`
+                    let qr = q * r;
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((qtq[(i, j)] - eye[(i, j)]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!((((qr[(i, j)] - a[(i, j)]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,123 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledAddAssign;
+
+use nalgebra::{
+    base::{
+        constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint},
+        dimension::Dim,
+        storage::{Storage, StorageMut},
+        Scalar,
+    },
+    ClosedAdd, ClosedMul, Matrix,
+};
+
+impl<N, R1, C1, R2, C2, SA, SB> ArgminScaledAddAssign<Matrix<N, R2, C2, SB>, N>
+    for Matrix<N, R1, C1, SA>
+where
+    N: Scalar + ClosedAdd + ClosedMul + Copy,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    SA: StorageMut<N, R1, C1>,
+    SB: Storage<N, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+{
+    #[inline]
+    fn scaled_add_assign(&mut self, factor: &N, vec: &Matrix<N, R2, C2, SB>) {
+        assert_eq!(self.shape(), vec.shape());
+        for (a, b) in self.iter_mut().zip(vec.iter()) {
+            *a += *factor * *b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{DMatrix, DVector, Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaled_add_assign_vec_ $t>]() {
+                    let mut a = Vector3::new(1 as $t, 4 as $t, 8 as $t);
+                    let b = 2 as $t;
+                    let c = Vector3::new(5 as $t, 6 as $t, 7 as $t);
+                    <Vector3<$t> as ArgminScaledAddAssign<Vector3<$t>, $t>>::scaled_add_assign(&mut a, &b, &c);
+                    let target = Vector3::new(11 as $t, 16 as $t, 22 as $t);
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaled_add_assign_vec_panic_ $t>]() {
+                    let mut a = DVector::from_vec(vec![1 as $t, 4 as $t]);
+                    let b = 2 as $t;
+                    let c = DVector::from_vec(vec![41 as $t, 38 as $t, 34 as $t]);
+                    <DVector<$t> as ArgminScaledAddAssign<DVector<$t>, $t>>::scaled_add_assign(&mut a, &b, &c);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_scaled_add_assign_mat_ $t>]() {
+                    let mut a = Matrix2x3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        2 as $t, 5 as $t, 9 as $t
+                    );
+                    let b = 2 as $t;
+                    let c = Matrix2x3::new(
+                        5 as $t, 6 as $t, 7 as $t,
+                        1 as $t, 2 as $t, 3 as $t
+                    );
+                    <Matrix2x3<$t> as ArgminScaledAddAssign<Matrix2x3<$t>, $t>>::scaled_add_assign(&mut a, &b, &c);
+                    let target = Matrix2x3::new(
+                        11 as $t, 16 as $t, 22 as $t,
+                        4 as $t, 9 as $t, 15 as $t
+                    );
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[(j, i)] as f64, a[(j, i)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaled_add_assign_mat_panic_ $t>]() {
+                    let mut a = DMatrix::from_vec(2, 3, vec![1 as $t, 4 as $t, 8 as $t, 2 as $t, 5 as $t, 9 as $t]);
+                    let b = 2 as $t;
+                    let c = DMatrix::from_vec(2, 2, vec![41 as $t, 38 as $t, 40 as $t, 37 as $t]);
+                    <DMatrix<$t> as ArgminScaledAddAssign<DMatrix<$t>, $t>>::scaled_add_assign(&mut a, &b, &c);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
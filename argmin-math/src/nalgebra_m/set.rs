@@ -0,0 +1,80 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSet;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix,
+};
+
+impl<N, R, C> ArgminSet<usize, N> for OMatrix<N, R, C>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn set_elem(&mut self, idx: usize, value: N) {
+        self[idx] = value;
+    }
+}
+
+impl<N, R, C> ArgminSet<(usize, usize), N> for OMatrix<N, R, C>
+where
+    N: Scalar,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn set_elem(&mut self, idx: (usize, usize), value: N) {
+        self[idx] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_set_vec_ $t>]() {
+                    let mut a = Vector3::new(1 as $t, 4 as $t, 8 as $t);
+                    a.set_elem(1, 2 as $t);
+                    assert_eq!(a, Vector3::new(1 as $t, 2 as $t, 8 as $t));
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_set_mat_ $t>]() {
+                    let mut a = Matrix2x3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        2 as $t, 5 as $t, 9 as $t
+                    );
+                    a.set_elem((1, 0), 3 as $t);
+                    let target = Matrix2x3::new(
+                        1 as $t, 4 as $t, 8 as $t,
+                        3 as $t, 5 as $t, 9 as $t
+                    );
+                    assert_eq!(a, target);
+                }
+            }
+        };
+    }
+
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,113 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminSolve, Error};
+use nalgebra::{
+    base::{
+        allocator::Allocator,
+        constraint::{SameNumberOfRows, ShapeConstraint},
+        dimension::{Dim, DimMin},
+        storage::Storage,
+    },
+    ComplexField, DefaultAllocator, Matrix, OMatrix, SquareMatrix,
+};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveError;
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+impl<N, D, S, R2, C2, S2> ArgminSolve<Matrix<N, R2, C2, S2>, OMatrix<N, R2, C2>>
+    for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    S: Storage<N, D, D>,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<N, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<(usize, usize), D> + Allocator<N, R2, C2>,
+{
+    #[inline]
+    fn solve(&self, b: &Matrix<N, R2, C2, S2>) -> Result<OMatrix<N, R2, C2>, Error> {
+        match self.clone_owned().lu().solve(b) {
+            Some(x) => Ok(x),
+            None => Err(SolveError {}.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DMatrix, DVector, Matrix2, Vector2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = Matrix2::new(
+                        2 as $t, 5 as $t,
+                        1 as $t, 3 as $t,
+                    );
+                    let b = Vector2::new(1 as $t, 2 as $t);
+                    let target = Vector2::new(-7 as $t, 3 as $t);
+                    let res = <Matrix2<$t> as ArgminSolve<Vector2<$t>, Vector2<$t>>>::solve(
+                        &a, &b
+                    ).unwrap();
+                    for i in 0..2 {
+                        assert!((((res[i] - target[i]) as f64).abs()) < 0.000001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_dynamic_ $t>]() {
+                    let a = DMatrix::from_row_slice(2, 2, &[
+                        2 as $t, 5 as $t,
+                        1 as $t, 3 as $t,
+                    ]);
+                    let b = DVector::from_vec(vec![1 as $t, 2 as $t]);
+                    let target = [-7 as $t, 3 as $t];
+                    let res = <DMatrix<$t> as ArgminSolve<DVector<$t>, DVector<$t>>>::solve(
+                        &a, &b
+                    ).unwrap();
+                    for i in 0..2 {
+                        assert!((((res[i] - target[i]) as f64).abs()) < 0.000001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_ $t>]() {
+                    let a = Matrix2::new(
+                        1 as $t, 2 as $t,
+                        2 as $t, 4 as $t,
+                    );
+                    let b = Vector2::new(1 as $t, 2 as $t);
+                    let err = <Matrix2<$t> as ArgminSolve<Vector2<$t>, Vector2<$t>>>::solve(
+                        &a, &b
+                    ).unwrap_err().downcast::<SolveError>().unwrap();
+                    assert_eq!(err.to_string(), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,74 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSqrt;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim},
+    DefaultAllocator, OMatrix, SimdComplexField,
+};
+
+impl<N, R, C> ArgminSqrt for OMatrix<N, R, C>
+where
+    N: SimdComplexField,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn sqrt(&self) -> OMatrix<N, R, C> {
+        self.map(|v| v.simd_sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{Matrix2x3, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sqrt_ $t>]() {
+                    let a = Vector3::new(4 as $t, 9 as $t, 16 as $t);
+                    let target = Vector3::new(2 as $t, 3 as $t, 4 as $t);
+                    let res = <Vector3<$t> as ArgminSqrt>::sqrt(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sqrt_mat_ $t>]() {
+                    let a = Matrix2x3::new(
+                        4 as $t, 9 as $t, 16 as $t,
+                        1 as $t, 25 as $t, 36 as $t
+                    );
+                    let target = Matrix2x3::new(
+                        2 as $t, 3 as $t, 4 as $t,
+                        1 as $t, 5 as $t, 6 as $t
+                    );
+                    let res = <Matrix2x3<$t> as ArgminSqrt>::sqrt(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
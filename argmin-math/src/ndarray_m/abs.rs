@@ -0,0 +1,88 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAbs;
+use ndarray::{Array1, Array2};
+
+macro_rules! make_abs {
+    ($t:ty) => {
+        impl ArgminAbs for Array1<$t> {
+            #[inline]
+            fn abs(&self) -> Array1<$t> {
+                self.mapv(|a| <$t>::abs(a))
+            }
+        }
+
+        impl ArgminAbs for Array2<$t> {
+            #[inline]
+            fn abs(&self) -> Array2<$t> {
+                self.mapv(|a| <$t>::abs(a))
+            }
+        }
+    };
+}
+
+make_abs!(isize);
+make_abs!(i8);
+make_abs!(i16);
+make_abs!(i32);
+make_abs!(i64);
+make_abs!(f32);
+make_abs!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_abs_vec_ $t>]() {
+                    let a = array![-1 as $t, 4 as $t, -8 as $t];
+                    let target = array![1 as $t, 4 as $t, 8 as $t];
+                    let res = <Array1<$t> as ArgminAbs>::abs(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_abs_mat_ $t>]() {
+                    let a = array![
+                        [-1 as $t, 4 as $t, -8 as $t],
+                        [2 as $t, -5 as $t, 9 as $t],
+                    ];
+                    let target = array![
+                        [1 as $t, 4 as $t, 8 as $t],
+                        [2 as $t, 5 as $t, 9 as $t],
+                    ];
+                    let res = <Array2<$t> as ArgminAbs>::abs(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
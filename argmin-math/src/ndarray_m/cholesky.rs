@@ -0,0 +1,71 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminCholesky;
+use crate::Error;
+use ndarray::Array2;
+use ndarray_linalg::{Cholesky, UPLO};
+
+macro_rules! make_cholesky {
+    ($t:ty) => {
+        impl ArgminCholesky<Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn cholesky(&self) -> Result<Array2<$t>, Error> {
+                Ok(<Self as Cholesky>::cholesky(self, UPLO::Lower)?)
+            }
+        }
+    };
+}
+
+make_cholesky!(f32);
+make_cholesky!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_ $t>]() {
+                    let a = array![
+                        [4 as $t, 2 as $t],
+                        [2 as $t, 5 as $t],
+                    ];
+                    let target = array![
+                        [2 as $t, 0 as $t],
+                        [1 as $t, 2 as $t],
+                    ];
+                    let res = <Array2<$t> as ArgminCholesky<Array2<$t>>>::cholesky(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[(i, j)] - target[(i, j)]) as f64).abs()) < 0.000001);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_error_ $t>]() {
+                    let a = array![
+                        [1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t],
+                    ];
+                    let res = <Array2<$t> as ArgminCholesky<Array2<$t>>>::cholesky(&a);
+                    assert!(res.is_err());
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
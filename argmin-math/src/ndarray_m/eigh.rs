@@ -0,0 +1,60 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminEigh;
+use crate::Error;
+use ndarray::{Array1, Array2};
+use ndarray_linalg::{Eigh, UPLO};
+
+macro_rules! make_eigh {
+    ($t:ty) => {
+        impl ArgminEigh<Array1<$t>, Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn eigh(&self) -> Result<(Array1<$t>, Array2<$t>), Error> {
+                Ok(<Self as Eigh>::eigh(self, UPLO::Lower)?)
+            }
+        }
+    };
+}
+
+make_eigh!(f32);
+make_eigh!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eigh_ $t>]() {
+                    let a = array![
+                        [2 as $t, 1 as $t],
+                        [1 as $t, 2 as $t],
+                    ];
+                    let (values, vectors) =
+                        <Array2<$t> as ArgminEigh<Array1<$t>, Array2<$t>>>::eigh(&a).unwrap();
+                    assert!((((values[0] - 1 as $t) as f64).abs()) < 0.000001);
+                    assert!((((values[1] - 3 as $t) as f64).abs()) < 0.000001);
+                    for j in 0..2 {
+                        let v = vectors.column(j);
+                        let av = a.dot(&v);
+                        for i in 0..2 {
+                            assert!((((av[i] - values[j] * v[i]) as f64).abs()) < 0.000001);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,119 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminExp;
+use ndarray::{Array1, Array2};
+use num_complex::Complex;
+
+macro_rules! make_exp {
+    ($t:ty) => {
+        impl ArgminExp for Array1<$t> {
+            #[inline]
+            fn exp(&self) -> Array1<$t> {
+                self.mapv(|a| <$t>::exp(a))
+            }
+        }
+
+        impl ArgminExp for Array2<$t> {
+            #[inline]
+            fn exp(&self) -> Array2<$t> {
+                self.mapv(|a| <$t>::exp(a))
+            }
+        }
+    };
+}
+
+macro_rules! make_exp_complex {
+    ($t:ty) => {
+        impl ArgminExp for Array1<$t> {
+            #[inline]
+            fn exp(&self) -> Array1<$t> {
+                self.mapv(|a| a.exp())
+            }
+        }
+
+        impl ArgminExp for Array2<$t> {
+            #[inline]
+            fn exp(&self) -> Array2<$t> {
+                self.mapv(|a| a.exp())
+            }
+        }
+    };
+}
+
+make_exp!(f32);
+make_exp!(f64);
+make_exp_complex!(Complex<f32>);
+make_exp_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_exp_vec_ $t>]() {
+                    let a = array![0 as $t, 1 as $t, 2 as $t];
+                    let target = array![1 as $t, std::f64::consts::E as $t, (std::f64::consts::E * std::f64::consts::E) as $t];
+                    let res = <Array1<$t> as ArgminExp>::exp(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = 1e-5);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_exp_mat_ $t>]() {
+                    let a = array![
+                        [0 as $t, 1 as $t, 2 as $t],
+                        [2 as $t, 1 as $t, 0 as $t],
+                    ];
+                    let target = array![
+                        [1 as $t, std::f64::consts::E as $t, (std::f64::consts::E * std::f64::consts::E) as $t],
+                        [(std::f64::consts::E * std::f64::consts::E) as $t, std::f64::consts::E as $t, 1 as $t],
+                    ];
+                    let res = <Array2<$t> as ArgminExp>::exp(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = 1e-5);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_exp_complex_vec_ $t>]() {
+                    let a = array![Complex::new(0 as $t, std::f64::consts::PI as $t)];
+                    let target = array![Complex::new(-1 as $t, 0 as $t)];
+                    let res = <Array1<Complex<$t>> as ArgminExp>::exp(&a);
+                    for i in 0..1 {
+                        assert_relative_eq!(res[i].re as f64, target[i].re as f64, epsilon = 1e-6);
+                        assert_relative_eq!(res[i].im as f64, target[i].im as f64, epsilon = 1e-6);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
@@ -0,0 +1,56 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminGet;
+use ndarray::{Array1, Array2};
+
+impl<T: Clone> ArgminGet<usize, T> for Array1<T> {
+    #[inline]
+    fn get_elem(&self, idx: usize) -> T {
+        self[idx].clone()
+    }
+}
+
+impl<T: Clone> ArgminGet<(usize, usize), T> for Array2<T> {
+    #[inline]
+    fn get_elem(&self, idx: (usize, usize)) -> T {
+        self[idx].clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_get_vec_ $t>]() {
+                    let a = array![1 as $t, 4 as $t, 8 as $t];
+                    assert!((((a.get_elem(1) as f64) - 4.0).abs()) < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_get_mat_ $t>]() {
+                    let a = array![[1 as $t, 4 as $t], [8 as $t, 3 as $t]];
+                    assert!((((a.get_elem((1, 0)) as f64) - 8.0).abs()) < f64::EPSILON);
+                    assert!((((a.get_elem((0, 1)) as f64) - 4.0).abs()) < f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(f32);
+    make_test!(f64);
+}
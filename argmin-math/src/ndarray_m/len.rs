@@ -0,0 +1,38 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+use ndarray::{Array1, Array2};
+
+impl<T> ArgminLen for Array1<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        Array1::len(self)
+    }
+}
+
+impl<T> ArgminLen for Array2<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        Array2::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_len() {
+        let a = array![1.0f64, 4.0, 8.0];
+        assert_eq!(<Array1<f64> as ArgminLen>::len(&a), 3);
+        let b = array![[1.0f64, 4.0, 8.0], [2.0, 3.0, 4.0]];
+        assert_eq!(<Array2<f64> as ArgminLen>::len(&b), 6);
+        assert!(!<Array2<f64> as ArgminLen>::is_empty(&b));
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLU;
+use crate::Error;
+use ndarray::Array2;
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct LUError;
+
+impl fmt::Display for LUError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LU decomposition requires a square matrix")
+    }
+}
+
+// `ndarray-linalg` does not expose the factors of its LU decomposition, therefore the Doolittle
+// algorithm with partial pivoting is implemented here. This also makes `ArgminLU` available
+// without `ndarray-linalg`.
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl ArgminLU<Array2<$t>> for Array2<$t> {
+            fn lu(&self) -> Result<(Array2<$t>, Array2<$t>, Array2<$t>), Error> {
+                if !self.is_square() {
+                    return Err(LUError {}.into());
+                }
+                let n = self.nrows();
+                let mut u = self.clone();
+                let mut l = Array2::<$t>::eye(n);
+                let mut perm: Vec<usize> = (0..n).collect();
+                for k in 0..n {
+                    let pivot = (k..n)
+                        .max_by(|&i, &j| u[(i, k)].abs().total_cmp(&u[(j, k)].abs()))
+                        .unwrap();
+                    if pivot != k {
+                        for j in 0..n {
+                            u.swap((k, j), (pivot, j));
+                        }
+                        for j in 0..k {
+                            l.swap((k, j), (pivot, j));
+                        }
+                        perm.swap(k, pivot);
+                    }
+                    if u[(k, k)] == 0.0 {
+                        // Singular column: nothing to eliminate
+                        continue;
+                    }
+                    for i in (k + 1)..n {
+                        let factor = u[(i, k)] / u[(k, k)];
+                        l[(i, k)] = factor;
+                        for j in k..n {
+                            u[(i, j)] -= factor * u[(k, j)];
+                        }
+                    }
+                }
+                let mut p = Array2::<$t>::zeros((n, n));
+                for (i, pi) in perm.into_iter().enumerate() {
+                    p[(i, pi)] = 1.0;
+                }
+                Ok((p, l, u))
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = array![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 10 as $t],
+                    ];
+                    let (p, l, u) = <Array2<$t> as ArgminLU<Array2<$t>>>::lu(&a).unwrap();
+                    let pa = p.dot(&a);
+                    let lu = l.dot(&u);
+                    for i in 0..3 {
+                        assert!((((l[(i, i)] - 1 as $t) as f64).abs()) < 0.000001);
+                        for j in 0..3 {
+                            if j > i {
+                                assert!(((l[(i, j)]) as f64).abs() < 0.000001);
+                            }
+                            if j < i {
+                                assert!(((u[(i, j)]) as f64).abs() < 0.000001);
+                            }
+                            assert!((((pa[(i, j)] - lu[(i, j)]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    // Partial pivoting selects the row with the largest element first
+                    assert!((((p[(0, 2)] - 1 as $t) as f64).abs()) < 0.000001);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_error_ $t>]() {
+                    let a = array![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let err = <Array2<$t> as ArgminLU<Array2<$t>>>::lu(&a)
+                        .unwrap_err()
+                        .downcast::<LUError>()
+                        .unwrap();
+                    assert_eq!(err, LUError {});
+                    assert_eq!(format!("{}", err), "LU decomposition requires a square matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
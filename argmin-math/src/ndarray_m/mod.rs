@@ -5,11 +5,30 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+mod abs;
 mod add;
+mod addassign;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+mod cholesky;
+mod choleskysolve;
+mod clamp;
 mod conj;
+mod diagonal;
 mod div;
 mod dot;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+mod eigh;
+mod exp;
 mod eye;
+mod get;
 #[cfg(any(
     feature = "ndarray-linalg_0_12",
     feature = "ndarray-linalg_0_13",
@@ -18,21 +37,64 @@ mod eye;
 mod inv;
 mod l1norm;
 mod l2norm;
+mod len;
+mod linfnorm;
+mod lu;
 mod minmax;
+mod modifiedcholesky;
 mod mul;
+mod mulassign;
+mod powi;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+mod qr;
 mod random;
 mod scaledadd;
+mod scaledaddassign;
 mod scaledsub;
+mod scaledsubassign;
+mod set;
 mod signum;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+mod solve;
+mod sqrt;
 mod sub;
+mod subassign;
+mod sum;
 mod transpose;
 mod zero;
 
+pub use abs::*;
 pub use add::*;
+pub use addassign::*;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+pub use cholesky::*;
+pub use choleskysolve::*;
+pub use clamp::*;
 pub use conj::*;
+pub use diagonal::*;
 pub use div::*;
 pub use dot::*;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+pub use eigh::*;
+pub use exp::*;
 pub use eye::*;
+pub use get::*;
 #[cfg(any(
     feature = "ndarray-linalg_0_12",
     feature = "ndarray-linalg_0_13",
@@ -41,11 +103,35 @@ pub use eye::*;
 pub use inv::*;
 pub use l1norm::*;
 pub use l2norm::*;
+pub use len::*;
+pub use linfnorm::*;
+pub use lu::*;
 pub use minmax::*;
+pub use modifiedcholesky::*;
 pub use mul::*;
+pub use mulassign::*;
+pub use powi::*;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+pub use qr::*;
 pub use scaledadd::*;
+pub use scaledaddassign::*;
 pub use scaledsub::*;
+pub use scaledsubassign::*;
+pub use set::*;
 pub use signum::*;
+#[cfg(any(
+    feature = "ndarray-linalg_0_12",
+    feature = "ndarray-linalg_0_13",
+    feature = "ndarray-linalg_0_16"
+))]
+pub use solve::*;
+pub use sqrt::*;
 pub use sub::*;
+pub use subassign::*;
+pub use sum::*;
 pub use transpose::*;
 pub use zero::*;
@@ -0,0 +1,70 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminQR;
+use crate::Error;
+use ndarray::Array2;
+use ndarray_linalg::QR;
+
+macro_rules! make_qr {
+    ($t:ty) => {
+        impl ArgminQR<Array2<$t>, Array2<$t>> for Array2<$t> {
+            #[inline]
+            fn qr(&self) -> Result<(Array2<$t>, Array2<$t>), Error> {
+                Ok(<Self as QR>::qr(self)?)
+            }
+        }
+    };
+}
+
+make_qr!(f32);
+make_qr!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_qr_ $t>]() {
+                    let a = array![
+                        [1 as $t, 2 as $t],
+                        [3 as $t, 4 as $t],
+                        [5 as $t, 6 as $t],
+                    ];
+                    let (q, r) = <Array2<$t> as ArgminQR<Array2<$t>, Array2<$t>>>::qr(&a).unwrap();
+                    assert_eq!(q.dim(), (3, 2));
+                    assert_eq!(r.dim(), (2, 2));
+                    // `R` is upper triangular
+                    assert!(((r[(1, 0)]) as f64).abs() < 0.000001);
+                    // `Q` has orthonormal columns
+                    let qtq = q.t().dot(&q);
+                    // `Q * R` reproduces `A`
+                    let qr = q.dot(&r);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let eye = if i == j { 1 as $t } else { 0 as $t };
+                            assert!((((qtq[(i, j)] - eye) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert!((((qr[(i, j)] - a[(i, j)]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
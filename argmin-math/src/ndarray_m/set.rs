@@ -0,0 +1,57 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSet;
+use ndarray::{Array1, Array2};
+
+impl<T> ArgminSet<usize, T> for Array1<T> {
+    #[inline]
+    fn set_elem(&mut self, idx: usize, value: T) {
+        self[idx] = value;
+    }
+}
+
+impl<T> ArgminSet<(usize, usize), T> for Array2<T> {
+    #[inline]
+    fn set_elem(&mut self, idx: (usize, usize), value: T) {
+        self[idx] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_set_vec_ $t>]() {
+                    let mut a = array![1 as $t, 4 as $t, 8 as $t];
+                    a.set_elem(1, 2 as $t);
+                    assert_eq!(a, array![1 as $t, 2 as $t, 8 as $t]);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_set_mat_ $t>]() {
+                    let mut a = array![[1 as $t, 4 as $t], [8 as $t, 3 as $t]];
+                    a.set_elem((1, 0), 2 as $t);
+                    assert_eq!(a, array![[1 as $t, 4 as $t], [2 as $t, 3 as $t]]);
+                }
+            }
+        };
+    }
+
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(f32);
+    make_test!(f64);
+}
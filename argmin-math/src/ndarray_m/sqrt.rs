@@ -0,0 +1,120 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSqrt;
+use ndarray::{Array1, Array2};
+use num_complex::Complex;
+
+macro_rules! make_sqrt {
+    ($t:ty) => {
+        impl ArgminSqrt for Array1<$t> {
+            #[inline]
+            fn sqrt(&self) -> Array1<$t> {
+                self.mapv(|a| <$t>::sqrt(a))
+            }
+        }
+
+        impl ArgminSqrt for Array2<$t> {
+            #[inline]
+            fn sqrt(&self) -> Array2<$t> {
+                self.mapv(|a| <$t>::sqrt(a))
+            }
+        }
+    };
+}
+
+macro_rules! make_sqrt_complex {
+    ($t:ty) => {
+        impl ArgminSqrt for Array1<$t> {
+            #[inline]
+            fn sqrt(&self) -> Array1<$t> {
+                self.mapv(|a| a.sqrt())
+            }
+        }
+
+        impl ArgminSqrt for Array2<$t> {
+            #[inline]
+            fn sqrt(&self) -> Array2<$t> {
+                self.mapv(|a| a.sqrt())
+            }
+        }
+    };
+}
+
+make_sqrt!(f32);
+make_sqrt!(f64);
+make_sqrt_complex!(Complex<f32>);
+make_sqrt_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sqrt_vec_ $t>]() {
+                    let a = array![4 as $t, 9 as $t, 16 as $t];
+                    let target = array![2 as $t, 3 as $t, 4 as $t];
+                    let res = <Array1<$t> as ArgminSqrt>::sqrt(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sqrt_mat_ $t>]() {
+                    let a = array![
+                        [4 as $t, 9 as $t, 16 as $t],
+                        [1 as $t, 25 as $t, 36 as $t],
+                    ];
+                    let target = array![
+                        [2 as $t, 3 as $t, 4 as $t],
+                        [1 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let res = <Array2<$t> as ArgminSqrt>::sqrt(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[(i, j)] as f64, target[(i, j)] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sqrt_complex_vec_ $t>]() {
+                    let a = array![Complex::new(3 as $t, 4 as $t), Complex::new(-4 as $t, 0 as $t)];
+                    let target = array![Complex::new(2 as $t, 1 as $t), Complex::new(0 as $t, 2 as $t)];
+                    let res = <Array1<Complex<$t>> as ArgminSqrt>::sqrt(&a);
+                    for i in 0..2 {
+                        assert_relative_eq!(res[i].re as f64, target[i].re as f64, epsilon = 1e-6);
+                        assert_relative_eq!(res[i].im as f64, target[i].im as f64, epsilon = 1e-6);
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
@@ -0,0 +1,64 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAbs;
+
+macro_rules! make_abs {
+    ($t:ty) => {
+        impl ArgminAbs for $t {
+            #[inline]
+            fn abs(&self) -> $t {
+                <$t>::abs(*self)
+            }
+        }
+    };
+}
+
+make_abs!(isize);
+make_abs!(i8);
+make_abs!(i16);
+make_abs!(i32);
+make_abs!(i64);
+make_abs!(f32);
+make_abs!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_abs_ $t>]() {
+                    let a = -8 as $t;
+                    let res = <$t as ArgminAbs>::abs(&a);
+                    assert_relative_eq!(res as f64, 8 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_abs_positive_ $t>]() {
+                    let a = 8 as $t;
+                    let res = <$t as ArgminAbs>::abs(&a);
+                    assert_relative_eq!(res as f64, 8 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
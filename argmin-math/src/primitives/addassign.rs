@@ -0,0 +1,79 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAddAssign;
+use num_complex::Complex;
+
+macro_rules! make_addassign {
+    ($t:ty) => {
+        impl ArgminAddAssign<$t> for $t {
+            #[inline]
+            fn add_assign(&mut self, other: &$t) {
+                *self += *other;
+            }
+        }
+    };
+}
+
+make_addassign!(isize);
+make_addassign!(usize);
+make_addassign!(i8);
+make_addassign!(i16);
+make_addassign!(i32);
+make_addassign!(i64);
+make_addassign!(u8);
+make_addassign!(u16);
+make_addassign!(u32);
+make_addassign!(u64);
+make_addassign!(f32);
+make_addassign!(f64);
+make_addassign!(Complex<isize>);
+make_addassign!(Complex<usize>);
+make_addassign!(Complex<i8>);
+make_addassign!(Complex<i16>);
+make_addassign!(Complex<i32>);
+make_addassign!(Complex<i64>);
+make_addassign!(Complex<u8>);
+make_addassign!(Complex<u16>);
+make_addassign!(Complex<u32>);
+make_addassign!(Complex<u64>);
+make_addassign!(Complex<f32>);
+make_addassign!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_add_assign_ $t>]() {
+                    let mut a = 8 as $t;
+                    let b = 34 as $t;
+                    <$t as ArgminAddAssign<$t>>::add_assign(&mut a, &b);
+                    assert_relative_eq!(42 as f64, a as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,89 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminClamp;
+
+macro_rules! make_clamp {
+    ($t:ty) => {
+        impl ArgminClamp<$t> for $t {
+            #[inline]
+            fn clamp(&self, min: &$t, max: &$t) -> $t {
+                if *self < *min {
+                    *min
+                } else if *self > *max {
+                    *max
+                } else {
+                    *self
+                }
+            }
+        }
+    };
+}
+
+make_clamp!(isize);
+make_clamp!(usize);
+make_clamp!(i8);
+make_clamp!(i16);
+make_clamp!(i32);
+make_clamp!(i64);
+make_clamp!(u8);
+make_clamp!(u16);
+make_clamp!(u32);
+make_clamp!(u64);
+make_clamp!(f32);
+make_clamp!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_clamp_lower_ $t>]() {
+                    let a = 1 as $t;
+                    let res = <$t as ArgminClamp<$t>>::clamp(&a, &(2 as $t), &(6 as $t));
+                    assert_relative_eq!(res as f64, 2 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_clamp_upper_ $t>]() {
+                    let a = 8 as $t;
+                    let res = <$t as ArgminClamp<$t>>::clamp(&a, &(2 as $t), &(6 as $t));
+                    assert_relative_eq!(res as f64, 6 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_clamp_inside_ $t>]() {
+                    let a = 4 as $t;
+                    let res = <$t as ArgminClamp<$t>>::clamp(&a, &(2 as $t), &(6 as $t));
+                    assert_relative_eq!(res as f64, 4 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
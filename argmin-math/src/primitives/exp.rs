@@ -0,0 +1,76 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminExp;
+use num_complex::Complex;
+
+macro_rules! make_exp {
+    ($t:ty) => {
+        impl ArgminExp for $t {
+            #[inline]
+            fn exp(&self) -> $t {
+                <$t>::exp(*self)
+            }
+        }
+    };
+}
+
+macro_rules! make_exp_complex {
+    ($t:ty) => {
+        impl ArgminExp for $t {
+            #[inline]
+            fn exp(&self) -> $t {
+                (*self).exp()
+            }
+        }
+    };
+}
+
+make_exp!(f32);
+make_exp!(f64);
+make_exp_complex!(Complex<f32>);
+make_exp_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_exp_ $t>]() {
+                    let a = 1 as $t;
+                    let res = <$t as ArgminExp>::exp(&a);
+                    assert_relative_eq!(res as f64, std::f64::consts::E as f64, epsilon = 1e-6);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_exp_complex_ $t>]() {
+                    let a = Complex::new(0 as $t, std::f64::consts::PI as $t);
+                    let res = <Complex<$t> as ArgminExp>::exp(&a);
+                    assert_relative_eq!(res.re as f64, -1 as f64, epsilon = 1e-6);
+                    assert_relative_eq!(res.im as f64, 0 as f64, epsilon = 1e-6);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
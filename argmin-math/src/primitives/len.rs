@@ -0,0 +1,74 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+use num_complex::Complex;
+
+macro_rules! make_len {
+    ($t:ty) => {
+        impl ArgminLen for $t {
+            #[inline]
+            fn len(&self) -> usize {
+                1
+            }
+        }
+
+        impl ArgminLen for Complex<$t> {
+            #[inline]
+            fn len(&self) -> usize {
+                1
+            }
+        }
+    };
+}
+
+make_len!(isize);
+make_len!(usize);
+make_len!(i8);
+make_len!(i16);
+make_len!(i32);
+make_len!(i64);
+make_len!(u8);
+make_len!(u16);
+make_len!(u32);
+make_len!(u64);
+make_len!(f32);
+make_len!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_len_ $t>]() {
+                    let a = 8 as $t;
+                    assert_eq!(<$t as ArgminLen>::len(&a), 1);
+                    assert!(!<$t as ArgminLen>::is_empty(&a));
+                    let b = Complex::new(8 as $t, 4 as $t);
+                    assert_eq!(<Complex<$t> as ArgminLen>::len(&b), 1);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
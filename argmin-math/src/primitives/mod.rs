@@ -5,32 +5,60 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+mod abs;
 mod add;
+mod addassign;
+mod clamp;
 mod conj;
 mod div;
 mod dot;
+mod exp;
 mod l1norm;
 mod l2norm;
+mod len;
+mod linfnorm;
 mod mul;
+mod mulassign;
+mod powi;
 mod random;
 mod scaledadd;
+mod scaledaddassign;
 mod scaledsub;
+mod scaledsubassign;
+mod solve;
+mod sqrt;
 mod sub;
+mod subassign;
+mod sum;
 mod transpose;
 mod weighteddot;
 mod zero;
 
+pub use abs::*;
 pub use add::*;
+pub use addassign::*;
+pub use clamp::*;
 pub use conj::*;
 pub use div::*;
 pub use dot::*;
+pub use exp::*;
 pub use l1norm::*;
 pub use l2norm::*;
+pub use len::*;
+pub use linfnorm::*;
 pub use mul::*;
+pub use mulassign::*;
+pub use powi::*;
 pub use random::*;
 pub use scaledadd::*;
+pub use scaledaddassign::*;
 pub use scaledsub::*;
+pub use scaledsubassign::*;
+pub use solve::*;
+pub use sqrt::*;
 pub use sub::*;
+pub use subassign::*;
+pub use sum::*;
 pub use transpose::*;
 pub use weighteddot::*;
 pub use zero::*;
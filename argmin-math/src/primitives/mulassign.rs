@@ -0,0 +1,79 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMulAssign;
+use num_complex::Complex;
+
+macro_rules! make_mulassign {
+    ($t:ty) => {
+        impl ArgminMulAssign<$t> for $t {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                *self *= *other;
+            }
+        }
+    };
+}
+
+make_mulassign!(isize);
+make_mulassign!(usize);
+make_mulassign!(i8);
+make_mulassign!(i16);
+make_mulassign!(i32);
+make_mulassign!(i64);
+make_mulassign!(u8);
+make_mulassign!(u16);
+make_mulassign!(u32);
+make_mulassign!(u64);
+make_mulassign!(f32);
+make_mulassign!(f64);
+make_mulassign!(Complex<isize>);
+make_mulassign!(Complex<usize>);
+make_mulassign!(Complex<i8>);
+make_mulassign!(Complex<i16>);
+make_mulassign!(Complex<i32>);
+make_mulassign!(Complex<i64>);
+make_mulassign!(Complex<u8>);
+make_mulassign!(Complex<u16>);
+make_mulassign!(Complex<u32>);
+make_mulassign!(Complex<u64>);
+make_mulassign!(Complex<f32>);
+make_mulassign!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_assign_ $t>]() {
+                    let mut a = 2 as $t;
+                    let b = 21 as $t;
+                    <$t as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    assert_relative_eq!(42 as f64, a as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
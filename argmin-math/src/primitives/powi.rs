@@ -0,0 +1,85 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminPowi;
+use num_complex::Complex;
+
+macro_rules! make_powi {
+    ($t:ty) => {
+        impl ArgminPowi for $t {
+            #[inline]
+            fn powi(&self, n: i32) -> $t {
+                <$t>::powi(*self, n)
+            }
+        }
+    };
+}
+
+macro_rules! make_powi_complex {
+    ($t:ty) => {
+        impl ArgminPowi for $t {
+            #[inline]
+            fn powi(&self, n: i32) -> $t {
+                <$t>::powi(self, n)
+            }
+        }
+    };
+}
+
+make_powi!(f32);
+make_powi!(f64);
+make_powi_complex!(Complex<f32>);
+make_powi_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_powi_ $t>]() {
+                    let a = -3 as $t;
+                    let res = <$t as ArgminPowi>::powi(&a, 2);
+                    assert_relative_eq!(res as f64, 9 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_powi_negative_ $t>]() {
+                    let a = 4 as $t;
+                    let res = <$t as ArgminPowi>::powi(&a, -1);
+                    assert_relative_eq!(res as f64, 0.25 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_powi_complex_ $t>]() {
+                    let a = Complex::new(1 as $t, 1 as $t);
+                    let res = <Complex<$t> as ArgminPowi>::powi(&a, 2);
+                    assert_relative_eq!(res.re as f64, 0 as f64, epsilon = 1e-6);
+                    assert_relative_eq!(res.im as f64, 2 as f64, epsilon = 1e-6);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
@@ -12,8 +12,8 @@ macro_rules! make_random {
     ($t:ty) => {
         impl ArgminRandom for $t {
             #[inline]
-            fn rand_from_range(min: &Self, max: &Self) -> $t {
-                rand::thread_rng().gen_range(*min..*max)
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> $t {
+                rng.gen_range(*min..*max)
             }
         }
     };
@@ -44,7 +44,8 @@ mod tests {
                 fn [<test_random_vec_ $t>]() {
                     let a = 1 as $t;
                     let b = 2 as $t;
-                    let random = $t::rand_from_range(&a, &b);
+                    let mut rng = rand::thread_rng();
+                    let random = $t::rand_from_range(&a, &b, &mut rng);
                     assert!(random >= a);
                     assert!(random <= b);
                 }
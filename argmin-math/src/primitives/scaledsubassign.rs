@@ -0,0 +1,80 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminScaledSubAssign;
+use num_complex::Complex;
+
+macro_rules! make_scaledsubassign {
+    ($t:ty) => {
+        impl ArgminScaledSubAssign<$t, $t> for $t {
+            #[inline]
+            fn scaled_sub_assign(&mut self, factor: &$t, vec: &$t) {
+                *self -= *factor * *vec;
+            }
+        }
+    };
+}
+
+make_scaledsubassign!(isize);
+make_scaledsubassign!(usize);
+make_scaledsubassign!(i8);
+make_scaledsubassign!(i16);
+make_scaledsubassign!(i32);
+make_scaledsubassign!(i64);
+make_scaledsubassign!(u8);
+make_scaledsubassign!(u16);
+make_scaledsubassign!(u32);
+make_scaledsubassign!(u64);
+make_scaledsubassign!(f32);
+make_scaledsubassign!(f64);
+make_scaledsubassign!(Complex<isize>);
+make_scaledsubassign!(Complex<usize>);
+make_scaledsubassign!(Complex<i8>);
+make_scaledsubassign!(Complex<i16>);
+make_scaledsubassign!(Complex<i32>);
+make_scaledsubassign!(Complex<i64>);
+make_scaledsubassign!(Complex<u8>);
+make_scaledsubassign!(Complex<u16>);
+make_scaledsubassign!(Complex<u32>);
+make_scaledsubassign!(Complex<u64>);
+make_scaledsubassign!(Complex<f32>);
+make_scaledsubassign!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaled_sub_assign_ $t>]() {
+                    let mut a = 42 as $t;
+                    let b = 4 as $t;
+                    let c = 8 as $t;
+                    <$t as ArgminScaledSubAssign<$t, $t>>::scaled_sub_assign(&mut a, &b, &c);
+                    assert_relative_eq!(10 as f64, a as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,73 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminSolve, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveError;
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Division by zero")
+    }
+}
+
+// Linear "systems" of scalars (1d solvers)
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<$t, $t> for $t {
+            #[inline]
+            fn solve(&self, b: &$t) -> Result<$t, Error> {
+                if *self == 0.0 {
+                    return Err(SolveError {}.into());
+                }
+                Ok(b / self)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = 4 as $t;
+                    let b = 2 as $t;
+                    let res = <$t as ArgminSolve<$t, $t>>::solve(&a, &b).unwrap();
+                    assert!(((res - 0.5) as f64).abs() < 0.000001);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_zero_ $t>]() {
+                    let a = 0 as $t;
+                    let b = 2 as $t;
+                    let err = <$t as ArgminSolve<$t, $t>>::solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                    assert_eq!(format!("{}", err), "Division by zero");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,76 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSqrt;
+use num_complex::Complex;
+
+macro_rules! make_sqrt {
+    ($t:ty) => {
+        impl ArgminSqrt for $t {
+            #[inline]
+            fn sqrt(&self) -> $t {
+                <$t>::sqrt(*self)
+            }
+        }
+    };
+}
+
+macro_rules! make_sqrt_complex {
+    ($t:ty) => {
+        impl ArgminSqrt for $t {
+            #[inline]
+            fn sqrt(&self) -> $t {
+                (*self).sqrt()
+            }
+        }
+    };
+}
+
+make_sqrt!(f32);
+make_sqrt!(f64);
+make_sqrt_complex!(Complex<f32>);
+make_sqrt_complex!(Complex<f64>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sqrt_ $t>]() {
+                    let a = 16 as $t;
+                    let res = <$t as ArgminSqrt>::sqrt(&a);
+                    assert_relative_eq!(res as f64, 4 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    macro_rules! make_test_complex {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sqrt_complex_ $t>]() {
+                    let a = Complex::new(3 as $t, 4 as $t);
+                    let res = <Complex<$t> as ArgminSqrt>::sqrt(&a);
+                    assert_relative_eq!(res.re as f64, 2 as f64, epsilon = 1e-6);
+                    assert_relative_eq!(res.im as f64, 1 as f64, epsilon = 1e-6);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+
+    make_test_complex!(f32);
+    make_test_complex!(f64);
+}
@@ -0,0 +1,83 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSum;
+use num_complex::Complex;
+
+macro_rules! make_sum {
+    ($t:ty) => {
+        impl ArgminSum<$t> for $t {
+            #[inline]
+            fn sum(&self) -> $t {
+                *self
+            }
+        }
+
+        impl ArgminSum<Complex<$t>> for Complex<$t> {
+            #[inline]
+            fn sum(&self) -> Complex<$t> {
+                *self
+            }
+        }
+    };
+}
+
+make_sum!(isize);
+make_sum!(usize);
+make_sum!(i8);
+make_sum!(i16);
+make_sum!(i32);
+make_sum!(i64);
+make_sum!(u8);
+make_sum!(u16);
+make_sum!(u32);
+make_sum!(u64);
+make_sum!(f32);
+make_sum!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sum_ $t>]() {
+                    let a = 8 as $t;
+                    let res = <$t as ArgminSum<$t>>::sum(&a);
+                    assert_relative_eq!(res as f64, 8 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sum_complex_ $t>]() {
+                    let a = Complex::new(8 as $t, 4 as $t);
+                    let res = <Complex<$t> as ArgminSum<Complex<$t>>>::sum(&a);
+                    assert_relative_eq!(res.re as f64, 8 as f64, epsilon = f64::EPSILON);
+                    assert_relative_eq!(res.im as f64, 4 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
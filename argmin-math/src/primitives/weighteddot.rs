@@ -132,3 +132,33 @@ mod tests_nalgebra {
     make_test!(f32);
     make_test!(f64);
 }
+
+#[cfg(feature = "faer_all")]
+#[cfg(test)]
+mod tests_faer {
+    use super::*;
+    use faer::{col, mat};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_ $t>]() {
+                    let a = col![2 as $t, 1 as $t, 2 as $t];
+                    let b = col![1 as $t, 2 as $t, 1 as $t];
+                    let w = mat![
+                        [8 as $t, 1 as $t, 6 as $t],
+                        [3 as $t, 5 as $t, 7 as $t],
+                        [4 as $t, 9 as $t, 2 as $t]
+                    ];
+                    let res: $t = a.weighted_dot(&w, &b);
+                    assert!((((res - 100 as $t) as f64).abs()) < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
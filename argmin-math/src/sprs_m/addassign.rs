@@ -0,0 +1,71 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAddAssign;
+use sprs::CsMat;
+
+macro_rules! make_addassign {
+    ($t:ty) => {
+        impl ArgminAddAssign<CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn add_assign(&mut self, other: &CsMat<$t>) {
+                // The sparsity pattern of the result generally differs from the one of `self`
+                *self = &*self + other;
+            }
+        }
+    };
+}
+
+make_addassign!(f32);
+make_addassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_add_assign_mat_mat_ $t>]() {
+                    let mut a = TriMat::new((2, 3));
+                    a.add_triplet(0, 0, 1 as $t);
+                    a.add_triplet(1, 2, 4 as $t);
+                    let mut a: CsMat<$t> = a.to_csr();
+                    let mut b = TriMat::new((2, 3));
+                    b.add_triplet(0, 0, 2 as $t);
+                    b.add_triplet(1, 1, 3 as $t);
+                    let b: CsMat<$t> = b.to_csr();
+                    let target = a.to_dense() + b.to_dense();
+                    <CsMat<$t> as ArgminAddAssign<CsMat<$t>>>::add_assign(&mut a, &b);
+                    assert_eq!(a.shape(), (2, 3));
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            let val = a.get(i, j).copied().unwrap_or(0 as $t);
+                            assert!(((val - target[(i, j)]) as f64).abs() < f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_add_assign_mat_mat_panic_ $t>]() {
+                    let mut a: CsMat<$t> = CsMat::eye(2);
+                    let b: CsMat<$t> = CsMat::eye(3);
+                    <CsMat<$t> as ArgminAddAssign<CsMat<$t>>>::add_assign(&mut a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
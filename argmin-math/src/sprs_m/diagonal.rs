@@ -0,0 +1,52 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiagonal;
+use sprs::CsMat;
+
+macro_rules! make_diagonal {
+    ($t:ty) => {
+        impl ArgminDiagonal<Vec<$t>> for CsMat<$t> {
+            #[inline]
+            fn diagonal(&self) -> Vec<$t> {
+                (0..self.rows().min(self.cols()))
+                    .map(|i| self.get(i, i).copied().unwrap_or(0 as $t))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_diagonal!(f32);
+make_diagonal!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diagonal_ $t>]() {
+                    let mut a = TriMat::new((3, 3));
+                    a.add_triplet(0, 0, 1 as $t);
+                    a.add_triplet(0, 1, 2 as $t);
+                    a.add_triplet(2, 2, 3 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    let res = <CsMat<$t> as ArgminDiagonal<Vec<$t>>>::diagonal(&a);
+                    assert_eq!(res, vec![1 as $t, 0 as $t, 3 as $t]);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,130 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use sprs::CsMat;
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl ArgminDot<Vec<$t>, Vec<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &Vec<$t>) -> Vec<$t> {
+                assert_eq!(self.cols(), other.len());
+                let mut res = vec![0 as $t; self.rows()];
+                for (&v, (i, j)) in self.iter() {
+                    res[i] += v * other[j];
+                }
+                res
+            }
+        }
+
+        impl ArgminDot<CsMat<$t>, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn dot(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                assert_eq!(self.cols(), other.rows());
+                self * other
+            }
+        }
+    };
+}
+
+make_dot!(f32);
+make_dot!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mat_vec_ $t>]() {
+                    let mut a = TriMat::new((3, 2));
+                    a.add_triplet(0, 0, 1 as $t);
+                    a.add_triplet(0, 1, 2 as $t);
+                    a.add_triplet(2, 1, 3 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    let b = vec![4 as $t, 5 as $t];
+                    let res = <CsMat<$t> as ArgminDot<Vec<$t>, Vec<$t>>>::dot(&a, &b);
+                    let target = vec![14 as $t, 0 as $t, 15 as $t];
+                    assert_eq!(res.len(), 3);
+                    for i in 0..3 {
+                        assert!(((res[i] - target[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_vec_csc_ $t>]() {
+                    let mut a = TriMat::new((3, 2));
+                    a.add_triplet(0, 0, 1 as $t);
+                    a.add_triplet(0, 1, 2 as $t);
+                    a.add_triplet(2, 1, 3 as $t);
+                    let a: CsMat<$t> = a.to_csc();
+                    let b = vec![4 as $t, 5 as $t];
+                    let res = <CsMat<$t> as ArgminDot<Vec<$t>, Vec<$t>>>::dot(&a, &b);
+                    let target = vec![14 as $t, 0 as $t, 15 as $t];
+                    for i in 0..3 {
+                        assert!(((res[i] - target[i]) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mat_vec_panic_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(3);
+                    let b = vec![4 as $t, 5 as $t];
+                    <CsMat<$t> as ArgminDot<Vec<$t>, Vec<$t>>>::dot(&a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_ $t>]() {
+                    let mut a = TriMat::new((2, 3));
+                    a.add_triplet(0, 0, 1 as $t);
+                    a.add_triplet(0, 2, 2 as $t);
+                    a.add_triplet(1, 1, 3 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    let mut b = TriMat::new((3, 2));
+                    b.add_triplet(0, 1, 4 as $t);
+                    b.add_triplet(1, 0, 5 as $t);
+                    b.add_triplet(2, 0, 6 as $t);
+                    let b: CsMat<$t> = b.to_csr();
+                    let res = <CsMat<$t> as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(&a, &b);
+                    let target = [[12 as $t, 4 as $t], [15 as $t, 0 as $t]];
+                    assert_eq!(res.shape(), (2, 2));
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let val = res.get(i, j).copied().unwrap_or(0 as $t);
+                            assert!(((val - target[i][j]) as f64).abs() < std::f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mat_mat_panic_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(3);
+                    let b: CsMat<$t> = CsMat::eye(2);
+                    <CsMat<$t> as ArgminDot<CsMat<$t>, CsMat<$t>>>::dot(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,73 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminEye;
+use sprs::CsMat;
+
+macro_rules! make_eye {
+    ($t:ty) => {
+        impl ArgminEye for CsMat<$t> {
+            #[inline]
+            fn eye_like(&self) -> CsMat<$t> {
+                assert_eq!(self.rows(), self.cols());
+                CsMat::eye(self.rows())
+            }
+
+            #[inline]
+            fn eye(n: usize) -> CsMat<$t> {
+                CsMat::eye(n)
+            }
+        }
+    };
+}
+
+make_eye!(f32);
+make_eye!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_eye_ $t>]() {
+                    let e: CsMat<$t> = <CsMat<$t> as ArgminEye>::eye(3);
+                    assert_eq!(e.shape(), (3, 3));
+                    assert_eq!(e.nnz(), 3);
+                    for i in 0..3 {
+                        assert!(((e.get(i, i).unwrap() - 1 as $t) as f64).abs() < std::f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_eye_like_ $t>]() {
+                    let a: CsMat<$t> = CsMat::zero((2, 2));
+                    let e = <CsMat<$t> as ArgminEye>::eye_like(&a);
+                    assert_eq!(e.shape(), (2, 2));
+                    assert_eq!(e.nnz(), 2);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_eye_like_panic_ $t>]() {
+                    let a: CsMat<$t> = CsMat::zero((2, 3));
+                    let _: CsMat<$t> = a.eye_like();
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
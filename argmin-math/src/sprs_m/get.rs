@@ -0,0 +1,59 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminGet;
+use sprs::CsMat;
+
+macro_rules! make_get {
+    ($t:ty) => {
+        impl ArgminGet<(usize, usize), $t> for CsMat<$t> {
+            /// Returns zero for entries which are not stored
+            #[inline]
+            fn get_elem(&self, (i, j): (usize, usize)) -> $t {
+                assert!(i < self.rows() && j < self.cols());
+                self.get(i, j).copied().unwrap_or(0 as $t)
+            }
+        }
+    };
+}
+
+make_get!(f32);
+make_get!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_get_ $t>]() {
+                    let mut a = TriMat::new((2, 2));
+                    a.add_triplet(0, 1, 4 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    assert!((((a.get_elem((0, 1)) as f64) - 4.0).abs()) < f64::EPSILON);
+                    assert!((((a.get_elem((1, 0)) as f64) - 0.0).abs()) < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_get_panic_ $t>]() {
+                    let a: CsMat<$t> = CsMat::eye(2);
+                    a.get_elem((2, 0));
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
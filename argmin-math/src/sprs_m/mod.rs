@@ -0,0 +1,38 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod addassign;
+mod diagonal;
+mod dot;
+mod eye;
+mod get;
+mod mul;
+mod mulassign;
+mod random;
+mod set;
+mod solve;
+mod sub;
+mod subassign;
+mod transpose;
+mod zero;
+
+pub use add::*;
+pub use addassign::*;
+pub use diagonal::*;
+pub use dot::*;
+pub use eye::*;
+pub use get::*;
+pub use mul::*;
+pub use mulassign::*;
+pub use random::*;
+pub use set::*;
+pub use solve::*;
+pub use sub::*;
+pub use subassign::*;
+pub use transpose::*;
+pub use zero::*;
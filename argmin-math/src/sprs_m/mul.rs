@@ -0,0 +1,74 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use sprs::CsMat;
+
+macro_rules! make_mul {
+    ($t:ty) => {
+        impl ArgminMul<$t, CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> CsMat<$t> {
+                self.map(|a| a * other)
+            }
+        }
+
+        impl ArgminMul<CsMat<$t>, CsMat<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &CsMat<$t>) -> CsMat<$t> {
+                other.map(|a| a * self)
+            }
+        }
+    };
+}
+
+make_mul!(f32);
+make_mul!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_mat_scalar_ $t>]() {
+                    let mut a = TriMat::new((2, 2));
+                    a.add_triplet(0, 1, 2 as $t);
+                    a.add_triplet(1, 0, 3 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    let b = 2 as $t;
+                    let res = <CsMat<$t> as ArgminMul<$t, CsMat<$t>>>::mul(&a, &b);
+                    assert_eq!(res.nnz(), 2);
+                    assert!(((res.get(0, 1).unwrap() - 4 as $t) as f64).abs() < std::f64::EPSILON);
+                    assert!(((res.get(1, 0).unwrap() - 6 as $t) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_scalar_mat_ $t>]() {
+                    let mut a = TriMat::new((2, 2));
+                    a.add_triplet(0, 1, 2 as $t);
+                    a.add_triplet(1, 0, 3 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    let b = 2 as $t;
+                    let res = <$t as ArgminMul<CsMat<$t>, CsMat<$t>>>::mul(&b, &a);
+                    assert_eq!(res.nnz(), 2);
+                    assert!(((res.get(0, 1).unwrap() - 4 as $t) as f64).abs() < std::f64::EPSILON);
+                    assert!(((res.get(1, 0).unwrap() - 6 as $t) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,52 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMulAssign;
+use sprs::CsMat;
+
+macro_rules! make_mulassign {
+    ($t:ty) => {
+        impl ArgminMulAssign<$t> for CsMat<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                self.map_inplace(|a| a * other);
+            }
+        }
+    };
+}
+
+make_mulassign!(f32);
+make_mulassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_assign_mat_scalar_ $t>]() {
+                    let mut a = TriMat::new((2, 2));
+                    a.add_triplet(0, 1, 2 as $t);
+                    a.add_triplet(1, 0, 3 as $t);
+                    let mut a: CsMat<$t> = a.to_csr();
+                    let b = 2 as $t;
+                    <CsMat<$t> as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    assert_eq!(a.nnz(), 2);
+                    assert!(((a.get(0, 1).unwrap() - 4 as $t) as f64).abs() < std::f64::EPSILON);
+                    assert!(((a.get(1, 0).unwrap() - 6 as $t) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
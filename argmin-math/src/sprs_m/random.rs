@@ -0,0 +1,118 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use rand::Rng;
+
+use crate::ArgminRandom;
+use sprs::{CsMat, TriMat};
+use std::collections::BTreeMap;
+
+macro_rules! make_random {
+    ($t:ty) => {
+        impl ArgminRandom for CsMat<$t> {
+            /// Only the entries stored in `min` or `max` are drawn at random, all other entries
+            /// remain zero. The result has the same storage order as `min`.
+            fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> CsMat<$t> {
+                assert_eq!(min.shape(), max.shape());
+                let mut bounds: BTreeMap<(usize, usize), ($t, $t)> = BTreeMap::new();
+                for (&v, (i, j)) in min.iter() {
+                    bounds.entry((i, j)).or_insert((0.0, 0.0)).0 = v;
+                }
+                for (&v, (i, j)) in max.iter() {
+                    bounds.entry((i, j)).or_insert((0.0, 0.0)).1 = v;
+                }
+                let mut res = TriMat::new(min.shape());
+                for ((i, j), (a, b)) in bounds {
+                    // We do want to know if a and b are *exactly* the same.
+                    #[allow(clippy::float_cmp)]
+                    let v = if a == b {
+                        a
+                    } else if a < b {
+                        rng.gen_range(a..b)
+                    } else {
+                        rng.gen_range(b..a)
+                    };
+                    res.add_triplet(i, j, v);
+                }
+                if min.is_csr() {
+                    res.to_csr()
+                } else {
+                    res.to_csc()
+                }
+            }
+        }
+    };
+}
+
+make_random!(f32);
+make_random!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_random_mat_ $t>]() {
+                    let dense_a = [[1 as $t, 2 as $t, 4 as $t], [2 as $t, 3 as $t, 5 as $t]];
+                    let dense_b = [[2 as $t, 3 as $t, 5 as $t], [3 as $t, 3 as $t, 4 as $t]];
+                    let mut a = TriMat::new((2, 3));
+                    let mut b = TriMat::new((2, 3));
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            a.add_triplet(i, j, dense_a[i][j]);
+                            b.add_triplet(i, j, dense_b[i][j]);
+                        }
+                    }
+                    let (a, b): (CsMat<$t>, CsMat<$t>) = (a.to_csr(), b.to_csr());
+                    let mut rng = rand::thread_rng();
+                    let random = CsMat::<$t>::rand_from_range(&a, &b, &mut rng);
+                    assert_eq!(random.shape(), (2, 3));
+                    assert!(random.is_csr());
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            let (lo, hi) = (*a.get(i, j).unwrap(), *b.get(i, j).unwrap());
+                            let v = *random.get(i, j).unwrap();
+                            assert!(v >= lo.min(hi));
+                            assert!(v <= lo.max(hi));
+                        }
+                    }
+                    assert!(((random.get(1, 1).unwrap() - 3 as $t) as f64).abs() < f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_random_mat_sparsity_ $t>]() {
+                    let mut a = TriMat::new((3, 3));
+                    a.add_triplet(0, 0, -1 as $t);
+                    a.add_triplet(2, 1, -1 as $t);
+                    let mut b = TriMat::new((3, 3));
+                    b.add_triplet(0, 0, 1 as $t);
+                    b.add_triplet(1, 2, 1 as $t);
+                    let (a, b): (CsMat<$t>, CsMat<$t>) = (a.to_csc(), b.to_csc());
+                    let mut rng = rand::thread_rng();
+                    let random = CsMat::<$t>::rand_from_range(&a, &b, &mut rng);
+                    assert!(random.is_csc());
+                    // Only the union of the stored entries of both bounds is populated
+                    assert_eq!(random.nnz(), 3);
+                    assert!(random.get(1, 1).is_none());
+                    let v = *random.get(2, 1).unwrap();
+                    assert!(v >= -1 as $t && v <= 0 as $t);
+                    let v = *random.get(1, 2).unwrap();
+                    assert!(v >= 0 as $t && v <= 1 as $t);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,49 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSet;
+use sprs::CsMat;
+
+macro_rules! make_set {
+    ($t:ty) => {
+        impl ArgminSet<(usize, usize), $t> for CsMat<$t> {
+            /// Inserts the entry if it is not stored yet
+            #[inline]
+            fn set_elem(&mut self, (i, j): (usize, usize), value: $t) {
+                self.insert(i, j, value);
+            }
+        }
+    };
+}
+
+make_set!(f32);
+make_set!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_set_ $t>]() {
+                    let mut a: CsMat<$t> = CsMat::eye(2);
+                    a.set_elem((0, 0), 3 as $t);
+                    a.set_elem((1, 0), 2 as $t);
+                    assert_eq!(a.nnz(), 3);
+                    assert!((((*a.get(0, 0).unwrap() as f64) - 3.0).abs()) < f64::EPSILON);
+                    assert!((((*a.get(1, 0).unwrap() as f64) - 2.0).abs()) < f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
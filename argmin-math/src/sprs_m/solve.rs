@@ -0,0 +1,231 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminSolve, Error};
+use sprs::CsMat;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveError;
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveDimensionError;
+
+impl fmt::Display for SolveDimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Matrix must be square with as many rows as the right-hand side has elements"
+        )
+    }
+}
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<Vec<$t>, Vec<$t>> for CsMat<$t> {
+            /// Sparse Gaussian elimination with partial pivoting. Only the nonzero entries (and
+            /// the fill-in) are stored, the matrix is never densified.
+            fn solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                let n = self.rows();
+                if self.cols() != n || b.len() != n {
+                    return Err(SolveDimensionError {}.into());
+                }
+                // Pivots below this (relative) tolerance are treated as zero
+                let max_abs = self.iter().fold(0.0 as $t, |acc, (&v, _)| acc.max(v.abs()));
+                let tol = n as $t * <$t>::EPSILON * max_abs;
+                // Rows of the matrix and, for each column, the rows with a nonzero in it
+                let mut rows: Vec<BTreeMap<usize, $t>> = vec![BTreeMap::new(); n];
+                let mut cols: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+                for (&v, (i, j)) in self.iter() {
+                    if v != 0.0 {
+                        rows[i].insert(j, v);
+                        cols[j].insert(i);
+                    }
+                }
+                let mut rhs = b.clone();
+                let mut eliminated = vec![false; n];
+                let mut pivots = Vec::with_capacity(n);
+                for k in 0..n {
+                    let p = cols[k]
+                        .iter()
+                        .copied()
+                        .filter(|&i| !eliminated[i])
+                        .max_by(|&i, &j| rows[i][&k].abs().total_cmp(&rows[j][&k].abs()))
+                        .ok_or(SolveError {})?;
+                    let d = rows[p][&k];
+                    if d.abs() <= tol || d.is_nan() {
+                        return Err(SolveError {}.into());
+                    }
+                    eliminated[p] = true;
+                    pivots.push(p);
+                    let pivot_row: Vec<(usize, $t)> =
+                        rows[p].range(k + 1..).map(|(&j, &v)| (j, v)).collect();
+                    let targets: Vec<usize> = cols[k]
+                        .iter()
+                        .copied()
+                        .filter(|&i| !eliminated[i])
+                        .collect();
+                    for i in targets {
+                        let factor = rows[i].remove(&k).unwrap() / d;
+                        cols[k].remove(&i);
+                        for &(j, v) in pivot_row.iter() {
+                            *rows[i].entry(j).or_insert(0.0) -= factor * v;
+                            cols[j].insert(i);
+                        }
+                        rhs[i] -= factor * rhs[p];
+                    }
+                }
+                // Back substitution; the k-th pivot row holds the k-th row of the triangular factor
+                let mut x = vec![0.0; n];
+                for (k, &p) in pivots.iter().enumerate().rev() {
+                    let s: $t = rows[p].range(k + 1..).map(|(&j, &v)| v * x[j]).sum();
+                    x[k] = (rhs[p] - s) / rows[p][&k];
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                        vec![7 as $t, 8 as $t, 10 as $t],
+                    ];
+                    let mut m = TriMat::new((3, 3));
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            m.add_triplet(i, j, a[i][j]);
+                        }
+                    }
+                    let m: CsMat<$t> = m.to_csr();
+                    let b = vec![6 as $t, 15 as $t, 25 as $t];
+                    let x = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&m, &b).unwrap();
+                    for i in 0..3 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_sparse_ $t>]() {
+                    // Tridiagonal matrix with a zero on the diagonal which requires pivoting
+                    let mut m = TriMat::new((4, 4));
+                    m.add_triplet(0, 1, 1 as $t);
+                    m.add_triplet(1, 0, 1 as $t);
+                    m.add_triplet(1, 1, 2 as $t);
+                    m.add_triplet(1, 2, 1 as $t);
+                    m.add_triplet(2, 1, 1 as $t);
+                    m.add_triplet(2, 2, 2 as $t);
+                    m.add_triplet(2, 3, 1 as $t);
+                    m.add_triplet(3, 2, 1 as $t);
+                    m.add_triplet(3, 3, 2 as $t);
+                    let m: CsMat<$t> = m.to_csc();
+                    let target = vec![1 as $t, 2 as $t, 3 as $t, 4 as $t];
+                    let b = crate::ArgminDot::<Vec<$t>, Vec<$t>>::dot(&m, &target);
+                    let x = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&m, &b).unwrap();
+                    for i in 0..4 {
+                        assert!((((x[i] - target[i]) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let mut m = TriMat::new((3, 3));
+                    m.add_triplet(0, 0, 1 as $t);
+                    m.add_triplet(1, 0, 2 as $t);
+                    m.add_triplet(2, 2, 1 as $t);
+                    let m: CsMat<$t> = m.to_csr();
+                    let b = vec![1 as $t, 2 as $t, 3 as $t];
+                    let err = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&m, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_near_singular_ $t>]() {
+                    // Exact arithmetic gives a zero pivot, rounding leaves a tiny one behind
+                    let mut m = TriMat::new((3, 3));
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            m.add_triplet(i, j, (3 * i + j + 1) as $t / 10 as $t);
+                        }
+                    }
+                    let m: CsMat<$t> = m.to_csr();
+                    let b = vec![1 as $t, 2 as $t, 3 as $t];
+                    let err = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&m, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_error_dimension_ $t>]() {
+                    let mut m = TriMat::new((2, 3));
+                    m.add_triplet(0, 0, 1 as $t);
+                    m.add_triplet(1, 1, 1 as $t);
+                    let m: CsMat<$t> = m.to_csr();
+                    let b = vec![1 as $t, 2 as $t];
+                    let err = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&m, &b)
+                        .unwrap_err()
+                        .downcast::<SolveDimensionError>()
+                        .unwrap();
+                    assert_eq!(err, SolveDimensionError {});
+
+                    let mut m = TriMat::new((2, 2));
+                    m.add_triplet(0, 0, 1 as $t);
+                    m.add_triplet(1, 1, 1 as $t);
+                    let m: CsMat<$t> = m.to_csr();
+                    let b = vec![1 as $t, 2 as $t, 3 as $t];
+                    let err = <CsMat<$t> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&m, &b)
+                        .unwrap_err()
+                        .downcast::<SolveDimensionError>()
+                        .unwrap();
+                    assert_eq!(
+                        format!("{}", err),
+                        "Matrix must be square with as many rows as the right-hand side has elements"
+                    );
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
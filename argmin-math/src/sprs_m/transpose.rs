@@ -0,0 +1,53 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminTranspose;
+use sprs::CsMat;
+
+macro_rules! make_transpose {
+    ($t:ty) => {
+        impl ArgminTranspose<CsMat<$t>> for CsMat<$t> {
+            #[inline]
+            fn t(self) -> CsMat<$t> {
+                // Reinterprets the storage (CSR <-> CSC), no data is copied
+                self.transpose_into()
+            }
+        }
+    };
+}
+
+make_transpose!(f32);
+make_transpose!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+    use sprs::TriMat;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_transpose_ $t>]() {
+                    let mut a = TriMat::new((2, 3));
+                    a.add_triplet(0, 1, 2 as $t);
+                    a.add_triplet(1, 2, 3 as $t);
+                    let a: CsMat<$t> = a.to_csr();
+                    let res = <CsMat<$t> as ArgminTranspose<CsMat<$t>>>::t(a);
+                    assert_eq!(res.shape(), (3, 2));
+                    assert_eq!(res.nnz(), 2);
+                    assert!(((res.get(1, 0).unwrap() - 2 as $t) as f64).abs() < std::f64::EPSILON);
+                    assert!(((res.get(2, 1).unwrap() - 3 as $t) as f64).abs() < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
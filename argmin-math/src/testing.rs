@@ -0,0 +1,751 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Conformance tests for math backends
+//!
+//! This module provides checks which verify that a type implements the traits of this crate
+//! consistently, independent of the concrete values involved. Each check draws [`SAMPLES`]
+//! random inputs from a seeded random number generator (via [`ArgminRandom`]) and asserts
+//! algebraic laws such as `(x + y) - y == x`, `x . y == y . x`, `eye * x == x` or
+//! `inv(A) * A * x == x` up to a tolerance of `sqrt(epsilon)` relative to the magnitude of the
+//! involved quantities. Matrices are compared through their action on random vectors, therefore
+//! only matrix-vector products are required and no matrix norm.
+//!
+//! The checks are plain functions and can be called from any test. The
+//! [`conformance_tests`](crate::conformance_tests) macro generates one `#[test]` per check for a
+//! given backend:
+//!
+//! ```
+//! argmin_math::conformance_tests!(
+//!     vec_f64,
+//!     scalar: f64,
+//!     vector: Vec<f64> = (vec![-10.0; 4], vec![10.0; 4]),
+//!     matrix: Vec<Vec<f64>> = (vec![vec![-1.0; 4]; 4], vec![vec![1.0; 4]; 4]),
+//!     checks: [vector_space, in_place, norms, elementwise, matrix, inverse, solve, cholesky, lu],
+//! );
+//! ```
+//!
+//! The bounds determine the shape of the random inputs as well as the range of their elements.
+//! The matrix checks require square matrices whose dimension matches the length of the vectors.
+//! The `matrix` line can be omitted if only vector checks are requested.
+//!
+//! Only real valued scalars (`f32`, `f64` or any other type implementing
+//! [`num_traits::Float`]) are supported.
+
+use crate::{
+    ArgminAbs, ArgminAdd, ArgminAddAssign, ArgminCholesky, ArgminCholeskySolve, ArgminClamp,
+    ArgminDot, ArgminExp, ArgminEye, ArgminInv, ArgminL1Norm, ArgminL2Norm, ArgminLInfNorm,
+    ArgminLU, ArgminLen, ArgminMul, ArgminMulAssign, ArgminPowi, ArgminRandom, ArgminScaledAdd,
+    ArgminScaledAddAssign, ArgminScaledSub, ArgminScaledSubAssign, ArgminSolve, ArgminSqrt,
+    ArgminSub, ArgminSubAssign, ArgminSum, ArgminTranspose, ArgminZeroLike,
+};
+use num_traits::Float;
+use rand::{rngs::StdRng, SeedableRng};
+use std::fmt::Debug;
+
+/// Number of random samples drawn per check
+pub const SAMPLES: usize = 32;
+
+/// Seed of the random number generator used by the checks
+pub const SEED: u64 = 0x5eed_a4c1;
+
+fn tolerance<F: Float>() -> F {
+    F::epsilon().sqrt()
+}
+
+fn cast<F: Float>(x: f64) -> F {
+    F::from(x).unwrap()
+}
+
+fn random_scalar<F: Float + ArgminRandom>(rng: &mut StdRng) -> F {
+    F::rand_from_range(&cast(-2.0), &cast(2.0), rng)
+}
+
+fn assert_scalar_close<F: Float + Debug>(law: &str, a: F, b: F) {
+    let tol = tolerance::<F>() * (F::one() + a.abs() + b.abs());
+    assert!(
+        (a - b).abs() <= tol,
+        "{law}: {a:?} and {b:?} differ by more than {tol:?}"
+    );
+}
+
+fn assert_vector_close<F, V>(law: &str, a: &V, b: &V)
+where
+    F: Float + Debug,
+    V: ArgminSub<V, V> + ArgminL2Norm<F>,
+{
+    let diff = <V as ArgminSub<V, V>>::sub(a, b).l2_norm();
+    let tol = tolerance::<F>() * (F::one() + a.l2_norm() + b.l2_norm());
+    assert!(
+        diff <= tol,
+        "{law}: distance {diff:?} exceeds tolerance {tol:?}"
+    );
+}
+
+/// Returns the symmetric positive definite (and therefore invertible) matrix `B^T * B + I`
+fn well_conditioned<M>(b: &M) -> M
+where
+    M: Clone + ArgminTranspose<M> + ArgminDot<M, M> + ArgminAdd<M, M> + ArgminEye,
+{
+    let btb: M = b.clone().t().dot(b);
+    <M as ArgminAdd<M, M>>::add(&btb, &b.eye_like())
+}
+
+/// Checks the vector space laws of addition, subtraction and multiplication with a scalar as well
+/// as the properties of the dot product.
+pub fn check_vector_space<F, V>(lower: &V, upper: &V)
+where
+    F: Float + ArgminRandom + Debug,
+    V: ArgminRandom
+        + ArgminAdd<V, V>
+        + ArgminSub<V, V>
+        + ArgminMul<F, V>
+        + ArgminDot<V, F>
+        + ArgminZeroLike
+        + ArgminScaledAdd<V, F, V>
+        + ArgminScaledSub<V, F, V>
+        + ArgminL2Norm<F>,
+{
+    let add = <V as ArgminAdd<V, V>>::add;
+    let sub = <V as ArgminSub<V, V>>::sub;
+    let mul = <V as ArgminMul<F, V>>::mul;
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(lower, upper, &mut rng);
+        let y = V::rand_from_range(lower, upper, &mut rng);
+        let z = V::rand_from_range(lower, upper, &mut rng);
+        let a: F = random_scalar(&mut rng);
+        let b: F = random_scalar(&mut rng);
+
+        assert_vector_close("(x + y) - y == x", &sub(&add(&x, &y), &y), &x);
+        assert_vector_close("x + y == y + x", &add(&x, &y), &add(&y, &x));
+        assert_vector_close(
+            "(x + y) + z == x + (y + z)",
+            &add(&add(&x, &y), &z),
+            &add(&x, &add(&y, &z)),
+        );
+        assert_vector_close("x + 0 == x", &add(&x, &x.zero_like()), &x);
+        assert_vector_close("x - x == 0", &sub(&x, &x), &x.zero_like());
+        assert_vector_close(
+            "a * (x + y) == a * x + a * y",
+            &mul(&add(&x, &y), &a),
+            &add(&mul(&x, &a), &mul(&y, &a)),
+        );
+        assert_vector_close(
+            "(a + b) * x == a * x + b * x",
+            &mul(&x, &(a + b)),
+            &add(&mul(&x, &a), &mul(&x, &b)),
+        );
+        assert_vector_close(
+            "x.scaled_add(a, y) == x + a * y",
+            &x.scaled_add(&a, &y),
+            &add(&x, &mul(&y, &a)),
+        );
+        assert_vector_close(
+            "x.scaled_sub(a, y) == x - a * y",
+            &x.scaled_sub(&a, &y),
+            &sub(&x, &mul(&y, &a)),
+        );
+        assert_scalar_close("x . y == y . x", x.dot(&y), y.dot(&x));
+        assert_scalar_close(
+            "x . (a * y) == a * (x . y)",
+            x.dot(&mul(&y, &a)),
+            a * x.dot(&y),
+        );
+        assert_scalar_close(
+            "x . (y + z) == x . y + x . z",
+            x.dot(&add(&y, &z)),
+            x.dot(&y) + x.dot(&z),
+        );
+        assert_scalar_close("x . x == |x|^2", x.dot(&x), x.l2_norm().powi(2));
+    }
+}
+
+/// Checks that the in-place operations give the same results as their allocating counterparts.
+pub fn check_in_place<F, V>(lower: &V, upper: &V)
+where
+    F: Float + ArgminRandom + Debug,
+    V: Clone
+        + ArgminRandom
+        + ArgminAdd<V, V>
+        + ArgminSub<V, V>
+        + ArgminMul<F, V>
+        + ArgminMul<V, V>
+        + ArgminScaledAdd<V, F, V>
+        + ArgminScaledSub<V, F, V>
+        + ArgminAddAssign<V>
+        + ArgminSubAssign<V>
+        + ArgminMulAssign<F>
+        + ArgminMulAssign<V>
+        + ArgminScaledAddAssign<V, F>
+        + ArgminScaledSubAssign<V, F>
+        + ArgminL2Norm<F>,
+{
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(lower, upper, &mut rng);
+        let y = V::rand_from_range(lower, upper, &mut rng);
+        let a: F = random_scalar(&mut rng);
+
+        let mut r = x.clone();
+        <V as ArgminAddAssign<V>>::add_assign(&mut r, &y);
+        assert_vector_close("x += y", &r, &<V as ArgminAdd<V, V>>::add(&x, &y));
+
+        let mut r = x.clone();
+        <V as ArgminSubAssign<V>>::sub_assign(&mut r, &y);
+        assert_vector_close("x -= y", &r, &<V as ArgminSub<V, V>>::sub(&x, &y));
+
+        let mut r = x.clone();
+        <V as ArgminMulAssign<F>>::mul_assign(&mut r, &a);
+        assert_vector_close("x *= a", &r, &<V as ArgminMul<F, V>>::mul(&x, &a));
+
+        let mut r = x.clone();
+        <V as ArgminMulAssign<V>>::mul_assign(&mut r, &y);
+        assert_vector_close("x *= y", &r, &<V as ArgminMul<V, V>>::mul(&x, &y));
+
+        let mut r = x.clone();
+        r.scaled_add_assign(&a, &y);
+        assert_vector_close("x += a * y", &r, &x.scaled_add(&a, &y));
+
+        let mut r = x.clone();
+        r.scaled_sub_assign(&a, &y);
+        assert_vector_close("x -= a * y", &r, &x.scaled_sub(&a, &y));
+    }
+}
+
+/// Checks that the l1-, l2- and l-infinity-norms are norms and ordered as expected
+/// (`|x|_inf <= |x|_2 <= |x|_1`), and that NaN entries propagate to all norms.
+pub fn check_norms<F, V>(lower: &V, upper: &V)
+where
+    F: Float + ArgminRandom + Debug,
+    V: ArgminRandom
+        + ArgminAdd<V, V>
+        + ArgminMul<F, V>
+        + ArgminL1Norm<F>
+        + ArgminL2Norm<F>
+        + ArgminLInfNorm<F>,
+{
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(lower, upper, &mut rng);
+        let y = V::rand_from_range(lower, upper, &mut rng);
+        let a: F = random_scalar(&mut rng);
+        let sum = <V as ArgminAdd<V, V>>::add(&x, &y);
+        let scaled = <V as ArgminMul<F, V>>::mul(&x, &a);
+        let tol = tolerance::<F>() * (F::one() + x.l1_norm() + y.l1_norm());
+
+        // (name, |x|, |y|, |x + y|, |a * x|)
+        let norms = [
+            (
+                "l1",
+                x.l1_norm(),
+                y.l1_norm(),
+                sum.l1_norm(),
+                scaled.l1_norm(),
+            ),
+            (
+                "l2",
+                x.l2_norm(),
+                y.l2_norm(),
+                sum.l2_norm(),
+                scaled.l2_norm(),
+            ),
+            (
+                "linf",
+                x.linf_norm(),
+                y.linf_norm(),
+                sum.linf_norm(),
+                scaled.linf_norm(),
+            ),
+        ];
+        for (name, nx, ny, nsum, nscaled) in norms {
+            assert!(nx >= F::zero(), "{name}: |x| >= 0");
+            assert!(nsum <= nx + ny + tol, "{name}: |x + y| <= |x| + |y|");
+            assert_scalar_close(
+                &format!("{name}: |a * x| == |a| * |x|"),
+                nscaled,
+                a.abs() * nx,
+            );
+        }
+        assert!(x.linf_norm() <= x.l2_norm() + tol, "|x|_inf <= |x|_2");
+        assert!(x.l2_norm() <= x.l1_norm() + tol, "|x|_2 <= |x|_1");
+
+        let nan = <V as ArgminMul<F, V>>::mul(&x, &F::nan());
+        assert!(nan.l1_norm().is_nan(), "l1: |NaN| is NaN");
+        assert!(nan.l2_norm().is_nan(), "l2: |NaN| is NaN");
+        assert!(nan.linf_norm().is_nan(), "linf: |NaN| is NaN");
+    }
+}
+
+/// Checks the elementwise operations (`abs`, `sqrt`, `exp`, `powi` and `clamp`) as well as `sum`
+/// and `len`.
+pub fn check_elementwise<F, V>(lower: &V, upper: &V)
+where
+    F: Float + ArgminRandom + Debug,
+    V: ArgminRandom
+        + ArgminAdd<V, V>
+        + ArgminSub<V, V>
+        + ArgminMul<V, V>
+        + ArgminZeroLike
+        + ArgminL2Norm<F>
+        + ArgminLInfNorm<F>
+        + ArgminAbs
+        + ArgminSqrt
+        + ArgminExp
+        + ArgminPowi
+        + ArgminClamp<F>
+        + ArgminSum<F>
+        + ArgminLen,
+{
+    let add = <V as ArgminAdd<V, V>>::add;
+    let mul = <V as ArgminMul<V, V>>::mul;
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(lower, upper, &mut rng);
+        let y = V::rand_from_range(lower, upper, &mut rng);
+        let c: F = random_scalar::<F>(&mut rng).abs();
+        let n: F = cast(x.len() as f64);
+
+        assert_vector_close("abs(abs(x)) == abs(x)", &x.abs().abs(), &x.abs());
+        assert_scalar_close(
+            "|abs(x)|_inf == |x|_inf",
+            x.abs().linf_norm(),
+            x.linf_norm(),
+        );
+        assert_vector_close(
+            "sqrt(abs(x))^2 == abs(x)",
+            &x.abs().sqrt().powi(2),
+            &x.abs(),
+        );
+        assert_vector_close("x^2 == x * x", &x.powi(2), &mul(&x, &x));
+        assert_vector_close("x^1 == x", &x.powi(1), &x);
+        assert_vector_close(
+            "exp(x + y) == exp(x) * exp(y)",
+            &add(&x, &y).exp(),
+            &mul(&x.exp(), &y.exp()),
+        );
+        assert_scalar_close("sum(exp(0)) == len(x)", x.zero_like().exp().sum(), n);
+        assert_scalar_close(
+            "sum(x + y) == sum(x) + sum(y)",
+            add(&x, &y).sum(),
+            x.sum() + y.sum(),
+        );
+        assert_eq!(add(&x, &y).len(), x.len(), "len(x + y) == len(x)");
+
+        let clamped = x.clamp(&-c, &c);
+        assert!(clamped.linf_norm() <= c, "|clamp(x, -c, c)|_inf <= c");
+        assert_vector_close(
+            "clamp(clamp(x, -c, c), -c, c) == clamp(x, -c, c)",
+            &clamped.clamp(&-c, &c),
+            &clamped,
+        );
+        let m = x.linf_norm();
+        assert_vector_close("clamp(x, -|x|_inf, |x|_inf) == x", &x.clamp(&-m, &m), &x);
+    }
+}
+
+/// Checks matrix-vector and matrix-matrix products, the identity matrix and the transpose.
+///
+/// Requires square matrices whose dimension matches the length of the vectors.
+pub fn check_matrix<F, V, M>(vlower: &V, vupper: &V, mlower: &M, mupper: &M)
+where
+    F: Float + ArgminRandom + Debug,
+    V: ArgminRandom
+        + ArgminAdd<V, V>
+        + ArgminSub<V, V>
+        + ArgminMul<F, V>
+        + ArgminDot<V, F>
+        + ArgminL2Norm<F>
+        + ArgminLen,
+    M: Clone
+        + ArgminRandom
+        + ArgminAdd<M, M>
+        + ArgminMul<F, M>
+        + ArgminDot<V, V>
+        + ArgminDot<M, M>
+        + ArgminTranspose<M>
+        + ArgminEye,
+{
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(vlower, vupper, &mut rng);
+        let y = V::rand_from_range(vlower, vupper, &mut rng);
+        let a = M::rand_from_range(mlower, mupper, &mut rng);
+        let b = M::rand_from_range(mlower, mupper, &mut rng);
+        let s: F = random_scalar(&mut rng);
+        let ax: V = a.dot(&x);
+        let at: M = a.clone().t();
+
+        assert_vector_close("eye_like(A) * x == x", &a.eye_like().dot(&x), &x);
+        assert_vector_close("eye(n) * x == x", &M::eye(x.len()).dot(&x), &x);
+        assert_vector_close("(A^T)^T * x == A * x", &at.clone().t().dot(&x), &ax);
+        assert_scalar_close(
+            "x . (A * y) == (A^T * x) . y",
+            x.dot(&a.dot(&y)),
+            at.dot(&x).dot(&y),
+        );
+        assert_vector_close(
+            "(A + B) * x == A * x + B * x",
+            &<M as ArgminAdd<M, M>>::add(&a, &b).dot(&x),
+            &<V as ArgminAdd<V, V>>::add(&ax, &b.dot(&x)),
+        );
+        let ab: M = a.dot(&b);
+        assert_vector_close(
+            "(A * B) * x == A * (B * x)",
+            &ab.dot(&x),
+            &a.dot(&b.dot(&x)),
+        );
+        assert_vector_close(
+            "(s * A) * x == s * (A * x)",
+            &<M as ArgminMul<F, M>>::mul(&a, &s).dot(&x),
+            &<V as ArgminMul<F, V>>::mul(&ax, &s),
+        );
+    }
+}
+
+/// Checks that `inv(A) * (A * x) == x` and `A * (inv(A) * x) == x` for invertible `A`.
+pub fn check_inverse<F, V, M>(vlower: &V, vupper: &V, mlower: &M, mupper: &M)
+where
+    F: Float + Debug,
+    V: ArgminRandom + ArgminSub<V, V> + ArgminL2Norm<F>,
+    M: Clone
+        + ArgminRandom
+        + ArgminAdd<M, M>
+        + ArgminDot<V, V>
+        + ArgminDot<M, M>
+        + ArgminTranspose<M>
+        + ArgminEye
+        + ArgminInv<M>,
+{
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(vlower, vupper, &mut rng);
+        let a = well_conditioned(&M::rand_from_range(mlower, mupper, &mut rng));
+        let a_inv = a.inv().expect("inverse of a well conditioned matrix");
+
+        assert_vector_close("inv(A) * (A * x) == x", &a_inv.dot(&a.dot(&x)), &x);
+        assert_vector_close("A * (inv(A) * x) == x", &a.dot(&a_inv.dot(&x)), &x);
+    }
+}
+
+/// Checks that `solve(A, A * x) == x` for invertible `A`.
+pub fn check_solve<F, V, M>(vlower: &V, vupper: &V, mlower: &M, mupper: &M)
+where
+    F: Float + Debug,
+    V: ArgminRandom + ArgminSub<V, V> + ArgminL2Norm<F>,
+    M: Clone
+        + ArgminRandom
+        + ArgminAdd<M, M>
+        + ArgminDot<V, V>
+        + ArgminDot<M, M>
+        + ArgminTranspose<M>
+        + ArgminEye
+        + ArgminSolve<V, V>,
+{
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(vlower, vupper, &mut rng);
+        let a = well_conditioned(&M::rand_from_range(mlower, mupper, &mut rng));
+        let x_solved = a
+            .solve(&a.dot(&x))
+            .expect("solve with a well conditioned matrix");
+
+        assert_vector_close("solve(A, A * x) == x", &x_solved, &x);
+    }
+}
+
+/// Checks that the Cholesky factor `L` of a symmetric positive definite matrix `A` satisfies
+/// `L * (L^T * x) == A * x` and that solving with it recovers `x` from `A * x`.
+pub fn check_cholesky<F, V, M>(vlower: &V, vupper: &V, mlower: &M, mupper: &M)
+where
+    F: Float + Debug,
+    V: ArgminRandom + ArgminSub<V, V> + ArgminL2Norm<F>,
+    M: Clone
+        + ArgminRandom
+        + ArgminAdd<M, M>
+        + ArgminDot<V, V>
+        + ArgminDot<M, M>
+        + ArgminTranspose<M>
+        + ArgminEye
+        + ArgminCholesky<M>
+        + ArgminCholeskySolve<V, V>,
+{
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(vlower, vupper, &mut rng);
+        let a = well_conditioned(&M::rand_from_range(mlower, mupper, &mut rng));
+        let l = a
+            .cholesky()
+            .expect("Cholesky decomposition of a positive definite matrix");
+        let lt: M = l.clone().t();
+
+        assert_vector_close("L * (L^T * x) == A * x", &l.dot(&lt.dot(&x)), &a.dot(&x));
+
+        let x_solved = l
+            .cholesky_solve(&a.dot(&x))
+            .expect("solve with a Cholesky factor");
+        assert_vector_close("cholesky_solve(L, A * x) == x", &x_solved, &x);
+    }
+}
+
+/// Checks that the LU decomposition `(P, L, U)` of `A` satisfies `P * (A * x) == L * (U * x)`.
+pub fn check_lu<F, V, M>(vlower: &V, vupper: &V, mlower: &M, mupper: &M)
+where
+    F: Float + Debug,
+    V: ArgminRandom + ArgminSub<V, V> + ArgminL2Norm<F>,
+    M: ArgminRandom + ArgminDot<V, V> + ArgminLU<M>,
+{
+    let mut rng = StdRng::seed_from_u64(SEED);
+    for _ in 0..SAMPLES {
+        let x = V::rand_from_range(vlower, vupper, &mut rng);
+        let a = M::rand_from_range(mlower, mupper, &mut rng);
+        let (p, l, u) = a.lu().expect("LU decomposition of a square matrix");
+
+        assert_vector_close(
+            "P * (A * x) == L * (U * x)",
+            &p.dot(&a.dot(&x)),
+            &l.dot(&u.dot(&x)),
+        );
+    }
+}
+
+/// Generates a module with one test per requested conformance check of the
+/// [`testing`](crate::testing) module.
+///
+/// The available checks are `vector_space`, `in_place`, `norms` and `elementwise`, which only
+/// need the vector type, as well as `matrix`, `inverse`, `solve`, `cholesky` and `lu`, which
+/// additionally need the (square) matrix type. Only the checks for the implemented traits should
+/// be listed. The bounds are passed to [`ArgminRandom::rand_from_range`] for generating the random
+/// inputs.
+///
+/// # Example
+///
+/// ```
+/// argmin_math::conformance_tests!(
+///     vec_f32,
+///     scalar: f32,
+///     vector: Vec<f32> = (vec![-1.0; 3], vec![1.0; 3]),
+///     checks: [vector_space, in_place, norms, elementwise],
+/// );
+/// ```
+#[macro_export]
+macro_rules! conformance_tests {
+    (
+        $name:ident,
+        scalar: $f:ty,
+        vector: $v:ty = ($vl:expr, $vu:expr),
+        matrix: $m:ty = ($ml:expr, $mu:expr),
+        checks: [$($check:ident),* $(,)?] $(,)?
+    ) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                fn $check() {
+                    let (vlower, vupper): ($v, $v) = ($vl, $vu);
+                    #[allow(unused_variables)]
+                    let (mlower, mupper): ($m, $m) = ($ml, $mu);
+                    $crate::conformance_tests!(
+                        @check $check, $f, $v, $m, vlower, vupper, mlower, mupper
+                    );
+                }
+            )*
+        }
+    };
+    (
+        $name:ident,
+        scalar: $f:ty,
+        vector: $v:ty = ($vl:expr, $vu:expr),
+        checks: [$($check:ident),* $(,)?] $(,)?
+    ) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $(
+                #[test]
+                fn $check() {
+                    let (vlower, vupper): ($v, $v) = ($vl, $vu);
+                    $crate::conformance_tests!(@check $check, $f, $v, vlower, vupper);
+                }
+            )*
+        }
+    };
+    (@check vector_space, $f:ty, $v:ty, $vl:ident, $vu:ident $(, $($rest:tt)*)?) => {
+        $crate::testing::check_vector_space::<$f, $v>(&$vl, &$vu)
+    };
+    (@check in_place, $f:ty, $v:ty, $vl:ident, $vu:ident $(, $($rest:tt)*)?) => {
+        $crate::testing::check_in_place::<$f, $v>(&$vl, &$vu)
+    };
+    (@check norms, $f:ty, $v:ty, $vl:ident, $vu:ident $(, $($rest:tt)*)?) => {
+        $crate::testing::check_norms::<$f, $v>(&$vl, &$vu)
+    };
+    (@check elementwise, $f:ty, $v:ty, $vl:ident, $vu:ident $(, $($rest:tt)*)?) => {
+        $crate::testing::check_elementwise::<$f, $v>(&$vl, &$vu)
+    };
+    (@check vector_space, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::conformance_tests!(@check vector_space, $f, $v, $vl, $vu)
+    };
+    (@check in_place, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::conformance_tests!(@check in_place, $f, $v, $vl, $vu)
+    };
+    (@check norms, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::conformance_tests!(@check norms, $f, $v, $vl, $vu)
+    };
+    (@check elementwise, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::conformance_tests!(@check elementwise, $f, $v, $vl, $vu)
+    };
+    (@check matrix, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::testing::check_matrix::<$f, $v, $m>(&$vl, &$vu, &$ml, &$mu)
+    };
+    (@check inverse, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::testing::check_inverse::<$f, $v, $m>(&$vl, &$vu, &$ml, &$mu)
+    };
+    (@check solve, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::testing::check_solve::<$f, $v, $m>(&$vl, &$vu, &$ml, &$mu)
+    };
+    (@check cholesky, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::testing::check_cholesky::<$f, $v, $m>(&$vl, &$vu, &$ml, &$mu)
+    };
+    (@check lu, $f:ty, $v:ty, $m:ty, $vl:ident, $vu:ident, $ml:ident, $mu:ident) => {
+        $crate::testing::check_lu::<$f, $v, $m>(&$vl, &$vu, &$ml, &$mu)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "primitives")]
+    crate::conformance_tests!(
+        primitives_f64,
+        scalar: f64,
+        vector: f64 = (-10.0, 10.0),
+        checks: [vector_space, in_place, norms, elementwise],
+    );
+
+    #[cfg(feature = "vec")]
+    crate::conformance_tests!(
+        vec_f64,
+        scalar: f64,
+        vector: Vec<f64> = (vec![-10.0; 4], vec![10.0; 4]),
+        matrix: Vec<Vec<f64>> = (vec![vec![-1.0; 4]; 4], vec![vec![1.0; 4]; 4]),
+        checks: [vector_space, in_place, norms, elementwise, matrix, inverse, solve, cholesky, lu],
+    );
+
+    #[cfg(feature = "vec")]
+    crate::conformance_tests!(
+        vec_f32,
+        scalar: f32,
+        vector: Vec<f32> = (vec![-10.0; 4], vec![10.0; 4]),
+        matrix: Vec<Vec<f32>> = (vec![vec![-1.0; 4]; 4], vec![vec![1.0; 4]; 4]),
+        checks: [vector_space, in_place, norms, elementwise, matrix, inverse, solve, cholesky, lu],
+    );
+
+    #[cfg(feature = "array")]
+    crate::conformance_tests!(
+        array_f64,
+        scalar: f64,
+        vector: [f64; 3] = ([-10.0; 3], [10.0; 3]),
+        matrix: [[f64; 3]; 3] = ([[-1.0; 3]; 3], [[1.0; 3]; 3]),
+        checks: [vector_space, in_place, norms, elementwise, matrix, inverse, solve, cholesky, lu],
+    );
+
+    #[cfg(feature = "ndarray_all")]
+    crate::conformance_tests!(
+        ndarray_f64,
+        scalar: f64,
+        vector: ndarray::Array1<f64> = (
+            ndarray::Array1::from_elem(4, -10.0),
+            ndarray::Array1::from_elem(4, 10.0)
+        ),
+        matrix: ndarray::Array2<f64> = (
+            ndarray::Array2::from_elem((4, 4), -1.0),
+            ndarray::Array2::from_elem((4, 4), 1.0)
+        ),
+        checks: [vector_space, in_place, norms, elementwise, matrix, lu],
+    );
+
+    #[cfg(all(
+        feature = "ndarray_all",
+        any(
+            feature = "ndarray-linalg_0_12",
+            feature = "ndarray-linalg_0_13",
+            feature = "ndarray-linalg_0_16"
+        )
+    ))]
+    crate::conformance_tests!(
+        ndarray_linalg_f64,
+        scalar: f64,
+        vector: ndarray::Array1<f64> = (
+            ndarray::Array1::from_elem(4, -10.0),
+            ndarray::Array1::from_elem(4, 10.0)
+        ),
+        matrix: ndarray::Array2<f64> = (
+            ndarray::Array2::from_elem((4, 4), -1.0),
+            ndarray::Array2::from_elem((4, 4), 1.0)
+        ),
+        checks: [inverse, solve, cholesky],
+    );
+
+    #[cfg(feature = "nalgebra_all")]
+    crate::conformance_tests!(
+        nalgebra_f64,
+        scalar: f64,
+        vector: nalgebra::DVector<f64> = (
+            nalgebra::DVector::from_element(4, -10.0),
+            nalgebra::DVector::from_element(4, 10.0)
+        ),
+        matrix: nalgebra::DMatrix<f64> = (
+            nalgebra::DMatrix::from_element(4, 4, -1.0),
+            nalgebra::DMatrix::from_element(4, 4, 1.0)
+        ),
+        checks: [vector_space, in_place, norms, elementwise, matrix, inverse, solve, cholesky, lu],
+    );
+
+    #[cfg(feature = "faer_all")]
+    crate::conformance_tests!(
+        faer_f64,
+        scalar: f64,
+        vector: faer::Col<f64> = (
+            faer::Col::from_fn(4, |_| -10.0),
+            faer::Col::from_fn(4, |_| 10.0)
+        ),
+        matrix: faer::Mat<f64> = (
+            faer::Mat::from_fn(4, 4, |_, _| -1.0),
+            faer::Mat::from_fn(4, 4, |_, _| 1.0)
+        ),
+        checks: [vector_space, in_place, matrix, inverse, solve, cholesky, lu],
+    );
+
+    #[cfg(feature = "faer_all")]
+    crate::conformance_tests!(
+        faer_col_f64,
+        scalar: f64,
+        vector: crate::FaerCol<f64> = (
+            crate::FaerCol(faer::Col::from_fn(4, |_| -10.0)),
+            crate::FaerCol(faer::Col::from_fn(4, |_| 10.0))
+        ),
+        matrix: faer::Mat<f64> = (
+            faer::Mat::from_fn(4, 4, |_, _| -1.0),
+            faer::Mat::from_fn(4, 4, |_, _| 1.0)
+        ),
+        checks: [vector_space, in_place, matrix, solve],
+    );
+
+    #[cfg(feature = "sprs_all")]
+    crate::conformance_tests!(
+        sprs_f64,
+        scalar: f64,
+        vector: Vec<f64> = (vec![-10.0; 4], vec![10.0; 4]),
+        matrix: sprs::CsMat<f64> = (
+            sprs::CsMat::new((4, 4), vec![0, 4, 8, 12, 16], [0, 1, 2, 3].repeat(4), vec![-1.0; 16]),
+            sprs::CsMat::new((4, 4), vec![0, 4, 8, 12, 16], [0, 1, 2, 3].repeat(4), vec![1.0; 16])
+        ),
+        checks: [matrix, solve],
+    );
+}
@@ -0,0 +1,88 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAbs;
+
+macro_rules! make_abs {
+    ($t:ty) => {
+        impl ArgminAbs for Vec<$t> {
+            #[inline]
+            fn abs(&self) -> Vec<$t> {
+                self.iter().map(|&a| <$t>::abs(a)).collect()
+            }
+        }
+
+        impl ArgminAbs for Vec<Vec<$t>> {
+            #[inline]
+            fn abs(&self) -> Vec<Vec<$t>> {
+                self.iter()
+                    .map(|row| <Vec<$t> as ArgminAbs>::abs(row))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_abs!(isize);
+make_abs!(i8);
+make_abs!(i16);
+make_abs!(i32);
+make_abs!(i64);
+make_abs!(f32);
+make_abs!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_abs_vec_ $t>]() {
+                    let a = vec![-1 as $t, 4 as $t, -8 as $t];
+                    let target = vec![1 as $t, 4 as $t, 8 as $t];
+                    let res = <Vec<$t> as ArgminAbs>::abs(&a);
+                    for i in 0..3 {
+                        assert_relative_eq!(res[i] as f64, target[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_abs_mat_ $t>]() {
+                    let a = vec![
+                        vec![-1 as $t, 4 as $t, -8 as $t],
+                        vec![2 as $t, -5 as $t, 9 as $t],
+                    ];
+                    let target = vec![
+                        vec![1 as $t, 4 as $t, 8 as $t],
+                        vec![2 as $t, 5 as $t, 9 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminAbs>::abs(&a);
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            assert_relative_eq!(res[i][j] as f64, target[i][j] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+        };
+    }
+
+    make_test!(isize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,101 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::choleskysolve::{cholesky_substitute, CholeskySolveDimensionError, CholeskySolveError};
+use crate::{ArgminCholeskySolve, Error};
+
+macro_rules! make_cholesky_solve {
+    ($t:ty) => {
+        impl ArgminCholeskySolve<Vec<$t>, Vec<$t>> for Vec<Vec<$t>> {
+            fn cholesky_solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                let n = self.len();
+                if b.len() != n || self.iter().any(|row| row.len() != n) {
+                    return Err(CholeskySolveDimensionError {}.into());
+                }
+                let mut x = b.clone();
+                if !cholesky_substitute(n, |i, j| self[i][j], &mut x) {
+                    return Err(CholeskySolveError {}.into());
+                }
+                Ok(x)
+            }
+        }
+    };
+}
+
+make_cholesky_solve!(f32);
+make_cholesky_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    // Cholesky factor of [[4, 12, -16], [12, 37, -43], [-16, -43, 98]]; the upper
+                    // triangle must not be accessed
+                    let l = vec![
+                        vec![2 as $t, 100 as $t, 100 as $t],
+                        vec![6 as $t, 1 as $t, 100 as $t],
+                        vec![-8 as $t, 5 as $t, 3 as $t],
+                    ];
+                    let b = vec![0 as $t, 6 as $t, 39 as $t];
+                    let x = <Vec<Vec<$t>> as ArgminCholeskySolve<Vec<$t>, Vec<$t>>>::cholesky_solve(
+                        &l, &b
+                    ).unwrap();
+                    let target = vec![1 as $t, 1 as $t, 1 as $t];
+                    for i in 0..3 {
+                        assert!((((x[i] - target[i]) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_singular_ $t>]() {
+                    let l = vec![
+                        vec![1 as $t, 0 as $t],
+                        vec![2 as $t, 0 as $t],
+                    ];
+                    let b = vec![1 as $t, 1 as $t];
+                    let err = <Vec<Vec<$t>> as ArgminCholeskySolve<Vec<$t>, Vec<$t>>>::cholesky_solve(
+                        &l, &b
+                    )
+                    .unwrap_err()
+                    .downcast::<CholeskySolveError>()
+                    .unwrap();
+                    assert_eq!(err, CholeskySolveError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_dimension_ $t>]() {
+                    let l = vec![
+                        vec![1 as $t, 0 as $t],
+                        vec![2 as $t, 1 as $t],
+                    ];
+                    let b = vec![1 as $t, 1 as $t, 1 as $t];
+                    let err = <Vec<Vec<$t>> as ArgminCholeskySolve<Vec<$t>, Vec<$t>>>::cholesky_solve(
+                        &l, &b
+                    )
+                    .unwrap_err()
+                    .downcast::<CholeskySolveDimensionError>()
+                    .unwrap();
+                    assert_eq!(err, CholeskySolveDimensionError {});
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
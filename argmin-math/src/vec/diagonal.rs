@@ -0,0 +1,71 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiagonal;
+
+impl<T: Clone> ArgminDiagonal<Vec<T>> for Vec<Vec<T>> {
+    #[inline]
+    fn diagonal(&self) -> Vec<T> {
+        self.iter()
+            .enumerate()
+            .map(|(i, row)| {
+                assert_eq!(row.len(), self.len());
+                row[i].clone()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_diagonal_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                        vec![7 as $t, 8 as $t, 9 as $t],
+                    ];
+                    let d = a.diagonal();
+                    let target = [1.0, 5.0, 9.0];
+                    assert_eq!(d.len(), 3);
+                    for i in 0..3 {
+                        assert!(((d[i] as f64) - target[i]).abs() < f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_diagonal_non_square_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    a.diagonal();
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -60,13 +60,12 @@ macro_rules! make_dot_vec {
                 let or = other.len();
                 assert!(or > 0);
                 let oc = other[0].len();
-                assert_eq!(sc, or);
-                assert!(oc > 0);
-                let v = vec![<$t>::default(); oc];
+                assert_eq!(sc, oc);
+                let v = vec![<$t>::default(); or];
                 let mut out = vec![v; sr];
                 for i in 0..sr {
                     assert_eq!(self[i].len(), sc);
-                    for j in 0..oc {
+                    for j in 0..or {
                         out[i][j] = self[i].dot(&other[j]);
                     }
                 }
@@ -416,12 +415,38 @@ mod tests {
                         vec![4 as $t, 5 as $t, 6 as $t],
                         vec![3 as $t, 2 as $t, 1 as $t]
                     ];
+                    let b = vec![
+                        vec![3 as $t, 2 as $t, 1 as $t],
+                        vec![6 as $t, 5 as $t, 4 as $t]
+                    ];
+                    a.dot(&b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mat_mat_non_square_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t]
+                    ];
                     let b = vec![
                         vec![3 as $t, 2 as $t],
                         vec![6 as $t, 5 as $t],
                         vec![3 as $t, 2 as $t]
                     ];
-                    a.dot(&b);
+                    let res = vec![
+                        vec![24 as $t, 18 as $t],
+                        vec![60 as $t, 45 as $t]
+                    ];
+                    let product = a.dot(&b);
+                    assert_eq!(product.len(), 2);
+                    for i in 0..2 {
+                        assert_eq!(product[i].len(), 2);
+                        for j in 0..2 {
+                            assert!((((res[i][j] - product[i][j]) as f64).abs()) < std::f64::EPSILON);
+                        }
+                    }
                 }
             }
 
@@ -0,0 +1,147 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::lufactor::{lu_factorize, lu_is_singular, lu_solve};
+use crate::{ArgminInv, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct InvError;
+
+impl fmt::Display for InvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct InvShapeError;
+
+impl fmt::Display for InvShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Inverse requires a square matrix")
+    }
+}
+
+macro_rules! make_inv {
+    ($t:ty) => {
+        impl ArgminInv<Vec<Vec<$t>>> for Vec<Vec<$t>> {
+            fn inv(&self) -> Result<Vec<Vec<$t>>, Error> {
+                let n = self.len();
+                if self.iter().any(|row| row.len() != n) {
+                    return Err(InvShapeError {}.into());
+                }
+                let (lu, perm) = lu_factorize(self);
+                if lu_is_singular(self, &lu) {
+                    return Err(InvError {}.into());
+                }
+                // Solve for one column of the inverse at a time with the same factorization and
+                // assemble the result row-wise
+                let mut res = vec![vec![0.0; n]; n];
+                let mut e = vec![0.0; n];
+                for j in 0..n {
+                    e[j] = 1.0;
+                    let col = lu_solve(&lu, &perm, &e);
+                    e[j] = 0.0;
+                    for (i, c) in col.into_iter().enumerate() {
+                        res[i][j] = c;
+                    }
+                }
+                Ok(res)
+            }
+        }
+    };
+}
+
+make_inv!(f32);
+make_inv!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_inv_ $t>]() {
+                    let a = vec![
+                        vec![2 as $t, 5 as $t],
+                        vec![1 as $t, 3 as $t],
+                    ];
+                    let target = vec![
+                        vec![3 as $t, -5 as $t],
+                        vec![-1 as $t, 2 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a).unwrap();
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((res[i][j] - target[i][j]) as f64).abs()) < 0.000001);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_pivoting_ $t>]() {
+                    let a = vec![
+                        vec![0 as $t, 1 as $t, 2 as $t],
+                        vec![1 as $t, 0 as $t, 3 as $t],
+                        vec![4 as $t, -3 as $t, 8 as $t],
+                    ];
+                    let target = vec![
+                        vec![-4.5 as $t, 7 as $t, -1.5 as $t],
+                        vec![-2 as $t, 4 as $t, -1 as $t],
+                        vec![1.5 as $t, -2 as $t, 0.5 as $t],
+                    ];
+                    let res = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a).unwrap();
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            assert!((((res[i][j] - target[i][j]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_singular_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t],
+                        vec![2 as $t, 4 as $t],
+                    ];
+                    let err = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a)
+                        .unwrap_err()
+                        .downcast::<InvError>()
+                        .unwrap();
+                    assert_eq!(err, InvError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_inv_non_square_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let err = <Vec<Vec<$t>> as ArgminInv<Vec<Vec<$t>>>>::inv(&a)
+                        .unwrap_err()
+                        .downcast::<InvShapeError>()
+                        .unwrap();
+                    assert_eq!(err, InvShapeError {});
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,29 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminLen;
+
+impl<T> ArgminLen for Vec<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        let a = vec![1.0f64, 4.0, 8.0];
+        assert_eq!(<Vec<f64> as ArgminLen>::len(&a), 3);
+        assert!(!<Vec<f64> as ArgminLen>::is_empty(&a));
+        let b: Vec<f64> = vec![];
+        assert!(<Vec<f64> as ArgminLen>::is_empty(&b));
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::lufactor::lu_factorize;
+use crate::ArgminLU;
+use crate::Error;
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct LUError;
+
+impl fmt::Display for LUError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LU decomposition requires a square matrix")
+    }
+}
+
+macro_rules! make_lu {
+    ($t:ty) => {
+        impl ArgminLU<Vec<Vec<$t>>> for Vec<Vec<$t>> {
+            fn lu(&self) -> Result<(Vec<Vec<$t>>, Vec<Vec<$t>>, Vec<Vec<$t>>), Error> {
+                let n = self.len();
+                if self.iter().any(|row| row.len() != n) {
+                    return Err(LUError {}.into());
+                }
+                let (lu, perm) = lu_factorize(self);
+                let mut p = vec![vec![0.0; n]; n];
+                let mut l = vec![vec![0.0; n]; n];
+                let mut u = vec![vec![0.0; n]; n];
+                for i in 0..n {
+                    p[i][perm[i]] = 1.0;
+                    l[i][..i].copy_from_slice(&lu[i][..i]);
+                    l[i][i] = 1.0;
+                    u[i][i..].copy_from_slice(&lu[i][i..]);
+                }
+                Ok((p, l, u))
+            }
+        }
+    };
+}
+
+make_lu!(f32);
+make_lu!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArgminDot;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_lu_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                        vec![7 as $t, 8 as $t, 10 as $t],
+                    ];
+                    let (p, l, u) = <Vec<Vec<$t>> as ArgminLU<Vec<Vec<$t>>>>::lu(&a).unwrap();
+                    let pa = p.dot(&a);
+                    let lu = l.dot(&u);
+                    for i in 0..3 {
+                        assert!((((l[i][i] - 1 as $t) as f64).abs()) < 0.000001);
+                        for j in 0..3 {
+                            if j > i {
+                                assert!(((l[i][j]) as f64).abs() < 0.000001);
+                            }
+                            if j < i {
+                                assert!(((u[i][j]) as f64).abs() < 0.000001);
+                            }
+                            assert!((((pa[i][j] - lu[i][j]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    // Partial pivoting selects the row with the largest element first
+                    assert!((((p[0][2] - 1 as $t) as f64).abs()) < 0.000001);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_singular_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t],
+                        vec![2 as $t, 4 as $t],
+                    ];
+                    let (p, l, u) = <Vec<Vec<$t>> as ArgminLU<Vec<Vec<$t>>>>::lu(&a).unwrap();
+                    let pa = p.dot(&a);
+                    let lu = l.dot(&u);
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            assert!((((pa[i][j] - lu[i][j]) as f64).abs()) < 0.0001);
+                        }
+                    }
+                    assert!(((u[1][1]) as f64).abs() < 0.000001);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_error_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let err = <Vec<Vec<$t>> as ArgminLU<Vec<Vec<$t>>>>::lu(&a)
+                        .unwrap_err()
+                        .downcast::<LUError>()
+                        .unwrap();
+                    assert_eq!(err, LUError {});
+                    assert_eq!(format!("{}", err), "LU decomposition requires a square matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,178 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMulAssign;
+
+macro_rules! make_mulassign {
+    ($t:ty) => {
+        impl ArgminMulAssign<$t> for Vec<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                for a in self.iter_mut() {
+                    *a *= *other;
+                }
+            }
+        }
+
+        impl ArgminMulAssign<Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn mul_assign(&mut self, other: &Vec<$t>) {
+                assert_eq!(self.len(), other.len());
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    *a *= *b;
+                }
+            }
+        }
+
+        impl ArgminMulAssign<$t> for Vec<Vec<$t>> {
+            #[inline]
+            fn mul_assign(&mut self, other: &$t) {
+                for row in self.iter_mut() {
+                    <Vec<$t> as ArgminMulAssign<$t>>::mul_assign(row, other);
+                }
+            }
+        }
+
+        impl ArgminMulAssign<Vec<Vec<$t>>> for Vec<Vec<$t>> {
+            #[inline]
+            fn mul_assign(&mut self, other: &Vec<Vec<$t>>) {
+                assert_eq!(self.len(), other.len());
+                for (a, b) in self.iter_mut().zip(other.iter()) {
+                    <Vec<$t> as ArgminMulAssign<Vec<$t>>>::mul_assign(a, b);
+                }
+            }
+        }
+    };
+}
+
+make_mulassign!(isize);
+make_mulassign!(usize);
+make_mulassign!(i8);
+make_mulassign!(i16);
+make_mulassign!(i32);
+make_mulassign!(i64);
+make_mulassign!(u8);
+make_mulassign!(u16);
+make_mulassign!(u32);
+make_mulassign!(u64);
+make_mulassign!(f32);
+make_mulassign!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_assign_vec_scalar_ $t>]() {
+                    let mut a = vec![1 as $t, 2 as $t, 3 as $t];
+                    let b = 2 as $t;
+                    <Vec<$t> as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = vec![2 as $t, 4 as $t, 6 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_assign_vec_vec_ $t>]() {
+                    let mut a = vec![1 as $t, 2 as $t, 3 as $t];
+                    let b = vec![6 as $t, 7 as $t, 8 as $t];
+                    <Vec<$t> as ArgminMulAssign<Vec<$t>>>::mul_assign(&mut a, &b);
+                    let target = vec![6 as $t, 14 as $t, 24 as $t];
+                    for i in 0..3 {
+                        assert_relative_eq!(target[i] as f64, a[i] as f64, epsilon = f64::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_assign_vec_vec_panic_ $t>]() {
+                    let mut a = vec![1 as $t, 4 as $t];
+                    let b = vec![41 as $t, 38 as $t, 34 as $t];
+                    <Vec<$t> as ArgminMulAssign<Vec<$t>>>::mul_assign(&mut a, &b);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_assign_mat_scalar_ $t>]() {
+                    let mut a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = 2 as $t;
+                    <Vec<Vec<$t>> as ArgminMulAssign<$t>>::mul_assign(&mut a, &b);
+                    let target = vec![
+                        vec![2 as $t, 4 as $t, 6 as $t],
+                        vec![8 as $t, 10 as $t, 12 as $t]
+                    ];
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, a[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_assign_mat_mat_ $t>]() {
+                    let mut a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t]
+                    ];
+                    let b = vec![
+                        vec![6 as $t, 7 as $t, 8 as $t],
+                        vec![2 as $t, 3 as $t, 4 as $t]
+                    ];
+                    <Vec<Vec<$t>> as ArgminMulAssign<Vec<Vec<$t>>>>::mul_assign(&mut a, &b);
+                    let target = vec![
+                        vec![6 as $t, 14 as $t, 24 as $t],
+                        vec![8 as $t, 15 as $t, 24 as $t]
+                    ];
+                    for i in 0..3 {
+                        for j in 0..2 {
+                            assert_relative_eq!(target[j][i] as f64, a[j][i] as f64, epsilon = f64::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_assign_mat_mat_panic_ $t>]() {
+                    let mut a = vec![vec![1 as $t, 4 as $t, 8 as $t], vec![2 as $t, 5 as $t, 9 as $t]];
+                    let b = vec![vec![41 as $t, 38 as $t, 34 as $t]];
+                    <Vec<Vec<$t>> as ArgminMulAssign<Vec<Vec<$t>>>>::mul_assign(&mut a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,55 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSet;
+
+impl<T> ArgminSet<usize, T> for Vec<T> {
+    #[inline]
+    fn set_elem(&mut self, idx: usize, value: T) {
+        self[idx] = value;
+    }
+}
+
+impl<T> ArgminSet<(usize, usize), T> for Vec<Vec<T>> {
+    #[inline]
+    fn set_elem(&mut self, (i, j): (usize, usize), value: T) {
+        self[i][j] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_set_vec_ $t>]() {
+                    let mut a = vec![1 as $t, 4 as $t, 8 as $t];
+                    a.set_elem(1, 2 as $t);
+                    assert_eq!(a, vec![1 as $t, 2 as $t, 8 as $t]);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_set_mat_ $t>]() {
+                    let mut a = vec![vec![1 as $t, 4 as $t], vec![8 as $t, 3 as $t]];
+                    a.set_elem((1, 0), 2 as $t);
+                    assert_eq!(a, vec![vec![1 as $t, 4 as $t], vec![2 as $t, 3 as $t]]);
+                }
+            }
+        };
+    }
+
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(f32);
+    make_test!(f64);
+}
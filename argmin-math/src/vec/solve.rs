@@ -0,0 +1,149 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::lufactor::{lu_factorize, lu_is_singular, lu_solve};
+use crate::{ArgminSolve, Error};
+use std::fmt;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveError;
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Singular matrix")
+    }
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+struct SolveDimensionError;
+
+impl fmt::Display for SolveDimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Matrix must be square with as many rows as the right-hand side has elements"
+        )
+    }
+}
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminSolve<Vec<$t>, Vec<$t>> for Vec<Vec<$t>> {
+            fn solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                let n = self.len();
+                if b.len() != n || self.iter().any(|row| row.len() != n) {
+                    return Err(SolveDimensionError {}.into());
+                }
+                let (lu, perm) = lu_factorize(self);
+                if lu_is_singular(self, &lu) {
+                    return Err(SolveError {}.into());
+                }
+                Ok(lu_solve(&lu, &perm, b))
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_solve_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                        vec![7 as $t, 8 as $t, 10 as $t],
+                    ];
+                    let b = vec![6 as $t, 15 as $t, 25 as $t];
+                    let x = <Vec<Vec<$t>> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b).unwrap();
+                    for i in 0..3 {
+                        assert!((((x[i] - 1 as $t) as f64).abs()) < 0.0001);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t],
+                        vec![2 as $t, 4 as $t],
+                    ];
+                    let b = vec![1 as $t, 2 as $t];
+                    let err = <Vec<Vec<$t>> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                    assert_eq!(format!("{}", err), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_nearly_singular_ $t>]() {
+                    // Singular in exact arithmetic, but the rounded pivot is not exactly zero
+                    let a = vec![
+                        vec![0.1 as $t, 0.2 as $t, 0.3 as $t],
+                        vec![0.4 as $t, 0.5 as $t, 0.6 as $t],
+                        vec![0.7 as $t, 0.8 as $t, 0.9 as $t],
+                    ];
+                    let b = vec![1 as $t, 2 as $t, 3 as $t];
+                    let err = <Vec<Vec<$t>> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<SolveError>()
+                        .unwrap();
+                    assert_eq!(err, SolveError {});
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_wrong_dimension_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t],
+                        vec![3 as $t, 4 as $t],
+                    ];
+                    let b = vec![1 as $t, 2 as $t, 3 as $t];
+                    let err = <Vec<Vec<$t>> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b)
+                        .unwrap_err()
+                        .downcast::<SolveDimensionError>()
+                        .unwrap();
+                    assert_eq!(err, SolveDimensionError {});
+                    assert_eq!(
+                        format!("{}", err),
+                        "Matrix must be square with as many rows as the right-hand side has elements"
+                    );
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_non_square_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                    ];
+                    let b = vec![1 as $t, 2 as $t];
+                    let res = <Vec<Vec<$t>> as ArgminSolve<Vec<$t>, Vec<$t>>>::solve(&a, &b);
+                    assert!(res.is_err());
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,99 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSum;
+use num_complex::Complex;
+
+macro_rules! make_sum {
+    ($t:ty) => {
+        impl ArgminSum<$t> for Vec<$t> {
+            #[inline]
+            fn sum(&self) -> $t {
+                self.iter().sum()
+            }
+        }
+    };
+}
+
+macro_rules! make_sum_complex {
+    ($t:ty) => {
+        impl ArgminSum<Complex<$t>> for Vec<Complex<$t>> {
+            #[inline]
+            fn sum(&self) -> Complex<$t> {
+                self.iter().sum()
+            }
+        }
+    };
+}
+
+make_sum!(isize);
+make_sum!(usize);
+make_sum!(i8);
+make_sum!(i16);
+make_sum!(i32);
+make_sum!(i64);
+make_sum!(u8);
+make_sum!(u16);
+make_sum!(u32);
+make_sum!(u64);
+make_sum!(f32);
+make_sum!(f64);
+make_sum_complex!(isize);
+make_sum_complex!(usize);
+make_sum_complex!(i8);
+make_sum_complex!(i16);
+make_sum_complex!(i32);
+make_sum_complex!(i64);
+make_sum_complex!(u8);
+make_sum_complex!(u16);
+make_sum_complex!(u32);
+make_sum_complex!(u64);
+make_sum_complex!(f32);
+make_sum_complex!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sum_ $t>]() {
+                    let a = vec![1 as $t, 4 as $t, 8 as $t];
+                    let res = <Vec<$t> as ArgminSum<$t>>::sum(&a);
+                    assert_relative_eq!(res as f64, 13 as f64, epsilon = f64::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sum_complex_ $t>]() {
+                    let a = vec![Complex::new(1 as $t, 2 as $t), Complex::new(3 as $t, 4 as $t)];
+                    let res = <Vec<Complex<$t>> as ArgminSum<Complex<$t>>>::sum(&a);
+                    assert_relative_eq!(res.re as f64, 4 as f64, epsilon = f64::EPSILON);
+                    assert_relative_eq!(res.im as f64, 6 as f64, epsilon = f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(u8);
+    make_test!(u16);
+    make_test!(u32);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,45 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor};
+use argmin::solver::direct::Direct;
+use argmin_testfunctions::himmelblau;
+
+struct Himmelblau {}
+
+impl CostFunction for Himmelblau {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(himmelblau(param))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let cost_function = Himmelblau {};
+
+    // Use `with_locally_biased(true)` for DIRECT-L
+    let solver = Direct::new((vec![-4.0, -4.0], vec![4.0, 4.0])).with_max_evals(1000);
+
+    let res = Executor::new(cost_function, solver)
+        .configure(|state| state.max_iters(100))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Print Result
+    println!("{res}");
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient};
+use argmin::solver::brent::BrentOpt;
+use argmin::solver::conjugategradient::{beta::PolakRibiere, NonlinearConjugateGradient};
+use argmin::solver::linesearch::ExactLineSearch;
+use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+struct Rosenbrock {
+    a: f64,
+    b: f64,
+}
+
+impl CostFunction for Rosenbrock {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rosenbrock_2d(p, self.a, self.b))
+    }
+}
+
+impl Gradient for Rosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(rosenbrock_2d_derivative(p, self.a, self.b))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Define cost function
+    let cost = Rosenbrock { a: 1.0, b: 100.0 };
+
+    // Define initial parameter vector
+    let init_param: Vec<f64> = vec![-1.2, 1.0];
+
+    // Set up an exact line search which uses Brent's method to minimize the cost function along
+    // the search direction. The bracket passed to `BrentOpt` is ignored because the line search
+    // determines a bracket along the search direction by itself. The number of iterations of
+    // Brent's method is reported as `linesearch_iters`.
+    let linesearch = ExactLineSearch::new(BrentOpt::new(0.0, 1.0).set_tolerance(1e-8, 1e-10));
+
+    // Set up nonlinear conjugate gradient method
+    let solver = NonlinearConjugateGradient::new(linesearch, PolakRibiere::new())
+        .restart_iters(10)
+        .restart_orthogonality(0.1);
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(100).target_cost(1e-16))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
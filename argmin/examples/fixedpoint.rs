@@ -0,0 +1,70 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{Error, Executor, Operator};
+use argmin::solver::fixedpoint::{AndersonAcceleration, PicardIteration};
+
+/// Landweber iteration `G(x) = x - omega * (A x - b)` for the minimization of
+/// `1/2 x^T A x - b^T x`, where `A` is the (ill-conditioned) finite difference discretization of
+/// `-u''` with homogeneous Dirichlet boundary conditions.
+struct Landweber {
+    b: Vec<f64>,
+    omega: f64,
+}
+
+impl Operator for Landweber {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+
+    fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        let n = x.len();
+        Ok((0..n)
+            .map(|i| {
+                let left = if i > 0 { x[i - 1] } else { 0.0 };
+                let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+                let grad = 2.0 * x[i] - left - right - self.b[i];
+                x[i] - self.omega * grad
+            })
+            .collect())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 30;
+    let operator = || Landweber {
+        b: vec![1.0; n],
+        omega: 0.25,
+    };
+
+    // Plain fixed-point iteration
+    let solver = PicardIteration::new().with_tolerance(1e-8)?;
+    let res = Executor::new(operator(), solver)
+        .configure(|state| state.param(vec![0.0; n]).max_iters(1000))
+        .run()?;
+    println!("{res}");
+
+    // Anderson acceleration of the same fixed-point iteration
+    let solver = AndersonAcceleration::new()
+        .with_memory(10)
+        .with_tolerance(1e-8)?;
+    let res = Executor::new(operator(), solver)
+        .configure(|state| state.param(vec![0.0; n]).max_iters(1000))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing to screen again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient};
+use argmin::solver::frankwolfe::{FrankWolfe, FrankWolfeVariant, ProbabilitySimplex};
+
+/// Projection of `c` onto the probability simplex: `min_x 1/2 ||x - c||^2`
+struct Projection {
+    c: Vec<f64>,
+}
+
+impl CostFunction for Projection {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(0.5
+            * x.iter()
+                .zip(self.c.iter())
+                .map(|(xi, ci)| (xi - ci).powi(2))
+                .sum::<f64>())
+    }
+}
+
+impl Gradient for Projection {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(x.iter()
+            .zip(self.c.iter())
+            .map(|(xi, ci)| xi - ci)
+            .collect())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 50;
+    // The solution lies on a face of the simplex: only some of its elements are nonzero.
+    let c: Vec<f64> = (0..n).map(|i| (i as f64).sin() / 5.0).collect();
+
+    for variant in [
+        FrankWolfeVariant::Standard,
+        FrankWolfeVariant::AwayStep,
+        FrankWolfeVariant::Pairwise,
+    ] {
+        let solver = FrankWolfe::new(ProbabilitySimplex::new(1.0))
+            .with_variant(variant)
+            .with_tolerance(1e-8)?;
+
+        let res = Executor::new(Projection { c: c.clone() }, solver)
+            .configure(|state| state.param(vec![1.0 / n as f64; n]).max_iters(500))
+            .add_observer(SlogLogger::term(), ObserverMode::Every(50))
+            .run()?;
+
+        // Wait a second (lets the logger flush everything before printing to screen again)
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        println!("{variant:?}\n{res}");
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
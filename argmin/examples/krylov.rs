@@ -0,0 +1,89 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{Error, Executor, Operator};
+use argmin::solver::krylov::{GMRES, MINRES};
+use argmin::solver::preconditioner::Preconditioner;
+
+/// Matrix-free finite difference discretization of `-u'' + c * u' - k * u` on `n` grid points
+/// with homogeneous Dirichlet boundary conditions
+struct ConvectionDiffusion {
+    /// Convection coefficient (the operator is symmetric if `c == 0`)
+    c: f64,
+    /// Shift (the operator is indefinite for large `k`)
+    k: f64,
+}
+
+impl ConvectionDiffusion {
+    fn diagonal(&self) -> f64 {
+        2.0 - self.k
+    }
+}
+
+impl Operator for ConvectionDiffusion {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+
+    fn apply(&self, u: &Self::Param) -> Result<Self::Output, Error> {
+        let n = u.len();
+        Ok((0..n)
+            .map(|i| {
+                let left = if i > 0 { u[i - 1] } else { 0.0 };
+                let right = if i + 1 < n { u[i + 1] } else { 0.0 };
+                self.diagonal() * u[i] - (1.0 + self.c) * left - (1.0 - self.c) * right
+            })
+            .collect())
+    }
+}
+
+/// Jacobi preconditioner for `ConvectionDiffusion`
+struct Jacobi(f64);
+
+impl Preconditioner<Vec<f64>> for Jacobi {
+    fn apply_inverse(&self, r: &Vec<f64>) -> Result<Vec<f64>, Error> {
+        Ok(r.iter().map(|ri| ri / self.0).collect())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 50;
+    let b = vec![1.0; n];
+
+    // Symmetric, indefinite operator
+    let operator = ConvectionDiffusion { c: 0.0, k: 0.1 };
+    let solver = MINRES::new(b.clone()).with_tolerance(1e-10)?;
+    let res = Executor::new(operator, solver)
+        .configure(|state| state.param(vec![0.0; n]).max_iters(200))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing to screen again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+
+    // Nonsymmetric operator
+    let operator = ConvectionDiffusion { c: 0.5, k: -0.5 };
+    let solver = GMRES::new(b)
+        .with_preconditioner(Jacobi(operator.diagonal()))
+        .with_restart(20)?
+        .with_tolerance(1e-10)?;
+    let res = Executor::new(operator, solver)
+        .configure(|state| state.param(vec![0.0; n]).max_iters(200))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{AdjointOperator, Error, Executor, Operator};
+use argmin::solver::linearleastsquares::{CGLS, LSMR, LSQR};
+
+/// Matrix-free first order finite difference operator `(Ax)_i = x_{i+1} - x_i`
+struct Difference {
+    n: usize,
+}
+
+impl Operator for Difference {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+
+    fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(x.windows(2).map(|w| w[1] - w[0]).collect())
+    }
+}
+
+impl AdjointOperator for Difference {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+
+    fn apply_adjoint(&self, y: &Self::Param) -> Result<Self::Output, Error> {
+        let mut x = vec![0.0; self.n];
+        for (i, yi) in y.iter().enumerate() {
+            x[i] -= yi;
+            x[i + 1] += yi;
+        }
+        Ok(x)
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 20;
+    // Noisy differences of a smooth signal
+    let b: Vec<f64> = (0..n - 1)
+        .map(|i| (i as f64 * 0.3).cos() * 0.3 + 0.05 * (i as f64 * 7.1).sin())
+        .collect();
+
+    // Reconstruct the signal with a little bit of damping, which also fixes the otherwise
+    // undetermined offset.
+    let solver = LSQR::new(b.clone()).with_damping(0.1)?;
+    let res = Executor::new(Difference { n }, solver)
+        .configure(|state| state.param(vec![0.0; n]).max_iters(100))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing to screen again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+
+    let solver = LSMR::new(b.clone()).with_damping(0.1)?;
+    let res = Executor::new(Difference { n }, solver)
+        .configure(|state| state.param(vec![0.0; n]).max_iters(100))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+
+    let solver = CGLS::new(b).with_damping(0.1)?;
+    let res = Executor::new(Difference { n }, solver)
+        .configure(|state| state.param(vec![0.0; n]).max_iters(100))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{Error, Executor, Jacobian, Operator};
+use argmin::solver::nnls::{LawsonHanson, BVLS};
+
+/// Linear least squares problem `min ||Ax - b||`
+struct LeastSquares {
+    a: Vec<Vec<f64>>,
+    b: Vec<f64>,
+}
+
+impl Operator for LeastSquares {
+    type Param = Vec<f64>;
+    type Output = Vec<f64>;
+
+    fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(self
+            .a
+            .iter()
+            .zip(self.b.iter())
+            .map(|(row, b)| row.iter().zip(x.iter()).map(|(a, x)| a * x).sum::<f64>() - b)
+            .collect())
+    }
+}
+
+impl Jacobian for LeastSquares {
+    type Param = Vec<f64>;
+    type Jacobian = Vec<Vec<f64>>;
+
+    fn jacobian(&self, _x: &Self::Param) -> Result<Self::Jacobian, Error> {
+        Ok(self.a.clone())
+    }
+}
+
+fn problem() -> LeastSquares {
+    // Unmix a measured spectrum `b` into nonnegative contributions of three reference spectra
+    // (the columns of `A`).
+    LeastSquares {
+        a: vec![
+            vec![1.0, 0.2, 0.0],
+            vec![0.8, 0.6, 0.1],
+            vec![0.3, 1.0, 0.4],
+            vec![0.1, 0.7, 0.9],
+            vec![0.0, 0.2, 1.0],
+        ],
+        b: vec![0.9, 0.8, 0.5, 0.3, 0.4],
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let res = Executor::new(problem(), LawsonHanson::new())
+        .configure(|state| state.param(vec![0.0; 3]).max_iters(10))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing to screen again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+
+    // Additionally restrict the contributions to at most 0.5
+    let solver = BVLS::new(vec![0.0; 3], vec![0.5; 3]);
+    let res = Executor::new(problem(), solver)
+        .configure(|state| state.param(vec![0.0; 3]).max_iters(10))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient};
+use argmin::solver::projectedgradient::{ProjectedGradient, SPG};
+use argmin::solver::projection::BoxProjection;
+use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+struct Rosenbrock {
+    a: f64,
+    b: f64,
+}
+
+impl CostFunction for Rosenbrock {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rosenbrock_2d(p, self.a, self.b))
+    }
+}
+
+impl Gradient for Rosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(rosenbrock_2d_derivative(p, self.a, self.b))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // The unconstrained minimum `[1, 1]` lies outside of the box, the constrained minimum is
+    // `[0.5, 0.25]`.
+    let bounds = BoxProjection::new(vec![-2.0, -2.0], vec![0.5, 2.0]);
+    let init_param: Vec<f64> = vec![-1.2, 1.0];
+
+    let solver = ProjectedGradient::new(bounds.clone()).with_tolerance(1e-6)?;
+    let res = Executor::new(Rosenbrock { a: 1.0, b: 100.0 }, solver)
+        .configure(|state| state.param(init_param.clone()).max_iters(10000))
+        .add_observer(SlogLogger::term(), ObserverMode::Every(1000))
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing to screen again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+
+    let solver = SPG::new(bounds).with_tolerance(1e-6)?;
+    let res = Executor::new(Rosenbrock { a: 1.0, b: 100.0 }, solver)
+        .configure(|state| state.param(init_param).max_iters(10000))
+        .add_observer(SlogLogger::term(), ObserverMode::Every(100))
+        .run()?;
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -49,7 +49,10 @@ pub use executor::Executor;
 pub use float::ArgminFloat;
 pub use kv::{KvValue, KV};
 pub use parallelization::{SendAlias, SyncAlias};
-pub use problem::{CostFunction, Gradient, Hessian, Jacobian, LinearProgram, Operator, Problem};
+pub use problem::{
+    AdjointOperator, CostFunction, Gradient, Hessian, HessianVectorProduct, Jacobian,
+    LinearProgram, Operator, Problem,
+};
 pub use result::OptimizationResult;
 pub use serialization::{DeserializeOwnedAlias, SerializeAlias};
 pub use solver::Solver;
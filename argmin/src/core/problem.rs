@@ -12,9 +12,9 @@ use std::collections::HashMap;
 
 /// Wrapper around problems defined by users.
 ///
-/// Keeps track of how many times methods such as `apply`, `cost`, `gradient`, `jacobian`,
-/// `hessian`, `anneal` and so on are called. It is used to pass the problem from one iteration of
-/// a solver to the next.
+/// Keeps track of how many times methods such as `apply`, `apply_adjoint`, `cost`, `gradient`,
+/// `jacobian`, `hessian`, `anneal` and so on are called. It is used to pass the problem from one
+/// iteration of a solver to the next.
 #[derive(Clone, Debug, Default)]
 pub struct Problem<O> {
     /// Problem defined by user
@@ -444,6 +444,45 @@ pub trait Hessian {
     bulk!(hessian, Self::Param, Self::Hessian);
 }
 
+/// Defines the computation of the product of the Hessian with a vector.
+///
+/// Allows second order methods to work without forming the Hessian, which is often prohibitively
+/// expensive for large problems. Typical implementations use automatic differentiation or
+/// exploit the structure of the problem.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{HessianVectorProduct, Error};
+///
+/// /// f(x) = sum_i x_i^4
+/// struct Quartic {}
+///
+/// impl HessianVectorProduct for Quartic {
+///     type Param = Vec<f64>;
+///
+///     /// The Hessian is diagonal with entries `12 * x_i^2`
+///     fn hessian_vector_product(
+///         &self,
+///         param: &Self::Param,
+///         v: &Self::Param,
+///     ) -> Result<Self::Param, Error> {
+///         Ok(param.iter().zip(v).map(|(x, vi)| 12.0 * x.powi(2) * vi).collect())
+///     }
+/// }
+/// ```
+pub trait HessianVectorProduct {
+    /// Type of the parameter vector
+    type Param;
+
+    /// Compute the product of the Hessian at `param` with the vector `v`
+    fn hessian_vector_product(
+        &self,
+        param: &Self::Param,
+        v: &Self::Param,
+    ) -> Result<Self::Param, Error>;
+}
+
 /// Defines the computation of the Jacobian.
 ///
 /// # Example
@@ -478,6 +517,45 @@ pub trait Jacobian {
     bulk!(jacobian, Self::Param, Self::Jacobian);
 }
 
+/// Defines the application of the adjoint (transpose) of an operator.
+///
+/// Iterative solvers for linear least squares problems such as
+/// [`LSQR`](`crate::solver::linearleastsquares::LSQR`) only access a matrix `A` via products
+/// `A * x` and `A^T * y`. The former is provided by [`Operator`] and the latter by this trait.
+/// Therefore, `Param` of this trait corresponds to `Output` of [`Operator`] and vice versa.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{AdjointOperator, Error};
+/// use argmin_math::{ArgminDot, ArgminTranspose};
+///
+/// struct Model {
+///     matrix: Vec<Vec<f64>>,
+/// }
+///
+/// impl AdjointOperator for Model {
+///     type Param = Vec<f64>;
+///     type Output = Vec<f64>;
+///
+///     /// Multiply the transpose of matrix `self.matrix` with vector `param`
+///     fn apply_adjoint(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(self.matrix.clone().t().dot(param))
+///     }
+/// }
+/// ```
+pub trait AdjointOperator {
+    /// Type of the vector the adjoint is applied to
+    type Param;
+    /// Type of the return value of the adjoint
+    type Output;
+
+    /// Applies the adjoint of the operator to `param`
+    fn apply_adjoint(&self, param: &Self::Param) -> Result<Self::Output, Error>;
+
+    bulk!(apply_adjoint, Self::Param, Self::Output);
+}
+
 /// Defines a linear Program
 ///
 /// # Example
@@ -938,6 +1016,133 @@ impl<O: Jacobian> Problem<O> {
     }
 }
 
+/// Wraps a call to `hessian_vector_product` defined in the `HessianVectorProduct` trait and as
+/// such allows to call `hessian_vector_product` on an instance of `Problem`. Internally, the number
+/// of evaluations of `hessian_vector_product` is counted.
+impl<O: HessianVectorProduct> Problem<O> {
+    /// Calls `hessian_vector_product` defined in the `HessianVectorProduct` trait and keeps track
+    /// of the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, HessianVectorProduct, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl HessianVectorProduct for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #
+    /// #     fn hessian_vector_product(
+    /// #         &self,
+    /// #         param: &Self::Param,
+    /// #         v: &Self::Param
+    /// #     ) -> Result<Self::Param, Error> {
+    /// #         Ok(vec![2.0f64, 2.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `HessianVectorProduct`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    /// let v = vec![1.0f64, 1.0f64];
+    ///
+    /// let res = problem1.hessian_vector_product(&param, &v);
+    ///
+    /// assert_eq!(problem1.counts["hessian_vector_product_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![2.0f64, 2.0f64]);
+    /// ```
+    pub fn hessian_vector_product(
+        &mut self,
+        param: &O::Param,
+        v: &O::Param,
+    ) -> Result<O::Param, Error> {
+        self.problem("hessian_vector_product_count", |problem| {
+            problem.hessian_vector_product(param, v)
+        })
+    }
+}
+
+/// Wraps a call to `apply_adjoint` defined in the `AdjointOperator` trait and as such allows to
+/// call `apply_adjoint` on an instance of `Problem`. Internally, the number of evaluations of
+/// `apply_adjoint` is counted.
+impl<O: AdjointOperator> Problem<O> {
+    /// Calls `apply_adjoint` defined in the `AdjointOperator` trait and keeps track of the number
+    /// of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, AdjointOperator, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl AdjointOperator for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #
+    /// #     fn apply_adjoint(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![1.0f64, 1.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `AdjointOperator`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.apply_adjoint(&param);
+    ///
+    /// assert_eq!(problem1.counts["adjoint_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![1.0f64, 1.0f64]);
+    /// ```
+    pub fn apply_adjoint(&mut self, param: &O::Param) -> Result<O::Output, Error> {
+        self.problem("adjoint_count", |problem| problem.apply_adjoint(param))
+    }
+
+    /// Calls `bulk_apply_adjoint` defined in the `AdjointOperator` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, AdjointOperator, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl AdjointOperator for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = Vec<f64>;
+    /// #
+    /// #     fn apply_adjoint(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(vec![1.0f64, 1.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `AdjointOperator`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let params = vec![vec![2.0f64, 1.0f64], vec![3.0f64, 5.0f64]];
+    ///
+    /// let res = problem1.bulk_apply_adjoint(&params);
+    ///
+    /// assert_eq!(problem1.counts["adjoint_count"], 2);
+    /// # assert_eq!(res.unwrap(), vec![vec![1.0f64, 1.0f64], vec![1.0f64, 1.0f64]]);
+    /// ```
+    #[allow(clippy::ptr_arg)]
+    pub fn bulk_apply_adjoint<P>(&mut self, params: &Vec<P>) -> Result<Vec<O::Output>, Error>
+    where
+        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        O::Output: SendAlias,
+        O: SyncAlias,
+    {
+        self.bulk_problem("adjoint_count", params.len(), |problem| {
+            problem.bulk_apply_adjoint(params)
+        })
+    }
+}
+
 /// Wraps a calls to `c`, `b` and `A` defined in the `LinearProgram` trait and as such allows to
 /// call those methods on an instance of `Problem`.
 impl<O: LinearProgram> Problem<O> {
@@ -38,8 +38,10 @@
 //!
 //! - [Line searches](`crate::solver::linesearch`)
 //!   - [Backtracking line search](`crate::solver::linesearch::BacktrackingLineSearch`)
+//!   - [Exact line search using Brent's method or golden-section search](`crate::solver::linesearch::ExactLineSearch`)
 //!   - [More-Thuente line search](`crate::solver::linesearch::MoreThuenteLineSearch`)
 //!   - [Hager-Zhang line search](`crate::solver::linesearch::HagerZhangLineSearch`)
+//!   - [Nonmonotone line search (Grippo-Lampariello-Lucidi and Zhang-Hager)](`crate::solver::linesearch::NonmonotoneLineSearch`)
 //!
 //! - [Trust region method](`crate::solver::trustregion::TrustRegion`)
 //!   - [Cauchy point method](`crate::solver::trustregion::CauchyPoint`)
@@ -48,6 +50,8 @@
 //!   
 //! - [Steepest descent](`crate::solver::gradientdescent::SteepestDescent`)
 //!
+//! - [Barzilai-Borwein method](`crate::solver::gradientdescent::BarzilaiBorwein`)
+//!
 //! - [Conjugate gradient methods](`crate::solver::conjugategradient`)
 //!   - [Conjugate gradient method](`crate::solver::conjugategradient::ConjugateGradient`)
 //!   - [Nonlinear conjugate gradient method](`crate::solver::conjugategradient::NonlinearConjugateGradient`)
@@ -55,6 +59,7 @@
 //! - [Newton methods](`crate::solver::newton`)
 //!   - [Newton's method](`crate::solver::newton::Newton`)
 //!   - [Newton-CG](solver/newton/newton_cg/struct.NewtonCG.html)
+//!   - [Newton's method with line search and Hessian modification](`crate::solver::newton::NewtonLS`)
 //!
 //! - [Quasi-Newton methods](`crate::solver::quasinewton`)
 //!   - [BFGS](`crate::solver::quasinewton::BFGS`)
@@ -71,6 +76,29 @@
 //!
 //! - [Landweber iteration](`crate::solver::landweber::Landweber`)
 //!
+//! - [Fixed-point iterations](`crate::solver::fixedpoint`)
+//!   - [Picard iteration](`crate::solver::fixedpoint::PicardIteration`)
+//!   - [Anderson acceleration](`crate::solver::fixedpoint::AndersonAcceleration`)
+//!
+//! - [Frank-Wolfe method (including away-step and pairwise variants)](`crate::solver::frankwolfe::FrankWolfe`)
+//!
+//! - [Projected gradient methods](`crate::solver::projectedgradient`)
+//!   - [Projected gradient method](`crate::solver::projectedgradient::ProjectedGradient`)
+//!   - [Spectral projected gradient method (SPG)](`crate::solver::projectedgradient::SPG`)
+//!
+//! - [Bound-constrained linear least squares](`crate::solver::nnls`)
+//!   - [Lawson-Hanson nonnegative least squares](`crate::solver::nnls::LawsonHanson`)
+//!   - [Bounded-variable least squares (BVLS)](`crate::solver::nnls::BVLS`)
+//!
+//! - [Iterative linear least squares](`crate::solver::linearleastsquares`)
+//!   - [LSQR](`crate::solver::linearleastsquares::LSQR`)
+//!   - [LSMR](`crate::solver::linearleastsquares::LSMR`)
+//!   - [CGLS](`crate::solver::linearleastsquares::CGLS`)
+//!
+//! - [Krylov subspace methods for linear systems](`crate::solver::krylov`)
+//!   - [MINRES](`crate::solver::krylov::MINRES`)
+//!   - [Restarted GMRES](`crate::solver::krylov::GMRES`)
+//!
 //! - [Brent's methods](`crate::solver::brent`)
 //!   - [Brent's minimization method](`crate::solver::brent::BrentOpt`)
 //!   - [Brent's root finding method](`crate::solver::brent::BrentRoot`)
@@ -81,6 +109,10 @@
 //!
 //! - [Particle Swarm Optimization](`crate::solver::particleswarm::ParticleSwarm`)
 //!
+//! - [DIRECT and DIRECT-L](`crate::solver::direct::Direct`)
+//!
+//! - [Bayesian optimization](`crate::solver::bayesian::BayesianOptimization`)
+//!
 //! ## External solvers compatible with argmin
 //!
 //! External solvers which implement the `Solver` trait are compatible with argmins `Executor`,
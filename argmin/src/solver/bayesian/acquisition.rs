@@ -0,0 +1,306 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface for acquisition functions of [`BayesianOptimization`](`super::BayesianOptimization`)
+///
+/// An acquisition function rates how promising it is to evaluate the cost function at a point,
+/// given the predicted mean and standard deviation of the surrogate model at this point as well
+/// as the lowest cost function value observed so far. Larger values are more promising.
+///
+/// # Example
+///
+/// ```
+/// use argmin::solver::bayesian::AcquisitionFunction;
+///
+/// /// Pure exploration: sample where the model is most uncertain
+/// #[derive(Clone)]
+/// struct MaxVariance {}
+///
+/// impl AcquisitionFunction<f64> for MaxVariance {
+///     fn evaluate(&self, _mean: f64, std_dev: f64, _best_cost: f64) -> f64 {
+///         std_dev
+///     }
+/// }
+/// ```
+pub trait AcquisitionFunction<F> {
+    /// Evaluates the acquisition function
+    fn evaluate(&self, mean: F, std_dev: F, best_cost: F) -> F;
+}
+
+/// Probability density function of the standard normal distribution
+fn normal_pdf<F: ArgminFloat>(z: F) -> F {
+    (float!(-0.5) * z * z).exp() / (float!(2.0) * F::PI()).sqrt()
+}
+
+/// Cumulative distribution function of the standard normal distribution
+///
+/// Uses the approximation of the complementary error function given in Numerical Recipes
+/// (fractional error below 1.2e-7).
+fn normal_cdf<F: ArgminFloat>(z: F) -> F {
+    let x = z.abs() / float!(std::f64::consts::SQRT_2);
+    let t = float!(1.0) / (float!(1.0) + float!(0.5) * x);
+    let coeffs = [
+        -1.26551223,
+        1.00002368,
+        0.37409196,
+        0.09678418,
+        -0.18628806,
+        0.27886807,
+        -1.13520398,
+        1.48851587,
+        -0.82215223,
+        0.17087277,
+    ];
+    let poly = coeffs
+        .iter()
+        .rev()
+        .fold(float!(0.0), |acc, c| acc * t + float!(*c));
+    let erfc = t * (-x * x + poly).exp();
+    if z >= float!(0.0) {
+        float!(1.0) - float!(0.5) * erfc
+    } else {
+        float!(0.5) * erfc
+    }
+}
+
+/// Expected improvement (EI)
+///
+/// `EI(x) = (f* - μ(x) - ξ) Φ(z) + σ(x) φ(z)` with `z = (f* - μ(x) - ξ) / σ(x)` where `f*` is the
+/// lowest cost function value observed so far, and `φ` and `Φ` are the probability density and
+/// cumulative distribution function of the standard normal distribution. The parameter `ξ ≥ 0`
+/// trades off exploration against exploitation and defaults to `0.01`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ExpectedImprovement<F> {
+    /// Exploration parameter
+    xi: F,
+}
+
+/// Probability of improvement (PI)
+///
+/// `PI(x) = Φ((f* - μ(x) - ξ) / σ(x))` where `f*` is the lowest cost function value observed so
+/// far and `Φ` is the cumulative distribution function of the standard normal distribution. The
+/// parameter `ξ ≥ 0` trades off exploration against exploitation and defaults to `0.01`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ProbabilityOfImprovement<F> {
+    /// Exploration parameter
+    xi: F,
+}
+
+macro_rules! improvement_acquisition {
+    ($name:ident) => {
+        impl<F: ArgminFloat> $name<F> {
+            #[doc = concat!("Construct a new instance of `", stringify!($name), "`")]
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use argmin::solver::bayesian::", stringify!($name), ";")]
+            #[doc = concat!("let acquisition: ", stringify!($name), "<f64> = ", stringify!($name), "::new();")]
+            /// ```
+            pub fn new() -> Self {
+                $name { xi: float!(0.01) }
+            }
+
+            /// Set exploration parameter `ξ`
+            ///
+            /// Must be non-negative and defaults to `0.01`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use argmin::solver::bayesian::", stringify!($name), ";")]
+            /// # use argmin::core::Error;
+            /// # fn main() -> Result<(), Error> {
+            #[doc = concat!("let acquisition = ", stringify!($name), "::new().with_xi(0.1f64)?;")]
+            /// # Ok(())
+            /// # }
+            /// ```
+            pub fn with_xi(mut self, xi: F) -> Result<Self, Error> {
+                if xi < float!(0.0) {
+                    return Err(argmin_error!(
+                        InvalidParameter,
+                        concat!("`", stringify!($name), "`: xi must be >= 0.")
+                    ));
+                }
+                self.xi = xi;
+                Ok(self)
+            }
+        }
+
+        impl<F: ArgminFloat> Default for $name<F> {
+            fn default() -> Self {
+                $name::new()
+            }
+        }
+    };
+}
+
+improvement_acquisition!(ExpectedImprovement);
+improvement_acquisition!(ProbabilityOfImprovement);
+
+impl<F: ArgminFloat> AcquisitionFunction<F> for ExpectedImprovement<F> {
+    fn evaluate(&self, mean: F, std_dev: F, best_cost: F) -> F {
+        let improvement = best_cost - mean - self.xi;
+        if std_dev <= float!(0.0) {
+            return improvement.max(float!(0.0));
+        }
+        let z = improvement / std_dev;
+        improvement * normal_cdf(z) + std_dev * normal_pdf(z)
+    }
+}
+
+impl<F: ArgminFloat> AcquisitionFunction<F> for ProbabilityOfImprovement<F> {
+    fn evaluate(&self, mean: F, std_dev: F, best_cost: F) -> F {
+        let improvement = best_cost - mean - self.xi;
+        if std_dev <= float!(0.0) {
+            return if improvement > float!(0.0) {
+                float!(1.0)
+            } else {
+                float!(0.0)
+            };
+        }
+        normal_cdf(improvement / std_dev)
+    }
+}
+
+/// Upper confidence bound (UCB)
+///
+/// Since the cost function is minimized, this is the negated lower confidence bound
+/// `UCB(x) = -(μ(x) - κ σ(x))`. The parameter `κ ≥ 0` trades off exploration against
+/// exploitation and defaults to `2`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct UpperConfidenceBound<F> {
+    /// Exploration parameter
+    kappa: F,
+}
+
+impl<F: ArgminFloat> UpperConfidenceBound<F> {
+    /// Construct a new instance of `UpperConfidenceBound`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::UpperConfidenceBound;
+    /// let acquisition: UpperConfidenceBound<f64> = UpperConfidenceBound::new();
+    /// ```
+    pub fn new() -> Self {
+        UpperConfidenceBound { kappa: float!(2.0) }
+    }
+
+    /// Set exploration parameter `κ`
+    ///
+    /// Must be non-negative and defaults to `2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::UpperConfidenceBound;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let acquisition = UpperConfidenceBound::new().with_kappa(3.0f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_kappa(mut self, kappa: F) -> Result<Self, Error> {
+        if kappa < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`UpperConfidenceBound`: kappa must be >= 0."
+            ));
+        }
+        self.kappa = kappa;
+        Ok(self)
+    }
+}
+
+impl<F: ArgminFloat> Default for UpperConfidenceBound<F> {
+    fn default() -> Self {
+        UpperConfidenceBound::new()
+    }
+}
+
+impl<F: ArgminFloat> AcquisitionFunction<F> for UpperConfidenceBound<F> {
+    fn evaluate(&self, mean: F, std_dev: F, _best_cost: F) -> F {
+        -(mean - self.kappa * std_dev)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(expected_improvement, ExpectedImprovement<f64>);
+    test_trait_impl!(probability_of_improvement, ProbabilityOfImprovement<f64>);
+    test_trait_impl!(upper_confidence_bound, UpperConfidenceBound<f64>);
+
+    #[test]
+    fn test_normal() {
+        assert_relative_eq!(normal_cdf(0.0f64), 0.5, epsilon = 1e-7);
+        assert_relative_eq!(normal_cdf(1.0f64), 0.8413447460685429, epsilon = 1e-7);
+        assert_relative_eq!(normal_cdf(-1.96f64), 0.024997895148220435, epsilon = 1e-7);
+        assert_relative_eq!(normal_pdf(0.0f64), 0.3989422804014327, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_error!(
+            ExpectedImprovement::new().with_xi(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`ExpectedImprovement`: xi must be >= 0.\""
+        );
+        assert_error!(
+            ProbabilityOfImprovement::new().with_xi(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`ProbabilityOfImprovement`: xi must be >= 0.\""
+        );
+        assert_error!(
+            UpperConfidenceBound::new().with_kappa(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`UpperConfidenceBound`: kappa must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_expected_improvement() {
+        let ei = ExpectedImprovement::new().with_xi(0.0f64).unwrap();
+        // Without uncertainty EI is the plain improvement
+        assert_relative_eq!(ei.evaluate(1.0, 0.0, 3.0), 2.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(ei.evaluate(4.0, 0.0, 3.0), 0.0, epsilon = f64::EPSILON);
+        // At the incumbent EI is sigma * pdf(0)
+        assert_relative_eq!(
+            ei.evaluate(3.0, 2.0, 3.0),
+            2.0 * 0.3989422804014327,
+            epsilon = 1e-7
+        );
+        // More uncertainty is more promising
+        assert!(ei.evaluate(3.0, 2.0, 3.0) > ei.evaluate(3.0, 1.0, 3.0));
+    }
+
+    #[test]
+    fn test_probability_of_improvement() {
+        let pi = ProbabilityOfImprovement::new().with_xi(0.0f64).unwrap();
+        assert_relative_eq!(pi.evaluate(3.0, 1.0, 3.0), 0.5, epsilon = 1e-7);
+        assert_relative_eq!(pi.evaluate(1.0, 0.0, 3.0), 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(pi.evaluate(4.0, 0.0, 3.0), 0.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_upper_confidence_bound() {
+        let ucb = UpperConfidenceBound::new().with_kappa(2.0f64).unwrap();
+        assert_relative_eq!(ucb.evaluate(1.0, 0.5, 0.0), 0.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(ucb.evaluate(1.0, 1.0, 0.0), 1.0, epsilon = f64::EPSILON);
+    }
+}
@@ -0,0 +1,411 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Kernel;
+use crate::core::{ArgminFloat, CostFunction, Error, Executor};
+use crate::solver::neldermead::NelderMead;
+use crate::solver::util::dense::{cholesky, dot, solve_lower, solve_upper_transposed};
+use argmin_math::{ArgminAdd, ArgminMul, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Hyperparameters (in log-space) are restricted to this interval during fitting.
+const LOG_HYPERPARAMETER_BOUND: f64 = 7.0;
+
+/// # Gaussian process regression
+///
+/// Gaussian process with zero prior mean and covariance function `K` which serves as the
+/// surrogate model of [`BayesianOptimization`](`super::BayesianOptimization`).
+///
+/// The observations are standardized to zero mean and unit variance internally. The given noise
+/// variance refers to the standardized observations and is added to the diagonal of the covariance
+/// matrix. If the Cholesky decomposition of the covariance matrix fails, additional jitter is
+/// added to the diagonal.
+///
+/// The hyperparameters of the kernel can be fitted by maximizing the log marginal likelihood of
+/// the observations via [`fit_hyperparameters`](`GaussianProcess::fit_hyperparameters`).
+///
+/// ## Reference
+///
+/// Carl E. Rasmussen and Christopher K. I. Williams (2006). Gaussian Processes for Machine
+/// Learning. MIT Press. ISBN 0-262-18253-X.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct GaussianProcess<K, F> {
+    /// Covariance function
+    kernel: K,
+    /// Noise variance
+    noise: F,
+    /// Observed points
+    x: Vec<Vec<F>>,
+    /// Standardized observations
+    y: Vec<F>,
+    /// Mean of observations
+    y_mean: F,
+    /// Standard deviation of observations
+    y_std: F,
+    /// Lower triangular Cholesky factor of the covariance matrix
+    chol: Vec<Vec<F>>,
+    /// Solution of `K alpha = y`
+    alpha: Vec<F>,
+}
+
+impl<K, F> GaussianProcess<K, F>
+where
+    K: Kernel<F> + Clone,
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `GaussianProcess`
+    ///
+    /// Takes the covariance function as input. The noise variance defaults to `1e-6`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{GaussianProcess, Matern52};
+    /// let gp: GaussianProcess<_, f64> = GaussianProcess::new(Matern52::default());
+    /// ```
+    pub fn new(kernel: K) -> Self {
+        GaussianProcess {
+            kernel,
+            noise: float!(1e-6),
+            x: vec![],
+            y: vec![],
+            y_mean: float!(0.0),
+            y_std: float!(1.0),
+            chol: vec![],
+            alpha: vec![],
+        }
+    }
+
+    /// Set noise variance
+    ///
+    /// Must be non-negative and defaults to `1e-6`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{GaussianProcess, Matern52};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let gp: GaussianProcess<_, f64> = GaussianProcess::new(Matern52::default()).with_noise(1e-3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_noise(mut self, noise: F) -> Result<Self, Error> {
+        if noise < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GaussianProcess`: noise must be >= 0."
+            ));
+        }
+        self.noise = noise;
+        Ok(self)
+    }
+
+    /// Returns the covariance function
+    pub fn kernel(&self) -> &K {
+        &self.kernel
+    }
+
+    /// Returns the number of observations
+    pub fn num_observations(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Condition the Gaussian process on the observations `y` at the points `x`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{GaussianProcess, Matern52};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut gp: GaussianProcess<_, f64> = GaussianProcess::new(Matern52::default());
+    /// gp.fit(vec![vec![0.0], vec![0.5], vec![1.0]], &[1.0, 0.0, 1.0])?;
+    /// let (mean, std_dev) = gp.predict(&[0.5]);
+    /// # assert!((mean - 0.0).abs() < 1e-2);
+    /// # assert!(std_dev < 1e-2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit(&mut self, x: Vec<Vec<F>>, y: &[F]) -> Result<(), Error> {
+        if x.is_empty() || x.len() != y.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GaussianProcess`: Number of points and observations must be equal and non-zero."
+            ));
+        }
+        let n = F::from_usize(y.len()).unwrap();
+        let y_mean = y.iter().fold(float!(0.0), |acc, v| acc + *v) / n;
+        let y_var = y
+            .iter()
+            .fold(float!(0.0), |acc, v| acc + (*v - y_mean).powi(2))
+            / n;
+        let y_std = if y_var.sqrt() > F::epsilon() {
+            y_var.sqrt()
+        } else {
+            float!(1.0)
+        };
+        self.x = x;
+        self.y = y.iter().map(|v| (*v - y_mean) / y_std).collect();
+        self.y_mean = y_mean;
+        self.y_std = y_std;
+        self.factorize()
+    }
+
+    /// Returns mean and standard deviation of the prediction at `x`
+    pub fn predict(&self, x: &[F]) -> (F, F) {
+        let k_star: Vec<F> = self.x.iter().map(|xi| self.kernel.eval(xi, x)).collect();
+        let mean = dot(&k_star, &self.alpha);
+        let v = solve_lower(&self.chol, &k_star);
+        let var = (self.kernel.eval(x, x) - dot(&v, &v)).max(float!(0.0));
+        (mean * self.y_std + self.y_mean, var.sqrt() * self.y_std)
+    }
+
+    /// Returns the log marginal likelihood of the (standardized) observations
+    pub fn log_marginal_likelihood(&self) -> F {
+        let n = F::from_usize(self.y.len()).unwrap();
+        let log_det = self
+            .chol
+            .iter()
+            .enumerate()
+            .fold(float!(0.0), |acc, (i, row)| acc + row[i].ln());
+        float!(-0.5) * dot(&self.y, &self.alpha)
+            - log_det
+            - float!(0.5) * n * (float!(2.0) * F::PI()).ln()
+    }
+
+    /// Fit the hyperparameters of the kernel by maximizing the log marginal likelihood
+    ///
+    /// Uses [`NelderMead`] with at most `max_iters` iterations. The hyperparameters (in log-space)
+    /// are restricted to `[-7, 7]`. Requires the Gaussian process to be conditioned on
+    /// observations via [`fit`](`GaussianProcess::fit`) first. If the optimization does not
+    /// improve on the current hyperparameters, they are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{GaussianProcess, SquaredExponential};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut gp: GaussianProcess<_, f64> = GaussianProcess::new(SquaredExponential::default());
+    /// gp.fit(vec![vec![0.0], vec![0.2], vec![0.4], vec![0.6]], &[0.0, 0.4, 0.7, 0.9])?;
+    /// gp.fit_hyperparameters(100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fit_hyperparameters(&mut self, max_iters: u64) -> Result<(), Error>
+    where
+        Vec<F>: ArgminAdd<Vec<F>, Vec<F>> + ArgminSub<Vec<F>, Vec<F>> + ArgminMul<F, Vec<F>>,
+        F: std::iter::Sum<F>,
+    {
+        if self.x.is_empty() {
+            return Err(argmin_error!(
+                NotInitialized,
+                "`GaussianProcess`: Hyperparameters can only be fitted after calling `fit`."
+            ));
+        }
+        let init = self.kernel.hyperparameters();
+        let mut simplex = vec![init.clone()];
+        for i in 0..init.len() {
+            let mut p = init.clone();
+            p[i] = p[i] + float!(1.0);
+            simplex.push(p);
+        }
+        let mut res = Executor::new(
+            NegLogMarginalLikelihood { gp: self.clone() },
+            NelderMead::new(simplex).with_sd_tolerance(float!(1e-6))?,
+        )
+        .configure(|state| state.max_iters(max_iters))
+        .ctrlc(false)
+        .run()?;
+
+        let current = -self.log_marginal_likelihood();
+        if res.state.get_best_cost() < current {
+            let params = res.state.take_best_param().unwrap();
+            let old_kernel = self.kernel.clone();
+            self.kernel.set_hyperparameters(&clamp_log_params(&params));
+            if self.factorize().is_err() {
+                self.kernel = old_kernel;
+                self.factorize()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes the Cholesky factorization of the covariance matrix and `alpha`
+    fn factorize(&mut self) -> Result<(), Error> {
+        let n = self.x.len();
+        let mut cov: Vec<Vec<F>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| self.kernel.eval(&self.x[i], &self.x[j]))
+                    .collect()
+            })
+            .collect();
+        let mut jitter = float!(0.0);
+        for _ in 0..8 {
+            for (i, row) in cov.iter_mut().enumerate() {
+                row[i] = self.kernel.eval(&self.x[i], &self.x[i]) + self.noise + jitter;
+            }
+            if let Some(chol) = cholesky(&cov) {
+                self.alpha = solve_upper_transposed(&chol, &solve_lower(&chol, &self.y));
+                self.chol = chol;
+                return Ok(());
+            }
+            jitter = if jitter > float!(0.0) {
+                jitter * float!(10.0)
+            } else {
+                float!(1e-10)
+            };
+        }
+        Err(argmin_error!(
+            PotentialBug,
+            "`GaussianProcess`: Covariance matrix is not positive definite."
+        ))
+    }
+}
+
+/// Negative log marginal likelihood as a function of the hyperparameters of the kernel
+struct NegLogMarginalLikelihood<K, F> {
+    gp: GaussianProcess<K, F>,
+}
+
+impl<K, F> CostFunction for NegLogMarginalLikelihood<K, F>
+where
+    K: Kernel<F> + Clone,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let mut gp = self.gp.clone();
+        gp.kernel.set_hyperparameters(&clamp_log_params(param));
+        Ok(match gp.factorize() {
+            Ok(()) => -gp.log_marginal_likelihood(),
+            Err(_) => F::infinity(),
+        })
+    }
+}
+
+fn clamp_log_params<F: ArgminFloat>(params: &[F]) -> Vec<F> {
+    let bound: F = float!(LOG_HYPERPARAMETER_BOUND);
+    params.iter().map(|p| p.max(-bound).min(bound)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::bayesian::{Matern52, SquaredExponential};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(
+        gaussian_process,
+        GaussianProcess<SquaredExponential<f64>, f64>
+    );
+
+    #[test]
+    fn test_new() {
+        let gp: GaussianProcess<_, f64> = GaussianProcess::new(Matern52::default());
+        let GaussianProcess {
+            kernel,
+            noise,
+            x,
+            y,
+            y_mean,
+            y_std,
+            chol,
+            alpha,
+        } = gp;
+        assert_eq!(kernel, Matern52::default());
+        assert_eq!(noise.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert!(x.is_empty());
+        assert!(y.is_empty());
+        assert_eq!(y_mean.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(y_std.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(chol.is_empty());
+        assert!(alpha.is_empty());
+    }
+
+    #[test]
+    fn test_with_noise() {
+        let gp: GaussianProcess<_, f64> = GaussianProcess::new(Matern52::default());
+        assert_error!(
+            gp.clone().with_noise(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`GaussianProcess`: noise must be >= 0.\""
+        );
+        let gp = gp.with_noise(0.1).unwrap();
+        assert_eq!(gp.noise.to_ne_bytes(), 0.1f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_fit_invalid() {
+        let mut gp: GaussianProcess<_, f64> = GaussianProcess::new(Matern52::default());
+        assert_error!(
+            gp.fit(vec![vec![0.0]], &[1.0, 2.0]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`GaussianProcess`: Number of points and observations ",
+                "must be equal and non-zero.\""
+            )
+        );
+        assert_error!(
+            gp.fit_hyperparameters(10),
+            ArgminError,
+            concat!(
+                "Not initialized: \"`GaussianProcess`: Hyperparameters can only be fitted ",
+                "after calling `fit`.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_predict_interpolates() {
+        let mut gp: GaussianProcess<_, f64> =
+            GaussianProcess::new(SquaredExponential::new(0.3, 1.0).unwrap());
+        let x = vec![vec![0.0], vec![0.25], vec![0.5], vec![0.75], vec![1.0]];
+        let y: Vec<f64> = x.iter().map(|v| (6.0f64 * v[0]).sin()).collect();
+        gp.fit(x.clone(), &y).unwrap();
+        for (xi, yi) in x.iter().zip(y.iter()) {
+            let (mean, std_dev) = gp.predict(xi);
+            assert_relative_eq!(mean, *yi, epsilon = 1e-3);
+            assert!(std_dev < 1e-2);
+        }
+        // Far away from the data the prediction reverts to the prior
+        let (mean, std_dev) = gp.predict(&[10.0]);
+        let y_mean = y.iter().sum::<f64>() / 5.0;
+        assert_relative_eq!(mean, y_mean, epsilon = 1e-6);
+        assert!(std_dev > 0.5);
+    }
+
+    #[test]
+    fn test_duplicate_points() {
+        let mut gp: GaussianProcess<_, f64> = GaussianProcess::new(SquaredExponential::default())
+            .with_noise(0.0)
+            .unwrap();
+        gp.fit(vec![vec![0.5], vec![0.5]], &[1.0, 1.0]).unwrap();
+        let (mean, _) = gp.predict(&[0.5]);
+        assert_relative_eq!(mean, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_fit_hyperparameters() {
+        let mut gp: GaussianProcess<_, f64> =
+            GaussianProcess::new(SquaredExponential::new(50.0, 1.0).unwrap());
+        let x: Vec<Vec<f64>> = (0..15).map(|i| vec![i as f64 / 14.0]).collect();
+        let y: Vec<f64> = x.iter().map(|v| (12.0f64 * v[0]).sin()).collect();
+        gp.fit(x, &y).unwrap();
+        let before = gp.log_marginal_likelihood();
+        gp.fit_hyperparameters(200).unwrap();
+        assert!(gp.log_marginal_likelihood() > before);
+        assert!(gp.kernel().length_scale() < 1.0);
+    }
+}
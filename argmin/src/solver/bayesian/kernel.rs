@@ -0,0 +1,253 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface for covariance functions (kernels) of a [`GaussianProcess`](`super::GaussianProcess`)
+///
+/// Kernels expose their hyperparameters in log-space, which allows them to be fitted by
+/// unconstrained optimization of the log marginal likelihood.
+///
+/// # Example
+///
+/// ```
+/// use argmin::solver::bayesian::Kernel;
+///
+/// #[derive(Clone)]
+/// struct Linear {
+///     variance: f64,
+/// }
+///
+/// impl Kernel<f64> for Linear {
+///     fn eval(&self, a: &[f64], b: &[f64]) -> f64 {
+///         self.variance * a.iter().zip(b.iter()).map(|(x, y)| x * y).sum::<f64>()
+///     }
+///
+///     fn hyperparameters(&self) -> Vec<f64> {
+///         vec![self.variance.ln()]
+///     }
+///
+///     fn set_hyperparameters(&mut self, params: &[f64]) {
+///         self.variance = params[0].exp();
+///     }
+/// }
+/// ```
+pub trait Kernel<F> {
+    /// Evaluates the kernel for the points `a` and `b`
+    fn eval(&self, a: &[F], b: &[F]) -> F;
+
+    /// Returns the hyperparameters in log-space
+    fn hyperparameters(&self) -> Vec<F>;
+
+    /// Sets the hyperparameters from log-space
+    fn set_hyperparameters(&mut self, params: &[F]);
+}
+
+/// Euclidean distance between `a` and `b`, divided by `length_scale`
+fn scaled_distance<F: ArgminFloat>(a: &[F], b: &[F], length_scale: F) -> F {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (*x - *y).powi(2))
+        .fold(float!(0.0), |acc, d| acc + d)
+        .sqrt()
+        / length_scale
+}
+
+/// Checks that length scale and variance are positive
+fn check_kernel_parameters<F: ArgminFloat>(
+    name: &str,
+    length_scale: F,
+    variance: F,
+) -> Result<(), Error> {
+    if length_scale <= float!(0.0) {
+        return Err(argmin_error!(
+            InvalidParameter,
+            format!("`{}`: length scale must be > 0.", name)
+        ));
+    }
+    if variance <= float!(0.0) {
+        return Err(argmin_error!(
+            InvalidParameter,
+            format!("`{}`: variance must be > 0.", name)
+        ));
+    }
+    Ok(())
+}
+
+macro_rules! isotropic_kernel {
+    ($name:ident, $display:expr, $doc:expr, $r:ident, $body:expr) => {
+        #[doc = $doc]
+        ///
+        /// The hyperparameters are the length scale `l` and the signal variance `s²` (in this
+        /// order). Both default to `1`.
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        pub struct $name<F> {
+            /// Length scale
+            length_scale: F,
+            /// Signal variance
+            variance: F,
+        }
+
+        impl<F: ArgminFloat> $name<F> {
+            #[doc = concat!("Construct a new instance of `", stringify!($name), "`")]
+            ///
+            /// Takes the length scale and the signal variance, both must be > 0.
+            ///
+            /// # Example
+            ///
+            /// ```
+            #[doc = concat!("# use argmin::solver::bayesian::", stringify!($name), ";")]
+            /// # use argmin::core::Error;
+            /// # fn main() -> Result<(), Error> {
+            #[doc = concat!("let kernel = ", stringify!($name), "::new(0.5f64, 2.0)?;")]
+            /// # Ok(())
+            /// # }
+            /// ```
+            pub fn new(length_scale: F, variance: F) -> Result<Self, Error> {
+                check_kernel_parameters($display, length_scale, variance)?;
+                Ok($name {
+                    length_scale,
+                    variance,
+                })
+            }
+
+            /// Returns the length scale
+            pub fn length_scale(&self) -> F {
+                self.length_scale
+            }
+
+            /// Returns the signal variance
+            pub fn variance(&self) -> F {
+                self.variance
+            }
+        }
+
+        impl<F: ArgminFloat> Default for $name<F> {
+            fn default() -> Self {
+                $name {
+                    length_scale: float!(1.0),
+                    variance: float!(1.0),
+                }
+            }
+        }
+
+        impl<F: ArgminFloat> Kernel<F> for $name<F> {
+            fn eval(&self, a: &[F], b: &[F]) -> F {
+                let $r = scaled_distance(a, b, self.length_scale);
+                self.variance * $body
+            }
+
+            fn hyperparameters(&self) -> Vec<F> {
+                vec![self.length_scale.ln(), self.variance.ln()]
+            }
+
+            fn set_hyperparameters(&mut self, params: &[F]) {
+                self.length_scale = params[0].exp();
+                self.variance = params[1].exp();
+            }
+        }
+    };
+}
+
+isotropic_kernel!(
+    SquaredExponential,
+    "SquaredExponential",
+    "Squared exponential (RBF) kernel `k(r) = s² exp(-r²/(2l²))`",
+    r,
+    (float!(-0.5) * r * r).exp()
+);
+
+isotropic_kernel!(
+    Matern32,
+    "Matern32",
+    "Matérn kernel with `ν = 3/2`: `k(r) = s² (1 + √3 r/l) exp(-√3 r/l)`",
+    r,
+    {
+        let s = float!(3.0f64.sqrt()) * r;
+        (float!(1.0) + s) * (-s).exp()
+    }
+);
+
+isotropic_kernel!(
+    Matern52,
+    "Matern52",
+    "Matérn kernel with `ν = 5/2`: `k(r) = s² (1 + √5 r/l + 5r²/(3l²)) exp(-√5 r/l)`",
+    r,
+    {
+        let s = float!(5.0f64.sqrt()) * r;
+        (float!(1.0) + s + s * s / float!(3.0)) * (-s).exp()
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(squared_exponential, SquaredExponential<f64>);
+    test_trait_impl!(matern32, Matern32<f64>);
+    test_trait_impl!(matern52, Matern52<f64>);
+
+    #[test]
+    fn test_new() {
+        let kernel = Matern52::new(0.5f64, 2.0).unwrap();
+        assert_eq!(kernel.length_scale().to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(kernel.variance().to_ne_bytes(), 2.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert_error!(
+            SquaredExponential::new(0.0f64, 1.0),
+            ArgminError,
+            "Invalid parameter: \"`SquaredExponential`: length scale must be > 0.\""
+        );
+        assert_error!(
+            Matern32::new(1.0f64, -1.0),
+            ArgminError,
+            "Invalid parameter: \"`Matern32`: variance must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_eval() {
+        let a = [0.0f64, 0.0];
+        let b = [0.3f64, 0.4];
+        let se = SquaredExponential::new(0.5f64, 2.0).unwrap();
+        assert_relative_eq!(se.eval(&a, &a), 2.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(se.eval(&a, &b), 2.0 * (-0.5f64).exp(), epsilon = 1e-12);
+        let m32 = Matern32::new(0.5f64, 2.0).unwrap();
+        let s = 3.0f64.sqrt();
+        assert_relative_eq!(
+            m32.eval(&a, &b),
+            2.0 * (1.0 + s) * (-s).exp(),
+            epsilon = 1e-12
+        );
+        let m52 = Matern52::new(0.5f64, 2.0).unwrap();
+        let s = 5.0f64.sqrt();
+        assert_relative_eq!(
+            m52.eval(&a, &b),
+            2.0 * (1.0 + s + s * s / 3.0) * (-s).exp(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_hyperparameters() {
+        let mut kernel: Matern32<f64> = Matern32::default();
+        kernel.set_hyperparameters(&[2.0f64.ln(), 3.0f64.ln()]);
+        assert_relative_eq!(kernel.length_scale(), 2.0, epsilon = 1e-12);
+        assert_relative_eq!(kernel.variance(), 3.0, epsilon = 1e-12);
+        let params = kernel.hyperparameters();
+        assert_relative_eq!(params[0], 2.0f64.ln(), epsilon = 1e-12);
+        assert_relative_eq!(params[1], 3.0f64.ln(), epsilon = 1e-12);
+    }
+}
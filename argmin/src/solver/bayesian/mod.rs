@@ -0,0 +1,1011 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Bayesian optimization
+//!
+//! Global optimization of expensive black-box cost functions using a Gaussian process surrogate
+//! model and an acquisition function, as outlined in \[0\].
+//!
+//! For details see [`BayesianOptimization`].
+//!
+//! ## References
+//!
+//! \[0\] B. Shahriari, K. Swersky, Z. Wang, R. P. Adams and N. de Freitas (2016). Taking the Human
+//! Out of the Loop: A Review of Bayesian Optimization. Proceedings of the IEEE 104(1), 148–175.
+//! <https://doi.org/10.1109/JPROC.2015.2494218>
+//!
+//! \[1\] Carl E. Rasmussen and Christopher K. I. Williams (2006). Gaussian Processes for Machine
+//! Learning. MIT Press. ISBN 0-262-18253-X.
+
+/// Acquisition functions
+mod acquisition;
+/// Gaussian process regression
+mod gp;
+/// Covariance functions
+mod kernel;
+
+pub use self::acquisition::*;
+pub use self::gp::*;
+pub use self::kernel::*;
+
+use crate::core::{
+    ArgminError, ArgminFloat, CostFunction, Error, Executor, Gradient, IterState, Problem,
+    SerializeAlias, Solver, State, SyncAlias, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::linesearch::MoreThuenteLineSearch;
+use crate::solver::particleswarm::ParticleSwarm;
+use crate::solver::quasinewton::LBFGS;
+use argmin_math::{
+    ArgminAdd, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul, ArgminMulAssign,
+    ArgminRandom, ArgminScaledAdd, ArgminScaledAddAssign, ArgminScaledSubAssign, ArgminSignum,
+    ArgminSub, ArgminZeroLike,
+};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Method used to find the maximum of the acquisition function
+///
+/// The acquisition function is maximized over the normalized search space (the unit hypercube).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum AcquisitionOptimizer {
+    /// [`ParticleSwarm`] with the given number of particles and iterations
+    ParticleSwarm {
+        /// Number of particles
+        num_particles: usize,
+        /// Maximum number of iterations
+        max_iters: u64,
+    },
+    /// Multi-start [`LBFGS`] with finite difference gradients. The first start is the best point
+    /// observed so far, all others are drawn uniformly at random.
+    LBFGS {
+        /// Number of starting points
+        num_restarts: usize,
+        /// Maximum number of iterations per start
+        max_iters: u64,
+    },
+}
+
+impl Default for AcquisitionOptimizer {
+    fn default() -> Self {
+        AcquisitionOptimizer::ParticleSwarm {
+            num_particles: 40,
+            max_iters: 50,
+        }
+    }
+}
+
+/// # Bayesian optimization
+///
+/// Sample-efficient global optimization method for expensive cost functions on box bounds.
+///
+/// The cost function is first evaluated at an initial design (a Latin hypercube sample, plus the
+/// initial parameter vector if one is provided via `configure`). In each iteration, a
+/// [`GaussianProcess`] is fitted to all observations and the next point(s) to evaluate are chosen
+/// by maximizing an [`AcquisitionFunction`] which balances exploration (high predicted
+/// uncertainty) against exploitation (low predicted cost). Available acquisition functions are
+/// [`ExpectedImprovement`], [`ProbabilityOfImprovement`] and [`UpperConfidenceBound`]; available
+/// covariance functions are [`SquaredExponential`], [`Matern32`] and [`Matern52`]. Custom ones
+/// can be provided by implementing [`AcquisitionFunction`] and [`Kernel`], respectively.
+///
+/// Inputs are normalized to the unit hypercube and observations are standardized before fitting
+/// the Gaussian process. By default, the kernel hyperparameters are refitted in each iteration by
+/// maximizing the log marginal likelihood (see
+/// [`with_hyperparameter_fitting`](`BayesianOptimization::with_hyperparameter_fitting`)).
+///
+/// The acquisition function is maximized with argmin's own solvers, either [`ParticleSwarm`]
+/// (default) or multi-start [`LBFGS`] (see [`AcquisitionOptimizer`]).
+///
+/// If a batch size `q > 1` is set via
+/// [`with_batch_size`](`BayesianOptimization::with_batch_size`), `q` points are proposed per
+/// iteration using the "kriging believer" heuristic (each proposal is added to the model with its
+/// predicted mean as observation before the next one is chosen). All points of a batch are
+/// evaluated with [`bulk_cost`](`Problem::bulk_cost`), which computes them in parallel if the
+/// `rayon` feature is enabled.
+///
+/// The solver stops when the number of cost function evaluations exceeds the budget set via
+/// [`with_max_evals`](`BayesianOptimization::with_max_evals`) or when the maximum number of
+/// iterations is reached. The number of observations (`num_samples`), the value of the
+/// acquisition function at the first proposal of the batch (`acquisition`) and the log marginal
+/// likelihood of the model (`log_likelihood`) are reported to the observers.
+///
+/// The parameter vector is required to be a `Vec<F>`.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## References
+///
+/// \[0\] B. Shahriari, K. Swersky, Z. Wang, R. P. Adams and N. de Freitas (2016). Taking the Human
+/// Out of the Loop: A Review of Bayesian Optimization. Proceedings of the IEEE 104(1), 148–175.
+/// <https://doi.org/10.1109/JPROC.2015.2494218>
+///
+/// \[1\] Carl E. Rasmussen and Christopher K. I. Williams (2006). Gaussian Processes for Machine
+/// Learning. MIT Press. ISBN 0-262-18253-X.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BayesianOptimization<K, A, F, R> {
+    /// Bounds on parameter space
+    bounds: (Vec<F>, Vec<F>),
+    /// Surrogate model
+    gp: GaussianProcess<K, F>,
+    /// Acquisition function
+    acquisition: A,
+    /// Method used to maximize the acquisition function
+    acquisition_optimizer: AcquisitionOptimizer,
+    /// Number of points of the initial design
+    num_initial_samples: usize,
+    /// Number of points proposed per iteration
+    batch_size: usize,
+    /// Maximum number of iterations for fitting the kernel hyperparameters
+    hyperparameter_iters: u64,
+    /// Maximum number of cost function evaluations
+    max_evals: u64,
+    /// Evaluated points in normalized coordinates
+    samples: Vec<Vec<F>>,
+    /// Cost function values of the evaluated points
+    costs: Vec<F>,
+    /// Random number generator
+    rng: R,
+}
+
+impl<K, A, F> BayesianOptimization<K, A, F, Xoshiro256PlusPlus>
+where
+    K: Kernel<F> + Clone,
+    A: AcquisitionFunction<F>,
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `BayesianOptimization`
+    ///
+    /// Takes the bounds on the search space, the covariance function of the Gaussian process and
+    /// the acquisition function as input. `bounds` is a tuple `(lower_bound, upper_bound)` where
+    /// both bounds are of the same length as the parameter vector.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`BayesianOptimization::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{BayesianOptimization, ExpectedImprovement, Matern52};
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let solver = BayesianOptimization::new(
+    ///     (lower_bound, upper_bound),
+    ///     Matern52::default(),
+    ///     ExpectedImprovement::new(),
+    /// );
+    /// ```
+    pub fn new(bounds: (Vec<F>, Vec<F>), kernel: K, acquisition: A) -> Self {
+        BayesianOptimization::new_with_rng(
+            bounds,
+            kernel,
+            acquisition,
+            Xoshiro256PlusPlus::from_entropy(),
+        )
+    }
+}
+
+impl<K, A, F, R> BayesianOptimization<K, A, F, R>
+where
+    K: Kernel<F> + Clone,
+    A: AcquisitionFunction<F>,
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `BayesianOptimization` with a given RNG
+    ///
+    /// Requires a RNG which must implement `rand::Rng` (and `serde::Serialize` if the `serde1`
+    /// feature is enabled). The RNG is used for the initial design and for the random starting
+    /// points of [`AcquisitionOptimizer::LBFGS`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{BayesianOptimization, ExpectedImprovement, Matern52};
+    /// # use rand::SeedableRng;
+    /// # use rand_xoshiro::Xoshiro256PlusPlus;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let solver = BayesianOptimization::new_with_rng(
+    ///     (lower_bound, upper_bound),
+    ///     Matern52::default(),
+    ///     ExpectedImprovement::new(),
+    ///     Xoshiro256PlusPlus::seed_from_u64(42),
+    /// );
+    /// ```
+    pub fn new_with_rng(bounds: (Vec<F>, Vec<F>), kernel: K, acquisition: A, rng: R) -> Self {
+        BayesianOptimization {
+            bounds,
+            gp: GaussianProcess::new(kernel),
+            acquisition,
+            acquisition_optimizer: AcquisitionOptimizer::default(),
+            num_initial_samples: 5,
+            batch_size: 1,
+            hyperparameter_iters: 100,
+            max_evals: u64::MAX,
+            samples: vec![],
+            costs: vec![],
+            rng,
+        }
+    }
+
+    /// Set noise variance of the Gaussian process
+    ///
+    /// The noise variance refers to the standardized cost function values. Must be non-negative
+    /// and defaults to `1e-6`. Larger values are appropriate for noisy cost functions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{BayesianOptimization, ExpectedImprovement, Matern52};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds: (Vec<f64>, Vec<f64>) = (vec![-1.0], vec![1.0]);
+    /// let solver = BayesianOptimization::new(bounds, Matern52::default(), ExpectedImprovement::new())
+    ///     .with_noise(1e-2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_noise(mut self, noise: F) -> Result<Self, Error> {
+        self.gp = self.gp.with_noise(noise)?;
+        Ok(self)
+    }
+
+    /// Set number of points of the initial design
+    ///
+    /// Must be larger than 0 and defaults to 5. If an initial parameter vector is provided, it is
+    /// evaluated in addition to these points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{BayesianOptimization, ExpectedImprovement, Matern52};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds: (Vec<f64>, Vec<f64>) = (vec![-1.0], vec![1.0]);
+    /// let solver = BayesianOptimization::new(bounds, Matern52::default(), ExpectedImprovement::new())
+    ///     .with_initial_samples(10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_initial_samples(mut self, num_initial_samples: usize) -> Result<Self, Error> {
+        if num_initial_samples == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BayesianOptimization`: number of initial samples must be > 0."
+            ));
+        }
+        self.num_initial_samples = num_initial_samples;
+        Ok(self)
+    }
+
+    /// Set number of points proposed and evaluated per iteration
+    ///
+    /// Must be larger than 0 and defaults to 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{BayesianOptimization, ExpectedImprovement, Matern52};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds: (Vec<f64>, Vec<f64>) = (vec![-1.0], vec![1.0]);
+    /// let solver = BayesianOptimization::new(bounds, Matern52::default(), ExpectedImprovement::new())
+    ///     .with_batch_size(4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_batch_size(mut self, batch_size: usize) -> Result<Self, Error> {
+        if batch_size == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BayesianOptimization`: batch size must be > 0."
+            ));
+        }
+        self.batch_size = batch_size;
+        Ok(self)
+    }
+
+    /// Set maximum number of iterations for fitting the kernel hyperparameters
+    ///
+    /// The hyperparameters are fitted by maximizing the log marginal likelihood with
+    /// [`NelderMead`](`crate::solver::neldermead::NelderMead`) after each iteration. Setting this
+    /// to 0 disables fitting and keeps the hyperparameters of the given kernel. Defaults to 100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{BayesianOptimization, ExpectedImprovement, Matern52};
+    /// # let bounds: (Vec<f64>, Vec<f64>) = (vec![-1.0], vec![1.0]);
+    /// let solver = BayesianOptimization::new(bounds, Matern52::default(), ExpectedImprovement::new())
+    ///     .with_hyperparameter_fitting(0);
+    /// ```
+    #[must_use]
+    pub fn with_hyperparameter_fitting(mut self, max_iters: u64) -> Self {
+        self.hyperparameter_iters = max_iters;
+        self
+    }
+
+    /// Set method used to maximize the acquisition function
+    ///
+    /// Defaults to [`ParticleSwarm`] with 40 particles and 50 iterations. The number of particles
+    /// or restarts must be larger than 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{
+    /// #     AcquisitionOptimizer, BayesianOptimization, ExpectedImprovement, Matern52
+    /// # };
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds: (Vec<f64>, Vec<f64>) = (vec![-1.0], vec![1.0]);
+    /// let solver = BayesianOptimization::new(bounds, Matern52::default(), ExpectedImprovement::new())
+    ///     .with_acquisition_optimizer(AcquisitionOptimizer::LBFGS {
+    ///         num_restarts: 10,
+    ///         max_iters: 50,
+    ///     })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_acquisition_optimizer(
+        mut self,
+        acquisition_optimizer: AcquisitionOptimizer,
+    ) -> Result<Self, Error> {
+        match acquisition_optimizer {
+            AcquisitionOptimizer::ParticleSwarm {
+                num_particles: 0, ..
+            }
+            | AcquisitionOptimizer::LBFGS {
+                num_restarts: 0, ..
+            } => Err(argmin_error!(
+                InvalidParameter,
+                "`BayesianOptimization`: number of particles or restarts must be > 0."
+            )),
+            _ => {
+                self.acquisition_optimizer = acquisition_optimizer;
+                Ok(self)
+            }
+        }
+    }
+
+    /// Set maximum number of cost function evaluations
+    ///
+    /// Defaults to `u64::MAX`. Note that an iteration is always completed, which means that the
+    /// budget may be exceeded by up to `batch_size - 1` evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bayesian::{BayesianOptimization, ExpectedImprovement, Matern52};
+    /// # let bounds: (Vec<f64>, Vec<f64>) = (vec![-1.0], vec![1.0]);
+    /// let solver = BayesianOptimization::new(bounds, Matern52::default(), ExpectedImprovement::new())
+    ///     .with_max_evals(100);
+    /// ```
+    #[must_use]
+    pub fn with_max_evals(mut self, max_evals: u64) -> Self {
+        self.max_evals = max_evals;
+        self
+    }
+
+    /// Returns the Gaussian process fitted to the (normalized) observations
+    pub fn gaussian_process(&self) -> &GaussianProcess<K, F> {
+        &self.gp
+    }
+
+    /// Maps a point of the unit hypercube to the search space
+    fn denormalize(&self, x: &[F]) -> Vec<F> {
+        let (lower, upper) = &self.bounds;
+        x.iter()
+            .zip(lower.iter().zip(upper.iter()))
+            .map(|(xi, (l, u))| *l + *xi * (*u - *l))
+            .collect()
+    }
+
+    /// Maps a point of the search space to the unit hypercube
+    fn normalize(&self, x: &[F]) -> Vec<F> {
+        let (lower, upper) = &self.bounds;
+        x.iter()
+            .zip(lower.iter().zip(upper.iter()))
+            .map(|(xi, (l, u))| ((*xi - *l) / (*u - *l)).max(float!(0.0)).min(float!(1.0)))
+            .collect()
+    }
+
+    /// Returns the index of the observation with the lowest cost function value
+    fn best_index(&self) -> usize {
+        let mut best = 0;
+        for (i, c) in self.costs.iter().enumerate() {
+            if *c < self.costs[best] {
+                best = i;
+            }
+        }
+        best
+    }
+
+    /// Fits the Gaussian process to all observations
+    fn update_model(&mut self) -> Result<(), Error>
+    where
+        Vec<F>: ArgminAdd<Vec<F>, Vec<F>> + ArgminSub<Vec<F>, Vec<F>> + ArgminMul<F, Vec<F>>,
+        F: std::iter::Sum<F>,
+    {
+        self.gp.fit(self.samples.clone(), &self.costs)?;
+        if self.hyperparameter_iters > 0 {
+            self.gp.fit_hyperparameters(self.hyperparameter_iters)?;
+        }
+        Ok(())
+    }
+}
+
+/// Negative acquisition function on the unit hypercube, minimized to find the next proposal
+struct AcquisitionProblem<'a, K, A, F> {
+    gp: &'a GaussianProcess<K, F>,
+    acquisition: &'a A,
+    best_cost: F,
+}
+
+impl<'a, K, A, F> CostFunction for AcquisitionProblem<'a, K, A, F>
+where
+    K: Kernel<F> + Clone,
+    A: AcquisitionFunction<F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let x: Vec<F> = param
+            .iter()
+            .map(|xi| xi.max(float!(0.0)).min(float!(1.0)))
+            .collect();
+        let (mean, std_dev) = self.gp.predict(&x);
+        Ok(-self.acquisition.evaluate(mean, std_dev, self.best_cost))
+    }
+}
+
+impl<'a, K, A, F> Gradient for AcquisitionProblem<'a, K, A, F>
+where
+    K: Kernel<F> + Clone,
+    A: AcquisitionFunction<F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        // Central differences
+        let h = F::epsilon().cbrt();
+        (0..param.len())
+            .map(|i| {
+                let mut plus = param.clone();
+                plus[i] = plus[i] + h;
+                let mut minus = param.clone();
+                minus[i] = minus[i] - h;
+                Ok((self.cost(&plus)? - self.cost(&minus)?) / (float!(2.0) * h))
+            })
+            .collect()
+    }
+}
+
+impl<K, A, F, R> BayesianOptimization<K, A, F, R>
+where
+    K: Kernel<F> + Clone + SyncAlias,
+    A: AcquisitionFunction<F> + SyncAlias,
+    R: Rng,
+    F: ArgminFloat + SyncAlias + std::iter::Sum<F>,
+    Vec<F>: ArgminAdd<Vec<F>, Vec<F>>
+        + ArgminAdd<F, Vec<F>>
+        + ArgminSub<Vec<F>, Vec<F>>
+        + ArgminSub<F, Vec<F>>
+        + ArgminMul<F, Vec<F>>
+        + ArgminMul<Vec<F>, Vec<F>>
+        + ArgminMulAssign<F>
+        + ArgminDot<Vec<F>, F>
+        + ArgminScaledAdd<Vec<F>, F, Vec<F>>
+        + ArgminScaledAddAssign<Vec<F>, F>
+        + ArgminScaledSubAssign<Vec<F>, F>
+        + ArgminL1Norm<F>
+        + ArgminL2Norm<F>
+        + ArgminSignum
+        + ArgminZeroLike
+        + ArgminMinMax
+        + ArgminRandom,
+{
+    /// Latin hypercube sample of `n` points in the unit hypercube
+    fn latin_hypercube(&mut self, n: usize) -> Vec<Vec<F>> {
+        let dim = self.bounds.0.len();
+        let mut points = vec![vec![float!(0.0); dim]; n];
+        let n_f = F::from_usize(n).unwrap();
+        for j in 0..dim {
+            let mut strata: Vec<usize> = (0..n).collect();
+            strata.shuffle(&mut self.rng);
+            for (point, stratum) in points.iter_mut().zip(strata) {
+                let u: F = float!(self.rng.gen_range(0.0..1.0));
+                point[j] = (F::from_usize(stratum).unwrap() + u) / n_f;
+            }
+        }
+        points
+    }
+
+    /// Returns the maximizer of the acquisition function (in normalized coordinates) and the
+    /// value of the acquisition function there.
+    fn maximize_acquisition(
+        &mut self,
+        gp: &GaussianProcess<K, F>,
+        best_cost: F,
+    ) -> Result<(Vec<F>, F), Error> {
+        let dim = self.bounds.0.len();
+        let problem = AcquisitionProblem {
+            gp,
+            acquisition: &self.acquisition,
+            best_cost,
+        };
+        let (x, cost) = match self.acquisition_optimizer {
+            AcquisitionOptimizer::ParticleSwarm {
+                num_particles,
+                max_iters,
+            } => {
+                // Seeded from `self.rng` such that the whole run is reproducible
+                let solver = ParticleSwarm::new_with_rng(
+                    (vec![float!(0.0); dim], vec![float!(1.0); dim]),
+                    num_particles,
+                    Xoshiro256PlusPlus::seed_from_u64(self.rng.gen()),
+                );
+                let mut res = Executor::new(problem, solver)
+                    .configure(|state| state.max_iters(max_iters))
+                    .ctrlc(false)
+                    .run()?;
+                let cost = res.state.get_best_cost();
+                let particle =
+                    res.state
+                        .take_best_individual()
+                        .ok_or_else(argmin_error_closure!(
+                            PotentialBug,
+                            "`BayesianOptimization`: No best particle returned by particle swarm."
+                        ))?;
+                (particle.position, cost)
+            }
+            AcquisitionOptimizer::LBFGS {
+                num_restarts,
+                max_iters,
+            } => {
+                let mut starts = vec![self.samples[self.best_index()].clone()];
+                for _ in 1..num_restarts {
+                    starts.push(
+                        (0..dim)
+                            .map(|_| float!(self.rng.gen_range(0.0..1.0)))
+                            .collect(),
+                    );
+                }
+                let mut best = starts[0].clone();
+                let mut best_value = problem.cost(&best)?;
+                for start in starts {
+                    let solver: LBFGS<_, Vec<F>, Vec<F>, F> =
+                        LBFGS::new(MoreThuenteLineSearch::new(), 7);
+                    // A line search which violates its conditions (for instance due to a flat
+                    // acquisition function) only discards this start; other errors are propagated.
+                    let candidate = match Executor::new(
+                        AcquisitionProblem {
+                            gp,
+                            acquisition: &self.acquisition,
+                            best_cost,
+                        },
+                        solver,
+                    )
+                    .configure(|state| state.param(start.clone()).max_iters(max_iters))
+                    .ctrlc(false)
+                    .run()
+                    {
+                        Ok(mut res) => res.state.take_best_param().unwrap_or(start),
+                        Err(e)
+                            if matches!(
+                                e.downcast_ref::<ArgminError>(),
+                                Some(ArgminError::ConditionViolated { .. })
+                            ) =>
+                        {
+                            start
+                        }
+                        Err(e) => return Err(e),
+                    };
+                    let value = problem.cost(&candidate)?;
+                    if value < best_value {
+                        best = candidate;
+                        best_value = value;
+                    }
+                }
+                (best, best_value)
+            }
+        };
+        let x = x
+            .into_iter()
+            .map(|xi| xi.max(float!(0.0)).min(float!(1.0)))
+            .collect();
+        Ok((x, -cost))
+    }
+}
+
+impl<O, K, A, F, R> Solver<O, IterState<Vec<F>, (), (), (), F>> for BayesianOptimization<K, A, F, R>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    K: Kernel<F> + Clone + SerializeAlias + SyncAlias,
+    A: AcquisitionFunction<F> + SerializeAlias + SyncAlias,
+    R: Rng + SerializeAlias,
+    F: ArgminFloat + SyncAlias + std::iter::Sum<F>,
+    Vec<F>: ArgminAdd<Vec<F>, Vec<F>>
+        + ArgminAdd<F, Vec<F>>
+        + ArgminSub<Vec<F>, Vec<F>>
+        + ArgminSub<F, Vec<F>>
+        + ArgminMul<F, Vec<F>>
+        + ArgminMul<Vec<F>, Vec<F>>
+        + ArgminMulAssign<F>
+        + ArgminDot<Vec<F>, F>
+        + ArgminScaledAdd<Vec<F>, F, Vec<F>>
+        + ArgminScaledAddAssign<Vec<F>, F>
+        + ArgminScaledSubAssign<Vec<F>, F>
+        + ArgminL1Norm<F>
+        + ArgminL2Norm<F>
+        + ArgminSignum
+        + ArgminZeroLike
+        + ArgminMinMax
+        + ArgminRandom,
+{
+    const NAME: &'static str = "Bayesian Optimization";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let (lower, upper) = &self.bounds;
+        if lower.is_empty() || lower.len() != upper.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BayesianOptimization`: Lower and upper bound must be non-empty and of the same length."
+            ));
+        }
+        if lower.iter().zip(upper.iter()).any(|(l, u)| l >= u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BayesianOptimization`: Lower bound must be smaller than upper bound."
+            ));
+        }
+
+        let mut samples = vec![];
+        if let Some(param) = state.take_param() {
+            if param.len() != lower.len() {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "`BayesianOptimization`: Initial parameter vector and bounds must be of the same length."
+                ));
+            }
+            samples.push(self.normalize(&param));
+        }
+        samples.extend(self.latin_hypercube(self.num_initial_samples));
+
+        let params: Vec<Vec<F>> = samples.iter().map(|x| self.denormalize(x)).collect();
+        self.costs = problem.bulk_cost(&params)?;
+        self.samples = samples;
+        self.update_model()?;
+
+        let best = self.best_index();
+        Ok((
+            state.param(params[best].clone()).cost(self.costs[best]),
+            Some(kv!("num_samples" => self.samples.len() as u64;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        if self.samples.is_empty() {
+            return Err(argmin_error!(
+                PotentialBug,
+                "`BayesianOptimization`: No observations available."
+            ));
+        }
+        let best_cost = self.costs[self.best_index()];
+
+        // Propose a batch of points. All but the last proposal are added to a copy of the model
+        // with the predicted mean as observation ("kriging believer").
+        let mut gp = self.gp.clone();
+        let mut samples = self.samples.clone();
+        let mut costs = self.costs.clone();
+        let mut proposals = Vec::with_capacity(self.batch_size);
+        let mut acquisition = float!(0.0);
+        for i in 0..self.batch_size {
+            let (x, value) = self.maximize_acquisition(&gp, best_cost)?;
+            if i == 0 {
+                acquisition = value;
+            }
+            if i + 1 < self.batch_size {
+                let (mean, _) = gp.predict(&x);
+                samples.push(x.clone());
+                costs.push(mean);
+                gp.fit(samples.clone(), &costs)?;
+            }
+            proposals.push(x);
+        }
+
+        let params: Vec<Vec<F>> = proposals.iter().map(|x| self.denormalize(x)).collect();
+        let new_costs = problem.bulk_cost(&params)?;
+        let mut best_new = 0;
+        for (i, c) in new_costs.iter().enumerate() {
+            if *c < new_costs[best_new] {
+                best_new = i;
+            }
+        }
+        let cost = new_costs[best_new];
+        self.samples.extend(proposals);
+        self.costs.extend(new_costs);
+        self.update_model()?;
+
+        Ok((
+            state.param(params[best_new].clone()).cost(cost),
+            Some(kv!(
+                "num_samples" => self.samples.len() as u64;
+                "acquisition" => acquisition;
+                "log_likelihood" => self.gp.log_marginal_likelihood();
+            )),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<Vec<F>, (), (), (), F>) -> TerminationStatus {
+        if let Some(&evals) = state.get_func_counts().get("cost_count") {
+            if evals >= self.max_evals {
+                return TerminationStatus::Terminated(TerminationReason::SolverExit(
+                    "Maximum number of cost function evaluations reached".to_string(),
+                ));
+            }
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError};
+    use approx::assert_relative_eq;
+
+    type Bo =
+        BayesianOptimization<Matern52<f64>, ExpectedImprovement<f64>, f64, Xoshiro256PlusPlus>;
+
+    test_trait_impl!(bayesian_optimization, Bo);
+
+    #[derive(Clone)]
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 0.3).powi(2) + (p[1] + 0.7).powi(2))
+        }
+    }
+
+    fn solver() -> Bo {
+        BayesianOptimization::new_with_rng(
+            (vec![-1.0, -1.0], vec![1.0, 1.0]),
+            Matern52::default(),
+            ExpectedImprovement::new(),
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let BayesianOptimization {
+            bounds,
+            gp,
+            acquisition,
+            acquisition_optimizer,
+            num_initial_samples,
+            batch_size,
+            hyperparameter_iters,
+            max_evals,
+            samples,
+            costs,
+            rng: _rng,
+        } = solver();
+        assert_eq!(bounds, (vec![-1.0, -1.0], vec![1.0, 1.0]));
+        assert_eq!(gp.kernel(), &Matern52::default());
+        assert_eq!(gp.num_observations(), 0);
+        assert_eq!(acquisition, ExpectedImprovement::new());
+        assert_eq!(acquisition_optimizer, AcquisitionOptimizer::default());
+        assert_eq!(num_initial_samples, 5);
+        assert_eq!(batch_size, 1);
+        assert_eq!(hyperparameter_iters, 100);
+        assert_eq!(max_evals, u64::MAX);
+        assert!(samples.is_empty());
+        assert!(costs.is_empty());
+    }
+
+    #[test]
+    fn test_builders() {
+        let bo = solver()
+            .with_noise(0.1)
+            .unwrap()
+            .with_initial_samples(8)
+            .unwrap()
+            .with_batch_size(3)
+            .unwrap()
+            .with_hyperparameter_fitting(0)
+            .with_acquisition_optimizer(AcquisitionOptimizer::LBFGS {
+                num_restarts: 4,
+                max_iters: 20,
+            })
+            .unwrap()
+            .with_max_evals(30);
+        assert_eq!(bo.num_initial_samples, 8);
+        assert_eq!(bo.batch_size, 3);
+        assert_eq!(bo.hyperparameter_iters, 0);
+        assert_eq!(
+            bo.acquisition_optimizer,
+            AcquisitionOptimizer::LBFGS {
+                num_restarts: 4,
+                max_iters: 20,
+            }
+        );
+        assert_eq!(bo.max_evals, 30);
+    }
+
+    #[test]
+    fn test_builders_invalid() {
+        assert_error!(
+            solver().with_noise(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`GaussianProcess`: noise must be >= 0.\""
+        );
+        assert_error!(
+            solver().with_initial_samples(0),
+            ArgminError,
+            "Invalid parameter: \"`BayesianOptimization`: number of initial samples must be > 0.\""
+        );
+        assert_error!(
+            solver().with_batch_size(0),
+            ArgminError,
+            "Invalid parameter: \"`BayesianOptimization`: batch size must be > 0.\""
+        );
+        for opt in [
+            AcquisitionOptimizer::ParticleSwarm {
+                num_particles: 0,
+                max_iters: 10,
+            },
+            AcquisitionOptimizer::LBFGS {
+                num_restarts: 0,
+                max_iters: 10,
+            },
+        ] {
+            assert_error!(
+                solver().with_acquisition_optimizer(opt),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`BayesianOptimization`: ",
+                    "number of particles or restarts must be > 0.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_invalid_bounds() {
+        for (bounds, msg) in [
+            (
+                (vec![], vec![]),
+                concat!(
+                    "Invalid parameter: \"`BayesianOptimization`: Lower and upper bound must be ",
+                    "non-empty and of the same length.\""
+                ),
+            ),
+            (
+                (vec![1.0], vec![0.0]),
+                concat!(
+                    "Invalid parameter: \"`BayesianOptimization`: Lower bound must be smaller ",
+                    "than upper bound.\""
+                ),
+            ),
+        ] {
+            let mut bo: Bo = BayesianOptimization::new_with_rng(
+                bounds,
+                Matern52::default(),
+                ExpectedImprovement::new(),
+                Xoshiro256PlusPlus::seed_from_u64(0),
+            );
+            let res = bo.init(&mut Problem::new(TestProblem::new()), IterState::new());
+            assert_error!(res, ArgminError, msg);
+        }
+    }
+
+    #[test]
+    fn test_latin_hypercube() {
+        let mut bo = solver();
+        let points = bo.latin_hypercube(10);
+        assert_eq!(points.len(), 10);
+        for j in 0..2 {
+            let mut strata: Vec<usize> = points.iter().map(|p| (p[j] * 10.0) as usize).collect();
+            strata.sort();
+            assert_eq!(strata, (0..10).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_init() {
+        let mut bo = solver();
+        let mut problem = Problem::new(Quadratic {});
+        let (state, kv) = bo
+            .init(&mut problem, IterState::new().param(vec![0.3, -0.7]))
+            .unwrap();
+        assert_eq!(bo.samples.len(), 6);
+        assert_eq!(bo.gp.num_observations(), 6);
+        assert_eq!(problem.counts["cost_count"], 6);
+        let param = state.get_param().unwrap();
+        assert_relative_eq!(param[0], 0.3, epsilon = 1e-12);
+        assert_relative_eq!(param[1], -0.7, epsilon = 1e-12);
+        assert_relative_eq!(state.get_cost(), 0.0, epsilon = 1e-12);
+        assert_eq!(kv.unwrap().get("num_samples").unwrap().get_uint(), Some(6));
+    }
+
+    #[test]
+    fn test_batch() {
+        let mut bo = solver()
+            .with_batch_size(3)
+            .unwrap()
+            .with_hyperparameter_fitting(0);
+        let mut problem = Problem::new(Quadratic {});
+        let (state, _) = bo.init(&mut problem, IterState::new()).unwrap();
+        let (_, kv) = bo.next_iter(&mut problem, state).unwrap();
+        assert_eq!(bo.samples.len(), 8);
+        assert_eq!(problem.counts["cost_count"], 8);
+        assert_eq!(kv.unwrap().get("num_samples").unwrap().get_uint(), Some(8));
+        for x in bo.samples.iter() {
+            assert!(x.iter().all(|xi| (0.0..=1.0).contains(xi)));
+        }
+    }
+
+    #[test]
+    fn test_max_evals() {
+        let res = Executor::new(Quadratic {}, solver().with_max_evals(8))
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_func_counts()["cost_count"], 8);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Maximum number of cost function evaluations reached".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        for opt in [
+            AcquisitionOptimizer::default(),
+            AcquisitionOptimizer::LBFGS {
+                num_restarts: 5,
+                max_iters: 30,
+            },
+        ] {
+            let res = Executor::new(
+                Quadratic {},
+                solver().with_acquisition_optimizer(opt).unwrap(),
+            )
+            .configure(|state| state.max_iters(20))
+            .run()
+            .unwrap();
+            let best = res.state.get_best_param().unwrap();
+            assert!(res.state.get_best_cost() < 1e-2);
+            assert!((best[0] - 0.3).abs() < 0.1);
+            assert!((best[1] + 0.7).abs() < 0.1);
+        }
+    }
+}
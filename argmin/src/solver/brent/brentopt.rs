@@ -8,6 +8,7 @@
 use crate::core::{
     ArgminFloat, CostFunction, Error, IterState, Problem, Solver, State, TerminationReason, KV,
 };
+use crate::solver::linesearch::BracketedMinimizer;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -97,6 +98,12 @@ impl<F: ArgminFloat> BrentOpt<F> {
     }
 }
 
+impl<F: ArgminFloat> BracketedMinimizer<F> for BrentOpt<F> {
+    fn with_bracket(&self, lower: F, upper: F) -> Result<Self, Error> {
+        Ok(BrentOpt::new(lower, upper).set_tolerance(self.eps, self.t))
+    }
+}
+
 impl<O, F> Solver<O, IterState<F, (), (), (), F>> for BrentOpt<F>
 where
     O: CostFunction<Param = F, Output = F>,
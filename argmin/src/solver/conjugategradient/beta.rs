@@ -17,7 +17,7 @@
 //! \[0\] Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 //! Springer. ISBN 0-387-30303-0.
 
-use crate::core::{ArgminFloat, SerializeAlias};
+use crate::core::{ArgminFloat, Error, SerializeAlias};
 use argmin_math::{ArgminDot, ArgminL2Norm, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -252,13 +252,487 @@ where
     }
 }
 
+/// Dai and Yuan (DY) method
+///
+/// Formula: `||\nabla f_{k+1}||^2 / <(\nabla f_{k+1} - \nabla f_k), p_k>`
+///
+/// # Reference
+///
+/// Yu-Hong Dai and Yaxiang Yuan (1999). A Nonlinear Conjugate Gradient Method with a Strong
+/// Global Convergence Property. SIAM Journal on Optimization 10(1), 177–182.
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct DaiYuan {}
+
+impl DaiYuan {
+    /// Construct a new instance of `DaiYuan`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::beta::DaiYuan;
+    /// let beta_method = DaiYuan::new();
+    /// ```
+    pub fn new() -> Self {
+        DaiYuan {}
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for DaiYuan
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G>,
+    F: ArgminFloat,
+{
+    /// Update beta using the Dai-Yuan method.
+    ///
+    /// Formula: `||\nabla f_{k+1}||^2 / <(\nabla f_{k+1} - \nabla f_k), p_k>`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate approx;
+    /// # use approx::assert_relative_eq;
+    /// # use argmin::solver::conjugategradient::beta::{NLCGBetaUpdate, DaiYuan};
+    /// # let dfk = vec![1f64, 2.0];
+    /// # let dfk1 = vec![3f64, 4.0];
+    /// # let pk = vec![5f64, 6.0];
+    /// let beta_method = DaiYuan::new();
+    /// let beta: f64 = beta_method.update(&dfk, &dfk1, &pk);
+    /// # assert_relative_eq!(beta, 25.0/22.0, epsilon = f64::EPSILON);
+    /// ```
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> F {
+        dfk1.dot(dfk1) / dfk1.sub(dfk).dot(pk)
+    }
+}
+
+/// Hager and Zhang (HZ) method
+///
+/// Formula: `max(beta_k, eta_k)` with
+///
+/// `beta_k = <y_k - 2 * p_k * ||y_k||^2 / <y_k, p_k>, \nabla f_{k+1}> / <y_k, p_k>`,
+///
+/// `eta_k = -1 / (||p_k|| * min(eta, ||\nabla f_k||))`
+///
+/// and `y_k = \nabla f_{k+1} - \nabla f_k`. The lower bound `eta_k` ensures global convergence
+/// for general nonlinear functions; `eta` defaults to `0.01`.
+///
+/// # Reference
+///
+/// William W. Hager and Hongchao Zhang (2005). A New Conjugate Gradient Method with Guaranteed
+/// Descent and an Efficient Line Search. SIAM Journal on Optimization 16(1), 170–192.
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HagerZhang<F> {
+    /// Parameter of the lower bound
+    eta: F,
+}
+
+impl<F: ArgminFloat> HagerZhang<F> {
+    /// Construct a new instance of `HagerZhang`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::beta::HagerZhang;
+    /// let beta_method: HagerZhang<f64> = HagerZhang::new();
+    /// ```
+    pub fn new() -> Self {
+        HagerZhang { eta: float!(0.01) }
+    }
+
+    /// Set the parameter `eta` of the lower bound.
+    ///
+    /// Must be positive and defaults to `0.01`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::beta::HagerZhang;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let beta_method: HagerZhang<f64> = HagerZhang::new().with_eta(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_eta(mut self, eta: F) -> Result<Self, Error> {
+        if eta <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HagerZhang`: eta must be > 0."
+            ));
+        }
+        self.eta = eta;
+        Ok(self)
+    }
+}
+
+impl<F: ArgminFloat> Default for HagerZhang<F> {
+    fn default() -> Self {
+        HagerZhang::new()
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for HagerZhang<F>
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G> + ArgminL2Norm<F>,
+    P: ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    /// Update beta using the Hager-Zhang method.
+    ///
+    /// Formula: `max(beta_k, eta_k)` (see [`HagerZhang`])
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate approx;
+    /// # use approx::assert_relative_eq;
+    /// # use argmin::solver::conjugategradient::beta::{NLCGBetaUpdate, HagerZhang};
+    /// # let dfk = vec![1f64, 2.0];
+    /// # let dfk1 = vec![3f64, 4.0];
+    /// # let pk = vec![5f64, 6.0];
+    /// let beta_method = HagerZhang::new();
+    /// let beta: f64 = beta_method.update(&dfk, &dfk1, &pk);
+    /// # assert_relative_eq!(beta, -79.0/121.0, epsilon = f64::EPSILON);
+    /// ```
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> F {
+        let y = dfk1.sub(dfk);
+        let ytp: F = y.dot(pk);
+        let beta = (y.dot(dfk1) - float!(2.0) * y.dot(&y) * dfk1.dot(pk) / ytp) / ytp;
+        let eta = float!(-1.0) / (pk.l2_norm() * self.eta.min(dfk.l2_norm()));
+        beta.max(eta)
+    }
+}
+
+/// Liu and Storey (LS) method
+///
+/// Formula: `-<\nabla f_{k+1}, (\nabla f_{k+1} - \nabla f_k)> / <\nabla f_k, p_k>`
+///
+/// # Reference
+///
+/// Y. Liu and C. Storey (1991). Efficient generalized conjugate gradient algorithms, part 1:
+/// Theory. Journal of Optimization Theory and Applications 69(1), 129–137.
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LiuStorey {}
+
+impl LiuStorey {
+    /// Construct a new instance of `LiuStorey`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::beta::LiuStorey;
+    /// let beta_method = LiuStorey::new();
+    /// ```
+    pub fn new() -> Self {
+        LiuStorey {}
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for LiuStorey
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G>,
+    F: ArgminFloat,
+{
+    /// Update beta using the Liu-Storey method.
+    ///
+    /// Formula: `-<\nabla f_{k+1}, (\nabla f_{k+1} - \nabla f_k)> / <\nabla f_k, p_k>`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate approx;
+    /// # use approx::assert_relative_eq;
+    /// # use argmin::solver::conjugategradient::beta::{NLCGBetaUpdate, LiuStorey};
+    /// # let dfk = vec![1f64, 2.0];
+    /// # let dfk1 = vec![3f64, 4.0];
+    /// # let pk = vec![5f64, 6.0];
+    /// let beta_method = LiuStorey::new();
+    /// let beta: f64 = beta_method.update(&dfk, &dfk1, &pk);
+    /// # assert_relative_eq!(beta, -14.0/17.0, epsilon = f64::EPSILON);
+    /// ```
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> F {
+        -dfk1.dot(&dfk1.sub(dfk)) / dfk.dot(pk)
+    }
+}
+
+/// Conjugate descent (CD) method by Fletcher
+///
+/// Formula: `-||\nabla f_{k+1}||^2 / <\nabla f_k, p_k>`
+///
+/// # Reference
+///
+/// Roger Fletcher (1987). Practical Methods of Optimization, vol. 1: Unconstrained Optimization.
+/// John Wiley & Sons.
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ConjugateDescent {}
+
+impl ConjugateDescent {
+    /// Construct a new instance of `ConjugateDescent`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::beta::ConjugateDescent;
+    /// let beta_method = ConjugateDescent::new();
+    /// ```
+    pub fn new() -> Self {
+        ConjugateDescent {}
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for ConjugateDescent
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    /// Update beta using the conjugate descent method.
+    ///
+    /// Formula: `-||\nabla f_{k+1}||^2 / <\nabla f_k, p_k>`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate approx;
+    /// # use approx::assert_relative_eq;
+    /// # use argmin::solver::conjugategradient::beta::{NLCGBetaUpdate, ConjugateDescent};
+    /// # let dfk = vec![1f64, 2.0];
+    /// # let dfk1 = vec![3f64, 4.0];
+    /// # let pk = vec![5f64, 6.0];
+    /// let beta_method = ConjugateDescent::new();
+    /// let beta: f64 = beta_method.update(&dfk, &dfk1, &pk);
+    /// # assert_relative_eq!(beta, -25.0/17.0, epsilon = f64::EPSILON);
+    /// ```
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> F {
+        -dfk1.dot(dfk1) / dfk.dot(pk)
+    }
+}
+
+/// Hybrid Hestenes-Stiefel and Dai-Yuan (HS-DY) method
+///
+/// Formula: `max(0, min(beta_HS, beta_DY))`, where `beta_HS` and `beta_DY` are computed by
+/// [`HestenesStiefel`] and [`DaiYuan`], respectively.
+///
+/// # Reference
+///
+/// Yu-Hong Dai and Yaxiang Yuan (2001). An Efficient Hybrid Conjugate Gradient Method for
+/// Unconstrained Optimization. Annals of Operations Research 103, 33–47.
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HestenesStiefelDaiYuan {}
+
+impl HestenesStiefelDaiYuan {
+    /// Construct a new instance of `HestenesStiefelDaiYuan`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::beta::HestenesStiefelDaiYuan;
+    /// let beta_method = HestenesStiefelDaiYuan::new();
+    /// ```
+    pub fn new() -> Self {
+        HestenesStiefelDaiYuan {}
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for HestenesStiefelDaiYuan
+where
+    G: ArgminDot<G, F> + ArgminDot<P, F> + ArgminSub<G, G>,
+    F: ArgminFloat,
+{
+    /// Update beta using the hybrid Hestenes-Stiefel/Dai-Yuan method.
+    ///
+    /// Formula: `max(0, min(beta_HS, beta_DY))`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate approx;
+    /// # use approx::assert_relative_eq;
+    /// # use argmin::solver::conjugategradient::beta::{NLCGBetaUpdate, HestenesStiefelDaiYuan};
+    /// # let dfk = vec![1f64, 2.0];
+    /// # let dfk1 = vec![3f64, 4.0];
+    /// # let pk = vec![5f64, 6.0];
+    /// let beta_method = HestenesStiefelDaiYuan::new();
+    /// let beta: f64 = beta_method.update(&dfk, &dfk1, &pk);
+    /// # assert_relative_eq!(beta, 14.0/22.0, epsilon = f64::EPSILON);
+    /// #
+    /// # let pk = vec![-5f64, -6.0];
+    /// # let beta: f64 = beta_method.update(&dfk, &dfk1, &pk);
+    /// # assert_relative_eq!(beta, 0.0, epsilon = f64::EPSILON);
+    /// ```
+    fn update(&self, dfk: &G, dfk1: &G, pk: &P) -> F {
+        let y = dfk1.sub(dfk);
+        let ytp: F = y.dot(pk);
+        let beta_hs = dfk1.dot(&y) / ytp;
+        let beta_dy = dfk1.dot(dfk1) / ytp;
+        float!(0.0).max(beta_hs.min(beta_dy))
+    }
+}
+
+/// Hybrid Polak-Ribiere and Fletcher-Reeves (PR-FR) method
+///
+/// Formula: `max(-beta_FR, min(beta_PR, beta_FR))`, where `beta_PR` and `beta_FR` are computed
+/// by [`PolakRibiere`] and [`FletcherReeves`], respectively.
+///
+/// # Reference
+///
+/// Jean Charles Gilbert and Jorge Nocedal (1992). Global Convergence Properties of Conjugate
+/// Gradient Methods for Optimization. SIAM Journal on Optimization 2(1), 21–42.
+#[derive(Default, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct PolakRibiereFletcherReeves {}
+
+impl PolakRibiereFletcherReeves {
+    /// Construct a new instance of `PolakRibiereFletcherReeves`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::beta::PolakRibiereFletcherReeves;
+    /// let beta_method = PolakRibiereFletcherReeves::new();
+    /// ```
+    pub fn new() -> Self {
+        PolakRibiereFletcherReeves {}
+    }
+}
+
+impl<G, P, F> NLCGBetaUpdate<G, P, F> for PolakRibiereFletcherReeves
+where
+    G: ArgminDot<G, F> + ArgminSub<G, G>,
+    F: ArgminFloat,
+{
+    /// Update beta using the hybrid Polak-Ribiere/Fletcher-Reeves method.
+    ///
+    /// Formula: `max(-beta_FR, min(beta_PR, beta_FR))`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate approx;
+    /// # use approx::assert_relative_eq;
+    /// # use argmin::solver::conjugategradient::beta::{NLCGBetaUpdate, PolakRibiereFletcherReeves};
+    /// # let dfk = vec![1f64, 2.0];
+    /// # let dfk1 = vec![3f64, 4.0];
+    /// let beta_method = PolakRibiereFletcherReeves::new();
+    /// let beta: f64 = beta_method.update(&dfk, &dfk1, &());
+    /// # assert_relative_eq!(beta, 14.0/5.0, epsilon = f64::EPSILON);
+    /// #
+    /// # let dfk = vec![1f64, 0.0];
+    /// # let dfk1 = vec![-2f64, 0.0];
+    /// # let beta: f64 = beta_method.update(&dfk, &dfk1, &());
+    /// # assert_relative_eq!(beta, 4.0, epsilon = f64::EPSILON);
+    /// ```
+    fn update(&self, dfk: &G, dfk1: &G, _pk: &P) -> F {
+        let dfk_norm_sq: F = dfk.dot(dfk);
+        let beta_fr = dfk1.dot(dfk1) / dfk_norm_sq;
+        let beta_pr = dfk1.dot(&dfk1.sub(dfk)) / dfk_norm_sq;
+        (-beta_fr).max(beta_pr.min(beta_fr))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{ArgminError, CostFunction, Executor, Gradient, State, TerminationReason};
+    use crate::solver::conjugategradient::NonlinearConjugateGradient;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
     use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
 
     test_trait_impl!(fletcher_reeves, FletcherReeves);
     test_trait_impl!(polak_ribiere, PolakRibiere);
     test_trait_impl!(polak_ribiere_plus, PolakRibierePlus);
     test_trait_impl!(hestenes_stiefel, HestenesStiefel);
+    test_trait_impl!(dai_yuan, DaiYuan);
+    test_trait_impl!(hager_zhang, HagerZhang<f64>);
+    test_trait_impl!(liu_storey, LiuStorey);
+    test_trait_impl!(conjugate_descent, ConjugateDescent);
+    test_trait_impl!(hestenes_stiefel_dai_yuan, HestenesStiefelDaiYuan);
+    test_trait_impl!(polak_ribiere_fletcher_reeves, PolakRibiereFletcherReeves);
+
+    #[test]
+    fn test_hager_zhang_eta() {
+        let beta_method: HagerZhang<f64> = HagerZhang::new();
+        assert_eq!(beta_method.eta.to_ne_bytes(), 0.01f64.to_ne_bytes());
+        let beta_method = beta_method.with_eta(0.1).unwrap();
+        assert_eq!(beta_method.eta.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        for eta in [0.0, -1.0] {
+            assert_error!(
+                HagerZhang::new().with_eta(eta),
+                ArgminError,
+                "Invalid parameter: \"`HagerZhang`: eta must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_hager_zhang_lower_bound() {
+        // `beta_k = -0.76 / 0.16` is below the lower bound `eta_k = -1 / (1 * min(1, 0.5))`
+        let dfk = vec![0.5f64, 0.0];
+        let dfk1 = vec![0.1f64, 1.0];
+        let pk = vec![1.0f64, 0.0];
+        let beta_method: HagerZhang<f64> = HagerZhang::new().with_eta(1.0).unwrap();
+        let beta: f64 = beta_method.update(&dfk, &dfk1, &pk);
+        assert_relative_eq!(beta, -2.0, epsilon = f64::EPSILON);
+    }
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    fn assert_converges<B>(beta_method: B)
+    where
+        B: NLCGBetaUpdate<Vec<f64>, Vec<f64>, f64>,
+    {
+        let linesearch = MoreThuenteLineSearch::new();
+        let solver = NonlinearConjugateGradient::new(linesearch, beta_method)
+            .restart_iters(10)
+            .restart_orthogonality(0.1);
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| {
+                state
+                    .param(vec![-1.2, 1.0])
+                    .max_iters(1000)
+                    .target_cost(1e-12)
+            })
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::TargetCostReached)
+        );
+        let x = res.state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(x[1], 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_convergence() {
+        assert_converges(DaiYuan::new());
+        assert_converges(HagerZhang::new());
+        assert_converges(LiuStorey::new());
+        assert_converges(ConjugateDescent::new());
+        assert_converges(HestenesStiefelDaiYuan::new());
+        assert_converges(PolakRibiereFletcherReeves::new());
+    }
 }
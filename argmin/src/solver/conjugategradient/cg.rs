@@ -8,7 +8,11 @@
 use crate::core::{
     ArgminFloat, Error, IterState, Operator, Problem, SerializeAlias, Solver, State, KV,
 };
-use argmin_math::{ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub};
+use crate::solver::preconditioner::{IdentityPreconditioner, Preconditioner};
+use argmin_math::{
+    ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminMulAssign, ArgminScaledAddAssign,
+    ArgminSub,
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +23,10 @@ use serde::{Deserialize, Serialize};
 /// Solves systems of the form `A * x = b` where `x` and `b` are vectors and `A` is a symmetric and
 /// positive-definite matrix.
 ///
+/// An optional symmetric positive definite preconditioner `M` can be provided via
+/// [`with_preconditioner`](`ConjugateGradient::with_preconditioner`), which improves convergence
+/// if `M^-1 * A` is better conditioned than `A`.
+///
 /// Requires an initial parameter vector.
 ///
 /// ## Requirements on the optimization problem
@@ -31,16 +39,18 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct ConjugateGradient<P, F> {
+pub struct ConjugateGradient<P, F, M = IdentityPreconditioner> {
     /// b (right hand side of `A * x = b`)
     b: P,
+    /// Preconditioner
+    preconditioner: M,
     /// Residual
     r: Option<P>,
     /// p
     p: Option<P>,
     /// previous p
     p_prev: Option<P>,
-    /// r^T * r
+    /// r^T * M^-1 * r
     rtr: F,
 }
 
@@ -62,12 +72,50 @@ where
     pub fn new(b: P) -> Self {
         ConjugateGradient {
             b,
+            preconditioner: IdentityPreconditioner,
             r: None,
             p: None,
             p_prev: None,
             rtr: F::nan(),
         }
     }
+}
+
+impl<P, F, M> ConjugateGradient<P, F, M>
+where
+    F: ArgminFloat,
+{
+    /// Set preconditioner
+    ///
+    /// The preconditioner must be symmetric and positive definite. Defaults to
+    /// [`IdentityPreconditioner`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::ConjugateGradient;
+    /// # use argmin::solver::preconditioner::JacobiPreconditioner;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let a = vec![vec![4.0f64, 1.0], vec![1.0, 100.0]];
+    /// # let b = vec![1.0f64, 1.0];
+    /// let preconditioner = JacobiPreconditioner::new(&a)?;
+    /// let cg: ConjugateGradient<_, f64, _> =
+    ///     ConjugateGradient::new(b).with_preconditioner(preconditioner);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_preconditioner<M2>(self, preconditioner: M2) -> ConjugateGradient<P, F, M2> {
+        ConjugateGradient {
+            b: self.b,
+            preconditioner,
+            r: self.r,
+            p: self.p,
+            p_prev: self.p_prev,
+            rtr: self.rtr,
+        }
+    }
 
     /// Return the previous search direction (Needed by [`NewtonCG`](`crate::solver::newton::NewtonCG`))
     ///
@@ -89,16 +137,18 @@ where
     }
 }
 
-impl<P, O, F> Solver<O, IterState<P, (), (), (), F>> for ConjugateGradient<P, F>
+impl<P, O, F, M> Solver<O, IterState<P, (), (), (), F>> for ConjugateGradient<P, F, M>
 where
     O: Operator<Param = P, Output = P>,
     P: Clone
         + SerializeAlias
         + ArgminDot<P, F>
         + ArgminSub<P, P>
-        + ArgminScaledAdd<P, F, P>
+        + ArgminScaledAddAssign<P, F>
+        + ArgminMulAssign<F>
         + ArgminConj
         + ArgminMul<F, P>,
+    M: Preconditioner<P>,
     F: ArgminFloat + ArgminL2Norm<F>,
 {
     const NAME: &'static str = "Conjugate Gradient";
@@ -117,8 +167,9 @@ where
         ))?;
         let ap = problem.apply(init_param)?;
         let r0 = self.b.sub(&ap).mul(&(float!(-1.0)));
-        self.p = Some(r0.mul(&(float!(-1.0))));
-        self.rtr = r0.dot(&r0.conj());
+        let z0 = self.preconditioner.apply_inverse(&r0)?;
+        self.p = Some(z0.mul(&(float!(-1.0))));
+        self.rtr = r0.dot(&z0.conj());
         self.r = Some(r0);
         Ok((state, None))
     }
@@ -127,32 +178,43 @@ where
     fn next_iter(
         &mut self,
         problem: &mut Problem<O>,
-        state: IterState<P, (), (), (), F>,
+        mut state: IterState<P, (), (), (), F>,
     ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
         let p = self.p.take().ok_or_else(argmin_error_closure!(
             PotentialBug,
             "`ConjugateGradient`: Field `p` not set"
         ))?;
-        let r = self.r.as_ref().ok_or_else(argmin_error_closure!(
+        let mut r = self.r.take().ok_or_else(argmin_error_closure!(
             PotentialBug,
             "`ConjugateGradient`: Field `r` not set"
         ))?;
 
         let apk = problem.apply(&p)?;
         let alpha = self.rtr.div(p.dot(&apk.conj()));
+        // Reuse the buffer of the previous parameter vector (if available) for the new one
+        let prev_param = state.take_prev_param();
         let state_param = state.get_param().ok_or_else(argmin_error_closure!(
             PotentialBug,
             "`ConjugateGradient`: Parameter vector in `state` not set"
         ))?;
-        let new_param = state_param.scaled_add(&alpha, &p);
-        let r = r.scaled_add(&alpha, &apk);
-        let rtr_n = r.dot(&r.conj());
+        let mut new_param = match prev_param {
+            Some(mut buf) => {
+                buf.clone_from(state_param);
+                buf
+            }
+            None => state_param.clone(),
+        };
+        new_param.scaled_add_assign(&alpha, &p);
+        r.scaled_add_assign(&alpha, &apk);
+        let mut z = self.preconditioner.apply_inverse(&r)?;
+        let rtr_n = r.dot(&z.conj());
         let beta = rtr_n.div(self.rtr);
         self.rtr = rtr_n;
-        let p_n = r.mul(&(float!(-1.0))).scaled_add(&beta, &p);
+        z.mul_assign(&(float!(-1.0)));
+        z.scaled_add_assign(&beta, &p);
         let norm = r.dot(&r.conj()).l2_norm();
 
-        self.p = Some(p_n);
+        self.p = Some(z);
         self.p_prev = Some(p);
         self.r = Some(r);
 
@@ -177,6 +239,7 @@ mod tests {
         let cg: ConjugateGradient<_, f64> = ConjugateGradient::new(vec![1.0f64, 2.0]);
         let ConjugateGradient {
             b,
+            preconditioner,
             r,
             p,
             p_prev,
@@ -188,6 +251,7 @@ mod tests {
         assert!(p.is_none());
         assert!(p_prev.is_none());
         assert!(rtr.is_nan());
+        assert_eq!(preconditioner, IdentityPreconditioner);
     }
 
     #[test]
@@ -243,6 +307,7 @@ mod tests {
             p,
             p_prev,
             rtr,
+            ..
         } = cg;
 
         assert_relative_eq!(b[0], 1.0, epsilon = f64::EPSILON);
@@ -342,4 +407,113 @@ mod tests {
         assert_relative_eq!(norm, state.get_cost());
         assert_relative_eq!(new_param, state.get_param().unwrap()[0]);
     }
+
+    #[test]
+    fn test_preconditioned() {
+        use crate::core::Executor;
+        use crate::solver::preconditioner::{IncompleteCholesky, JacobiPreconditioner};
+        use argmin_math::ArgminDot;
+
+        #[derive(Clone)]
+        struct Matrix(Vec<Vec<f64>>);
+
+        impl Operator for Matrix {
+            type Param = Vec<f64>;
+            type Output = Vec<f64>;
+
+            fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(self.0.dot(x))
+            }
+        }
+
+        // Badly scaled tridiagonal matrix
+        let n = 8;
+        let scale: Vec<f64> = (0..n).map(|i| 10.0f64.powi(i as i32 - 3)).collect();
+        let a: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        let v = if i == j {
+                            4.0
+                        } else if (i as i64 - j as i64).abs() == 1 {
+                            -1.0
+                        } else {
+                            0.0
+                        };
+                        v * scale[i] * scale[j]
+                    })
+                    .collect()
+            })
+            .collect();
+        let b = vec![1.0; n];
+
+        let run = |cg: ConjugateGradient<Vec<f64>, f64, _>| {
+            let res = Executor::new(Matrix(a.clone()), cg)
+                .configure(|state| state.param(vec![0.0; n]).max_iters(n as u64))
+                .run()
+                .unwrap();
+            let x = res.state.get_param().unwrap().clone();
+            let ax = a.dot(&x);
+            (0..n).map(|i| (ax[i] - b[i]).abs()).fold(0.0, f64::max)
+        };
+
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        assert!(run(ConjugateGradient::new(b.clone()).with_preconditioner(jacobi)) < 1e-8);
+        // IC(0) is exact for tridiagonal matrices
+        let ic = IncompleteCholesky::new(&a).unwrap();
+        let cg: ConjugateGradient<_, f64, _> =
+            ConjugateGradient::new(b.clone()).with_preconditioner(ic);
+        let res = Executor::new(Matrix(a.clone()), cg)
+            .configure(|state| state.param(vec![0.0; n]).max_iters(1))
+            .run()
+            .unwrap();
+        let ax = a.dot(res.state.get_param().unwrap());
+        for i in 0..n {
+            assert_relative_eq!(ax[i], b[i], epsilon = 1e-8);
+        }
+    }
+
+    #[cfg(feature = "_sprsl")]
+    #[test]
+    fn test_solver_sprs() {
+        use crate::core::Executor;
+        use crate::solver::preconditioner::JacobiPreconditioner;
+        use sprs::{CsMat, TriMat};
+
+        /// Applies a sparse matrix to a dense vector
+        struct SparseMatrix(CsMat<f64>);
+
+        impl Operator for SparseMatrix {
+            type Param = Vec<f64>;
+            type Output = Vec<f64>;
+
+            fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(self.0.dot(x))
+            }
+        }
+
+        // Tridiagonal, badly scaled
+        let n = 50;
+        let mut a = TriMat::new((n, n));
+        for i in 0..n {
+            a.add_triplet(i, i, 2.0 * (i + 1) as f64);
+            if i + 1 < n {
+                a.add_triplet(i, i + 1, -1.0);
+                a.add_triplet(i + 1, i, -1.0);
+            }
+        }
+        let a: CsMat<f64> = a.to_csr();
+        let b: Vec<f64> = a.dot(&vec![1.0; n]);
+
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        let cg: ConjugateGradient<_, f64, _> =
+            ConjugateGradient::new(b).with_preconditioner(jacobi);
+        let res = Executor::new(SparseMatrix(a), cg)
+            .configure(|state| state.param(vec![0.0; n]).max_iters(n as u64))
+            .run()
+            .unwrap();
+        for xi in res.state.get_param().unwrap() {
+            assert_relative_eq!(*xi, 1.0, epsilon = 1e-8);
+        }
+    }
 }
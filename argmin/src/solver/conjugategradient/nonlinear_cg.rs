@@ -9,7 +9,7 @@ use crate::core::{
     ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
     LineSearch, NLCGBetaUpdate, OptimizationResult, Problem, SerializeAlias, Solver, State, KV,
 };
-use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminMulAssign, ArgminSubAssign};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -122,7 +122,7 @@ impl<O, P, G, L, B, F> Solver<O, IterState<P, G, (), (), F>>
     for NonlinearConjugateGradient<P, L, B, F>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
-    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminAdd<P, P> + ArgminMul<F, P>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminSubAssign<G> + ArgminMulAssign<F>,
     G: Clone
         + SerializeAlias
         + DeserializeOwnedAlias
@@ -158,7 +158,7 @@ where
         problem: &mut Problem<O>,
         mut state: IterState<P, G, (), (), F>,
     ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
-        let p = self.p.as_ref().ok_or_else(argmin_error_closure!(
+        let mut p = self.p.take().ok_or_else(argmin_error_closure!(
             PotentialBug,
             "`NonlinearConjugateGradient`: Field `p` not set"
         ))?;
@@ -173,6 +173,7 @@ where
         let cur_cost = state.cost;
 
         // Linesearch
+        self.linesearch.record_cost(cur_cost);
         self.linesearch.search_direction(p.clone());
 
         // Run solver
@@ -213,11 +214,13 @@ where
         if restart_iter || restart_orthogonality {
             self.beta = float!(0.0);
         } else {
-            self.beta = self.beta_method.update(&grad, &new_grad, p);
+            self.beta = self.beta_method.update(&grad, &new_grad, &p);
         }
 
         // Update of p
-        self.p = Some(new_grad.mul(&(float!(-1.0))).add(&p.mul(&self.beta)));
+        p.mul_assign(&self.beta);
+        p.sub_assign(&new_grad);
+        self.p = Some(p);
 
         // Housekeeping
         let cost = problem.cost(&xk1)?;
@@ -227,6 +230,7 @@ where
             Some(kv!("beta" => self.beta;
              "restart_iter" => restart_iter;
              "restart_orthogonality" => restart_orthogonality;
+             "linesearch_iters" => line_state.get_iter();
             )),
         ))
     }
@@ -452,7 +456,12 @@ mod tests {
         assert!(kv.is_none());
         let (mut state, kv) = nlcg.next_iter(&mut problem, state).unwrap();
         state.update();
-        let kv2 = kv!("beta" => 0.0; "restart_iter" => false; "restart_orthogonality" => false;);
+        let kv2 = kv!(
+            "beta" => 0.0;
+            "restart_iter" => false;
+            "restart_orthogonality" => false;
+            "linesearch_iters" => 356u64;
+        );
         assert_eq!(kv.unwrap(), kv2);
         assert_relative_eq!(
             state.param.as_ref().unwrap()[0],
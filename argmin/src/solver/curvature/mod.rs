@@ -0,0 +1,382 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Curvature information for second order methods
+//!
+//! Second order methods such as [`NewtonCG`](`crate::solver::newton::NewtonCG`),
+//! [`Steihaug`](`crate::solver::trustregion::Steihaug`) and
+//! [`TrustRegion`](`crate::solver::trustregion::TrustRegion`) only require products of the Hessian
+//! with vectors. How these products are obtained is determined by one of the following types:
+//!
+//! * [`ExactHessian`]: The Hessian is computed via [`Hessian`] (default)
+//! * [`HessianVectorProducts`]: Hessian-vector products are computed via [`HessianVectorProduct`]
+//!   without ever forming the Hessian
+//! * [`FiniteDiffHessianVectorProducts`]: Hessian-vector products are approximated by forward
+//!   differences of gradients. This only requires [`Gradient`].
+//!
+//! The latter two allow the solvers to be applied to large-scale problems.
+//!
+//! At each iterate, [`EvaluateCurvature`] computes a representation of the Hessian which is
+//! stored in the Hessian field of the state. [`Curvature`] then multiplies this representation
+//! with vectors.
+
+use crate::core::{ArgminFloat, Error, Gradient, Hessian, HessianVectorProduct, Problem};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface for computing products of the Hessian with vectors
+///
+/// `H` is the representation of the Hessian stored in the state, as returned by
+/// [`EvaluateCurvature`].
+pub trait Curvature<O, P, H> {
+    /// Compute the product of the Hessian represented by `hessian` with `v`
+    fn hessian_vector_product(
+        &self,
+        problem: &mut Problem<O>,
+        hessian: &H,
+        v: &P,
+    ) -> Result<P, Error>;
+}
+
+/// Interface for evaluating the curvature information at a point
+pub trait EvaluateCurvature<O, P, G, H>: Curvature<O, P, H> {
+    /// Compute the representation of the Hessian at `param` with gradient `grad`
+    fn hessian(&self, problem: &mut Problem<O>, param: &P, grad: &G) -> Result<H, Error>;
+}
+
+/// Hessian computed via [`Hessian`]
+///
+/// The representation stored in the state is the Hessian itself. Products with the Hessian do not
+/// require the optimization problem, therefore this also works with Hessians which are not
+/// computed by the problem (for instance quasi-Newton approximations).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ExactHessian;
+
+impl<O, P, H> Curvature<O, P, H> for ExactHessian
+where
+    H: ArgminDot<P, P>,
+{
+    fn hessian_vector_product(
+        &self,
+        _problem: &mut Problem<O>,
+        hessian: &H,
+        v: &P,
+    ) -> Result<P, Error> {
+        Ok(hessian.dot(v))
+    }
+}
+
+impl<O, P, G, H> EvaluateCurvature<O, P, G, H> for ExactHessian
+where
+    O: Hessian<Param = P, Hessian = H>,
+    H: ArgminDot<P, P>,
+{
+    fn hessian(&self, problem: &mut Problem<O>, param: &P, _grad: &G) -> Result<H, Error> {
+        problem.hessian(param)
+    }
+}
+
+/// Hessian-vector products computed via [`HessianVectorProduct`]
+///
+/// The representation stored in the state is the parameter vector at which the Hessian is
+/// evaluated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HessianVectorProducts;
+
+impl<O, P> Curvature<O, P, P> for HessianVectorProducts
+where
+    O: HessianVectorProduct<Param = P>,
+{
+    fn hessian_vector_product(
+        &self,
+        problem: &mut Problem<O>,
+        hessian: &P,
+        v: &P,
+    ) -> Result<P, Error> {
+        problem.hessian_vector_product(hessian, v)
+    }
+}
+
+impl<O, P, G> EvaluateCurvature<O, P, G, P> for HessianVectorProducts
+where
+    O: HessianVectorProduct<Param = P>,
+    P: Clone,
+{
+    fn hessian(&self, _problem: &mut Problem<O>, param: &P, _grad: &G) -> Result<P, Error> {
+        Ok(param.clone())
+    }
+}
+
+/// Hessian-vector products approximated by forward differences of gradients
+///
+/// The product of the Hessian at `x` with `v` is approximated by
+///
+/// `H(x) * v ≈ (g(x + h * v) - g(x)) / h`
+///
+/// with `h = step * (1 + ||x||) / ||v||`, which requires one gradient evaluation per product. The
+/// representation stored in the state is the tuple `(x, g(x))`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FiniteDiffHessianVectorProducts<F> {
+    /// Relative step size
+    step: F,
+}
+
+impl<F: ArgminFloat> FiniteDiffHessianVectorProducts<F> {
+    /// Construct a new instance of [`FiniteDiffHessianVectorProducts`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::curvature::FiniteDiffHessianVectorProducts;
+    /// let fd: FiniteDiffHessianVectorProducts<f64> = FiniteDiffHessianVectorProducts::new();
+    /// ```
+    pub fn new() -> Self {
+        FiniteDiffHessianVectorProducts {
+            step: F::epsilon().sqrt(),
+        }
+    }
+
+    /// Set relative step size
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::curvature::FiniteDiffHessianVectorProducts;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fd: FiniteDiffHessianVectorProducts<f64> =
+    ///     FiniteDiffHessianVectorProducts::new().with_step(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step(mut self, step: F) -> Result<Self, Error> {
+        if step <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FiniteDiffHessianVectorProducts`: step must be > 0."
+            ));
+        }
+        self.step = step;
+        Ok(self)
+    }
+}
+
+impl<F: ArgminFloat> Default for FiniteDiffHessianVectorProducts<F> {
+    fn default() -> Self {
+        FiniteDiffHessianVectorProducts::new()
+    }
+}
+
+impl<O, P, F> Curvature<O, P, (P, P)> for FiniteDiffHessianVectorProducts<F>
+where
+    O: Gradient<Param = P, Gradient = P>,
+    P: ArgminScaledAdd<P, F, P> + ArgminSub<P, P> + ArgminMul<F, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn hessian_vector_product(
+        &self,
+        problem: &mut Problem<O>,
+        hessian: &(P, P),
+        v: &P,
+    ) -> Result<P, Error> {
+        let (param, grad) = hessian;
+        let v_norm = v.l2_norm();
+        if v_norm == float!(0.0) {
+            return Ok(v.mul(&float!(0.0)));
+        }
+        let h = self.step * (float!(1.0) + param.l2_norm()) / v_norm;
+        let grad_h = problem.gradient(&param.scaled_add(&h, v))?;
+        Ok(grad_h.sub(grad).mul(&(float!(1.0) / h)))
+    }
+}
+
+impl<O, P, F> EvaluateCurvature<O, P, P, (P, P)> for FiniteDiffHessianVectorProducts<F>
+where
+    O: Gradient<Param = P, Gradient = P>,
+    P: Clone + ArgminScaledAdd<P, F, P> + ArgminSub<P, P> + ArgminMul<F, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn hessian(&self, _problem: &mut Problem<O>, param: &P, grad: &P) -> Result<(P, P), Error> {
+        Ok((param.clone(), grad.clone()))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::core::{ArgminError, CostFunction};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(exact_hessian, ExactHessian);
+    test_trait_impl!(hessian_vector_products, HessianVectorProducts);
+    test_trait_impl!(finite_diff, FiniteDiffHessianVectorProducts<f64>);
+
+    /// `f(x) = 0.5 * x^T A x - b^T x + 0.25 * c * ||x||^4`, which is strictly convex for symmetric
+    /// positive definite `A` and `c >= 0`.
+    #[derive(Clone)]
+    pub(crate) struct Quartic {
+        pub(crate) a: Vec<Vec<f64>>,
+        pub(crate) b: Vec<f64>,
+        pub(crate) c: f64,
+    }
+
+    impl Quartic {
+        pub(crate) fn new() -> Self {
+            Quartic {
+                a: vec![
+                    vec![4.0, 1.0, 0.0],
+                    vec![1.0, 3.0, -1.0],
+                    vec![0.0, -1.0, 2.0],
+                ],
+                b: vec![1.0, -2.0, 3.0],
+                c: 0.1,
+            }
+        }
+
+        fn norm2(x: &[f64]) -> f64 {
+            x.iter().map(|xi| xi * xi).sum()
+        }
+    }
+
+    impl CostFunction for Quartic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            let ax: Vec<f64> = self.a.dot(x);
+            let xax: f64 = x.dot(&ax);
+            let bx: f64 = self.b.dot(x);
+            Ok(0.5 * xax - bx + 0.25 * self.c * Quartic::norm2(x).powi(2))
+        }
+    }
+
+    impl Gradient for Quartic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            let ax: Vec<f64> = self.a.dot(x);
+            let n2 = Quartic::norm2(x);
+            Ok(ax
+                .iter()
+                .zip(self.b.iter().zip(x))
+                .map(|(axi, (bi, xi))| axi - bi + self.c * n2 * xi)
+                .collect())
+        }
+    }
+
+    impl Hessian for Quartic {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, x: &Self::Param) -> Result<Self::Hessian, Error> {
+            let n2 = Quartic::norm2(x);
+            Ok(self
+                .a
+                .iter()
+                .enumerate()
+                .map(|(i, row)| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(j, aij)| {
+                            let diag = if i == j { n2 } else { 0.0 };
+                            aij + self.c * (diag + 2.0 * x[i] * x[j])
+                        })
+                        .collect()
+                })
+                .collect())
+        }
+    }
+
+    impl HessianVectorProduct for Quartic {
+        type Param = Vec<f64>;
+
+        fn hessian_vector_product(
+            &self,
+            x: &Self::Param,
+            v: &Self::Param,
+        ) -> Result<Self::Param, Error> {
+            let av: Vec<f64> = self.a.dot(v);
+            let n2 = Quartic::norm2(x);
+            let xv: f64 = x.dot(v);
+            Ok(av
+                .iter()
+                .zip(v.iter().zip(x))
+                .map(|(avi, (vi, xi))| avi + self.c * (n2 * vi + 2.0 * xv * xi))
+                .collect())
+        }
+    }
+
+    fn products<C, H>(curvature: C) -> (Vec<f64>, Problem<Quartic>)
+    where
+        C: EvaluateCurvature<Quartic, Vec<f64>, Vec<f64>, H>,
+    {
+        let mut problem = Problem::new(Quartic::new());
+        let x = vec![1.0, -0.5, 2.0];
+        let v = vec![0.3, 1.0, -2.0];
+        let grad = problem.gradient(&x).unwrap();
+        let hessian = curvature.hessian(&mut problem, &x, &grad).unwrap();
+        let hv = curvature
+            .hessian_vector_product(&mut problem, &hessian, &v)
+            .unwrap();
+        (hv, problem)
+    }
+
+    #[test]
+    fn test_consistency() {
+        let (expected, problem) = products(ExactHessian);
+        assert_eq!(problem.counts["hessian_count"], 1);
+
+        let (hv, problem) = products(HessianVectorProducts);
+        assert_eq!(problem.counts["hessian_vector_product_count"], 1);
+        assert!(!problem.counts.contains_key("hessian_count"));
+        for (a, b) in hv.iter().zip(expected.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-12);
+        }
+
+        let (hv, problem) = products(FiniteDiffHessianVectorProducts::new());
+        assert_eq!(problem.counts["gradient_count"], 2);
+        assert!(!problem.counts.contains_key("hessian_count"));
+        for (a, b) in hv.iter().zip(expected.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_finite_diff_zero_vector() {
+        let mut problem = Problem::new(Quartic::new());
+        let x = vec![1.0, -0.5, 2.0];
+        let grad = problem.gradient(&x).unwrap();
+        let hv = FiniteDiffHessianVectorProducts::new()
+            .hessian_vector_product(&mut problem, &(x, grad), &vec![0.0; 3])
+            .unwrap();
+        assert_eq!(hv, vec![0.0; 3]);
+        assert_eq!(problem.counts["gradient_count"], 1);
+    }
+
+    #[test]
+    fn test_finite_diff_with_step() {
+        let fd: FiniteDiffHessianVectorProducts<f64> = FiniteDiffHessianVectorProducts::new();
+        assert_eq!(fd.step.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        let fd = fd.with_step(1e-4).unwrap();
+        assert_eq!(fd.step.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+
+        for step in [0.0, -1.0] {
+            assert_error!(
+                FiniteDiffHessianVectorProducts::new().with_step(step),
+                ArgminError,
+                "Invalid parameter: \"`FiniteDiffHessianVectorProducts`: step must be > 0.\""
+            );
+        }
+    }
+}
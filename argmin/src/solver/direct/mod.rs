@@ -0,0 +1,732 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # DIRECT (DIviding RECTangles)
+//!
+//! Deterministic global optimization of Lipschitz-continuous functions on box bounds as
+//! introduced in \[0\], including the locally-biased variant DIRECT-L \[1\].
+//!
+//! For details see [`Direct`].
+//!
+//! ## References
+//!
+//! \[0\] D. R. Jones, C. D. Perttunen and B. E. Stuckman (1993). Lipschitzian optimization without
+//! the Lipschitz constant. Journal of Optimization Theory and Applications 79, 157–181.
+//! <https://doi.org/10.1007/BF00941892>
+//!
+//! \[1\] J. M. Gablonsky and C. T. Kelley (2001). A locally-biased form of the DIRECT algorithm.
+//! Journal of Global Optimization 21, 27–37. <https://doi.org/10.1023/A:1017930332101>
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, Solver, State, SyncAlias,
+    TerminationReason, TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// # DIRECT (DIviding RECTangles)
+///
+/// Deterministic, derivative-free global optimization method for Lipschitz-continuous cost
+/// functions on box bounds.
+///
+/// The search space is normalized to the unit hypercube, which is iteratively divided into
+/// hyperrectangles. The cost function is evaluated at the center of each hyperrectangle. In each
+/// iteration, all "potentially optimal" hyperrectangles are trisected along their longest sides.
+/// A hyperrectangle is potentially optimal if there exists a Lipschitz constant for which it
+/// could contain the global minimum. This balances global exploration (large hyperrectangles)
+/// and local refinement (hyperrectangles with low cost function values). In contrast to
+/// [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`), no randomness is involved.
+///
+/// The locally-biased variant DIRECT-L (enabled via
+/// [`with_locally_biased`](`Direct::with_locally_biased`)) measures hyperrectangles by their
+/// longest side instead of by their half-diagonal and divides at most one hyperrectangle per size.
+/// This typically works better for problems with few local minima.
+///
+/// The solver stops when the number of cost function evaluations exceeds the budget set via
+/// [`with_max_evals`](`Direct::with_max_evals`) or when the hyperrectangle containing the best
+/// point found so far becomes smaller than the size set via
+/// [`with_min_size`](`Direct::with_min_size`). Note that an iteration is always completed, which
+/// means that the evaluation budget may be exceeded slightly.
+///
+/// The number of hyperrectangles (`num_rectangles`), the number of hyperrectangles divided in the
+/// current iteration (`num_divided`), the best cost function value found so far (`best_cost`) and
+/// the size of the hyperrectangle containing the best point (`best_size`) are reported to the
+/// observers.
+///
+/// The parameter vector is required to be a `Vec<F>`.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## References
+///
+/// \[0\] D. R. Jones, C. D. Perttunen and B. E. Stuckman (1993). Lipschitzian optimization
+/// without the Lipschitz constant. Journal of Optimization Theory and Applications 79, 157–181.
+/// <https://doi.org/10.1007/BF00941892>
+///
+/// \[1\] J. M. Gablonsky and C. T. Kelley (2001). A locally-biased form of the DIRECT algorithm.
+/// Journal of Global Optimization 21, 27–37. <https://doi.org/10.1023/A:1017930332101>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Direct<F> {
+    /// Bounds on parameter space
+    bounds: (Vec<F>, Vec<F>),
+    /// Required relative improvement over the best cost function value
+    epsilon: F,
+    /// Use locally-biased variant DIRECT-L
+    locally_biased: bool,
+    /// Maximum number of cost function evaluations
+    max_evals: u64,
+    /// Minimum size of the hyperrectangle containing the best point
+    min_size: F,
+    /// Hyperrectangles in normalized coordinates
+    rects: Vec<Hyperrectangle<F>>,
+    /// Index of the hyperrectangle with the lowest cost function value
+    best: usize,
+}
+
+/// A hyperrectangle in normalized coordinates (unit hypercube).
+///
+/// The side length in dimension `i` is `3^(-levels[i])`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct Hyperrectangle<F> {
+    /// Center of the hyperrectangle
+    center: Vec<F>,
+    /// Number of trisections per dimension
+    levels: Vec<u32>,
+    /// Cost function value at the center
+    cost: F,
+}
+
+impl<F> Direct<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `Direct`
+    ///
+    /// Takes the bounds on the search space as input. `bounds` is a tuple
+    /// `(lower_bound, upper_bound)` where both bounds are of the same length as the parameter
+    /// vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::direct::Direct;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let direct: Direct<f64> = Direct::new((lower_bound, upper_bound));
+    /// ```
+    pub fn new(bounds: (Vec<F>, Vec<F>)) -> Self {
+        Direct {
+            bounds,
+            epsilon: float!(1e-4),
+            locally_biased: false,
+            max_evals: u64::MAX,
+            min_size: F::epsilon(),
+            rects: vec![],
+            best: 0,
+        }
+    }
+
+    /// Use the locally-biased variant DIRECT-L
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::direct::Direct;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let direct: Direct<f64> = Direct::new((lower_bound, upper_bound)).with_locally_biased(true);
+    /// ```
+    #[must_use]
+    pub fn with_locally_biased(mut self, locally_biased: bool) -> Self {
+        self.locally_biased = locally_biased;
+        self
+    }
+
+    /// Set the parameter `epsilon`
+    ///
+    /// A hyperrectangle is only considered potentially optimal if it can improve the best cost
+    /// function value found so far by at least `epsilon * |f_min|`. Must be non-negative and
+    /// defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::direct::Direct;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let direct: Direct<f64> = Direct::new((lower_bound, upper_bound)).with_epsilon(1e-3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_epsilon(mut self, epsilon: F) -> Result<Self, Error> {
+        if epsilon < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Direct`: epsilon must be >= 0."
+            ));
+        }
+        self.epsilon = epsilon;
+        Ok(self)
+    }
+
+    /// Set the maximum number of cost function evaluations
+    ///
+    /// The solver stops after the iteration in which this budget is exceeded. Defaults to
+    /// `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::direct::Direct;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let direct: Direct<f64> = Direct::new((lower_bound, upper_bound)).with_max_evals(1000);
+    /// ```
+    #[must_use]
+    pub fn with_max_evals(mut self, max_evals: u64) -> Self {
+        self.max_evals = max_evals;
+        self
+    }
+
+    /// Set the minimum hyperrectangle size
+    ///
+    /// The solver stops once the hyperrectangle containing the best point is smaller than
+    /// `min_size`. The size is measured in normalized coordinates (the search space is mapped to
+    /// the unit hypercube), either as half-diagonal (DIRECT) or as longest side (DIRECT-L).
+    /// Must be non-negative and defaults to `EPSILON`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::direct::Direct;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let direct: Direct<f64> = Direct::new((lower_bound, upper_bound)).with_min_size(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_min_size(mut self, min_size: F) -> Result<Self, Error> {
+        if min_size < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Direct`: min_size must be >= 0."
+            ));
+        }
+        self.min_size = min_size;
+        Ok(self)
+    }
+
+    /// Maps a point from the unit hypercube to the search space
+    fn denormalize(&self, x: &[F]) -> Vec<F> {
+        let (lower, upper) = &self.bounds;
+        x.iter()
+            .zip(lower.iter().zip(upper.iter()))
+            .map(|(&xi, (&l, &u))| l + xi * (u - l))
+            .collect()
+    }
+
+    /// Size of a hyperrectangle
+    ///
+    /// Half-diagonal for DIRECT, longest side for DIRECT-L.
+    fn size(&self, levels: &[u32]) -> F {
+        let third = float!(1.0 / 3.0);
+        if self.locally_biased {
+            third.powi(*levels.iter().min().unwrap_or(&0) as i32)
+        } else {
+            float!(0.5)
+                * levels
+                    .iter()
+                    .map(|&l| third.powi(2 * l as i32))
+                    .fold(float!(0.0), |acc, x| acc + x)
+                    .sqrt()
+        }
+    }
+
+    /// Key which groups hyperrectangles of equal size; larger keys correspond to smaller sizes.
+    ///
+    /// Since only the longest sides are ever divided, the side lengths of a hyperrectangle differ
+    /// by at most one level. Therefore the sum of the levels determines the half-diagonal.
+    fn size_key(&self, levels: &[u32]) -> u64 {
+        if self.locally_biased {
+            u64::from(*levels.iter().min().unwrap_or(&0))
+        } else {
+            levels.iter().map(|&l| u64::from(l)).sum()
+        }
+    }
+
+    /// Returns the indices of all potentially optimal hyperrectangles
+    fn potentially_optimal(&self) -> Vec<usize> {
+        // Group hyperrectangles by size and keep the ones with the lowest cost in each group.
+        let mut groups: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+        for (i, rect) in self.rects.iter().enumerate() {
+            groups
+                .entry(self.size_key(&rect.levels))
+                .or_default()
+                .push(i);
+        }
+
+        // (size, lowest cost, indices), sorted by increasing size
+        let candidates: Vec<(F, F, Vec<usize>)> = groups
+            .values()
+            .rev()
+            .map(|idxs| {
+                let min_cost = idxs
+                    .iter()
+                    .map(|&i| self.rects[i].cost)
+                    .fold(F::infinity(), |acc, c| if c < acc { c } else { acc });
+                let mut best: Vec<usize> = idxs
+                    .iter()
+                    .cloned()
+                    .filter(|&i| self.rects[i].cost <= min_cost)
+                    .collect();
+                if self.locally_biased {
+                    best.truncate(1);
+                }
+                (self.size(&self.rects[idxs[0]].levels), min_cost, best)
+            })
+            .collect();
+
+        // Start from the group with the lowest cost (if ambiguous, the largest one).
+        let mut start = 0;
+        for (i, (_, c, _)) in candidates.iter().enumerate() {
+            if *c <= candidates[start].1 {
+                start = i;
+            }
+        }
+        let f_min = candidates[start].1;
+
+        // Lower right convex hull of (size, cost)
+        let mut hull: Vec<usize> = vec![];
+        for i in start..candidates.len() {
+            while hull.len() >= 2 {
+                let (d0, f0, _) = &candidates[hull[hull.len() - 2]];
+                let (d1, f1, _) = &candidates[hull[hull.len() - 1]];
+                let (d2, f2, _) = &candidates[i];
+                if (*d1 - *d0) * (*f2 - *f0) - (*f1 - *f0) * (*d2 - *d0) <= float!(0.0) {
+                    hull.pop();
+                } else {
+                    break;
+                }
+            }
+            hull.push(i);
+        }
+
+        // A hull point is only accepted if it can improve the best cost by at least
+        // `epsilon * |f_min|` for the largest possible rate of change (slope to the next point).
+        let threshold = f_min - self.epsilon * f_min.abs();
+        let mut selected = vec![];
+        for (k, &i) in hull.iter().enumerate() {
+            let (d, f, idxs) = &candidates[i];
+            let accept = match hull.get(k + 1) {
+                Some(&j) => {
+                    let (d_next, f_next, _) = &candidates[j];
+                    let slope = (*f_next - *f) / (*d_next - *d);
+                    *f - slope * *d <= threshold
+                }
+                None => true,
+            };
+            if accept {
+                selected.extend(idxs.iter().cloned());
+            }
+        }
+        selected
+    }
+
+    /// Trisects the hyperrectangle with index `idx` along all of its longest sides
+    fn divide<O>(&mut self, problem: &mut Problem<O>, idx: usize) -> Result<(), Error>
+    where
+        O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+        F: SyncAlias,
+    {
+        let Hyperrectangle { center, levels, .. } = self.rects[idx].clone();
+        let min_level = *levels.iter().min().unwrap();
+        let dims: Vec<usize> = (0..levels.len())
+            .filter(|&i| levels[i] == min_level)
+            .collect();
+        let delta = float!(1.0 / 3.0).powi(min_level as i32 + 1);
+
+        let centers: Vec<Vec<F>> = dims
+            .iter()
+            .flat_map(|&i| {
+                let mut plus = center.clone();
+                plus[i] = plus[i] + delta;
+                let mut minus = center.clone();
+                minus[i] = minus[i] - delta;
+                [plus, minus]
+            })
+            .collect();
+        let params: Vec<Vec<F>> = centers.iter().map(|c| self.denormalize(c)).collect();
+        let costs = problem.bulk_cost(&params)?;
+
+        // Divide along the dimension with the lowest cost first such that the best new
+        // hyperrectangles end up being the largest ones.
+        let mut order: Vec<usize> = (0..dims.len()).collect();
+        order.sort_by(|&a, &b| {
+            let wa = costs[2 * a].min(costs[2 * a + 1]);
+            let wb = costs[2 * b].min(costs[2 * b + 1]);
+            wa.partial_cmp(&wb).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut new_levels = levels;
+        for k in order {
+            new_levels[dims[k]] += 1;
+            for j in [2 * k, 2 * k + 1] {
+                self.rects.push(Hyperrectangle {
+                    center: centers[j].clone(),
+                    levels: new_levels.clone(),
+                    cost: costs[j],
+                });
+            }
+        }
+        self.rects[idx].levels = new_levels;
+        Ok(())
+    }
+
+    /// Updates the index of the best hyperrectangle
+    fn update_best(&mut self) {
+        for (i, rect) in self.rects.iter().enumerate() {
+            if rect.cost < self.rects[self.best].cost {
+                self.best = i;
+            }
+        }
+    }
+}
+
+impl<O, F> Solver<O, IterState<Vec<F>, (), (), (), F>> for Direct<F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+{
+    const NAME: &'static str = "DIRECT";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let (lower, upper) = &self.bounds;
+        if lower.is_empty() || lower.len() != upper.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Direct`: Lower and upper bound must be non-empty and of the same length."
+            ));
+        }
+        if lower.iter().zip(upper.iter()).any(|(l, u)| l >= u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Direct`: Lower bound must be smaller than upper bound."
+            ));
+        }
+
+        let center = vec![float!(0.5); lower.len()];
+        let param = self.denormalize(&center);
+        let cost = problem.cost(&param)?;
+        self.rects = vec![Hyperrectangle {
+            levels: vec![0; center.len()],
+            center,
+            cost,
+        }];
+        self.best = 0;
+
+        Ok((
+            state.param(param).cost(cost),
+            Some(kv!("num_rectangles" => 1u64;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        if self.rects.is_empty() {
+            return Err(argmin_error!(
+                PotentialBug,
+                "`Direct`: No hyperrectangles available."
+            ));
+        }
+
+        let selected = self.potentially_optimal();
+        for &idx in selected.iter() {
+            self.divide(problem, idx)?;
+        }
+        self.update_best();
+
+        let best = &self.rects[self.best];
+        let best_size = self.size(&best.levels);
+        let param = self.denormalize(&best.center);
+        let cost = best.cost;
+
+        Ok((
+            state.param(param).cost(cost),
+            Some(kv!(
+                "num_rectangles" => self.rects.len() as u64;
+                "num_divided" => selected.len() as u64;
+                "best_cost" => cost;
+                "best_size" => best_size;
+            )),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<Vec<F>, (), (), (), F>) -> TerminationStatus {
+        if let Some(&evals) = state.get_func_counts().get("cost_count") {
+            if evals >= self.max_evals {
+                return TerminationStatus::Terminated(TerminationReason::SolverExit(
+                    "Maximum number of cost function evaluations reached".to_string(),
+                ));
+            }
+        }
+        if let Some(best) = self.rects.get(self.best) {
+            if self.size(&best.levels) < self.min_size {
+                return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+            }
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(direct, Direct<f64>);
+
+    #[derive(Clone)]
+    struct ShiftedSphere {}
+
+    impl CostFunction for ShiftedSphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 0.3).powi(2) + (p[1] + 0.7).powi(2))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let direct: Direct<f64> = Direct::new((vec![-1.0, -2.0], vec![1.0, 2.0]));
+        let Direct {
+            bounds,
+            epsilon,
+            locally_biased,
+            max_evals,
+            min_size,
+            rects,
+            best,
+        } = direct;
+        assert_eq!(bounds, (vec![-1.0, -2.0], vec![1.0, 2.0]));
+        assert_eq!(epsilon.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert!(!locally_biased);
+        assert_eq!(max_evals, u64::MAX);
+        assert_eq!(min_size.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
+        assert!(rects.is_empty());
+        assert_eq!(best, 0);
+    }
+
+    #[test]
+    fn test_with_epsilon() {
+        for eps in [0.0, f64::EPSILON, 1e-4, 1.0] {
+            let res = Direct::new((vec![-1.0], vec![1.0])).with_epsilon(eps);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap().epsilon.to_ne_bytes(), eps.to_ne_bytes());
+        }
+
+        for eps in [-f64::EPSILON, -1.0] {
+            let res = Direct::new((vec![-1.0], vec![1.0])).with_epsilon(eps);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Direct`: epsilon must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_min_size() {
+        for size in [0.0, f64::EPSILON, 1e-4, 1.0] {
+            let res = Direct::new((vec![-1.0], vec![1.0])).with_min_size(size);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap().min_size.to_ne_bytes(), size.to_ne_bytes());
+        }
+
+        for size in [-f64::EPSILON, -1.0] {
+            let res = Direct::new((vec![-1.0], vec![1.0])).with_min_size(size);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Direct`: min_size must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_invalid_bounds() {
+        for bounds in [(vec![], vec![]), (vec![-1.0, -1.0], vec![1.0])] {
+            let mut direct: Direct<f64> = Direct::new(bounds);
+            let res = direct.init(&mut Problem::new(TestProblem::new()), IterState::new());
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`Direct`: Lower and upper bound must be non-empty ",
+                    "and of the same length.\""
+                )
+            );
+        }
+
+        let mut direct: Direct<f64> = Direct::new((vec![-1.0, 1.0], vec![1.0, 1.0]));
+        let res = direct.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Direct`: Lower bound must be smaller than upper bound.\""
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut direct: Direct<f64> = Direct::new((vec![-1.0, 0.0], vec![1.0, 4.0]));
+        let mut problem = Problem::new(ShiftedSphere {});
+        let (state, kv) = direct.init(&mut problem, IterState::new()).unwrap();
+        assert_eq!(state.param.as_ref().unwrap(), &vec![0.0, 2.0]);
+        assert_relative_eq!(state.cost, 0.09 + 2.7f64.powi(2), epsilon = f64::EPSILON);
+        assert_eq!(
+            kv.unwrap().get("num_rectangles").unwrap().get_uint(),
+            Some(1)
+        );
+        assert_eq!(direct.rects.len(), 1);
+        assert_eq!(direct.rects[0].levels, vec![0, 0]);
+        assert_eq!(problem.counts["cost_count"], 1);
+    }
+
+    #[test]
+    fn test_first_division() {
+        let mut direct: Direct<f64> = Direct::new((vec![-1.0, -1.0], vec![1.0, 1.0]));
+        let mut problem = Problem::new(ShiftedSphere {});
+        let (state, _) = direct.init(&mut problem, IterState::new()).unwrap();
+        let (state, kv) = direct.next_iter(&mut problem, state).unwrap();
+        let kv = kv.unwrap();
+
+        // The unit square is divided along both dimensions: 1 + 2 * 2 hyperrectangles.
+        assert_eq!(direct.rects.len(), 5);
+        assert_eq!(kv.get("num_rectangles").unwrap().get_uint(), Some(5));
+        assert_eq!(kv.get("num_divided").unwrap().get_uint(), Some(1));
+        assert_eq!(problem.counts["cost_count"], 5);
+        assert_eq!(direct.rects[0].levels, vec![1, 1]);
+
+        // The best point is at (0, -2/3)
+        let param = state.param.as_ref().unwrap();
+        assert_relative_eq!(param[0], 0.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(param[1], -2.0 / 3.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(
+            kv.get("best_cost").unwrap().get_float().unwrap(),
+            state.cost,
+            epsilon = f64::EPSILON
+        );
+
+        // Dimension 1 has the lower cost and is therefore divided first, which leaves the new
+        // hyperrectangles along dimension 1 larger than the ones along dimension 0.
+        for rect in direct.rects[1..3].iter() {
+            assert_eq!(rect.levels, vec![0, 1]);
+        }
+        for rect in direct.rects[3..5].iter() {
+            assert_eq!(rect.levels, vec![1, 1]);
+        }
+    }
+
+    #[test]
+    fn test_potentially_optimal() {
+        let mut direct: Direct<f64> = Direct::new((vec![0.0], vec![1.0]))
+            .with_epsilon(0.0)
+            .unwrap();
+        let rect = |levels: u32, cost: f64| Hyperrectangle {
+            center: vec![0.5],
+            levels: vec![levels],
+            cost,
+        };
+        direct.rects = vec![
+            // large, bad: selected because it is the largest
+            rect(0, 10.0),
+            // medium: dominated by the hull between the smallest and the largest
+            rect(1, 9.0),
+            rect(1, 9.5),
+            // small, best
+            rect(2, 1.0),
+            // small, worse than best of same size
+            rect(2, 2.0),
+        ];
+        assert_eq!(direct.potentially_optimal(), vec![3, 0]);
+
+        // Ties within a size group are all selected for DIRECT, but only once for DIRECT-L.
+        direct.rects.push(rect(2, 1.0));
+        assert_eq!(direct.potentially_optimal(), vec![3, 5, 0]);
+        direct.locally_biased = true;
+        assert_eq!(direct.potentially_optimal(), vec![3, 0]);
+    }
+
+    #[test]
+    fn test_optimization() {
+        for locally_biased in [false, true] {
+            let solver = Direct::new((vec![-2.0, -2.0], vec![2.0, 2.0]))
+                .with_locally_biased(locally_biased)
+                .with_max_evals(2000);
+            let res = Executor::new(ShiftedSphere {}, solver).run().unwrap();
+            let param = res.state.best_param.as_ref().unwrap();
+            assert_relative_eq!(param[0], 0.3, epsilon = 1e-3);
+            assert_relative_eq!(param[1], -0.7, epsilon = 1e-3);
+            assert!(res.state.counts["cost_count"] >= 2000);
+            assert_eq!(
+                res.state.termination_status,
+                TerminationStatus::Terminated(TerminationReason::SolverExit(
+                    "Maximum number of cost function evaluations reached".to_string()
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_size() {
+        let solver = Direct::new((vec![-2.0, -2.0], vec![2.0, 2.0]))
+            .with_min_size(1e-3)
+            .unwrap();
+        let res = Executor::new(ShiftedSphere {}, solver).run().unwrap();
+        assert_eq!(
+            res.state.termination_status,
+            TerminationStatus::Terminated(TerminationReason::SolverConverged)
+        );
+        let solver = res.solver;
+        assert!(solver.size(&solver.rects[solver.best].levels) < 1e-3);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let run = || {
+            let solver = Direct::new((vec![-2.0, -2.0], vec![2.0, 2.0])).with_max_evals(500);
+            let res = Executor::new(ShiftedSphere {}, solver).run().unwrap();
+            (res.state.best_param.unwrap(), res.state.best_cost)
+        };
+        let (p1, c1) = run();
+        let (p2, c2) = run();
+        assert_eq!(p1[0].to_ne_bytes(), p2[0].to_ne_bytes());
+        assert_eq!(p1[1].to_ne_bytes(), p2[1].to_ne_bytes());
+        assert_eq!(c1.to_ne_bytes(), c2.to_ne_bytes());
+    }
+}
@@ -0,0 +1,604 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Operator, Problem, Solver, State, TerminationReason,
+    TerminationStatus, KV,
+};
+use crate::solver::util::dense::{cholesky, solve_lower, solve_upper_transposed};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledAdd, ArgminScaledSub, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// # Anderson acceleration
+///
+/// Accelerated fixed-point iteration for problems of the form `x = G(x)`. With the residuals
+/// `f_k = G(x_k) - x_k` and the differences `dF_i = f_{i+1} - f_i` and
+/// `dG_i = G(x_{i+1}) - G(x_i)` of the last `m` iterations, the coefficients `gamma` are obtained
+/// from the (regularized) least squares problem
+///
+/// `min_gamma ||f_k - dF * gamma||^2 + lambda * ||dF^T dF|| * ||gamma||^2`
+///
+/// and the new iterate is computed as
+///
+/// `x_{k+1} = x_k + beta * f_k - sum_i gamma_i * (dG_i - (1 - beta) * dF_i)`.
+///
+/// For `m = 0`, this reduces to the [Picard iteration](`crate::solver::fixedpoint::PicardIteration`).
+/// For affine maps `G`, the method is closely related to GMRES.
+///
+/// The following parameters can be configured:
+///
+/// * [memory depth `m`](`AndersonAcceleration::with_memory`) (default `5`)
+/// * [relative regularization `lambda`](`AndersonAcceleration::with_regularization`) of the least
+///   squares problem (default `0`)
+/// * [relaxation `beta`](`AndersonAcceleration::with_relaxation`) (default `1`)
+/// * [safeguard](`AndersonAcceleration::with_safeguard`): An accelerated step is rejected if it
+///   does not sufficiently reduce the residual. In this case, a plain fixed-point step is taken
+///   instead. (disabled by default)
+///
+/// Whenever the least squares problem cannot be solved or an accelerated step is rejected, the
+/// memory is cleared (restart). This is reported via the `restart` key of the KV.
+///
+/// The iteration stops once the norm of the residual `G(x_k) - x_k` (reported as cost) is below
+/// the [tolerance](`AndersonAcceleration::with_tolerance`).
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] (the map `G`).
+///
+/// ## References
+///
+/// Donald G. Anderson (1965). Iterative Procedures for Nonlinear Integral Equations. Journal of
+/// the ACM 12(4), 547–560. <https://doi.org/10.1145/321296.321305>
+///
+/// Homer F. Walker and Peng Ni (2011). Anderson Acceleration for Fixed-Point Iterations. SIAM
+/// Journal on Numerical Analysis 49(4), 1715–1735. <https://doi.org/10.1137/10078356X>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct AndersonAcceleration<P, F> {
+    /// Memory depth
+    memory: usize,
+    /// Relative regularization of the least squares problem
+    regularization: F,
+    /// Relaxation parameter
+    relaxation: F,
+    /// Accelerated steps must reduce the residual by this factor
+    safeguard: Option<F>,
+    /// Tolerance on the norm of the residual
+    tol: F,
+    /// `G(x_k)`
+    g: Option<P>,
+    /// Residual `G(x_k) - x_k`
+    f: Option<P>,
+    /// Differences of the residuals
+    df: VecDeque<P>,
+    /// Differences of `G`
+    dg: VecDeque<P>,
+}
+
+impl<P, F: ArgminFloat> AndersonAcceleration<P, F> {
+    /// Construct a new instance of [`AndersonAcceleration`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::AndersonAcceleration;
+    /// let anderson: AndersonAcceleration<Vec<f64>, f64> = AndersonAcceleration::new();
+    /// ```
+    pub fn new() -> Self {
+        AndersonAcceleration {
+            memory: 5,
+            regularization: float!(0.0),
+            relaxation: float!(1.0),
+            safeguard: None,
+            tol: F::epsilon().sqrt(),
+            g: None,
+            f: None,
+            df: VecDeque::new(),
+            dg: VecDeque::new(),
+        }
+    }
+
+    /// Set memory depth `m`
+    ///
+    /// Number of previous iterations used for the extrapolation. Defaults to `5`. A memory depth
+    /// of `0` results in a plain fixed-point iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::AndersonAcceleration;
+    /// let anderson: AndersonAcceleration<Vec<f64>, f64> = AndersonAcceleration::new().with_memory(10);
+    /// ```
+    #[must_use]
+    pub fn with_memory(mut self, memory: usize) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Set relative regularization `lambda` of the least squares problem
+    ///
+    /// The regularization is scaled by the norm of `dF^T dF` and improves the robustness when the
+    /// differences of the residuals become (nearly) linearly dependent. Must be non-negative and
+    /// defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::AndersonAcceleration;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let anderson: AndersonAcceleration<Vec<f64>, f64> =
+    ///     AndersonAcceleration::new().with_regularization(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_regularization(mut self, regularization: F) -> Result<Self, Error> {
+        if regularization < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AndersonAcceleration`: regularization must be >= 0."
+            ));
+        }
+        self.regularization = regularization;
+        Ok(self)
+    }
+
+    /// Set relaxation parameter `beta`
+    ///
+    /// Must be positive and defaults to `1` (no relaxation).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::AndersonAcceleration;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let anderson: AndersonAcceleration<Vec<f64>, f64> =
+    ///     AndersonAcceleration::new().with_relaxation(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_relaxation(mut self, relaxation: F) -> Result<Self, Error> {
+        if relaxation <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AndersonAcceleration`: relaxation must be > 0."
+            ));
+        }
+        self.relaxation = relaxation;
+        Ok(self)
+    }
+
+    /// Enable safeguarding
+    ///
+    /// An accelerated step from `x_k` to `x_{k+1}` is only accepted if
+    /// `||f_{k+1}|| <= factor * ||f_k||`. Otherwise the memory is cleared and a plain fixed-point
+    /// step is taken instead, which requires an additional evaluation of `G`. `factor` must be
+    /// positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::AndersonAcceleration;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let anderson: AndersonAcceleration<Vec<f64>, f64> =
+    ///     AndersonAcceleration::new().with_safeguard(1.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_safeguard(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AndersonAcceleration`: safeguard factor must be > 0."
+            ));
+        }
+        self.safeguard = Some(factor);
+        Ok(self)
+    }
+
+    /// Set tolerance on the norm of the residual `G(x) - x`
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::AndersonAcceleration;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let anderson: AndersonAcceleration<Vec<f64>, f64> =
+    ///     AndersonAcceleration::new().with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AndersonAcceleration`: tol must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<P, F: ArgminFloat> Default for AndersonAcceleration<P, F> {
+    fn default() -> Self {
+        AndersonAcceleration::new()
+    }
+}
+
+impl<P, F> AndersonAcceleration<P, F>
+where
+    P: ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    /// Solves the regularized least squares problem for the extrapolation coefficients via the
+    /// normal equations. Returns `None` if these are singular.
+    fn coefficients(&self, f: &P) -> Option<Vec<F>> {
+        let m = self.df.len();
+        let mut a = vec![vec![float!(0.0); m]; m];
+        for (i, dfi) in self.df.iter().enumerate() {
+            for (j, dfj) in self.df.iter().enumerate().take(i + 1) {
+                let aij = dfi.dot(dfj);
+                a[i][j] = aij;
+                a[j][i] = aij;
+            }
+        }
+        if self.regularization > float!(0.0) {
+            let norm = a
+                .iter()
+                .flatten()
+                .fold(float!(0.0), |acc, aij| acc + aij.powi(2))
+                .sqrt();
+            for (i, row) in a.iter_mut().enumerate() {
+                row[i] = row[i] + self.regularization * norm;
+            }
+        }
+        let b: Vec<F> = self.df.iter().map(|dfi| dfi.dot(f)).collect();
+        let l = cholesky(&a)?;
+        let gamma = solve_upper_transposed(&l, &solve_lower(&l, &b));
+        if gamma.iter().all(|gi| gi.is_finite()) {
+            Some(gamma)
+        } else {
+            None
+        }
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for AndersonAcceleration<P, F>
+where
+    O: Operator<Param = P, Output = P>,
+    P: Clone
+        + ArgminSub<P, P>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminScaledSub<P, F, P>
+        + ArgminDot<P, F>
+        + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Anderson acceleration";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`AndersonAcceleration` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let g = problem.apply(x0)?;
+        let f = g.sub(x0);
+        let cost = f.l2_norm();
+        self.g = Some(g);
+        self.f = Some(f);
+        self.df.clear();
+        self.dg.clear();
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`AndersonAcceleration`: Parameter vector in `state` not set"
+        ))?;
+        let g = self.g.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`AndersonAcceleration`: Field `g` not set"
+        ))?;
+        let f = self.f.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`AndersonAcceleration`: Field `f` not set"
+        ))?;
+        let f_norm = state.get_cost();
+        let beta = self.relaxation;
+
+        let mut restart = false;
+        let gamma = if self.df.is_empty() {
+            None
+        } else {
+            let gamma = self.coefficients(&f);
+            restart = gamma.is_none();
+            gamma
+        };
+
+        let (mut x_new, mut g_new, mut f_new, mut f_new_norm);
+        if let Some(gamma) = gamma {
+            x_new = x.scaled_add(&beta, &f);
+            for ((gi, dgi), dfi) in gamma.iter().zip(self.dg.iter()).zip(self.df.iter()) {
+                x_new = x_new
+                    .scaled_sub(gi, dgi)
+                    .scaled_add(&(*gi * (float!(1.0) - beta)), dfi);
+            }
+            g_new = problem.apply(&x_new)?;
+            f_new = g_new.sub(&x_new);
+            f_new_norm = f_new.l2_norm();
+            if let Some(factor) = self.safeguard {
+                if f_new_norm.is_nan() || f_new_norm > factor * f_norm {
+                    restart = true;
+                }
+            }
+            if !restart {
+                return self.finish(state, (x_new, g_new, f_new, f_new_norm), (g, f), false);
+            }
+        }
+
+        // Plain fixed-point step
+        x_new = x.scaled_add(&beta, &f);
+        g_new = problem.apply(&x_new)?;
+        f_new = g_new.sub(&x_new);
+        f_new_norm = f_new.l2_norm();
+        if restart {
+            self.df.clear();
+            self.dg.clear();
+        }
+        self.finish(state, (x_new, g_new, f_new, f_new_norm), (g, f), restart)
+    }
+
+    fn terminate(&mut self, state: &IterState<P, (), (), (), F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+impl<P, F> AndersonAcceleration<P, F>
+where
+    P: Clone + ArgminSub<P, P>,
+    F: ArgminFloat,
+{
+    /// Updates the memory with the new iterate and returns the new state
+    #[allow(clippy::type_complexity)]
+    fn finish(
+        &mut self,
+        state: IterState<P, (), (), (), F>,
+        (x_new, g_new, f_new, f_new_norm): (P, P, P, F),
+        (g, f): (P, P),
+        restart: bool,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        if self.memory > 0 {
+            self.df.push_back(f_new.sub(&f));
+            self.dg.push_back(g_new.sub(&g));
+            if self.df.len() > self.memory {
+                self.df.pop_front();
+                self.dg.pop_front();
+            }
+        }
+        self.g = Some(g_new);
+        self.f = Some(f_new);
+        let memory = self.df.len() as u64;
+        Ok((
+            state.param(x_new).cost(f_new_norm),
+            Some(kv!("memory" => memory; "restart" => restart;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{AffineMap, Cosine, DOTTIE};
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::fixedpoint::PicardIteration;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(anderson, AndersonAcceleration<Vec<f64>, f64>);
+
+    #[test]
+    fn test_new() {
+        let anderson: AndersonAcceleration<Vec<f64>, f64> = AndersonAcceleration::new();
+        let AndersonAcceleration {
+            memory,
+            regularization,
+            relaxation,
+            safeguard,
+            tol,
+            g,
+            f,
+            df,
+            dg,
+        } = anderson;
+        assert_eq!(memory, 5);
+        assert_eq!(regularization.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(relaxation.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(safeguard.is_none());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(g.is_none());
+        assert!(f.is_none());
+        assert!(df.is_empty());
+        assert!(dg.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_error!(
+            AndersonAcceleration::<Vec<f64>, f64>::new().with_regularization(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`AndersonAcceleration`: regularization must be >= 0.\""
+        );
+        for value in [0.0, -1.0] {
+            assert_error!(
+                AndersonAcceleration::<Vec<f64>, f64>::new().with_relaxation(value),
+                ArgminError,
+                "Invalid parameter: \"`AndersonAcceleration`: relaxation must be > 0.\""
+            );
+            assert_error!(
+                AndersonAcceleration::<Vec<f64>, f64>::new().with_safeguard(value),
+                ArgminError,
+                "Invalid parameter: \"`AndersonAcceleration`: safeguard factor must be > 0.\""
+            );
+            assert_error!(
+                AndersonAcceleration::<Vec<f64>, f64>::new().with_tolerance(value),
+                ArgminError,
+                "Invalid parameter: \"`AndersonAcceleration`: tol must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let res = AndersonAcceleration::<Vec<f64>, f64>::new()
+            .init(&mut Problem::new(Cosine {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`AndersonAcceleration` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_memory() {
+        let mut anderson = AndersonAcceleration::new().with_memory(2);
+        let mut problem = Problem::new(Cosine {});
+        let state = IterState::new().param(vec![0.0f64, 1.0]);
+        let (mut state, _) = anderson.init(&mut problem, state).unwrap();
+        for expected in [1, 2, 2, 2] {
+            let (new_state, kv) = anderson.next_iter(&mut problem, state).unwrap();
+            state = new_state;
+            assert_eq!(anderson.df.len(), expected);
+            assert_eq!(anderson.dg.len(), expected);
+            let kv = kv.unwrap();
+            assert_eq!(kv.get("memory").unwrap().get_uint(), Some(expected as u64));
+            assert_eq!(kv.get("restart").unwrap().get_bool(), Some(false));
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        let res = Executor::new(Cosine {}, AndersonAcceleration::new())
+            .configure(|state| state.param(vec![0.0, 1.0, 3.0]).max_iters(50))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        for xi in res.state.get_best_param().unwrap() {
+            assert_relative_eq!(*xi, DOTTIE, epsilon = 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_affine() {
+        // For an affine map in n dimensions, Anderson acceleration with memory >= n terminates
+        // after at most n + 1 iterations (up to rounding), while the plain fixed-point iteration
+        // converges slowly.
+        let solver = AndersonAcceleration::new().with_tolerance(1e-10).unwrap();
+        let res = Executor::new(AffineMap::new(), solver)
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100))
+            .run()
+            .unwrap();
+        assert!(res.state.get_iter() <= 5);
+        let x = res.state.get_best_param().unwrap();
+        for (xi, expected) in x.iter().zip([1.0, 2.0, 3.0]) {
+            assert_relative_eq!(*xi, expected, epsilon = 1e-9);
+        }
+
+        let picard = PicardIteration::new().with_tolerance(1e-10).unwrap();
+        let res = Executor::new(AffineMap::new(), picard)
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        let solvers = [
+            AndersonAcceleration::new().with_memory(0),
+            AndersonAcceleration::new().with_memory(1),
+            AndersonAcceleration::new()
+                .with_regularization(1e-6)
+                .unwrap(),
+            AndersonAcceleration::new().with_relaxation(0.5).unwrap(),
+            AndersonAcceleration::new().with_safeguard(0.9).unwrap(),
+        ];
+        for solver in solvers {
+            let res = Executor::new(AffineMap::new(), solver)
+                .configure(|state| state.param(vec![0.0; 3]).max_iters(1000))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            for (xi, expected) in x.iter().zip([1.0, 2.0, 3.0]) {
+                assert_relative_eq!(*xi, expected, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_safeguard() {
+        // A safeguard factor close to zero rejects every accelerated step. The iterates are then
+        // identical to those of the plain fixed-point iteration and every step but the first one
+        // restarts.
+        let mut anderson = AndersonAcceleration::new().with_safeguard(1e-12).unwrap();
+        let mut picard = PicardIteration::new();
+        let mut problem = Problem::new(AffineMap::new());
+        let state = IterState::new().param(vec![0.0f64; 3]);
+        let (mut state_a, _) = anderson.init(&mut problem, state.clone()).unwrap();
+        let (mut state_p, _) = picard.init(&mut problem, state).unwrap();
+        for iter in 0..6 {
+            let (new_state, kv) = anderson.next_iter(&mut problem, state_a).unwrap();
+            state_a = new_state;
+            assert_eq!(
+                kv.unwrap().get("restart").unwrap().get_bool(),
+                Some(iter > 0)
+            );
+            (state_p, _) = picard.next_iter(&mut problem, state_p).unwrap();
+            let xa = state_a.get_param().unwrap();
+            let xp = state_p.get_param().unwrap();
+            for (a, p) in xa.iter().zip(xp.iter()) {
+                assert_relative_eq!(a, p, epsilon = 1e-12);
+            }
+        }
+    }
+}
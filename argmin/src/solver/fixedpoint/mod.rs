@@ -0,0 +1,86 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Fixed-point iterations
+//!
+//! Solvers for fixed-point problems `x = G(x)`, where the map `G` is provided via
+//! [`Operator`](`crate::core::Operator`). Both solvers stop once the norm of the residual
+//! `G(x) - x` falls below a given tolerance. The norm of the residual is reported as cost.
+//!
+//! * [`PicardIteration`]: Plain (relaxed) fixed-point iteration `x_{k+1} = G(x_k)`
+//! * [`AndersonAcceleration`]: Extrapolates from the last few iterates and is often considerably
+//!   faster than the plain iteration
+//!
+//! Many iterative schemes can be written as fixed-point iterations and therefore be accelerated.
+//! For instance, the [`Landweber`](`crate::solver::landweber::Landweber`) iteration
+//! corresponds to the map `G(x) = x - omega * \nabla f(x)`.
+//!
+//! ## References
+//!
+//! \[0\] Donald G. Anderson (1965). Iterative Procedures for Nonlinear Integral Equations.
+//! Journal of the ACM 12(4), 547–560. <https://doi.org/10.1145/321296.321305>
+//!
+//! \[1\] Homer F. Walker and Peng Ni (2011). Anderson Acceleration for Fixed-Point Iterations.
+//! SIAM Journal on Numerical Analysis 49(4), 1715–1735. <https://doi.org/10.1137/10078356X>
+
+mod anderson;
+mod picard;
+
+pub use anderson::AndersonAcceleration;
+pub use picard::PicardIteration;
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{Error, Operator};
+    use argmin_math::ArgminDot;
+
+    /// Affine map `G(x) = M x + c` with fixed point `[1, 2, 3]`
+    #[derive(Clone)]
+    pub(super) struct AffineMap {
+        pub m: Vec<Vec<f64>>,
+        pub c: Vec<f64>,
+    }
+
+    impl AffineMap {
+        pub(super) fn new() -> Self {
+            let m = vec![
+                vec![0.9, 0.05, 0.0],
+                vec![0.05, 0.8, 0.1],
+                vec![0.0, 0.1, 0.7],
+            ];
+            let x = vec![1.0, 2.0, 3.0];
+            let mx: Vec<f64> = m.dot(&x);
+            let c = x.iter().zip(mx.iter()).map(|(xi, mxi)| xi - mxi).collect();
+            AffineMap { m, c }
+        }
+    }
+
+    impl Operator for AffineMap {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            let mx: Vec<f64> = self.m.dot(x);
+            Ok(mx.iter().zip(self.c.iter()).map(|(a, b)| a + b).collect())
+        }
+    }
+
+    /// `G(x) = cos(x)` elementwise, the fixed point is the Dottie number
+    #[derive(Clone)]
+    pub(super) struct Cosine {}
+
+    pub(super) const DOTTIE: f64 = 0.739_085_133_215_160_6;
+
+    impl Operator for Cosine {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(x.iter().map(|xi| xi.cos()).collect())
+        }
+    }
+}
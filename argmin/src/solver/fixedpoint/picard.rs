@@ -0,0 +1,259 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Operator, Problem, Solver, State, TerminationReason,
+    TerminationStatus, KV,
+};
+use argmin_math::{ArgminL2Norm, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Picard iteration
+///
+/// Plain fixed-point iteration for problems of the form `x = G(x)`:
+///
+/// `x_{k+1} = (1 - beta) * x_k + beta * G(x_k)`
+///
+/// where `beta` is the [relaxation parameter](`PicardIteration::with_relaxation`). The iteration
+/// converges if `G` is a contraction, albeit only linearly. See
+/// [`AndersonAcceleration`](`crate::solver::fixedpoint::AndersonAcceleration`) for a usually much
+/// faster alternative.
+///
+/// The iteration stops once the norm of the residual `G(x_k) - x_k` (reported as cost) is below
+/// the [tolerance](`PicardIteration::with_tolerance`).
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] (the map `G`).
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct PicardIteration<P, F> {
+    /// Relaxation parameter
+    relaxation: F,
+    /// Tolerance on the norm of the residual
+    tol: F,
+    /// Residual `G(x_k) - x_k`
+    f: Option<P>,
+}
+
+impl<P, F: ArgminFloat> PicardIteration<P, F> {
+    /// Construct a new instance of [`PicardIteration`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::PicardIteration;
+    /// let picard: PicardIteration<Vec<f64>, f64> = PicardIteration::new();
+    /// ```
+    pub fn new() -> Self {
+        PicardIteration {
+            relaxation: float!(1.0),
+            tol: F::epsilon().sqrt(),
+            f: None,
+        }
+    }
+
+    /// Set relaxation parameter `beta`
+    ///
+    /// Must be positive and defaults to `1` (no relaxation). Values below `1` damp the iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::PicardIteration;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let picard: PicardIteration<Vec<f64>, f64> = PicardIteration::new().with_relaxation(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_relaxation(mut self, relaxation: F) -> Result<Self, Error> {
+        if relaxation <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`PicardIteration`: relaxation must be > 0."
+            ));
+        }
+        self.relaxation = relaxation;
+        Ok(self)
+    }
+
+    /// Set tolerance on the norm of the residual `G(x) - x`
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::PicardIteration;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let picard: PicardIteration<Vec<f64>, f64> = PicardIteration::new().with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`PicardIteration`: tol must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<P, F: ArgminFloat> Default for PicardIteration<P, F> {
+    fn default() -> Self {
+        PicardIteration::new()
+    }
+}
+
+impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for PicardIteration<P, F>
+where
+    O: Operator<Param = P, Output = P>,
+    P: Clone + ArgminSub<P, P> + ArgminScaledAdd<P, F, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Picard iteration";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`PicardIteration` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let f = problem.apply(x0)?.sub(x0);
+        let cost = f.l2_norm();
+        self.f = Some(f);
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`PicardIteration`: Parameter vector in `state` not set"
+        ))?;
+        let f = self.f.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`PicardIteration`: Field `f` not set"
+        ))?;
+        let x = x.scaled_add(&self.relaxation, &f);
+        let f = problem.apply(&x)?.sub(&x);
+        let cost = f.l2_norm();
+        self.f = Some(f);
+        Ok((state.param(x).cost(cost), None))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, (), (), (), F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{AffineMap, Cosine, DOTTIE};
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(picard, PicardIteration<Vec<f64>, f64>);
+
+    #[test]
+    fn test_new() {
+        let picard: PicardIteration<Vec<f64>, f64> = PicardIteration::new();
+        let PicardIteration { relaxation, tol, f } = picard;
+        assert_eq!(relaxation.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(f.is_none());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        for value in [0.0, -1.0] {
+            assert_error!(
+                PicardIteration::<Vec<f64>, f64>::new().with_relaxation(value),
+                ArgminError,
+                "Invalid parameter: \"`PicardIteration`: relaxation must be > 0.\""
+            );
+            assert_error!(
+                PicardIteration::<Vec<f64>, f64>::new().with_tolerance(value),
+                ArgminError,
+                "Invalid parameter: \"`PicardIteration`: tol must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let res = PicardIteration::<Vec<f64>, f64>::new()
+            .init(&mut Problem::new(Cosine {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`PicardIteration` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_next_iter() {
+        let mut picard = PicardIteration::new().with_relaxation(0.5).unwrap();
+        let mut problem = Problem::new(Cosine {});
+        let state = IterState::new().param(vec![0.0f64]);
+        let (state, _) = picard.init(&mut problem, state).unwrap();
+        assert_relative_eq!(state.get_cost(), 1.0, epsilon = f64::EPSILON);
+        let (state, _) = picard.next_iter(&mut problem, state).unwrap();
+        let x = state.get_param().unwrap();
+        assert_relative_eq!(x[0], 0.5, epsilon = f64::EPSILON);
+        assert_relative_eq!(state.get_cost(), 0.5f64.cos() - 0.5, epsilon = f64::EPSILON);
+        assert_eq!(problem.counts["operator_count"], 2);
+    }
+
+    #[test]
+    fn test_solver() {
+        let res = Executor::new(Cosine {}, PicardIteration::new())
+            .configure(|state| state.param(vec![0.0, 1.0, 3.0]).max_iters(200))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        for xi in res.state.get_best_param().unwrap() {
+            assert_relative_eq!(*xi, DOTTIE, epsilon = 1e-7);
+        }
+
+        let res = Executor::new(AffineMap::new(), PicardIteration::new())
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(1000))
+            .run()
+            .unwrap();
+        let x = res.state.get_best_param().unwrap();
+        for (xi, expected) in x.iter().zip([1.0, 2.0, 3.0]) {
+            assert_relative_eq!(*xi, expected, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,698 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::frankwolfe::LinearMinimizationOracle;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Variants of the Frank-Wolfe method
+///
+/// The away-step and pairwise variants keep track of the current iterate as a convex combination
+/// of vertices (the active set). They are able to move weight away from "bad" vertices, which
+/// avoids the zig-zagging of the standard method when the solution lies on a face of the feasible
+/// set and leads to linear convergence for strongly convex functions over polytopes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum FrankWolfeVariant {
+    /// Move towards the vertex returned by the oracle
+    #[default]
+    Standard,
+    /// Either move towards the vertex returned by the oracle or away from the worst vertex of
+    /// the active set, whichever promises more progress
+    AwayStep,
+    /// Move weight from the worst vertex of the active set to the vertex returned by the oracle
+    Pairwise,
+}
+
+/// Step size rules of the Frank-Wolfe method
+///
+/// With the search direction `d`, the gradient `g` and the maximum feasible step size
+/// `gamma_max`, the step size `gamma` in iteration `k` is given as follows:
+///
+/// * `FrankWolfeStepSize::Agnostic`: `gamma = min(2 / (k + 2), gamma_max)`
+/// * `FrankWolfeStepSize::ShortStep(L)`: `gamma = min(-<g, d> / (L * ||d||^2), gamma_max)`,
+///   where `L` is the Lipschitz constant of the gradient
+/// * `FrankWolfeStepSize::Adaptive`: Short step with a local estimate of `L` which is adapted by
+///   backtracking until the quadratic upper bound holds (Pedregosa et al., 2020).
+///
+/// The agnostic step size does not require evaluations of the cost function but is not suited
+/// for the away-step and pairwise variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum FrankWolfeStepSize<F> {
+    /// `gamma = 2 / (k + 2)`
+    Agnostic,
+    /// Short step with given Lipschitz constant of the gradient
+    ShortStep(F),
+    /// Short step with backtracking estimate of the Lipschitz constant of the gradient
+    #[default]
+    Adaptive,
+}
+
+/// # Frank-Wolfe method
+///
+/// Also known as conditional gradient method. Minimizes a smooth function over a compact convex
+/// set which is given via a [`LinearMinimizationOracle`]. In each iteration, the oracle returns a
+/// vertex `s` of the feasible set which minimizes the linearization of the cost function at the
+/// current iterate `x`, and the new iterate is a convex combination of `x` and `s`. All iterates
+/// are therefore feasible without ever projecting onto the feasible set.
+///
+/// The [variant](`FrankWolfe::with_variant`) can be chosen from [`FrankWolfeVariant`] and the
+/// [step size rule](`FrankWolfe::with_step_size`) from [`FrankWolfeStepSize`].
+///
+/// The Frank-Wolfe gap `<g, x - s>` is an upper bound on the suboptimality `f(x) - f(x*)` of
+/// convex functions. It is reported as `gap` in the KV and the algorithm stops once it falls
+/// below the [tolerance](`FrankWolfe::with_tolerance`).
+///
+/// Requires an initial parameter vector which must be feasible.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## References
+///
+/// Marguerite Frank and Philip Wolfe (1956). An algorithm for quadratic programming. Naval
+/// Research Logistics Quarterly 3(1-2), 95–110. <https://doi.org/10.1002/nav.3800030109>
+///
+/// Simon Lacoste-Julien and Martin Jaggi (2015). On the Global Linear Convergence of Frank-Wolfe
+/// Optimization Variants. Advances in Neural Information Processing Systems 28.
+///
+/// Fabian Pedregosa, Geoffrey Negiar, Armin Askari and Martin Jaggi (2020). Linearly Convergent
+/// Frank-Wolfe with Backtracking Line-Search. Proceedings of the 23rd International Conference on
+/// Artificial Intelligence and Statistics, 1–10.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FrankWolfe<L, P, F> {
+    /// Linear minimization oracle
+    oracle: L,
+    /// Variant
+    variant: FrankWolfeVariant,
+    /// Step size rule
+    step_size: FrankWolfeStepSize<F>,
+    /// Tolerance on the Frank-Wolfe gap
+    tol: F,
+    /// Current estimate of the Lipschitz constant (adaptive step size)
+    lipschitz: Option<F>,
+    /// Vertex returned by the oracle for the current iterate
+    vertex: Option<P>,
+    /// Frank-Wolfe gap of the current iterate
+    gap: F,
+    /// Vertices and weights of the convex combination of the current iterate
+    active_set: Vec<(P, F)>,
+}
+
+impl<L, P, F: ArgminFloat> FrankWolfe<L, P, F> {
+    /// Construct a new instance of [`FrankWolfe`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, ProbabilitySimplex};
+    /// let fw: FrankWolfe<_, Vec<f64>, f64> = FrankWolfe::new(ProbabilitySimplex::new(1.0));
+    /// ```
+    pub fn new(oracle: L) -> Self {
+        FrankWolfe {
+            oracle,
+            variant: FrankWolfeVariant::default(),
+            step_size: FrankWolfeStepSize::default(),
+            tol: F::epsilon().sqrt(),
+            lipschitz: None,
+            vertex: None,
+            gap: F::infinity(),
+            active_set: vec![],
+        }
+    }
+
+    /// Set variant
+    ///
+    /// Defaults to [`FrankWolfeVariant::Standard`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, FrankWolfeVariant, ProbabilitySimplex};
+    /// let fw: FrankWolfe<_, Vec<f64>, f64> = FrankWolfe::new(ProbabilitySimplex::new(1.0))
+    ///     .with_variant(FrankWolfeVariant::AwayStep);
+    /// ```
+    #[must_use]
+    pub fn with_variant(mut self, variant: FrankWolfeVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Set step size rule
+    ///
+    /// Defaults to [`FrankWolfeStepSize::Adaptive`]. The Lipschitz constant of
+    /// [`FrankWolfeStepSize::ShortStep`] must be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, FrankWolfeStepSize, ProbabilitySimplex};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fw: FrankWolfe<_, Vec<f64>, f64> = FrankWolfe::new(ProbabilitySimplex::new(1.0))
+    ///     .with_step_size(FrankWolfeStepSize::ShortStep(2.0))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_size(mut self, step_size: FrankWolfeStepSize<F>) -> Result<Self, Error> {
+        if let FrankWolfeStepSize::ShortStep(lipschitz) = step_size {
+            if lipschitz <= float!(0.0) {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "`FrankWolfe`: Lipschitz constant must be > 0."
+                ));
+            }
+        }
+        self.step_size = step_size;
+        Ok(self)
+    }
+
+    /// Set tolerance on the Frank-Wolfe gap
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, ProbabilitySimplex};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fw: FrankWolfe<_, Vec<f64>, f64> = FrankWolfe::new(ProbabilitySimplex::new(1.0))
+    ///     .with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FrankWolfe`: tol must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<L, P, F> FrankWolfe<L, P, F>
+where
+    P: PartialEq,
+    F: ArgminFloat,
+{
+    /// Adds `weight` to the weight of `vertex` in the active set
+    fn add_vertex(&mut self, vertex: P, weight: F) {
+        if let Some(entry) = self.active_set.iter_mut().find(|(v, _)| *v == vertex) {
+            entry.1 = entry.1 + weight;
+        } else {
+            self.active_set.push((vertex, weight));
+        }
+    }
+
+    /// Updates the convex combination after a step of size `gamma`. `away` is the index of the
+    /// away vertex for away and pairwise steps and `drop` indicates that the step size was the
+    /// maximum step size, which removes the away vertex from the active set.
+    fn update_active_set(&mut self, vertex: P, gamma: F, away: Option<usize>, drop: bool) {
+        match (self.variant, away) {
+            (FrankWolfeVariant::Standard, _) => return,
+            (_, None) => {
+                if drop {
+                    self.active_set.clear();
+                } else {
+                    for (_, w) in self.active_set.iter_mut() {
+                        *w = *w * (float!(1.0) - gamma);
+                    }
+                }
+                self.add_vertex(vertex, gamma);
+            }
+            (FrankWolfeVariant::AwayStep, Some(j)) => {
+                for (_, w) in self.active_set.iter_mut() {
+                    *w = *w * (float!(1.0) + gamma);
+                }
+                self.active_set[j].1 = self.active_set[j].1 - gamma;
+                if drop {
+                    self.active_set[j].1 = float!(0.0);
+                }
+            }
+            (FrankWolfeVariant::Pairwise, Some(j)) => {
+                self.active_set[j].1 = self.active_set[j].1 - gamma;
+                if drop {
+                    self.active_set[j].1 = float!(0.0);
+                }
+                self.add_vertex(vertex, gamma);
+            }
+        }
+        self.active_set.retain(|(_, w)| *w > float!(0.0));
+    }
+}
+
+impl<O, L, P, G, F> Solver<O, IterState<P, G, (), (), F>> for FrankWolfe<L, P, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    L: LinearMinimizationOracle<G, P>,
+    P: Clone
+        + PartialEq
+        + ArgminSub<P, P>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminDot<G, F>
+        + ArgminL2Norm<F>,
+    G: ArgminSub<G, G> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Frank-Wolfe";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`FrankWolfe` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = problem.cost(x0)?;
+        let grad = problem.gradient(x0)?;
+        let vertex = self.oracle.minimize(&grad)?;
+        self.gap = x0.sub(&vertex).dot(&grad);
+        self.active_set.clear();
+        if self.variant != FrankWolfeVariant::Standard {
+            self.active_set.push((x0.clone(), float!(1.0)));
+        }
+        self.vertex = Some(vertex);
+        self.lipschitz = None;
+        let gap = self.gap;
+        Ok((state.cost(cost).gradient(grad), Some(kv!("gap" => gap;))))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FrankWolfe`: Parameter vector in `state` not set"
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FrankWolfe`: Gradient in `state` not set"
+        ))?;
+        let vertex = self.vertex.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FrankWolfe`: Field `vertex` not set"
+        ))?;
+        let cost = state.get_cost();
+
+        // Away vertex: the vertex of the active set with the largest `<g, v>`
+        let away = self
+            .active_set
+            .iter()
+            .map(|(v, w)| (v.dot(&grad), *w))
+            .enumerate()
+            .fold(
+                None,
+                |acc: Option<(usize, F, F)>, (j, (value, w))| match acc {
+                    Some((_, best, _)) if best >= value => acc,
+                    _ => Some((j, value, w)),
+                },
+            );
+
+        let (direction, gamma_max, away) = match (self.variant, away) {
+            (FrankWolfeVariant::AwayStep, Some((j, value, weight))) => {
+                let away_gap = value - x.dot(&grad);
+                if self.gap >= away_gap || weight >= float!(1.0) {
+                    (vertex.sub(&x), float!(1.0), None)
+                } else {
+                    let direction = x.sub(&self.active_set[j].0);
+                    (direction, weight / (float!(1.0) - weight), Some(j))
+                }
+            }
+            (FrankWolfeVariant::Pairwise, Some((j, _, weight))) => {
+                (vertex.sub(&self.active_set[j].0), weight, Some(j))
+            }
+            _ => (vertex.sub(&x), float!(1.0), None),
+        };
+
+        let slope = direction.dot(&grad);
+        let dd = direction.l2_norm().powi(2);
+
+        let (gamma, x_new, cost_new) = if slope >= float!(0.0) || dd <= float!(0.0) {
+            // No descent possible along this direction
+            (float!(0.0), x, cost)
+        } else {
+            match self.step_size {
+                FrankWolfeStepSize::Agnostic => {
+                    let k = F::from_u64(state.get_iter()).unwrap();
+                    let gamma = (float!(2.0) / (k + float!(2.0))).min(gamma_max);
+                    let x_new = x.scaled_add(&gamma, &direction);
+                    let cost_new = problem.cost(&x_new)?;
+                    (gamma, x_new, cost_new)
+                }
+                FrankWolfeStepSize::ShortStep(lipschitz) => {
+                    let gamma = (-slope / (lipschitz * dd)).min(gamma_max);
+                    let x_new = x.scaled_add(&gamma, &direction);
+                    let cost_new = problem.cost(&x_new)?;
+                    (gamma, x_new, cost_new)
+                }
+                FrankWolfeStepSize::Adaptive => {
+                    let mut lipschitz = match self.lipschitz {
+                        Some(lipschitz) => lipschitz,
+                        None => {
+                            // Initial estimate from a gradient difference
+                            let eps = float!(1e-3);
+                            let grad_eps = problem.gradient(&x.scaled_add(&eps, &direction))?;
+                            let estimate = grad_eps.sub(&grad).l2_norm() / (eps * dd.sqrt());
+                            if estimate.is_finite() && estimate > float!(0.0) {
+                                estimate
+                            } else {
+                                float!(1.0)
+                            }
+                        }
+                    } * float!(0.9);
+                    loop {
+                        let gamma = (-slope / (lipschitz * dd)).min(gamma_max);
+                        let x_new = x.scaled_add(&gamma, &direction);
+                        let cost_new = problem.cost(&x_new)?;
+                        let bound = cost
+                            + gamma * slope
+                            + float!(0.5) * gamma.powi(2) * lipschitz * dd
+                            + F::epsilon() * cost.abs();
+                        if cost_new <= bound {
+                            self.lipschitz = Some(lipschitz);
+                            break (gamma, x_new, cost_new);
+                        }
+                        lipschitz = lipschitz * float!(2.0);
+                        if !lipschitz.is_finite() {
+                            return Err(argmin_error!(
+                                ConditionViolated,
+                                "`FrankWolfe`: Unable to find a step size which decreases the cost."
+                            ));
+                        }
+                    }
+                }
+            }
+        };
+
+        let drop = gamma > float!(0.0) && gamma >= gamma_max;
+        self.update_active_set(vertex, gamma, away, drop);
+
+        let grad_new = problem.gradient(&x_new)?;
+        let vertex_new = self.oracle.minimize(&grad_new)?;
+        self.gap = x_new.sub(&vertex_new).dot(&grad_new);
+        self.vertex = Some(vertex_new);
+
+        let gap = self.gap;
+        let mut kv = kv!("gap" => gap; "gamma" => gamma;);
+        if self.variant != FrankWolfeVariant::Standard {
+            let active_set = self.active_set.len() as u64;
+            kv = kv.merge(kv!("active_set" => active_set; "away" => away.is_some();));
+        }
+        Ok((
+            state.param(x_new).gradient(grad_new).cost(cost_new),
+            Some(kv),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        if self.gap <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::frankwolfe::{ConvexHull, L1Ball, ProbabilitySimplex};
+    use approx::assert_relative_eq;
+
+    /// `f(x) = 1/2 ||x - c||^2`, the solution is the projection of `c` onto the feasible set
+    #[derive(Clone)]
+    struct Distance {
+        c: Vec<f64>,
+    }
+
+    impl CostFunction for Distance {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(0.5 * x.sub(&self.c).l2_norm().powi(2))
+        }
+    }
+
+    impl Gradient for Distance {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(x.sub(&self.c))
+        }
+    }
+
+    test_trait_impl!(
+        frankwolfe,
+        FrankWolfe<ProbabilitySimplex<f64>, Vec<f64>, f64>
+    );
+
+    #[test]
+    fn test_new() {
+        let fw: FrankWolfe<_, Vec<f64>, f64> = FrankWolfe::new(ProbabilitySimplex::new(1.0));
+        let FrankWolfe {
+            oracle,
+            variant,
+            step_size,
+            tol,
+            lipschitz,
+            vertex,
+            gap,
+            active_set,
+        } = fw;
+        assert_eq!(oracle, ProbabilitySimplex::new(1.0));
+        assert_eq!(variant, FrankWolfeVariant::Standard);
+        assert_eq!(step_size, FrankWolfeStepSize::Adaptive);
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(lipschitz.is_none());
+        assert!(vertex.is_none());
+        assert!(gap.is_infinite());
+        assert!(active_set.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        for value in [0.0, -1.0] {
+            assert_error!(
+                FrankWolfe::<_, Vec<f64>, f64>::new(ProbabilitySimplex::new(1.0))
+                    .with_step_size(FrankWolfeStepSize::ShortStep(value)),
+                ArgminError,
+                "Invalid parameter: \"`FrankWolfe`: Lipschitz constant must be > 0.\""
+            );
+            assert_error!(
+                FrankWolfe::<_, Vec<f64>, f64>::new(ProbabilitySimplex::new(1.0))
+                    .with_tolerance(value),
+                ArgminError,
+                "Invalid parameter: \"`FrankWolfe`: tol must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let mut fw: FrankWolfe<_, Vec<f64>, f64> = FrankWolfe::new(ProbabilitySimplex::new(1.0));
+        let res = fw.init(
+            &mut Problem::new(Distance { c: vec![1.0] }),
+            IterState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`FrankWolfe` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_next_iter() {
+        // Exact line search on a quadratic with unit curvature: the first step goes from the
+        // vertex `e_0` towards `e_1` with step size 0.5 and ends at the solution.
+        let mut fw = FrankWolfe::new(ProbabilitySimplex::new(1.0))
+            .with_step_size(FrankWolfeStepSize::ShortStep(1.0))
+            .unwrap();
+        let mut problem = Problem::new(Distance {
+            c: vec![0.5, 0.5, 0.0],
+        });
+        let state = IterState::new().param(vec![1.0, 0.0, 0.0]);
+        let (state, kv) = fw.init(&mut problem, state).unwrap();
+        assert_relative_eq!(
+            kv.unwrap().get("gap").unwrap().get_float().unwrap(),
+            1.0,
+            epsilon = f64::EPSILON
+        );
+        let (state, kv) = fw.next_iter(&mut problem, state).unwrap();
+        let kv = kv.unwrap();
+        assert_relative_eq!(
+            kv.get("gamma").unwrap().get_float().unwrap(),
+            0.5,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            kv.get("gap").unwrap().get_float().unwrap(),
+            0.0,
+            epsilon = f64::EPSILON
+        );
+        let x = state.get_param().unwrap();
+        for (xi, expected) in x.iter().zip([0.5, 0.5, 0.0]) {
+            assert_relative_eq!(*xi, expected, epsilon = f64::EPSILON);
+        }
+        assert_eq!(
+            <FrankWolfe<_, _, _> as Solver<Distance, _>>::terminate(&mut fw, &state),
+            TerminationStatus::Terminated(TerminationReason::SolverConverged)
+        );
+    }
+
+    #[test]
+    fn test_active_set() {
+        let mut fw = FrankWolfe::new(ProbabilitySimplex::new(1.0))
+            .with_variant(FrankWolfeVariant::Pairwise)
+            .with_step_size(FrankWolfeStepSize::ShortStep(1.0))
+            .unwrap();
+        let mut problem = Problem::new(Distance {
+            c: vec![0.8, 0.5, -0.3],
+        });
+        let state = IterState::new().param(vec![0.0, 0.0, 1.0]);
+        let (mut state, _) = fw.init(&mut problem, state).unwrap();
+        for _ in 0..5 {
+            (state, _) = fw.next_iter(&mut problem, state).unwrap();
+            // The iterate must coincide with the convex combination of the active set
+            let x = state.get_param().unwrap();
+            let mut combination = vec![0.0; 3];
+            let mut weights = 0.0;
+            for (v, w) in fw.active_set.iter() {
+                combination = combination.scaled_add(w, v);
+                weights += w;
+            }
+            assert_relative_eq!(weights, 1.0, epsilon = 1e-12);
+            for (xi, ci) in x.iter().zip(combination.iter()) {
+                assert_relative_eq!(*xi, *ci, epsilon = 1e-12);
+            }
+        }
+        // The initial vertex `e_2` is not part of the solution and has been dropped
+        assert!(fw.active_set.iter().all(|(v, _)| v[2] == 0.0));
+    }
+
+    #[test]
+    fn test_variants() {
+        // The solution lies in the interior of a face of the simplex
+        let expected = [0.65, 0.35, 0.0];
+        let problem = Distance {
+            c: vec![0.8, 0.5, -0.3],
+        };
+        let run = |variant, step_size| {
+            let solver = FrankWolfe::new(ProbabilitySimplex::new(1.0))
+                .with_variant(variant)
+                .with_step_size(step_size)
+                .unwrap()
+                .with_tolerance(1e-10)
+                .unwrap();
+            Executor::new(problem.clone(), solver)
+                .configure(|state| state.param(vec![1.0 / 3.0; 3]).max_iters(100))
+                .run()
+                .unwrap()
+        };
+
+        for variant in [FrankWolfeVariant::AwayStep, FrankWolfeVariant::Pairwise] {
+            for step_size in [
+                FrankWolfeStepSize::ShortStep(1.0),
+                FrankWolfeStepSize::Adaptive,
+            ] {
+                let res = run(variant, step_size);
+                assert_eq!(
+                    res.state.get_termination_reason(),
+                    Some(&TerminationReason::SolverConverged)
+                );
+                let x = res.state.get_best_param().unwrap();
+                for (xi, expected) in x.iter().zip(expected) {
+                    assert_relative_eq!(*xi, expected, epsilon = 1e-8);
+                }
+            }
+        }
+
+        // The standard method zig-zags and converges only sublinearly
+        for step_size in [
+            FrankWolfeStepSize::ShortStep(1.0),
+            FrankWolfeStepSize::Adaptive,
+        ] {
+            let res = run(FrankWolfeVariant::Standard, step_size);
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::MaxItersReached)
+            );
+            let x = res.state.get_best_param().unwrap();
+            for (xi, expected) in x.iter().zip(expected) {
+                assert_relative_eq!(*xi, expected, epsilon = 1e-1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_oracles() {
+        // Projection of `[2.0, 0.5, -0.2]` onto the L1 ball is the vertex `[1, 0, 0]`
+        for step_size in [FrankWolfeStepSize::Agnostic, FrankWolfeStepSize::Adaptive] {
+            let solver = FrankWolfe::new(L1Ball::new(1.0))
+                .with_step_size(step_size)
+                .unwrap();
+            let res = Executor::new(
+                Distance {
+                    c: vec![2.0, 0.5, -0.2],
+                },
+                solver,
+            )
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100))
+            .run()
+            .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            for (xi, expected) in x.iter().zip([1.0, 0.0, 0.0]) {
+                assert_relative_eq!(*xi, expected, epsilon = 1e-8);
+            }
+        }
+
+        // Projection of `[1, 1]` onto the triangle with vertices `[0, 0]`, `[1, 0]`, `[0, 1]`
+        let triangle: ConvexHull<Vec<f64>, f64> =
+            ConvexHull::new(vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]]);
+        let solver = FrankWolfe::new(triangle).with_variant(FrankWolfeVariant::AwayStep);
+        let res = Executor::new(Distance { c: vec![1.0, 1.0] }, solver)
+            .configure(|state| state.param(vec![0.0; 2]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        for xi in x.iter() {
+            assert_relative_eq!(*xi, 0.5, epsilon = 1e-8);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Frank-Wolfe (conditional gradient) methods
+//!
+//! Minimization of a smooth function over a compact convex set which is only accessed via a
+//! [`LinearMinimizationOracle`], i.e. the minimization of linear functions over the set. This is
+//! attractive for sets like simplices, norm balls or polytopes, where projections are expensive
+//! but linear minimization is cheap.
+//!
+//! * [`FrankWolfe`]: Frank-Wolfe method including the away-step and pairwise variants (see
+//!   [`FrankWolfeVariant`])
+//!
+//! The following oracles are available:
+//!
+//! * [`ProbabilitySimplex`]: `{x : x_i >= 0, sum_i x_i = r}`
+//! * [`L1Ball`]: `{x : ||x||_1 <= r}`
+//! * [`ConvexHull`]: Convex hull of a finite set of vertices (polytope)
+//!
+//! ## References
+//!
+//! \[0\] Marguerite Frank and Philip Wolfe (1956). An algorithm for quadratic programming. Naval
+//! Research Logistics Quarterly 3(1-2), 95–110. <https://doi.org/10.1002/nav.3800030109>
+//!
+//! \[1\] Martin Jaggi (2013). Revisiting Frank-Wolfe: Projection-Free Sparse Convex
+//! Optimization. Proceedings of the 30th International Conference on Machine Learning, 427–435.
+//!
+//! \[2\] Simon Lacoste-Julien and Martin Jaggi (2015). On the Global Linear Convergence of
+//! Frank-Wolfe Optimization Variants. Advances in Neural Information Processing Systems 28.
+//!
+//! \[3\] Fabian Pedregosa, Geoffrey Negiar, Armin Askari and Martin Jaggi (2020). Linearly
+//! Convergent Frank-Wolfe with Backtracking Line-Search. Proceedings of the 23rd International
+//! Conference on Artificial Intelligence and Statistics, 1–10.
+
+mod frankwolfe_method;
+
+pub use frankwolfe_method::{FrankWolfe, FrankWolfeStepSize, FrankWolfeVariant};
+
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{ArgminDot, ArgminGet, ArgminLen, ArgminSet, ArgminZeroLike};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Interface for linear minimization oracles
+///
+/// Given a direction `g` (usually the gradient), returns a vertex `v` of the feasible set which
+/// minimizes `<g, v>`. Ties may be broken arbitrarily.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::frankwolfe::LinearMinimizationOracle;
+///
+/// /// Box `[-1, 1]^n`
+/// struct UnitBox {}
+///
+/// impl LinearMinimizationOracle<Vec<f64>, Vec<f64>> for UnitBox {
+///     fn minimize(&self, g: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         Ok(g.iter().map(|gi| if *gi > 0.0 { -1.0 } else { 1.0 }).collect())
+///     }
+/// }
+/// ```
+pub trait LinearMinimizationOracle<G, P> {
+    /// Compute a minimizer of `<g, v>` over the feasible set
+    fn minimize(&self, g: &G) -> Result<P, Error>;
+}
+
+impl<G, P, L: LinearMinimizationOracle<G, P>> LinearMinimizationOracle<G, P> for &L {
+    fn minimize(&self, g: &G) -> Result<P, Error> {
+        (*self).minimize(g)
+    }
+}
+
+/// Index of the smallest element of `g` (or of the largest if `largest` is `true`). If `abs` is
+/// `true`, the absolute values are compared.
+fn extreme_index<P, F>(g: &P, abs: bool, largest: bool) -> Result<usize, Error>
+where
+    P: ArgminGet<usize, F> + ArgminLen,
+    F: ArgminFloat,
+{
+    let mut best: Option<(usize, F)> = None;
+    for i in 0..g.len() {
+        let gi = g.get_elem(i);
+        let gi = if abs { gi.abs() } else { gi };
+        let gi = if largest { -gi } else { gi };
+        if best.map(|(_, b)| gi < b).unwrap_or(true) {
+            best = Some((i, gi));
+        }
+    }
+    best.map(|(i, _)| i).ok_or_else(argmin_error_closure!(
+        InvalidParameter,
+        "Linear minimization oracle: direction must not be empty."
+    ))
+}
+
+/// Probability simplex `{x : x_i >= 0, sum_i x_i = r}` scaled by a radius `r > 0`
+///
+/// The vertices are the scaled unit vectors `r * e_i`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ProbabilitySimplex<F> {
+    radius: F,
+}
+
+impl<F> ProbabilitySimplex<F> {
+    /// Construct a new instance of [`ProbabilitySimplex`] with radius `r`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::ProbabilitySimplex;
+    /// let simplex = ProbabilitySimplex::new(1.0f64);
+    /// ```
+    pub fn new(radius: F) -> Self {
+        ProbabilitySimplex { radius }
+    }
+}
+
+impl<P, F> LinearMinimizationOracle<P, P> for ProbabilitySimplex<F>
+where
+    P: ArgminGet<usize, F> + ArgminSet<usize, F> + ArgminLen + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    fn minimize(&self, g: &P) -> Result<P, Error> {
+        let i = extreme_index(g, false, false)?;
+        let mut v = g.zero_like();
+        v.set_elem(i, self.radius);
+        Ok(v)
+    }
+}
+
+/// L1 ball `{x : ||x||_1 <= r}` with radius `r > 0`
+///
+/// The vertices are the scaled signed unit vectors `+-r * e_i`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct L1Ball<F> {
+    radius: F,
+}
+
+impl<F> L1Ball<F> {
+    /// Construct a new instance of [`L1Ball`] with radius `r`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::L1Ball;
+    /// let ball = L1Ball::new(1.0f64);
+    /// ```
+    pub fn new(radius: F) -> Self {
+        L1Ball { radius }
+    }
+}
+
+impl<P, F> LinearMinimizationOracle<P, P> for L1Ball<F>
+where
+    P: ArgminGet<usize, F> + ArgminSet<usize, F> + ArgminLen + ArgminZeroLike,
+    F: ArgminFloat,
+{
+    fn minimize(&self, g: &P) -> Result<P, Error> {
+        let i = extreme_index(g, true, true)?;
+        let mut v = g.zero_like();
+        if g.get_elem(i) > float!(0.0) {
+            v.set_elem(i, -self.radius);
+        } else {
+            v.set_elem(i, self.radius);
+        }
+        Ok(v)
+    }
+}
+
+/// Convex hull of a finite set of vertices (polytope)
+///
+/// The oracle evaluates `<g, v>` for all vertices `v`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ConvexHull<P, F> {
+    vertices: Vec<P>,
+    phantom: PhantomData<F>,
+}
+
+impl<P, F> ConvexHull<P, F> {
+    /// Construct a new instance of [`ConvexHull`] from its vertices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::ConvexHull;
+    /// let triangle: ConvexHull<Vec<f64>, f64> =
+    ///     ConvexHull::new(vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]]);
+    /// ```
+    pub fn new(vertices: Vec<P>) -> Self {
+        ConvexHull {
+            vertices,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<G, P, F> LinearMinimizationOracle<G, P> for ConvexHull<P, F>
+where
+    P: Clone + ArgminDot<G, F>,
+    F: ArgminFloat,
+{
+    fn minimize(&self, g: &G) -> Result<P, Error> {
+        let mut best: Option<(&P, F)> = None;
+        for v in self.vertices.iter() {
+            let value = v.dot(g);
+            if best.map(|(_, b)| value < b).unwrap_or(true) {
+                best = Some((v, value));
+            }
+        }
+        best.map(|(v, _)| v.clone())
+            .ok_or_else(argmin_error_closure!(
+                InvalidParameter,
+                "`ConvexHull`: at least one vertex is required."
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+
+    test_trait_impl!(probability_simplex, ProbabilitySimplex<f64>);
+    test_trait_impl!(l1_ball, L1Ball<f64>);
+    test_trait_impl!(convex_hull, ConvexHull<Vec<f64>, f64>);
+
+    #[test]
+    fn test_probability_simplex() {
+        let simplex = ProbabilitySimplex::new(2.0f64);
+        let v: Vec<f64> = simplex.minimize(&vec![1.0, -3.0, 2.0, -1.0]).unwrap();
+        assert_eq!(v, vec![0.0, 2.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_l1_ball() {
+        let ball = L1Ball::new(2.0f64);
+        let v: Vec<f64> = ball.minimize(&vec![1.0, -3.0, 2.0, -1.0]).unwrap();
+        assert_eq!(v, vec![0.0, 2.0, 0.0, 0.0]);
+        let v: Vec<f64> = ball.minimize(&vec![1.0, -3.0, 4.0, -1.0]).unwrap();
+        assert_eq!(v, vec![0.0, 0.0, -2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let hull: ConvexHull<Vec<f64>, f64> =
+            ConvexHull::new(vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]]);
+        let v: Vec<f64> = hull.minimize(&vec![1.0, -1.0]).unwrap();
+        assert_eq!(v, vec![0.0, 1.0]);
+        let v: Vec<f64> = hull.minimize(&vec![1.0, 1.0]).unwrap();
+        assert_eq!(v, vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let res: Result<Vec<f64>, Error> = ProbabilitySimplex::new(1.0f64).minimize(&vec![]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"Linear minimization oracle: direction must not be empty.\""
+        );
+        let res: Result<Vec<f64>, Error> =
+            ConvexHull::<Vec<f64>, f64>::new(vec![]).minimize(&vec![1.0]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`ConvexHull`: at least one vertex is required.\""
+        );
+    }
+}
@@ -10,7 +10,7 @@ use crate::core::{
     Jacobian, LineSearch, Operator, OptimizationResult, Problem, SerializeAlias, Solver,
     TerminationReason, TerminationStatus, KV,
 };
-use argmin_math::{ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminTranspose};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminSolve, ArgminTranspose};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -91,9 +91,8 @@ where
         + SerializeAlias
         + DeserializeOwnedAlias
         + ArgminTranspose<J>
-        + ArgminInv<J>
+        + ArgminSolve<G, P>
         + ArgminDot<J, J>
-        + ArgminDot<G, P>
         + ArgminDot<U, G>,
     L: Clone + LineSearch<P, F> + Solver<LineSearchProblem<O, F>, IterState<P, G, (), (), F>>,
     F: ArgminFloat,
@@ -117,8 +116,10 @@ where
         let jacobian_t = jacobian.clone().t();
         let grad = jacobian_t.dot(&residuals);
 
-        let p: P = jacobian_t.dot(&jacobian).inv()?.dot(&grad);
+        let p: P = jacobian_t.dot(&jacobian).solve(&grad)?;
+        let cost = residuals.l2_norm();
 
+        self.linesearch.record_cost(cost);
         self.linesearch.search_direction(p.mul(&(float!(-1.0))));
 
         // perform linesearch
@@ -133,7 +134,7 @@ where
             ))?),
             self.linesearch.clone(),
         )
-        .configure(|config| config.param(param).gradient(grad).cost(residuals.l2_norm()))
+        .configure(|config| config.param(param).gradient(grad).cost(cost))
         .ctrlc(false)
         .run()?;
 
@@ -467,4 +468,108 @@ mod tests {
             epsilon = f64::EPSILON
         );
     }
+
+    #[test]
+    fn test_next_iter_records_cost() {
+        use crate::core::State;
+        use std::cell::RefCell;
+
+        /// Wraps a line search and keeps track of the costs passed to `record_cost`
+        #[derive(Clone)]
+        struct RecordingLineSearch<L> {
+            linesearch: L,
+            costs: Vec<f64>,
+        }
+
+        impl<P, L: LineSearch<P, f64>> LineSearch<P, f64> for RecordingLineSearch<L> {
+            fn search_direction(&mut self, direction: P) {
+                self.linesearch.search_direction(direction);
+            }
+
+            fn initial_step_length(&mut self, step_length: f64) -> Result<(), Error> {
+                self.linesearch.initial_step_length(step_length)
+            }
+
+            fn record_cost(&mut self, cost: f64) {
+                self.costs.push(cost);
+            }
+        }
+
+        impl<O, I, L> Solver<O, I> for RecordingLineSearch<L>
+        where
+            I: State,
+            L: Solver<O, I>,
+        {
+            const NAME: &'static str = "Recording line search";
+
+            fn init(
+                &mut self,
+                problem: &mut Problem<O>,
+                state: I,
+            ) -> Result<(I, Option<KV>), Error> {
+                self.linesearch.init(problem, state)
+            }
+
+            fn next_iter(
+                &mut self,
+                problem: &mut Problem<O>,
+                state: I,
+            ) -> Result<(I, Option<KV>), Error> {
+                self.linesearch.next_iter(problem, state)
+            }
+
+            fn terminate(&mut self, state: &I) -> TerminationStatus {
+                self.linesearch.terminate(state)
+            }
+        }
+
+        struct MyProblem {
+            counter: RefCell<usize>,
+        }
+
+        impl Operator for MyProblem {
+            type Param = Vec<f64>;
+            type Output = Vec<f64>;
+
+            fn apply(&self, _p: &Self::Param) -> Result<Self::Output, Error> {
+                if *self.counter.borrow() == 0 {
+                    let mut c = self.counter.borrow_mut();
+                    *c += 1;
+                    Ok(vec![0.5, 2.0])
+                } else {
+                    Ok(vec![0.3, 1.0])
+                }
+            }
+        }
+
+        impl Jacobian for MyProblem {
+            type Param = Vec<f64>;
+            type Jacobian = Vec<Vec<f64>>;
+
+            fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+            }
+        }
+
+        let linesearch = RecordingLineSearch {
+            linesearch: BacktrackingLineSearch::<Vec<f64>, Vec<f64>, _, f64>::new(
+                ArmijoCondition::new(0.2).unwrap(),
+            ),
+            costs: vec![],
+        };
+        let mut gnls = GaussNewtonLS::<_, f64>::new(linesearch);
+        let state: IterState<Vec<f64>, Vec<f64>, Vec<Vec<f64>>, (), f64> =
+            IterState::new().param(vec![1.0, 2.0]);
+        let mut problem = Problem::new(MyProblem {
+            counter: RefCell::new(0),
+        });
+        gnls.next_iter(&mut problem, state).unwrap();
+
+        // The cost at the starting point is recorded before the line search is run
+        assert_eq!(gnls.linesearch.costs.len(), 1);
+        assert_eq!(
+            gnls.linesearch.costs[0].to_ne_bytes(),
+            ArgminL2Norm::<f64>::l2_norm(&vec![0.5f64, 2.0]).to_ne_bytes()
+        );
+    }
 }
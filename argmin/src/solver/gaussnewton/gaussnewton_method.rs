@@ -9,7 +9,7 @@ use crate::core::{
     ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, State, TerminationReason,
     TerminationStatus, KV,
 };
-use argmin_math::{ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminSub, ArgminTranspose};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminSolve, ArgminSub, ArgminTranspose};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -114,12 +114,7 @@ where
     O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
     P: Clone + ArgminSub<P, P> + ArgminMul<F, P>,
     U: ArgminL2Norm<F>,
-    J: Clone
-        + ArgminTranspose<J>
-        + ArgminInv<J>
-        + ArgminDot<J, J>
-        + ArgminDot<U, P>
-        + ArgminDot<P, P>,
+    J: Clone + ArgminTranspose<J> + ArgminSolve<P, P> + ArgminDot<J, J> + ArgminDot<U, P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Gauss-Newton method";
@@ -143,8 +138,7 @@ where
             .clone()
             .t()
             .dot(&jacobian)
-            .inv()?
-            .dot(&jacobian.t().dot(&residuals));
+            .solve(&jacobian.t().dot(&residuals))?;
 
         let new_param = param.sub(&p.mul(&self.gamma));
 
@@ -163,11 +157,10 @@ where
 mod tests {
     use super::*;
     use crate::core::ArgminError;
-    #[cfg(feature = "_ndarrayl")]
-    use crate::core::Executor;
+    use crate::core::{DeserializeOwnedAlias, Executor, SerializeAlias};
     use crate::test_trait_impl;
-    #[cfg(feature = "_ndarrayl")]
     use approx::assert_relative_eq;
+    use argmin_math::ArgminGet;
 
     test_trait_impl!(gauss_newton_method, GaussNewton<f64>);
 
@@ -379,4 +372,100 @@ mod tests {
         assert_relative_eq!(param[0], -0.7, epsilon = f64::EPSILON.sqrt());
         assert_relative_eq!(param[1], 0.15, epsilon = f64::EPSILON.sqrt());
     }
+
+    /// Problem whose residuals change from `[0.5, 2]` in the first evaluation to `[0.3, 1]` in all
+    /// later ones, with a constant Jacobian, used to run the same test on several backends
+    struct ResidualProblem<P, J> {
+        residuals: (P, P),
+        jacobian: J,
+        counter: std::cell::RefCell<usize>,
+    }
+
+    impl<P: Clone, J> Operator for ResidualProblem<P, J> {
+        type Param = P;
+        type Output = P;
+
+        fn apply(&self, _p: &Self::Param) -> Result<Self::Output, Error> {
+            if *self.counter.borrow() == 0 {
+                let mut c = self.counter.borrow_mut();
+                *c += 1;
+                Ok(self.residuals.0.clone())
+            } else {
+                Ok(self.residuals.1.clone())
+            }
+        }
+    }
+
+    impl<P, J: Clone> Jacobian for ResidualProblem<P, J> {
+        type Param = P;
+        type Jacobian = J;
+
+        fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(self.jacobian.clone())
+        }
+    }
+
+    /// Runs two iterations (gamma = 1) from `[0, 0]` with the Jacobian `[[1, 2], [3, 4]]`, which
+    /// must end up at `[-1.4, 0.3]`.
+    fn check_solver_backend<P, J>(init: P, residuals: (P, P), jacobian: J)
+    where
+        P: Clone
+            + ArgminSub<P, P>
+            + ArgminMul<f64, P>
+            + ArgminL2Norm<f64>
+            + ArgminGet<usize, f64>
+            + SerializeAlias
+            + DeserializeOwnedAlias,
+        J: Clone
+            + ArgminTranspose<J>
+            + ArgminSolve<P, P>
+            + ArgminDot<J, J>
+            + ArgminDot<P, P>
+            + SerializeAlias
+            + DeserializeOwnedAlias,
+    {
+        use crate::core::State;
+
+        let problem = ResidualProblem {
+            residuals,
+            jacobian,
+            counter: std::cell::RefCell::new(0),
+        };
+        let param = Executor::new(problem, GaussNewton::<f64>::new())
+            .configure(|config| config.param(init).max_iters(2))
+            .run()
+            .unwrap()
+            .state
+            .get_best_param()
+            .unwrap()
+            .clone();
+        assert_relative_eq!(param.get_elem(0), -1.4, epsilon = f64::EPSILON.sqrt());
+        assert_relative_eq!(param.get_elem(1), 0.3, epsilon = f64::EPSILON.sqrt());
+    }
+
+    #[test]
+    fn test_solver_vec() {
+        check_solver_backend(
+            vec![0.0, 0.0],
+            (vec![0.5, 2.0], vec![0.3, 1.0]),
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]],
+        );
+    }
+
+    #[cfg(feature = "_sprsl")]
+    #[test]
+    fn test_solver_sprs() {
+        use sprs::TriMat;
+
+        let mut jacobian = TriMat::new((2, 2));
+        jacobian.add_triplet(0, 0, 1.0);
+        jacobian.add_triplet(0, 1, 2.0);
+        jacobian.add_triplet(1, 0, 3.0);
+        jacobian.add_triplet(1, 1, 4.0);
+        check_solver_backend(
+            vec![0.0, 0.0],
+            (vec![0.5, 2.0], vec![0.3, 1.0]),
+            jacobian.to_csr::<usize>(),
+        );
+    }
 }
@@ -20,6 +20,7 @@ use crate::core::{
     ArgminFloat, CostFunction, Error, IterState, Problem, Solver, TerminationReason,
     TerminationStatus, KV,
 };
+use crate::solver::linesearch::BracketedMinimizer;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -134,6 +135,15 @@ where
     }
 }
 
+impl<F> BracketedMinimizer<F> for GoldenSectionSearch<F>
+where
+    F: ArgminFloat,
+{
+    fn with_bracket(&self, lower: F, upper: F) -> Result<Self, Error> {
+        GoldenSectionSearch::new(lower, upper)?.with_tolerance(self.tolerance)
+    }
+}
+
 impl<O, F> Solver<O, IterState<F, (), (), (), F>> for GoldenSectionSearch<F>
 where
     O: CostFunction<Param = F, Output = F>,
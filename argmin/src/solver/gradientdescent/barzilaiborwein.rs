@@ -0,0 +1,683 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::util::nonmonotone::nonmonotone_backtracking;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledSub, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Step length choice of the [`BarzilaiBorwein`] method
+///
+/// With `s = x_k - x_{k-1}` and `y = g_k - g_{k-1}`:
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum BarzilaiBorweinStep {
+    /// BB1 (long) step length `<s, s> / <s, y>`
+    #[default]
+    Long,
+    /// BB2 (short) step length `<s, y> / <y, y>`
+    Short,
+    /// Alternate between BB1 (odd iterations) and BB2 (even iterations)
+    Alternating,
+}
+
+/// # Barzilai-Borwein method
+///
+/// Gradient method `x_{k+1} = x_k - alpha_k * g_k` where the step length `alpha_k` is computed
+/// from the previous step `s = x_k - x_{k-1}` and the change of the gradient
+/// `y = g_k - g_{k-1}` according to [`BarzilaiBorweinStep`], safeguarded by the
+/// [step bounds](`BarzilaiBorwein::with_step_bounds`). The first step length is `1 / ||g_0||`,
+/// which is also used whenever `<s, y> <= 0`. No line search is performed, therefore only a single
+/// cost function and gradient evaluation are needed per iteration.
+///
+/// The method is not monotone and is only guaranteed to converge for strictly convex quadratic
+/// functions. For general functions, a [nonmonotone safeguard](`BarzilaiBorwein::with_nonmonotone`)
+/// can be enabled, which backtracks along `-g_k` until
+///
+/// `f(x_k - lambda * alpha_k * g_k) <= max_{0 <= j < M} f(x_{k-j}) - gamma * lambda * alpha_k * ||g_k||^2`
+///
+/// holds (Raydan, 1997). Rejected values of `lambda` are reduced by safeguarded quadratic
+/// interpolation. The [sufficient decrease parameter](`BarzilaiBorwein::with_sufficient_decrease`)
+/// `gamma` defaults to `1e-4`.
+///
+/// The algorithm stops once the norm of the gradient falls below the
+/// [tolerance](`BarzilaiBorwein::with_tolerance_grad`).
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## References
+///
+/// Jonathan Barzilai and Jonathan M. Borwein (1988). Two-Point Step Size Gradient Methods.
+/// IMA Journal of Numerical Analysis 8(1), 141–148. <https://doi.org/10.1093/imanum/8.1.141>
+///
+/// Marcos Raydan (1997). The Barzilai and Borwein Gradient Method for the Large Scale
+/// Unconstrained Minimization Problem. SIAM Journal on Optimization 7(1), 26–33.
+/// <https://doi.org/10.1137/S1052623494266365>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BarzilaiBorwein<F> {
+    /// Step length choice
+    step_choice: BarzilaiBorweinStep,
+    /// Number of previous cost function values considered by the nonmonotone safeguard
+    memory: Option<usize>,
+    /// Sufficient decrease parameter
+    sufficient_decrease: F,
+    /// Lower bound on the step length
+    step_min: F,
+    /// Upper bound on the step length
+    step_max: F,
+    /// Tolerance on the norm of the gradient
+    tol_grad: F,
+    /// Current step length
+    step: F,
+    /// Previous cost function values
+    costs: VecDeque<F>,
+}
+
+impl<F: ArgminFloat> BarzilaiBorwein<F> {
+    /// Construct a new instance of [`BarzilaiBorwein`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::BarzilaiBorwein;
+    /// let bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new();
+    /// ```
+    pub fn new() -> Self {
+        BarzilaiBorwein {
+            step_choice: BarzilaiBorweinStep::default(),
+            memory: None,
+            sufficient_decrease: float!(1e-4),
+            step_min: float!(1e-30),
+            step_max: float!(1e30),
+            tol_grad: F::epsilon().sqrt(),
+            step: float!(1.0),
+            costs: VecDeque::new(),
+        }
+    }
+
+    /// Set the step length choice
+    ///
+    /// Defaults to [`BarzilaiBorweinStep::Long`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::{BarzilaiBorwein, BarzilaiBorweinStep};
+    /// let bb: BarzilaiBorwein<f64> =
+    ///     BarzilaiBorwein::new().with_step(BarzilaiBorweinStep::Alternating);
+    /// ```
+    #[must_use]
+    pub fn with_step(mut self, step_choice: BarzilaiBorweinStep) -> Self {
+        self.step_choice = step_choice;
+        self
+    }
+
+    /// Enable the nonmonotone safeguard with memory `M`
+    ///
+    /// Must be positive. With `M = 1`, the safeguard is a monotone backtracking line search.
+    /// Disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::BarzilaiBorwein;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new().with_nonmonotone(10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_nonmonotone(mut self, memory: usize) -> Result<Self, Error> {
+        if memory < 1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarzilaiBorwein`: memory must be >= 1."
+            ));
+        }
+        self.memory = Some(memory);
+        Ok(self)
+    }
+
+    /// Set sufficient decrease parameter `gamma` of the nonmonotone safeguard
+    ///
+    /// Must be in `(0, 1)` and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::BarzilaiBorwein;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new().with_sufficient_decrease(1e-3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sufficient_decrease(mut self, gamma: F) -> Result<Self, Error> {
+        if gamma <= float!(0.0) || gamma >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarzilaiBorwein`: sufficient decrease parameter must be in (0, 1)."
+            ));
+        }
+        self.sufficient_decrease = gamma;
+        Ok(self)
+    }
+
+    /// Set lower and upper bound on the step length
+    ///
+    /// Must satisfy `0 < min < max` and default to `1e-30` and `1e30`, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::BarzilaiBorwein;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new().with_step_bounds(1e-10, 1e10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_bounds(mut self, min: F, max: F) -> Result<Self, Error> {
+        if min <= float!(0.0) || max <= min {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarzilaiBorwein`: step bounds must satisfy 0 < min < max."
+            ));
+        }
+        self.step_min = min;
+        self.step_max = max;
+        Ok(self)
+    }
+
+    /// Set tolerance on the norm of the gradient
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::BarzilaiBorwein;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new().with_tolerance_grad(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_grad(mut self, tol_grad: F) -> Result<Self, Error> {
+        if tol_grad <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarzilaiBorwein`: gradient tolerance must be > 0."
+            ));
+        }
+        self.tol_grad = tol_grad;
+        Ok(self)
+    }
+
+    /// Clamps the step length to the step bounds
+    fn safeguard(&self, step: F) -> F {
+        if step.is_nan() {
+            self.step_max
+        } else {
+            step.max(self.step_min).min(self.step_max)
+        }
+    }
+}
+
+impl<F: ArgminFloat> Default for BarzilaiBorwein<F> {
+    fn default() -> Self {
+        BarzilaiBorwein::new()
+    }
+}
+
+impl<O, P, G, F> Solver<O, IterState<P, G, (), (), F>> for BarzilaiBorwein<F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + ArgminSub<P, P> + ArgminScaledSub<G, F, P> + ArgminDot<P, F> + ArgminDot<G, F>,
+    G: ArgminSub<G, G> + ArgminDot<G, F> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Barzilai-Borwein";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x0 = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`BarzilaiBorwein` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&x0)?
+        } else {
+            cost
+        };
+        let grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&x0))?;
+        self.step = self.safeguard(float!(1.0) / grad.l2_norm());
+        self.costs.clear();
+        self.costs.push_back(cost);
+        Ok((state.param(x0).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`BarzilaiBorwein`: Parameter vector in `state` not set"
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`BarzilaiBorwein`: Gradient in `state` not set"
+        ))?;
+        let cost = state.get_cost();
+
+        let (x_new, cost_new, lambda) = if let Some(memory) = self.memory {
+            // Nonmonotone safeguard with safeguarded quadratic interpolation
+            let slope = -self.step * grad.dot(&grad);
+            let cost_max = self.costs.iter().fold(cost, |acc, c| acc.max(*c));
+            let step = self.step;
+            let (x_new, cost_new, lambda) = nonmonotone_backtracking(
+                "BarzilaiBorwein",
+                cost,
+                cost_max,
+                slope,
+                self.sufficient_decrease,
+                |lambda| {
+                    let x_new = x.scaled_sub(&(lambda * step), &grad);
+                    let cost_new = problem.cost(&x_new)?;
+                    Ok((x_new, cost_new))
+                },
+            )?;
+            self.costs.push_back(cost_new);
+            if self.costs.len() > memory {
+                self.costs.pop_front();
+            }
+            (x_new, cost_new, lambda)
+        } else {
+            let x_new = x.scaled_sub(&self.step, &grad);
+            let cost_new = problem.cost(&x_new)?;
+            (x_new, cost_new, float!(1.0))
+        };
+        let step_length = lambda * self.step;
+
+        let grad_new = problem.gradient(&x_new)?;
+
+        // Barzilai-Borwein step length
+        let s = x_new.sub(&x);
+        let y = grad_new.sub(&grad);
+        let sty: F = s.dot(&y);
+        let long = match self.step_choice {
+            BarzilaiBorweinStep::Long => true,
+            BarzilaiBorweinStep::Short => false,
+            BarzilaiBorweinStep::Alternating => state.get_iter() & 1 == 0,
+        };
+        self.step = if sty <= float!(0.0) {
+            self.safeguard(float!(1.0) / grad_new.l2_norm())
+        } else if long {
+            self.safeguard(s.dot(&s) / sty)
+        } else {
+            self.safeguard(sty / y.dot(&y))
+        };
+
+        Ok((
+            state.param(x_new).cost(cost_new).gradient(grad_new),
+            Some(kv!("step_length" => step_length; "bb_step" => self.step;)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        match state.get_gradient() {
+            Some(grad) if grad.l2_norm() <= self.tol_grad => {
+                TerminationStatus::Terminated(TerminationReason::SolverConverged)
+            }
+            _ => TerminationStatus::NotTerminated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(barzilai_borwein, BarzilaiBorwein<f64>);
+
+    /// `f(x) = 0.5 * sum_i i * (x_i - 1)^2` for `i = 1, ..., 20`
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter()
+                .enumerate()
+                .map(|(i, x)| 0.5 * (i + 1) as f64 * (x - 1.0).powi(2))
+                .sum())
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(p.iter()
+                .enumerate()
+                .map(|(i, x)| (i + 1) as f64 * (x - 1.0))
+                .collect())
+        }
+    }
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    /// Cost function which is NaN if any component is negative
+    struct NanIfNegative {}
+
+    impl CostFunction for NanIfNegative {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            if p.iter().all(|x| *x >= 0.0) {
+                Ok(p.iter().sum())
+            } else {
+                Ok(f64::NAN)
+            }
+        }
+    }
+
+    impl Gradient for NanIfNegative {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![1.0; p.len()])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new();
+        let BarzilaiBorwein {
+            step_choice,
+            memory,
+            sufficient_decrease,
+            step_min,
+            step_max,
+            tol_grad,
+            step,
+            costs,
+        } = bb;
+        assert_eq!(step_choice, BarzilaiBorweinStep::Long);
+        assert!(memory.is_none());
+        assert_eq!(sufficient_decrease.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(step_min.to_ne_bytes(), 1e-30f64.to_ne_bytes());
+        assert_eq!(step_max.to_ne_bytes(), 1e30f64.to_ne_bytes());
+        assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(costs.is_empty());
+    }
+
+    #[test]
+    fn test_with_parameters() {
+        let bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new()
+            .with_step(BarzilaiBorweinStep::Short)
+            .with_nonmonotone(5)
+            .unwrap()
+            .with_sufficient_decrease(1e-3)
+            .unwrap()
+            .with_step_bounds(1e-10, 1e10)
+            .unwrap()
+            .with_tolerance_grad(1e-6)
+            .unwrap();
+        assert_eq!(bb.step_choice, BarzilaiBorweinStep::Short);
+        assert_eq!(bb.memory, Some(5));
+        assert_eq!(bb.sufficient_decrease.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+        assert_eq!(bb.step_min.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+        assert_eq!(bb.step_max.to_ne_bytes(), 1e10f64.to_ne_bytes());
+        assert_eq!(bb.tol_grad.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let bb = BarzilaiBorwein::<f64>::new;
+        assert_error!(
+            bb().with_nonmonotone(0),
+            ArgminError,
+            "Invalid parameter: \"`BarzilaiBorwein`: memory must be >= 1.\""
+        );
+        for value in [0.0, -1.0, 1.0, 2.0] {
+            assert_error!(
+                bb().with_sufficient_decrease(value),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`BarzilaiBorwein`: sufficient decrease parameter ",
+                    "must be in (0, 1).\""
+                )
+            );
+        }
+        for (min, max) in [(0.0, 1.0), (-1.0, 1.0), (1.0, 1.0), (2.0, 1.0)] {
+            assert_error!(
+                bb().with_step_bounds(min, max),
+                ArgminError,
+                "Invalid parameter: \"`BarzilaiBorwein`: step bounds must satisfy 0 < min < max.\""
+            );
+        }
+        for value in [0.0, -1.0] {
+            assert_error!(
+                bb().with_tolerance_grad(value),
+                ArgminError,
+                "Invalid parameter: \"`BarzilaiBorwein`: gradient tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let mut bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new();
+        let res = bb.init(&mut Problem::new(Quadratic {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`BarzilaiBorwein` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_step_lengths() {
+        // For `f(x) = 0.5 * sum_i i * (x_i - 1)^2` with `x_0 = 0` and `g_0 = [-1, -2]`, the first
+        // step is `s = -g_0 / ||g_0||` and `y = [1, 2] * s`.
+        let norm = 5.0f64.sqrt();
+        let s = [1.0 / norm, 2.0 / norm];
+        let y = [s[0], 2.0 * s[1]];
+        let sts = s[0] * s[0] + s[1] * s[1];
+        let sty = s[0] * y[0] + s[1] * y[1];
+        let yty = y[0] * y[0] + y[1] * y[1];
+        for (step_choice, expected) in [
+            (BarzilaiBorweinStep::Long, sts / sty),
+            (BarzilaiBorweinStep::Short, sty / yty),
+            (BarzilaiBorweinStep::Alternating, sts / sty),
+        ] {
+            let mut bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new().with_step(step_choice);
+            let mut problem = Problem::new(Quadratic {});
+            let state = IterState::new().param(vec![0.0, 0.0]);
+            let (state, _) = bb.init(&mut problem, state).unwrap();
+            assert_relative_eq!(bb.step, 1.0 / norm);
+            let (state, kv) = bb.next_iter(&mut problem, state).unwrap();
+            assert_relative_eq!(bb.step, expected);
+            let kv = kv.unwrap();
+            assert_relative_eq!(
+                kv.get("step_length").unwrap().get_float().unwrap(),
+                1.0 / norm
+            );
+            assert_relative_eq!(kv.get("bb_step").unwrap().get_float().unwrap(), expected);
+            let x = state.get_param().unwrap();
+            assert_relative_eq!(x[0], s[0]);
+            assert_relative_eq!(x[1], s[1]);
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        for step_choice in [
+            BarzilaiBorweinStep::Long,
+            BarzilaiBorweinStep::Short,
+            BarzilaiBorweinStep::Alternating,
+        ] {
+            let solver = BarzilaiBorwein::new().with_step(step_choice);
+            let res = Executor::new(Quadratic {}, solver)
+                .configure(|state| state.param(vec![0.0; 20]).max_iters(1000))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            for xi in res.state.get_best_param().unwrap() {
+                assert_relative_eq!(*xi, 1.0, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nonmonotone() {
+        for step_choice in [
+            BarzilaiBorweinStep::Long,
+            BarzilaiBorweinStep::Short,
+            BarzilaiBorweinStep::Alternating,
+        ] {
+            let solver = BarzilaiBorwein::new()
+                .with_step(step_choice)
+                .with_nonmonotone(10)
+                .unwrap();
+            let res = Executor::new(Rosenbrock {}, solver)
+                .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10000))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            assert_relative_eq!(x[0], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(x[1], 1.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_memory() {
+        let mut bb: BarzilaiBorwein<f64> = BarzilaiBorwein::new().with_nonmonotone(2).unwrap();
+        let mut problem = Problem::new(Rosenbrock {});
+        let state = IterState::new().param(vec![-1.2, 1.0]);
+        let (mut state, _) = bb.init(&mut problem, state).unwrap();
+        assert_eq!(bb.costs.len(), 1);
+        for expected in [2, 2, 2] {
+            (state, _) = bb.next_iter(&mut problem, state).unwrap();
+            assert_eq!(bb.costs.len(), expected);
+            assert_relative_eq!(*bb.costs.back().unwrap(), state.get_cost());
+        }
+    }
+
+    #[test]
+    fn test_line_search_fails() {
+        let res = Executor::new(
+            NanIfNegative {},
+            BarzilaiBorwein::new().with_nonmonotone(5).unwrap(),
+        )
+        .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+        .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`BarzilaiBorwein`: Line search did not find a ",
+                "sufficient decrease.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_fewer_cost_evaluations_than_steepest_descent() {
+        let res = Executor::new(Quadratic {}, BarzilaiBorwein::new())
+            .configure(|state| {
+                state
+                    .param(vec![0.0; 20])
+                    .max_iters(1000)
+                    .target_cost(1e-10)
+            })
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::TargetCostReached)
+        );
+        let bb_counts = res.state.get_func_counts()["cost_count"];
+
+        let linesearch = MoreThuenteLineSearch::new();
+        let res = Executor::new(Quadratic {}, SteepestDescent::new(linesearch))
+            .configure(|state| {
+                state
+                    .param(vec![0.0; 20])
+                    .max_iters(1000)
+                    .target_cost(1e-10)
+            })
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::TargetCostReached)
+        );
+        assert!(3 * bb_counts < res.state.get_func_counts()["cost_count"]);
+    }
+}
@@ -9,11 +9,15 @@
 //!
 //! [`SteepestDescent`]
 //!
+//! [`BarzilaiBorwein`]
+//!
 //! ## Reference
 //!
 //! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 //! Springer. ISBN 0-387-30303-0.
 
+mod barzilaiborwein;
 mod steepestdescent;
 
+pub use self::barzilaiborwein::*;
 pub use self::steepestdescent::*;
@@ -7,7 +7,7 @@
 
 use crate::core::{
     ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
-    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, KV,
+    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, State, KV,
 };
 use argmin_math::ArgminMul;
 #[cfg(feature = "serde1")]
@@ -75,6 +75,7 @@ where
         let new_cost = problem.cost(&param_new)?;
         let new_grad = problem.gradient(&param_new)?;
 
+        self.linesearch.record_cost(new_cost);
         self.linesearch
             .search_direction(new_grad.mul(&(float!(-1.0))));
 
@@ -108,7 +109,7 @@ where
                         ))?,
                 )
                 .cost(linesearch_state.get_cost()),
-            None,
+            Some(kv!("linesearch_iters" => linesearch_state.get_iter();)),
         ))
     }
 }
@@ -117,7 +118,7 @@ where
 mod tests {
     use super::*;
     use crate::core::test_utils::TestProblem;
-    use crate::core::{ArgminError, State};
+    use crate::core::ArgminError;
     use crate::solver::linesearch::{
         condition::ArmijoCondition, BacktrackingLineSearch, MoreThuenteLineSearch,
     };
@@ -185,7 +186,7 @@ mod tests {
             )
             .unwrap();
 
-        assert!(kv.is_none());
+        assert_eq!(kv.unwrap(), kv!("linesearch_iters" => 3u64;));
 
         assert_relative_eq!(
             state.param.as_ref().unwrap()[0],
@@ -0,0 +1,434 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Operator, Problem, Solver, State, TerminationReason, KV,
+};
+use crate::solver::linearleastsquares::sym_ortho;
+use crate::solver::preconditioner::{IdentityPreconditioner, Preconditioner};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Restarted GMRES
+///
+/// Solves systems of linear equations `A * x = b` for general (nonsymmetric) nonsingular matrices
+/// `A` by minimizing the norm of the residual `||b - A * x||` over a Krylov subspace generated by
+/// the Arnoldi process. Since the memory requirements and the computational cost per iteration
+/// grow with the dimension of the Krylov subspace, the method is restarted from the current
+/// iterate after every [`restart`](`GMRES::with_restart`) iterations (GMRES(m)).
+///
+/// Every iteration of the solver performs one step of the Arnoldi process. An optional
+/// preconditioner can be provided via [`with_preconditioner`](`GMRES::with_preconditioner`) which
+/// is applied from the right, i.e. `A * M^-1 * u = b` is solved for `u` and `x = M^-1 * u`.
+/// Therefore the residual norm is not affected by the preconditioner.
+///
+/// The iteration stops once `||b - A * x|| <= tol * ||b||`. The cost as well as the entry
+/// `residual_norm` of the key-value store contain the norm of the residual in each iteration,
+/// which makes the residual history accessible to observers.
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`].
+///
+/// ## Reference
+///
+/// Youcef Saad and Martin H. Schultz (1986). GMRES: A Generalized Minimal Residual Algorithm for
+/// Solving Nonsymmetric Linear Systems. SIAM Journal on Scientific and Statistical Computing 7(3),
+/// 856–869. <https://doi.org/10.1137/0907058>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct GMRES<P, M, F> {
+    /// Right hand side `b`
+    b: P,
+    /// Preconditioner
+    preconditioner: M,
+    /// Number of iterations after which the method is restarted
+    restart: usize,
+    /// Tolerance
+    tol: F,
+    /// Parameter vector at the beginning of the current cycle
+    x0: Option<P>,
+    /// Orthonormal basis of the Krylov subspace
+    v: Vec<P>,
+    /// Columns of the triangular factor of the Hessenberg matrix
+    h: Vec<Vec<F>>,
+    /// Givens rotations
+    cs: Vec<F>,
+    sn: Vec<F>,
+    /// Rotated right hand side of the least squares problem
+    g: Vec<F>,
+    /// Norm of `b`
+    norm_b: F,
+}
+
+impl<P, F: ArgminFloat> GMRES<P, IdentityPreconditioner, F> {
+    /// Construct a new instance of [`GMRES`]
+    ///
+    /// Takes `b`, the right hand side of `A * x = b` as input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::krylov::GMRES;
+    /// # let b = vec![1.0f64, 1.0];
+    /// let gmres: GMRES<_, _, f64> = GMRES::new(b);
+    /// ```
+    pub fn new(b: P) -> Self {
+        GMRES {
+            b,
+            preconditioner: IdentityPreconditioner,
+            restart: 30,
+            tol: F::epsilon().sqrt(),
+            x0: None,
+            v: vec![],
+            h: vec![],
+            cs: vec![],
+            sn: vec![],
+            g: vec![],
+            norm_b: float!(0.0),
+        }
+    }
+}
+
+impl<P, M, F: ArgminFloat> GMRES<P, M, F> {
+    /// Set preconditioner
+    ///
+    /// The preconditioner is applied from the right. Defaults to [`IdentityPreconditioner`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::krylov::GMRES;
+    /// # use argmin::solver::preconditioner::IdentityPreconditioner;
+    /// # let b = vec![1.0f64, 1.0];
+    /// # let preconditioner = IdentityPreconditioner;
+    /// let gmres: GMRES<_, _, f64> = GMRES::new(b).with_preconditioner(preconditioner);
+    /// ```
+    #[must_use]
+    pub fn with_preconditioner<M2>(self, preconditioner: M2) -> GMRES<P, M2, F> {
+        GMRES {
+            b: self.b,
+            preconditioner,
+            restart: self.restart,
+            tol: self.tol,
+            x0: self.x0,
+            v: self.v,
+            h: self.h,
+            cs: self.cs,
+            sn: self.sn,
+            g: self.g,
+            norm_b: self.norm_b,
+        }
+    }
+
+    /// Set number of iterations after which the method is restarted
+    ///
+    /// Must be positive and defaults to `30`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::krylov::GMRES;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0];
+    /// let gmres: GMRES<_, _, f64> = GMRES::new(b).with_restart(50)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_restart(mut self, restart: usize) -> Result<Self, Error> {
+        if restart == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GMRES`: restart must be > 0."
+            ));
+        }
+        self.restart = restart;
+        Ok(self)
+    }
+
+    /// Set tolerance
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::krylov::GMRES;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0];
+    /// let gmres: GMRES<_, _, f64> = GMRES::new(b).with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GMRES`: tol must be positive."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Starts a new cycle of the Arnoldi process at `x` with residual `r`
+    fn start_cycle(&mut self, x: &P, r: P, norm_r: F)
+    where
+        P: Clone + ArgminMul<F, P>,
+    {
+        self.x0 = Some(x.clone());
+        self.v = vec![r.mul(&(float!(1.0) / norm_r))];
+        self.h = vec![];
+        self.cs = vec![];
+        self.sn = vec![];
+        self.g = vec![norm_r];
+    }
+}
+
+impl<O, P, M, F> Solver<O, IterState<P, (), (), (), F>> for GMRES<P, M, F>
+where
+    O: Operator<Param = P, Output = P>,
+    P: Clone
+        + ArgminSub<P, P>
+        + ArgminDot<P, F>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminMul<F, P>
+        + ArgminL2Norm<F>,
+    M: Preconditioner<P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "GMRES";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`GMRES` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        self.norm_b = self.b.l2_norm();
+        let r = self.b.sub(&problem.apply(x0)?);
+        let norm_r = r.l2_norm();
+
+        if norm_r <= self.tol * self.norm_b || norm_r == float!(0.0) {
+            // `x0` already solves the system
+            return Ok((
+                state
+                    .cost(norm_r)
+                    .terminate_with(TerminationReason::SolverConverged),
+                Some(kv!("residual_norm" => norm_r;)),
+            ));
+        }
+        self.start_cycle(x0, r, norm_r);
+        Ok((state.cost(norm_r), Some(kv!("residual_norm" => norm_r;))))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = self.x0.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`GMRES`: Field `x0` not set"
+        ))?;
+        let j = self.g.len() - 1;
+        let zero = float!(0.0);
+
+        // Arnoldi step with modified Gram-Schmidt orthogonalization
+        let mut w = problem.apply(&self.preconditioner.apply_inverse(&self.v[j])?)?;
+        let mut h = Vec::with_capacity(j + 2);
+        for vi in self.v.iter() {
+            let hi = w.dot(vi);
+            w = w.scaled_add(&(-hi), vi);
+            h.push(hi);
+        }
+        let h_next = w.l2_norm();
+
+        // Apply previous Givens rotations to the new column and eliminate `h_next`
+        for (i, (&c, &s)) in self.cs.iter().zip(self.sn.iter()).enumerate() {
+            let tmp = c * h[i] + s * h[i + 1];
+            h[i + 1] = -s * h[i] + c * h[i + 1];
+            h[i] = tmp;
+        }
+        let (c, s, r) = sym_ortho(h[j], h_next);
+        h[j] = r;
+        self.cs.push(c);
+        self.sn.push(s);
+        let gj = self.g[j];
+        self.g[j] = c * gj;
+        self.g.push(-s * gj);
+        self.h.push(h);
+        let mut norm_r = self.g[j + 1].abs();
+
+        // Solve the triangular system and update `x`
+        let mut y = vec![zero; j + 1];
+        for i in (0..=j).rev() {
+            let sum = ((i + 1)..=j).fold(self.g[i], |acc, l| acc - self.h[l][i] * y[l]);
+            y[i] = sum / self.h[i][i];
+        }
+        let u = self
+            .v
+            .iter()
+            .zip(y.iter())
+            .skip(1)
+            .fold(self.v[0].mul(&y[0]), |acc, (vi, yi)| acc.scaled_add(yi, vi));
+        let x = x0.scaled_add(&float!(1.0), &self.preconditioner.apply_inverse(&u)?);
+
+        let mut converged = h_next == zero || norm_r <= self.tol * self.norm_b;
+        if !converged && j + 1 >= self.restart {
+            // Restart with the true residual
+            let r = self.b.sub(&problem.apply(&x)?);
+            norm_r = r.l2_norm();
+            converged = norm_r <= self.tol * self.norm_b || norm_r == zero;
+            if !converged {
+                self.start_cycle(&x, r, norm_r);
+            }
+        } else {
+            if !converged {
+                self.v.push(w.mul(&(float!(1.0) / h_next)));
+            }
+            self.x0 = Some(x0);
+        }
+
+        state = state.param(x).cost(norm_r);
+        if converged {
+            state = state.terminate_with(TerminationReason::SolverConverged);
+        }
+
+        Ok((state, Some(kv!("residual_norm" => norm_r;))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{residual_norm, Diagonal, Matrix, ResidualHistory};
+    use super::*;
+    use crate::core::observers::ObserverMode;
+    use crate::core::{ArgminError, Executor};
+    use std::sync::{Arc, Mutex};
+
+    test_trait_impl!(gmres, GMRES<Vec<f64>, IdentityPreconditioner, f64>);
+
+    #[test]
+    fn test_new() {
+        let gmres: GMRES<_, _, f64> = GMRES::new(vec![1.0f64, 2.0]);
+        assert_eq!(gmres.b, vec![1.0, 2.0]);
+        assert_eq!(gmres.preconditioner, IdentityPreconditioner);
+        assert_eq!(gmres.restart, 30);
+        assert_eq!(gmres.tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(gmres.x0.is_none());
+        assert!(gmres.v.is_empty());
+        assert!(gmres.h.is_empty());
+        assert!(gmres.g.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_error!(
+            GMRES::<_, _, f64>::new(vec![1.0f64, 2.0]).with_restart(0),
+            ArgminError,
+            "Invalid parameter: \"`GMRES`: restart must be > 0.\""
+        );
+        assert_error!(
+            GMRES::<_, _, f64>::new(vec![1.0f64, 2.0]).with_tolerance(0.0),
+            ArgminError,
+            "Invalid parameter: \"`GMRES`: tol must be positive.\""
+        );
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let a = Matrix::nonsymmetric();
+        let res = GMRES::new(vec![1.0f64; 4]).init(&mut Problem::new(a), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`GMRES` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let b = vec![1.0, -2.0, 0.5, 3.0];
+        for a in [Matrix::nonsymmetric(), Matrix::symmetric_indefinite()] {
+            for x0 in [vec![0.0; 4], vec![1.0, -2.0, 0.5, 3.0]] {
+                let history = Arc::new(Mutex::new(vec![]));
+                let solver = GMRES::new(b.clone()).with_tolerance(1e-12).unwrap();
+                let res = Executor::new(a.clone(), solver)
+                    .configure(|state| state.param(x0).max_iters(10))
+                    .add_observer(ResidualHistory(history.clone()), ObserverMode::Always)
+                    .run()
+                    .unwrap();
+                assert_eq!(
+                    res.state.get_termination_reason(),
+                    Some(&TerminationReason::SolverConverged)
+                );
+                // Converges in at most `n` iterations
+                assert!(res.state.get_iter() <= 4);
+                assert!(residual_norm(&a, &b, res.state.get_param().unwrap()) < 1e-9);
+                // The residual norm decreases monotonically
+                let history = history.lock().unwrap();
+                assert!(history.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_solver_restarted() {
+        let a = Matrix::nonsymmetric();
+        let b = vec![1.0, -2.0, 0.5, 3.0];
+        let solver = GMRES::new(b.clone())
+            .with_restart(2)
+            .unwrap()
+            .with_tolerance(1e-12)
+            .unwrap();
+        let res = Executor::new(a.clone(), solver)
+            .configure(|state| state.param(vec![0.0; 4]).max_iters(200))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert!(res.state.get_iter() > 4);
+        assert!(residual_norm(&a, &b, res.state.get_param().unwrap()) < 1e-9);
+    }
+
+    #[test]
+    fn test_solver_preconditioned() {
+        let a = Matrix::nonsymmetric();
+        let b = vec![1.0, -2.0, 0.5, 3.0];
+        let solver = GMRES::new(b.clone())
+            .with_preconditioner(Diagonal(vec![4.0, 3.0, 5.0, 2.0]))
+            .with_tolerance(1e-12)
+            .unwrap();
+        let res = Executor::new(a.clone(), solver)
+            .configure(|state| state.param(vec![0.0; 4]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert!(residual_norm(&a, &b, res.state.get_param().unwrap()) < 1e-9);
+    }
+}
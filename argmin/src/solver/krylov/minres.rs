@@ -0,0 +1,494 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Operator, Problem, Solver, State, TerminationReason, KV,
+};
+use crate::solver::preconditioner::{IdentityPreconditioner, Preconditioner};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub, ArgminZeroLike};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # MINRES
+///
+/// Solves systems of linear equations `A * x = b` where `A` is symmetric, but possibly indefinite,
+/// by minimizing the norm of the residual `||b - A * x||` over a Krylov subspace generated by the
+/// Lanczos process. Singular matrices are supported as long as the system is compatible, i.e. `b`
+/// lies in the range of `A`.
+///
+/// An optional symmetric positive definite preconditioner can be provided via
+/// [`with_preconditioner`](`MINRES::with_preconditioner`). In this case, all residual norms are
+/// measured in the norm induced by `M^-1`.
+///
+/// The iteration stops once either `||r|| <= tol * (||A|| * ||x|| + ||r_0||)` or
+/// `||A * r|| <= tol * ||A|| * ||r||`, where `||A||` is an estimate of the Frobenius norm of `A`
+/// and `r_0` is the initial residual. The cost as well as the entry `residual_norm` of the
+/// key-value store contain the (estimated) norm of the residual in each iteration, which makes
+/// the residual history accessible to observers. The key-value store additionally contains
+/// estimates of `||A||` (`norm_a`) and of the condition number of `A` (`cond_a`).
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`].
+///
+/// ## Reference
+///
+/// Christopher C. Paige and Michael A. Saunders (1975). Solution of Sparse Indefinite Systems of
+/// Linear Equations. SIAM Journal on Numerical Analysis 12(4), 617–629.
+/// <https://doi.org/10.1137/0712047>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct MINRES<P, M, F> {
+    /// Right hand side `b`
+    b: P,
+    /// Preconditioner
+    preconditioner: M,
+    /// Tolerance
+    tol: F,
+    /// Previous and current Lanczos vectors (unscaled)
+    r1: Option<P>,
+    r2: Option<P>,
+    /// Preconditioned current Lanczos vector
+    y: Option<P>,
+    /// Search directions
+    w: Option<P>,
+    w2: Option<P>,
+    /// Variables of the Lanczos process
+    beta: F,
+    oldb: F,
+    /// Variables of the QR factorization of the tridiagonal matrix
+    dbar: F,
+    epsln: F,
+    phibar: F,
+    cs: F,
+    sn: F,
+    /// Variables for the estimation of `||A||` and `cond(A)`
+    tnorm2: F,
+    gmax: F,
+    gmin: F,
+    /// Norm of the initial residual
+    beta1: F,
+}
+
+impl<P, F: ArgminFloat> MINRES<P, IdentityPreconditioner, F> {
+    /// Construct a new instance of [`MINRES`]
+    ///
+    /// Takes `b`, the right hand side of `A * x = b` as input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::krylov::MINRES;
+    /// # let b = vec![1.0f64, 1.0];
+    /// let minres: MINRES<_, _, f64> = MINRES::new(b);
+    /// ```
+    pub fn new(b: P) -> Self {
+        MINRES {
+            b,
+            preconditioner: IdentityPreconditioner,
+            tol: F::epsilon().sqrt(),
+            r1: None,
+            r2: None,
+            y: None,
+            w: None,
+            w2: None,
+            beta: float!(0.0),
+            oldb: float!(0.0),
+            dbar: float!(0.0),
+            epsln: float!(0.0),
+            phibar: float!(0.0),
+            cs: float!(-1.0),
+            sn: float!(0.0),
+            tnorm2: float!(0.0),
+            gmax: float!(0.0),
+            gmin: F::infinity(),
+            beta1: float!(0.0),
+        }
+    }
+}
+
+impl<P, M, F: ArgminFloat> MINRES<P, M, F> {
+    /// Set preconditioner
+    ///
+    /// The preconditioner must be symmetric and positive definite. Defaults to
+    /// [`IdentityPreconditioner`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::krylov::MINRES;
+    /// # use argmin::solver::preconditioner::IdentityPreconditioner;
+    /// # let b = vec![1.0f64, 1.0];
+    /// # let preconditioner = IdentityPreconditioner;
+    /// let minres: MINRES<_, _, f64> = MINRES::new(b).with_preconditioner(preconditioner);
+    /// ```
+    #[must_use]
+    pub fn with_preconditioner<M2>(self, preconditioner: M2) -> MINRES<P, M2, F> {
+        MINRES {
+            b: self.b,
+            preconditioner,
+            tol: self.tol,
+            r1: self.r1,
+            r2: self.r2,
+            y: self.y,
+            w: self.w,
+            w2: self.w2,
+            beta: self.beta,
+            oldb: self.oldb,
+            dbar: self.dbar,
+            epsln: self.epsln,
+            phibar: self.phibar,
+            cs: self.cs,
+            sn: self.sn,
+            tnorm2: self.tnorm2,
+            gmax: self.gmax,
+            gmin: self.gmin,
+            beta1: self.beta1,
+        }
+    }
+
+    /// Set tolerance
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::krylov::MINRES;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0];
+    /// let minres: MINRES<_, _, f64> = MINRES::new(b).with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MINRES`: tol must be positive."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Computes `sqrt(r^T M^-1 r)`
+    fn preconditioned_norm(r: &P, y: &P) -> Result<F, Error>
+    where
+        P: ArgminDot<P, F>,
+    {
+        let rty = r.dot(y);
+        if rty < float!(0.0) {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`MINRES`: Preconditioner is not positive definite."
+            ));
+        }
+        Ok(rty.sqrt())
+    }
+}
+
+impl<O, P, M, F> Solver<O, IterState<P, (), (), (), F>> for MINRES<P, M, F>
+where
+    O: Operator<Param = P, Output = P>,
+    P: Clone
+        + ArgminSub<P, P>
+        + ArgminDot<P, F>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminMul<F, P>
+        + ArgminL2Norm<F>
+        + ArgminZeroLike,
+    M: Preconditioner<P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "MINRES";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`MINRES` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let r1 = self.b.sub(&problem.apply(x0)?);
+        let y = self.preconditioner.apply_inverse(&r1)?;
+        let beta1 = Self::preconditioned_norm(&r1, &y)?;
+
+        self.w = Some(x0.zero_like());
+        self.w2 = Some(x0.zero_like());
+        self.r2 = Some(r1.clone());
+        self.r1 = Some(r1);
+        self.y = Some(y);
+        self.beta1 = beta1;
+        self.beta = beta1;
+        self.oldb = float!(0.0);
+        self.dbar = float!(0.0);
+        self.epsln = float!(0.0);
+        self.phibar = beta1;
+        self.cs = float!(-1.0);
+        self.sn = float!(0.0);
+        self.tnorm2 = float!(0.0);
+        self.gmax = float!(0.0);
+        self.gmin = F::infinity();
+
+        let state = state.cost(beta1);
+        if beta1 == float!(0.0) {
+            // `x0` already solves the system
+            return Ok((
+                state.terminate_with(TerminationReason::SolverConverged),
+                Some(kv!("residual_norm" => beta1;)),
+            ));
+        }
+        Ok((state, Some(kv!("residual_norm" => beta1;))))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let r1 = self.r1.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MINRES`: Field `r1` not set"
+        ))?;
+        let r2 = self.r2.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MINRES`: Field `r2` not set"
+        ))?;
+        let y = self.y.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MINRES`: Field `y` not set"
+        ))?;
+        let w = self.w.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MINRES`: Field `w` not set"
+        ))?;
+        let w1 = self.w2.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MINRES`: Field `w2` not set"
+        ))?;
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MINRES`: Parameter vector in `state` not set"
+        ))?;
+        let zero = float!(0.0);
+        let one = float!(1.0);
+
+        // Lanczos step
+        let v = y.mul(&(one / self.beta));
+        let mut y = problem.apply(&v)?;
+        if self.oldb > zero {
+            y = y.scaled_add(&(-self.beta / self.oldb), &r1);
+        }
+        let alpha = v.dot(&y);
+        let y = y.scaled_add(&(-alpha / self.beta), &r2);
+        let r1 = r2;
+        let r2 = y;
+        let y = self.preconditioner.apply_inverse(&r2)?;
+        self.oldb = self.beta;
+        self.beta = Self::preconditioned_norm(&r2, &y)?;
+        self.tnorm2 = self.tnorm2 + alpha.powi(2) + self.oldb.powi(2) + self.beta.powi(2);
+
+        // Apply previous rotation and compute the next one
+        let oldeps = self.epsln;
+        let delta = self.cs * self.dbar + self.sn * alpha;
+        let gbar = self.sn * self.dbar - self.cs * alpha;
+        self.epsln = self.sn * self.beta;
+        self.dbar = -self.cs * self.beta;
+        let root = gbar.hypot(self.dbar);
+        let norm_ar = self.phibar * root;
+        let gamma = gbar.hypot(self.beta).max(F::epsilon());
+        self.cs = gbar / gamma;
+        self.sn = self.beta / gamma;
+        let phi = self.cs * self.phibar;
+        self.phibar = self.sn * self.phibar;
+
+        // Update `x` and the search directions
+        let w2 = w;
+        let w = v
+            .scaled_add(&(-oldeps), &w1)
+            .scaled_add(&(-delta), &w2)
+            .mul(&(one / gamma));
+        let x = x.scaled_add(&phi, &w);
+
+        self.gmax = self.gmax.max(gamma);
+        self.gmin = self.gmin.min(gamma);
+        let norm_a = self.tnorm2.sqrt();
+        let cond_a = self.gmax / self.gmin;
+        let norm_r = self.phibar;
+        let norm_x = x.l2_norm();
+
+        self.r1 = Some(r1);
+        self.r2 = Some(r2);
+        self.y = Some(y);
+        self.w = Some(w);
+        self.w2 = Some(w2);
+
+        let test1 = norm_r / (norm_a * norm_x + self.beta1);
+        let test2 = if norm_a * norm_r > zero {
+            norm_ar / (norm_a * norm_r)
+        } else {
+            F::infinity()
+        };
+        let mut state = state.param(x).cost(norm_r);
+        if test1 <= self.tol || test2 <= self.tol {
+            state = state.terminate_with(TerminationReason::SolverConverged);
+        }
+
+        Ok((
+            state,
+            Some(kv!(
+                "residual_norm" => norm_r;
+                "norm_a" => norm_a;
+                "cond_a" => cond_a;
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{residual_norm, Diagonal, Matrix, ResidualHistory};
+    use super::*;
+    use crate::core::observers::ObserverMode;
+    use crate::core::{ArgminError, Executor};
+    use approx::assert_relative_eq;
+    use std::sync::{Arc, Mutex};
+
+    test_trait_impl!(minres, MINRES<Vec<f64>, IdentityPreconditioner, f64>);
+
+    #[test]
+    fn test_new() {
+        let minres: MINRES<_, _, f64> = MINRES::new(vec![1.0f64, 2.0]);
+        assert_eq!(minres.b, vec![1.0, 2.0]);
+        assert_eq!(minres.preconditioner, IdentityPreconditioner);
+        assert_eq!(minres.tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(minres.r1.is_none());
+        assert!(minres.r2.is_none());
+        assert!(minres.y.is_none());
+        assert!(minres.w.is_none());
+        assert!(minres.w2.is_none());
+    }
+
+    #[test]
+    fn test_invalid_tolerance() {
+        assert_error!(
+            MINRES::<_, _, f64>::new(vec![1.0f64, 2.0]).with_tolerance(0.0),
+            ArgminError,
+            "Invalid parameter: \"`MINRES`: tol must be positive.\""
+        );
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let a = Matrix::symmetric_indefinite();
+        let res = MINRES::new(vec![1.0f64; 4]).init(&mut Problem::new(a), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`MINRES` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_preconditioner_not_positive_definite() {
+        struct Negative;
+
+        impl Preconditioner<Vec<f64>> for Negative {
+            fn apply_inverse(&self, r: &Vec<f64>) -> Result<Vec<f64>, Error> {
+                Ok(r.iter().map(|ri| -ri).collect())
+            }
+        }
+
+        let a = Matrix::symmetric_indefinite();
+        let res = MINRES::new(vec![1.0f64; 4])
+            .with_preconditioner(Negative)
+            .init(&mut Problem::new(a), IterState::new().param(vec![0.0; 4]));
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`MINRES`: Preconditioner is not positive definite.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let a = Matrix::symmetric_indefinite();
+        let b = vec![1.0, -2.0, 0.5, 3.0];
+        for x0 in [vec![0.0; 4], vec![1.0, -2.0, 0.5, 3.0]] {
+            let history = Arc::new(Mutex::new(vec![]));
+            let solver = MINRES::new(b.clone()).with_tolerance(1e-12).unwrap();
+            let res = Executor::new(a.clone(), solver)
+                .configure(|state| state.param(x0).max_iters(20))
+                .add_observer(ResidualHistory(history.clone()), ObserverMode::Always)
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_param().unwrap();
+            assert!(residual_norm(&a, &b, x) < 1e-9);
+            // The residual norm decreases monotonically
+            let history = history.lock().unwrap();
+            assert!(!history.is_empty());
+            assert!(history.windows(2).all(|w| w[1] <= w[0] * (1.0 + 1e-12)));
+        }
+    }
+
+    #[test]
+    fn test_solver_preconditioned() {
+        let a = Matrix::symmetric_indefinite();
+        let b = vec![1.0, -2.0, 0.5, 3.0];
+        let solver = MINRES::new(b.clone())
+            .with_preconditioner(Diagonal(vec![4.0, 3.0, 5.0, 2.0]))
+            .with_tolerance(1e-12)
+            .unwrap();
+        let res = Executor::new(a.clone(), solver)
+            .configure(|state| state.param(vec![0.0; 4]).max_iters(20))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert!(residual_norm(&a, &b, res.state.get_param().unwrap()) < 1e-9);
+    }
+
+    #[test]
+    fn test_solver_singular() {
+        // Singular, but compatible system. Starting from zero, the iterates remain in the range
+        // of `A`, which yields the minimum norm solution.
+        let a = Matrix {
+            a: vec![vec![1.0, 1.0], vec![1.0, 1.0]],
+        };
+        let solver = MINRES::new(vec![2.0, 2.0]).with_tolerance(1e-12).unwrap();
+        let res = Executor::new(a, solver)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_param().unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 1.0, epsilon = 1e-10);
+    }
+}
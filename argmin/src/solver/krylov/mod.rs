@@ -0,0 +1,115 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Krylov subspace methods for linear systems
+//!
+//! Solvers for systems of linear equations `A * x = b` which only access the matrix `A` via the
+//! product `A * x` ([`Operator`](`crate::core::Operator`)). Unlike
+//! [`ConjugateGradient`](`crate::solver::conjugategradient::ConjugateGradient`), these methods
+//! do not require `A` to be positive definite.
+//!
+//! * [MINRES](`MINRES`): symmetric, possibly indefinite matrices
+//! * [GMRES](`GMRES`): general (nonsymmetric) matrices
+//!
+//! Both solvers accept an optional [preconditioner](`crate::solver::preconditioner`).
+//!
+//! ## References
+//!
+//! \[0\] Christopher C. Paige and Michael A. Saunders (1975). Solution of Sparse Indefinite
+//! Systems of Linear Equations. SIAM Journal on Numerical Analysis 12(4), 617–629.
+//! <https://doi.org/10.1137/0712047>
+//!
+//! \[1\] Youcef Saad and Martin H. Schultz (1986). GMRES: A Generalized Minimal Residual
+//! Algorithm for Solving Nonsymmetric Linear Systems. SIAM Journal on Scientific and Statistical
+//! Computing 7(3), 856–869. <https://doi.org/10.1137/0907058>
+
+mod gmres;
+mod minres;
+
+pub use gmres::GMRES;
+pub use minres::MINRES;
+
+#[cfg(test)]
+mod tests {
+    use crate::core::observers::Observe;
+    use crate::core::{Error, Operator, KV};
+    use crate::solver::preconditioner::Preconditioner;
+    use argmin_math::ArgminDot;
+    use std::sync::{Arc, Mutex};
+
+    /// Dense linear system
+    #[derive(Clone)]
+    pub(super) struct Matrix {
+        pub a: Vec<Vec<f64>>,
+    }
+
+    impl Matrix {
+        /// Symmetric matrix with eigenvalues of both signs
+        pub(super) fn symmetric_indefinite() -> Self {
+            Matrix {
+                a: vec![
+                    vec![4.0, 1.0, 0.0, 2.0],
+                    vec![1.0, -3.0, 1.0, 0.0],
+                    vec![0.0, 1.0, 5.0, -1.0],
+                    vec![2.0, 0.0, -1.0, -2.0],
+                ],
+            }
+        }
+
+        /// Nonsymmetric, nonsingular matrix
+        pub(super) fn nonsymmetric() -> Self {
+            Matrix {
+                a: vec![
+                    vec![4.0, 1.0, 0.0, 2.0],
+                    vec![-2.0, 3.0, 1.0, 0.0],
+                    vec![0.0, 4.0, 5.0, -1.0],
+                    vec![1.0, 0.0, 3.0, 2.0],
+                ],
+            }
+        }
+    }
+
+    impl Operator for Matrix {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self.a.dot(x))
+        }
+    }
+
+    /// Diagonal preconditioner
+    pub(super) struct Diagonal(pub Vec<f64>);
+
+    impl Preconditioner<Vec<f64>> for Diagonal {
+        fn apply_inverse(&self, r: &Vec<f64>) -> Result<Vec<f64>, Error> {
+            Ok(r.iter().zip(self.0.iter()).map(|(r, d)| r / d).collect())
+        }
+    }
+
+    /// Observer which records the residual norm of every iteration
+    pub(super) struct ResidualHistory(pub Arc<Mutex<Vec<f64>>>);
+
+    impl<I> Observe<I> for ResidualHistory {
+        fn observe_iter(&mut self, _state: &I, kv: &KV) -> Result<(), Error> {
+            let norm = kv.get("residual_norm").unwrap().get_float().unwrap();
+            self.0.lock().unwrap().push(norm);
+            Ok(())
+        }
+    }
+
+    /// Norm of `b - A * x`
+    pub(super) fn residual_norm(m: &Matrix, b: &[f64], x: &[f64]) -> f64 {
+        m.apply(&x.to_vec())
+            .unwrap()
+            .iter()
+            .zip(b.iter())
+            .map(|(ax, b)| (b - ax).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    AdjointOperator, ArgminFloat, Error, IterState, Operator, Problem, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminL2Norm, ArgminScaledAdd, ArgminScaledSub, ArgminSub, ArgminZeroLike};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # CGLS
+///
+/// Solves the (damped) linear least squares problem `min ||Ax - b||^2 + damp^2 ||x||^2` by
+/// applying the conjugate gradient method to the normal equations
+/// `(A^T A + damp^2 I) x = A^T b` without forming `A^T A`.
+///
+/// The iteration stops once the norm of the residual of the normal equations
+/// `s = A^T (b - Ax) - damp^2 (x - x0)` has been reduced by the factor
+/// [`tol`](`CGLS::with_tolerance`) relative to its initial value. The cost is the norm of the
+/// residual of the damped problem.
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] (`A * x`) and
+/// [`AdjointOperator`] (`A^T * y`).
+///
+/// ## Reference
+///
+/// Åke Björck (1996). Numerical Methods for Least Squares Problems. SIAM.
+/// ISBN 0-89871-360-9.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CGLS<P, U, F> {
+    /// Right hand side `b`
+    b: U,
+    /// Damping parameter
+    damp: F,
+    /// Relative tolerance on the residual of the normal equations
+    tol: F,
+    /// Residual `b - Ax`
+    r: Option<U>,
+    /// Correction `x - x0`
+    d: Option<P>,
+    /// Search direction
+    p: Option<P>,
+    /// Squared norm of the residual of the normal equations
+    gamma: F,
+    /// Norm of the initial residual of the normal equations
+    norm_s0: F,
+}
+
+impl<P, U, F: ArgminFloat> CGLS<P, U, F> {
+    /// Construct a new instance of [`CGLS`]
+    ///
+    /// Takes `b`, the right hand side of `Ax = b` as input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::CGLS;
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let cgls: CGLS<Vec<f64>, _, f64> = CGLS::new(b);
+    /// ```
+    pub fn new(b: U) -> Self {
+        CGLS {
+            b,
+            damp: float!(0.0),
+            tol: F::epsilon().sqrt(),
+            r: None,
+            d: None,
+            p: None,
+            gamma: F::nan(),
+            norm_s0: F::nan(),
+        }
+    }
+
+    /// Set damping parameter
+    ///
+    /// Solves `min ||Ax - b||^2 + damp^2 ||x||^2` (Tikhonov regularization). If the initial
+    /// parameter vector `x0` is nonzero, the damping term is `damp^2 ||x - x0||^2` instead. Must be
+    /// non-negative and defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::CGLS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let cgls: CGLS<Vec<f64>, _, f64> = CGLS::new(b).with_damping(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_damping(mut self, damp: F) -> Result<Self, Error> {
+        if damp < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CGLS`: damping must be >= 0."
+            ));
+        }
+        self.damp = damp;
+        Ok(self)
+    }
+
+    /// Set relative tolerance on the residual of the normal equations
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::CGLS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let cgls: CGLS<Vec<f64>, _, f64> = CGLS::new(b).with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CGLS`: tol must be positive."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, P, U, F> Solver<O, IterState<P, (), (), (), F>> for CGLS<P, U, F>
+where
+    O: Operator<Param = P, Output = U> + AdjointOperator<Param = U, Output = P>,
+    P: Clone
+        + ArgminScaledAdd<P, F, P>
+        + ArgminScaledSub<P, F, P>
+        + ArgminL2Norm<F>
+        + ArgminZeroLike,
+    U: Clone + ArgminSub<U, U> + ArgminScaledSub<U, F, U> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "CGLS";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`CGLS` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let r = self.b.sub(&problem.apply(x0)?);
+        let s = problem.apply_adjoint(&r)?;
+        self.norm_s0 = s.l2_norm();
+        self.gamma = self.norm_s0.powi(2);
+        let cost = r.l2_norm();
+        self.d = Some(x0.zero_like());
+        self.r = Some(r);
+        self.p = Some(s);
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let r = self.r.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CGLS`: Field `r` not set"
+        ))?;
+        let d = self.d.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CGLS`: Field `d` not set"
+        ))?;
+        let p = self.p.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CGLS`: Field `p` not set"
+        ))?;
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CGLS`: Parameter vector in `state` not set"
+        ))?;
+        let damp2 = self.damp.powi(2);
+
+        let q = problem.apply(&p)?;
+        let delta = q.l2_norm().powi(2) + damp2 * p.l2_norm().powi(2);
+        let alpha = self.gamma / delta;
+        let x = x.scaled_add(&alpha, &p);
+        let d = d.scaled_add(&alpha, &p);
+        let r = r.scaled_sub(&alpha, &q);
+        let s = problem.apply_adjoint(&r)?.scaled_sub(&damp2, &d);
+        let gamma = s.l2_norm().powi(2);
+        let beta = gamma / self.gamma;
+        self.gamma = gamma;
+
+        let cost = (r.l2_norm().powi(2) + damp2 * d.l2_norm().powi(2)).sqrt();
+        self.p = Some(s.scaled_add(&beta, &p));
+        self.d = Some(d);
+        self.r = Some(r);
+
+        Ok((
+            state.param(x).cost(cost),
+            Some(kv!("alpha" => alpha; "beta" => beta;)),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, (), (), (), F>) -> TerminationStatus {
+        if self.gamma.sqrt() <= self.tol * self.norm_s0 {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{normal_equation_residual, problem, Matrix};
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(cgls, CGLS<Vec<f64>, Vec<f64>, f64>);
+
+    #[test]
+    fn test_new() {
+        let cgls: CGLS<Vec<f64>, _, f64> = CGLS::new(vec![1.0, 2.0]);
+        assert_eq!(cgls.b, vec![1.0, 2.0]);
+        assert_eq!(cgls.damp.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(cgls.tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(cgls.r.is_none());
+        assert!(cgls.d.is_none());
+        assert!(cgls.p.is_none());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let b = vec![1.0f64, 2.0];
+        assert_error!(
+            CGLS::<Vec<f64>, _, f64>::new(b.clone()).with_damping(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`CGLS`: damping must be >= 0.\""
+        );
+        assert_error!(
+            CGLS::<Vec<f64>, _, f64>::new(b).with_tolerance(0.0),
+            ArgminError,
+            "Invalid parameter: \"`CGLS`: tol must be positive.\""
+        );
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let (a, b) = problem();
+        let res = CGLS::new(b).init(&mut Problem::new(a), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`CGLS` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let (a, b) = problem();
+        for damp in [0.0, 0.5] {
+            for x0 in [vec![0.0; 4], vec![1.0, -2.0, 0.5, 3.0]] {
+                let x0_clone = x0.clone();
+                let solver = CGLS::new(b.clone())
+                    .with_damping(damp)
+                    .unwrap()
+                    .with_tolerance(1e-12)
+                    .unwrap();
+                let res = Executor::new(a.clone(), solver)
+                    .configure(|state| state.param(x0).max_iters(50))
+                    .run()
+                    .unwrap();
+                assert_eq!(
+                    res.state.get_termination_reason(),
+                    Some(&TerminationReason::SolverConverged)
+                );
+                let x = res.state.get_param().unwrap();
+                assert!(normal_equation_residual(&a, &b, damp, &x0_clone, x) < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solver_consistent() {
+        // Unconstrained solution of the overdetermined problem is [1.5, 1], with damping
+        // `(A^T A + I) x = A^T b` gives [1, 0.5].
+        let a = Matrix {
+            a: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]],
+        };
+        for (damp, expected) in [(0.0, [1.5, 1.0]), (1.0, [1.0, 0.5])] {
+            let solver = CGLS::new(vec![2.0, 1.0, 1.0]).with_damping(damp).unwrap();
+            let res = Executor::new(a.clone(), solver)
+                .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+                .run()
+                .unwrap();
+            let x = res.state.get_param().unwrap();
+            assert_relative_eq!(x[0], expected[0], epsilon = 1e-10);
+            assert_relative_eq!(x[1], expected[1], epsilon = 1e-10);
+        }
+    }
+}
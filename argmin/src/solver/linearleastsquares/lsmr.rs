@@ -0,0 +1,522 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::{sym_ortho, StoppingCriteria};
+use crate::core::{
+    AdjointOperator, ArgminFloat, Error, IterState, Operator, Problem, Solver, State,
+    TerminationReason, KV,
+};
+use argmin_math::{
+    ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminScaledSub, ArgminSub, ArgminZeroLike,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # LSMR
+///
+/// Solves the (damped) linear least squares problem `min ||Ax - b||^2 + damp^2 ||x||^2` for a
+/// possibly rectangular matrix `A`. Like [`LSQR`](`super::LSQR`), LSMR is based on the
+/// Golub-Kahan bidiagonalization, but it is analytically equivalent to MINRES applied to the
+/// normal equations. Therefore `||A^T r||` decreases monotonically, which makes it safer to stop
+/// the iteration early than with LSQR.
+///
+/// The iteration stops according to the stopping tests of Paige and Saunders (see
+/// [`with_atol`](`LSMR::with_atol`), [`with_btol`](`LSMR::with_btol`) and
+/// [`with_conlim`](`LSMR::with_conlim`)). The key-value store contains estimates of `||A^T r||`
+/// (`norm_ar`), of the Frobenius norm of `A` (`norm_a`) and of the condition number of `A`
+/// (`cond_a`). The cost is an estimate of the norm of the residual of the damped problem.
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] (`A * x`) and
+/// [`AdjointOperator`] (`A^T * y`).
+///
+/// ## Reference
+///
+/// David Chin-Lung Fong and Michael A. Saunders (2011). LSMR: An Iterative Algorithm for Sparse
+/// Least-Squares Problems. SIAM Journal on Scientific Computing 33(5), 2950–2971.
+/// <https://doi.org/10.1137/10079687X>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LSMR<P, U, F> {
+    /// Right hand side `b`
+    b: U,
+    /// Damping parameter
+    damp: F,
+    /// Stopping tests
+    criteria: StoppingCriteria<F>,
+    /// Left Lanczos vector
+    u: Option<U>,
+    /// Right Lanczos vector
+    v: Option<P>,
+    /// Search directions
+    h: Option<P>,
+    hbar: Option<P>,
+    /// Variables of the QR factorizations of the bidiagonal matrix
+    alpha: F,
+    alphabar: F,
+    rho: F,
+    rhobar: F,
+    cbar: F,
+    sbar: F,
+    zeta: F,
+    zetabar: F,
+    /// Variables for the estimation of `||r||`
+    betadd: F,
+    betad: F,
+    rhodold: F,
+    tautildeold: F,
+    thetatilde: F,
+    d: F,
+    /// Norm of the initial residual
+    norm_b: F,
+    /// Variables for the estimation of `||A||` and `cond(A)`
+    norm_a2: F,
+    maxrbar: F,
+    minrbar: F,
+}
+
+impl<P, U, F: ArgminFloat> LSMR<P, U, F> {
+    /// Construct a new instance of [`LSMR`]
+    ///
+    /// Takes `b`, the right hand side of `Ax = b` as input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSMR;
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsmr: LSMR<Vec<f64>, _, f64> = LSMR::new(b);
+    /// ```
+    pub fn new(b: U) -> Self {
+        LSMR {
+            b,
+            damp: float!(0.0),
+            criteria: StoppingCriteria::new(),
+            u: None,
+            v: None,
+            h: None,
+            hbar: None,
+            alpha: float!(0.0),
+            alphabar: float!(0.0),
+            rho: float!(1.0),
+            rhobar: float!(1.0),
+            cbar: float!(1.0),
+            sbar: float!(0.0),
+            zeta: float!(0.0),
+            zetabar: float!(0.0),
+            betadd: float!(0.0),
+            betad: float!(0.0),
+            rhodold: float!(1.0),
+            tautildeold: float!(0.0),
+            thetatilde: float!(0.0),
+            d: float!(0.0),
+            norm_b: float!(0.0),
+            norm_a2: float!(0.0),
+            maxrbar: float!(0.0),
+            minrbar: F::infinity(),
+        }
+    }
+
+    /// Set damping parameter
+    ///
+    /// Solves `min ||Ax - b||^2 + damp^2 ||x||^2` (Tikhonov regularization). If the initial
+    /// parameter vector `x0` is nonzero, the damping term is `damp^2 ||x - x0||^2` instead. Must be
+    /// non-negative and defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSMR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsmr: LSMR<Vec<f64>, _, f64> = LSMR::new(b).with_damping(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_damping(mut self, damp: F) -> Result<Self, Error> {
+        if damp < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LSMR`: damping must be >= 0."
+            ));
+        }
+        self.damp = damp;
+        Ok(self)
+    }
+
+    /// Set relative tolerance on `A`
+    ///
+    /// The iteration stops if `||A^T r|| <= atol * ||A|| * ||r||`. Must be non-negative and
+    /// defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSMR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsmr: LSMR<Vec<f64>, _, f64> = LSMR::new(b).with_atol(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_atol(mut self, atol: F) -> Result<Self, Error> {
+        self.criteria = self.criteria.with_atol("LSMR", atol)?;
+        Ok(self)
+    }
+
+    /// Set relative tolerance on `b`
+    ///
+    /// The iteration stops if `||r|| <= btol * ||b|| + atol * ||A|| * ||x||`. Must be non-negative
+    /// and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSMR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsmr: LSMR<Vec<f64>, _, f64> = LSMR::new(b).with_btol(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_btol(mut self, btol: F) -> Result<Self, Error> {
+        self.criteria = self.criteria.with_btol("LSMR", btol)?;
+        Ok(self)
+    }
+
+    /// Set limit on the condition number of `A`
+    ///
+    /// The iteration stops if the estimated condition number of `A` exceeds `conlim`. Must be
+    /// positive and defaults to `1e8`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSMR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsmr: LSMR<Vec<f64>, _, f64> = LSMR::new(b).with_conlim(1e12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_conlim(mut self, conlim: F) -> Result<Self, Error> {
+        self.criteria = self.criteria.with_conlim("LSMR", conlim)?;
+        Ok(self)
+    }
+}
+
+impl<O, P, U, F> Solver<O, IterState<P, (), (), (), F>> for LSMR<P, U, F>
+where
+    O: Operator<Param = P, Output = U> + AdjointOperator<Param = U, Output = P>,
+    P: Clone
+        + ArgminScaledAdd<P, F, P>
+        + ArgminScaledSub<P, F, P>
+        + ArgminMul<F, P>
+        + ArgminL2Norm<F>
+        + ArgminZeroLike,
+    U: Clone + ArgminSub<U, U> + ArgminScaledSub<U, F, U> + ArgminMul<F, U> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "LSMR";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`LSMR` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let mut u = self.b.sub(&problem.apply(x0)?);
+        let beta = u.l2_norm();
+        let (v, alpha) = if beta > float!(0.0) {
+            u = u.mul(&(float!(1.0) / beta));
+            let v = problem.apply_adjoint(&u)?;
+            let alpha = v.l2_norm();
+            if alpha > float!(0.0) {
+                (v.mul(&(float!(1.0) / alpha)), alpha)
+            } else {
+                (v, alpha)
+            }
+        } else {
+            (x0.zero_like(), float!(0.0))
+        };
+
+        self.hbar = Some(x0.zero_like());
+        self.h = Some(v.clone());
+        self.u = Some(u);
+        self.v = Some(v);
+        self.alpha = alpha;
+        self.alphabar = alpha;
+        self.rho = float!(1.0);
+        self.rhobar = float!(1.0);
+        self.cbar = float!(1.0);
+        self.sbar = float!(0.0);
+        self.zeta = float!(0.0);
+        self.zetabar = alpha * beta;
+        self.betadd = beta;
+        self.betad = float!(0.0);
+        self.rhodold = float!(1.0);
+        self.tautildeold = float!(0.0);
+        self.thetatilde = float!(0.0);
+        self.d = float!(0.0);
+        self.norm_b = beta;
+        self.norm_a2 = alpha.powi(2);
+        self.maxrbar = float!(0.0);
+        self.minrbar = F::infinity();
+
+        let state = state.cost(beta);
+        if alpha * beta == float!(0.0) {
+            // `x0` already solves the least squares problem
+            return Ok((
+                state.terminate_with(TerminationReason::SolverConverged),
+                None,
+            ));
+        }
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let u = self.u.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSMR`: Field `u` not set"
+        ))?;
+        let v = self.v.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSMR`: Field `v` not set"
+        ))?;
+        let h = self.h.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSMR`: Field `h` not set"
+        ))?;
+        let hbar = self.hbar.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSMR`: Field `hbar` not set"
+        ))?;
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSMR`: Parameter vector in `state` not set"
+        ))?;
+        let zero = float!(0.0);
+        let one = float!(1.0);
+
+        // Continue the bidiagonalization
+        let mut u = problem.apply(&v)?.scaled_sub(&self.alpha, &u);
+        let beta = u.l2_norm();
+        let mut v = v;
+        if beta > zero {
+            u = u.mul(&(one / beta));
+            v = problem.apply_adjoint(&u)?.scaled_sub(&beta, &v);
+            self.alpha = v.l2_norm();
+            if self.alpha > zero {
+                v = v.mul(&(one / self.alpha));
+            }
+        }
+
+        // Construct rotation to eliminate the damping parameter
+        let (chat, shat, alphahat) = sym_ortho(self.alphabar, self.damp);
+
+        // Plane rotation to turn the lower bidiagonal matrix into an upper bidiagonal matrix
+        let rhoold = self.rho;
+        let (c, s, rho) = sym_ortho(alphahat, beta);
+        self.rho = rho;
+        let thetanew = s * self.alpha;
+        self.alphabar = c * self.alpha;
+
+        // Plane rotation to turn the upper bidiagonal matrix into a lower bidiagonal matrix
+        let rhobarold = self.rhobar;
+        let zetaold = self.zeta;
+        let thetabar = self.sbar * rho;
+        let rhotemp = self.cbar * rho;
+        let (cbar, sbar, rhobar) = sym_ortho(self.cbar * rho, thetanew);
+        self.cbar = cbar;
+        self.sbar = sbar;
+        self.rhobar = rhobar;
+        self.zeta = cbar * self.zetabar;
+        self.zetabar = -sbar * self.zetabar;
+
+        // Update `h`, `hbar` and `x`
+        let hbar = h.scaled_sub(&(thetabar * rho / (rhoold * rhobarold)), &hbar);
+        let x = x.scaled_add(&(self.zeta / (rho * rhobar)), &hbar);
+        let h = v.scaled_sub(&(thetanew / rho), &h);
+
+        // Estimate `||r||`
+        let betaacute = chat * self.betadd;
+        let betacheck = -shat * self.betadd;
+        let betahat = c * betaacute;
+        self.betadd = -s * betaacute;
+
+        let thetatildeold = self.thetatilde;
+        let (ctildeold, stildeold, rhotildeold) = sym_ortho(self.rhodold, thetabar);
+        self.thetatilde = stildeold * rhobar;
+        self.rhodold = ctildeold * rhobar;
+        self.betad = -stildeold * self.betad + ctildeold * betahat;
+
+        self.tautildeold = (zetaold - thetatildeold * self.tautildeold) / rhotildeold;
+        let taud = (self.zeta - self.thetatilde * self.tautildeold) / self.rhodold;
+        self.d = self.d + betacheck.powi(2);
+        let norm_r = (self.d + (self.betad - taud).powi(2) + self.betadd.powi(2)).sqrt();
+
+        // Estimate `||A||` and `cond(A)`
+        self.norm_a2 = self.norm_a2 + beta.powi(2);
+        let norm_a = self.norm_a2.sqrt();
+        self.norm_a2 = self.norm_a2 + self.alpha.powi(2);
+        self.maxrbar = self.maxrbar.max(rhobarold);
+        if state.get_iter() > 0 {
+            self.minrbar = self.minrbar.min(rhobarold);
+        }
+        let cond_a = self.maxrbar.max(rhotemp) / self.minrbar.min(rhotemp);
+
+        let norm_ar = self.zetabar.abs();
+        let norm_x = x.l2_norm();
+
+        self.u = Some(u);
+        self.v = Some(v);
+        self.h = Some(h);
+        self.hbar = Some(hbar);
+
+        let mut state = state.param(x).cost(norm_r);
+        if let Some(reason) =
+            self.criteria
+                .check(self.norm_b, norm_a, cond_a, norm_r, norm_ar, norm_x)
+        {
+            state = state.terminate_with(reason);
+        }
+
+        Ok((
+            state,
+            Some(kv!(
+                "norm_ar" => norm_ar;
+                "norm_a" => norm_a;
+                "cond_a" => cond_a;
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{normal_equation_residual, problem, Matrix};
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(lsmr, LSMR<Vec<f64>, Vec<f64>, f64>);
+
+    #[test]
+    fn test_new() {
+        let lsmr: LSMR<Vec<f64>, _, f64> = LSMR::new(vec![1.0, 2.0]);
+        assert_eq!(lsmr.b, vec![1.0, 2.0]);
+        assert_eq!(lsmr.damp.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(lsmr.criteria, StoppingCriteria::new());
+        assert!(lsmr.u.is_none());
+        assert!(lsmr.v.is_none());
+        assert!(lsmr.h.is_none());
+        assert!(lsmr.hbar.is_none());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let b = vec![1.0f64, 2.0];
+        assert_error!(
+            LSMR::<Vec<f64>, _, f64>::new(b.clone()).with_damping(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`LSMR`: damping must be >= 0.\""
+        );
+        assert_error!(
+            LSMR::<Vec<f64>, _, f64>::new(b.clone()).with_atol(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`LSMR`: atol must be >= 0.\""
+        );
+        assert_error!(
+            LSMR::<Vec<f64>, _, f64>::new(b.clone()).with_btol(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`LSMR`: btol must be >= 0.\""
+        );
+        assert_error!(
+            LSMR::<Vec<f64>, _, f64>::new(b).with_conlim(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`LSMR`: conlim must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let (a, b) = problem();
+        let res = LSMR::new(b).init(&mut Problem::new(a), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`LSMR` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let (a, b) = problem();
+        for damp in [0.0, 0.5] {
+            for x0 in [vec![0.0; 4], vec![1.0, -2.0, 0.5, 3.0]] {
+                let x0_clone = x0.clone();
+                let solver = LSMR::new(b.clone())
+                    .with_damping(damp)
+                    .unwrap()
+                    .with_atol(1e-12)
+                    .unwrap()
+                    .with_btol(1e-12)
+                    .unwrap();
+                let res = Executor::new(a.clone(), solver)
+                    .configure(|state| state.param(x0).max_iters(50))
+                    .run()
+                    .unwrap();
+                assert_eq!(
+                    res.state.get_termination_reason(),
+                    Some(&TerminationReason::SolverConverged)
+                );
+                let x = res.state.get_param().unwrap();
+                assert!(normal_equation_residual(&a, &b, damp, &x0_clone, x) < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solver_consistent() {
+        // Unconstrained solution of the overdetermined problem is [1.5, 1], with damping
+        // `(A^T A + I) x = A^T b` gives [1, 0.5].
+        let a = Matrix {
+            a: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]],
+        };
+        for (damp, expected) in [(0.0, [1.5, 1.0]), (1.0, [1.0, 0.5])] {
+            let solver = LSMR::new(vec![2.0, 1.0, 1.0]).with_damping(damp).unwrap();
+            let res = Executor::new(a.clone(), solver)
+                .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+                .run()
+                .unwrap();
+            let x = res.state.get_param().unwrap();
+            assert_relative_eq!(x[0], expected[0], epsilon = 1e-10);
+            assert_relative_eq!(x[1], expected[1], epsilon = 1e-10);
+        }
+    }
+}
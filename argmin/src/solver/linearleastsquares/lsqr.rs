@@ -0,0 +1,495 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::{sym_ortho, StoppingCriteria};
+use crate::core::{
+    AdjointOperator, ArgminFloat, Error, IterState, Operator, Problem, Solver, State,
+    TerminationReason, KV,
+};
+use argmin_math::{
+    ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminScaledSub, ArgminSub, ArgminZeroLike,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # LSQR
+///
+/// Solves the (damped) linear least squares problem `min ||Ax - b||^2 + damp^2 ||x||^2` for a
+/// possibly rectangular matrix `A`. LSQR is based on the Golub-Kahan bidiagonalization and is
+/// analytically equivalent to the conjugate gradient method applied to the normal equations, but
+/// has better numerical properties. If the system `Ax = b` is consistent, its solution is found.
+///
+/// The iteration stops according to the stopping tests of Paige and Saunders (see
+/// [`with_atol`](`LSQR::with_atol`), [`with_btol`](`LSQR::with_btol`) and
+/// [`with_conlim`](`LSQR::with_conlim`)). The key-value store contains estimates of `||A^T r||`
+/// (`norm_ar`), of the Frobenius norm of `A` (`norm_a`) and of the condition number of `A`
+/// (`cond_a`). The cost is the norm of the residual of the damped problem.
+///
+/// Requires an initial parameter vector.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] (`A * x`) and
+/// [`AdjointOperator`] (`A^T * y`).
+///
+/// ## Reference
+///
+/// Christopher C. Paige and Michael A. Saunders (1982). LSQR: An Algorithm for Sparse Linear
+/// Equations and Sparse Least Squares. ACM Transactions on Mathematical Software 8(1), 43–71.
+/// <https://doi.org/10.1145/355984.355989>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LSQR<P, U, F> {
+    /// Right hand side `b`
+    b: U,
+    /// Damping parameter
+    damp: F,
+    /// Stopping tests
+    criteria: StoppingCriteria<F>,
+    /// Left Lanczos vector
+    u: Option<U>,
+    /// Right Lanczos vector
+    v: Option<P>,
+    /// Search direction
+    w: Option<P>,
+    /// alpha
+    alpha: F,
+    /// rhobar
+    rhobar: F,
+    /// phibar
+    phibar: F,
+    /// Norm of the initial residual
+    norm_b: F,
+    /// Estimate of the Frobenius norm of `A`
+    norm_a: F,
+    /// Sum of squared norms of the columns of `D_k` (condition number estimate)
+    ddnorm: F,
+    /// Contribution of the damping to the residual norm
+    res2: F,
+    /// Variables for the estimation of `||x||`
+    xxnorm: F,
+    z: F,
+    cs2: F,
+    sn2: F,
+}
+
+impl<P, U, F: ArgminFloat> LSQR<P, U, F> {
+    /// Construct a new instance of [`LSQR`]
+    ///
+    /// Takes `b`, the right hand side of `Ax = b` as input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSQR;
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsqr: LSQR<Vec<f64>, _, f64> = LSQR::new(b);
+    /// ```
+    pub fn new(b: U) -> Self {
+        LSQR {
+            b,
+            damp: float!(0.0),
+            criteria: StoppingCriteria::new(),
+            u: None,
+            v: None,
+            w: None,
+            alpha: float!(0.0),
+            rhobar: float!(0.0),
+            phibar: float!(0.0),
+            norm_b: float!(0.0),
+            norm_a: float!(0.0),
+            ddnorm: float!(0.0),
+            res2: float!(0.0),
+            xxnorm: float!(0.0),
+            z: float!(0.0),
+            cs2: float!(-1.0),
+            sn2: float!(0.0),
+        }
+    }
+
+    /// Set damping parameter
+    ///
+    /// Solves `min ||Ax - b||^2 + damp^2 ||x||^2` (Tikhonov regularization). If the initial
+    /// parameter vector `x0` is nonzero, the damping term is `damp^2 ||x - x0||^2` instead. Must be
+    /// non-negative and defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSQR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsqr: LSQR<Vec<f64>, _, f64> = LSQR::new(b).with_damping(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_damping(mut self, damp: F) -> Result<Self, Error> {
+        if damp < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LSQR`: damping must be >= 0."
+            ));
+        }
+        self.damp = damp;
+        Ok(self)
+    }
+
+    /// Set relative tolerance on `A`
+    ///
+    /// The iteration stops if `||A^T r|| <= atol * ||A|| * ||r||`. Must be non-negative and
+    /// defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSQR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsqr: LSQR<Vec<f64>, _, f64> = LSQR::new(b).with_atol(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_atol(mut self, atol: F) -> Result<Self, Error> {
+        self.criteria = self.criteria.with_atol("LSQR", atol)?;
+        Ok(self)
+    }
+
+    /// Set relative tolerance on `b`
+    ///
+    /// The iteration stops if `||r|| <= btol * ||b|| + atol * ||A|| * ||x||`. Must be non-negative
+    /// and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSQR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsqr: LSQR<Vec<f64>, _, f64> = LSQR::new(b).with_btol(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_btol(mut self, btol: F) -> Result<Self, Error> {
+        self.criteria = self.criteria.with_btol("LSQR", btol)?;
+        Ok(self)
+    }
+
+    /// Set limit on the condition number of `A`
+    ///
+    /// The iteration stops if the estimated condition number of `A` exceeds `conlim`. Must be
+    /// positive and defaults to `1e8`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linearleastsquares::LSQR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let b = vec![1.0f64, 1.0, 2.0];
+    /// let lsqr: LSQR<Vec<f64>, _, f64> = LSQR::new(b).with_conlim(1e12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_conlim(mut self, conlim: F) -> Result<Self, Error> {
+        self.criteria = self.criteria.with_conlim("LSQR", conlim)?;
+        Ok(self)
+    }
+}
+
+impl<O, P, U, F> Solver<O, IterState<P, (), (), (), F>> for LSQR<P, U, F>
+where
+    O: Operator<Param = P, Output = U> + AdjointOperator<Param = U, Output = P>,
+    P: Clone
+        + ArgminScaledAdd<P, F, P>
+        + ArgminScaledSub<P, F, P>
+        + ArgminMul<F, P>
+        + ArgminL2Norm<F>
+        + ArgminZeroLike,
+    U: Clone + ArgminSub<U, U> + ArgminScaledSub<U, F, U> + ArgminMul<F, U> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "LSQR";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`LSQR` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let mut u = self.b.sub(&problem.apply(x0)?);
+        let beta = u.l2_norm();
+        let (v, alpha) = if beta > float!(0.0) {
+            u = u.mul(&(float!(1.0) / beta));
+            let v = problem.apply_adjoint(&u)?;
+            let alpha = v.l2_norm();
+            if alpha > float!(0.0) {
+                (v.mul(&(float!(1.0) / alpha)), alpha)
+            } else {
+                (v, alpha)
+            }
+        } else {
+            (x0.zero_like(), float!(0.0))
+        };
+
+        self.w = Some(v.clone());
+        self.u = Some(u);
+        self.v = Some(v);
+        self.alpha = alpha;
+        self.rhobar = alpha;
+        self.phibar = beta;
+        self.norm_b = beta;
+        self.norm_a = float!(0.0);
+        self.ddnorm = float!(0.0);
+        self.res2 = float!(0.0);
+        self.xxnorm = float!(0.0);
+        self.z = float!(0.0);
+        self.cs2 = float!(-1.0);
+        self.sn2 = float!(0.0);
+
+        let state = state.cost(beta);
+        if alpha * beta == float!(0.0) {
+            // `x0` already solves the least squares problem
+            return Ok((
+                state.terminate_with(TerminationReason::SolverConverged),
+                None,
+            ));
+        }
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let u = self.u.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSQR`: Field `u` not set"
+        ))?;
+        let v = self.v.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSQR`: Field `v` not set"
+        ))?;
+        let w = self.w.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSQR`: Field `w` not set"
+        ))?;
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSQR`: Parameter vector in `state` not set"
+        ))?;
+        let zero = float!(0.0);
+        let one = float!(1.0);
+
+        // Continue the bidiagonalization
+        let mut u = problem.apply(&v)?.scaled_sub(&self.alpha, &u);
+        let beta = u.l2_norm();
+        let mut v = v;
+        if beta > zero {
+            u = u.mul(&(one / beta));
+            self.norm_a =
+                (self.norm_a.powi(2) + self.alpha.powi(2) + beta.powi(2) + self.damp.powi(2))
+                    .sqrt();
+            v = problem.apply_adjoint(&u)?.scaled_sub(&beta, &v);
+            self.alpha = v.l2_norm();
+            if self.alpha > zero {
+                v = v.mul(&(one / self.alpha));
+            }
+        }
+
+        // Eliminate the damping parameter
+        let (rhobar1, psi) = if self.damp > zero {
+            let rhobar1 = (self.rhobar.powi(2) + self.damp.powi(2)).sqrt();
+            let cs1 = self.rhobar / rhobar1;
+            let sn1 = self.damp / rhobar1;
+            let psi = sn1 * self.phibar;
+            self.phibar = cs1 * self.phibar;
+            (rhobar1, psi)
+        } else {
+            (self.rhobar, zero)
+        };
+
+        // Eliminate the subdiagonal element of the lower bidiagonal matrix
+        let (cs, sn, rho) = sym_ortho(rhobar1, beta);
+        let theta = sn * self.alpha;
+        self.rhobar = -cs * self.alpha;
+        let phi = cs * self.phibar;
+        self.phibar = sn * self.phibar;
+        let tau = sn * phi;
+
+        // Update `x` and `w`
+        let x = x.scaled_add(&(phi / rho), &w);
+        self.ddnorm = self.ddnorm + (w.l2_norm() / rho).powi(2);
+        let w = v.scaled_add(&(-theta / rho), &w);
+
+        // Estimate the norm of `x`
+        let delta = self.sn2 * rho;
+        let gambar = -self.cs2 * rho;
+        let rhs = phi - delta * self.z;
+        let zbar = rhs / gambar;
+        let norm_x = (self.xxnorm + zbar.powi(2)).sqrt();
+        let gamma = (gambar.powi(2) + theta.powi(2)).sqrt();
+        self.cs2 = gambar / gamma;
+        self.sn2 = theta / gamma;
+        self.z = rhs / gamma;
+        self.xxnorm = self.xxnorm + self.z.powi(2);
+
+        let cond_a = self.norm_a * self.ddnorm.sqrt();
+        self.res2 = self.res2 + psi.powi(2);
+        let norm_r = (self.phibar.powi(2) + self.res2).sqrt();
+        let norm_ar = self.alpha * tau.abs();
+
+        self.u = Some(u);
+        self.v = Some(v);
+        self.w = Some(w);
+
+        let mut state = state.param(x).cost(norm_r);
+        if let Some(reason) =
+            self.criteria
+                .check(self.norm_b, self.norm_a, cond_a, norm_r, norm_ar, norm_x)
+        {
+            state = state.terminate_with(reason);
+        }
+
+        Ok((
+            state,
+            Some(kv!(
+                "norm_ar" => norm_ar;
+                "norm_a" => self.norm_a;
+                "cond_a" => cond_a;
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{normal_equation_residual, problem, Matrix};
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(lsqr, LSQR<Vec<f64>, Vec<f64>, f64>);
+
+    #[test]
+    fn test_new() {
+        let lsqr: LSQR<Vec<f64>, _, f64> = LSQR::new(vec![1.0, 2.0]);
+        assert_eq!(lsqr.b, vec![1.0, 2.0]);
+        assert_eq!(lsqr.damp.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(lsqr.criteria, StoppingCriteria::new());
+        assert!(lsqr.u.is_none());
+        assert!(lsqr.v.is_none());
+        assert!(lsqr.w.is_none());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let b = vec![1.0f64, 2.0];
+        assert_error!(
+            LSQR::<Vec<f64>, _, f64>::new(b.clone()).with_damping(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`LSQR`: damping must be >= 0.\""
+        );
+        assert_error!(
+            LSQR::<Vec<f64>, _, f64>::new(b.clone()).with_atol(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`LSQR`: atol must be >= 0.\""
+        );
+        assert_error!(
+            LSQR::<Vec<f64>, _, f64>::new(b.clone()).with_btol(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`LSQR`: btol must be >= 0.\""
+        );
+        assert_error!(
+            LSQR::<Vec<f64>, _, f64>::new(b).with_conlim(0.0),
+            ArgminError,
+            "Invalid parameter: \"`LSQR`: conlim must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let (a, b) = problem();
+        let res = LSQR::new(b).init(&mut Problem::new(a), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`LSQR` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let (a, b) = problem();
+        for damp in [0.0, 0.5] {
+            for x0 in [vec![0.0; 4], vec![1.0, -2.0, 0.5, 3.0]] {
+                let x0_clone = x0.clone();
+                let solver = LSQR::new(b.clone())
+                    .with_damping(damp)
+                    .unwrap()
+                    .with_atol(1e-12)
+                    .unwrap()
+                    .with_btol(1e-12)
+                    .unwrap();
+                let res = Executor::new(a.clone(), solver)
+                    .configure(|state| state.param(x0).max_iters(50))
+                    .run()
+                    .unwrap();
+                assert_eq!(
+                    res.state.get_termination_reason(),
+                    Some(&TerminationReason::SolverConverged)
+                );
+                let x = res.state.get_param().unwrap();
+                assert!(normal_equation_residual(&a, &b, damp, &x0_clone, x) < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solver_consistent() {
+        // Unconstrained solution of the overdetermined problem is [1.5, 1], with damping
+        // `(A^T A + I) x = A^T b` gives [1, 0.5].
+        let a = Matrix {
+            a: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]],
+        };
+        for (damp, expected) in [(0.0, [1.5, 1.0]), (1.0, [1.0, 0.5])] {
+            let solver = LSQR::new(vec![2.0, 1.0, 1.0]).with_damping(damp).unwrap();
+            let res = Executor::new(a.clone(), solver)
+                .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+                .run()
+                .unwrap();
+            let x = res.state.get_param().unwrap();
+            assert_relative_eq!(x[0], expected[0], epsilon = 1e-10);
+            assert_relative_eq!(x[1], expected[1], epsilon = 1e-10);
+        }
+
+        // The initial guess already solves the system
+        let solver = LSQR::new(vec![1.0, 1.0, 2.0]);
+        let res = Executor::new(a, solver)
+            .configure(|state| state.param(vec![1.0, 2.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_iter(), 0);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+    }
+}
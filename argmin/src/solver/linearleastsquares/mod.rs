@@ -0,0 +1,264 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Iterative solvers for linear least squares problems
+//!
+//! Solvers for `min ||Ax - b||^2 + damp^2 ||x||^2` which only access the (possibly rectangular)
+//! matrix `A` via the products `A * x` ([`Operator`](`crate::core::Operator`)) and `A^T * y`
+//! ([`AdjointOperator`](`crate::core::AdjointOperator`)). This makes them suitable for large
+//! and sparse problems as well as for matrix-free operators.
+//!
+//! * [LSQR](`LSQR`)
+//! * [LSMR](`LSMR`)
+//! * [CGLS](`CGLS`)
+//!
+//! ## References
+//!
+//! \[0\] Christopher C. Paige and Michael A. Saunders (1982). LSQR: An Algorithm for Sparse
+//! Linear Equations and Sparse Least Squares. ACM Transactions on Mathematical Software 8(1),
+//! 43–71. <https://doi.org/10.1145/355984.355989>
+//!
+//! \[1\] David Chin-Lung Fong and Michael A. Saunders (2011). LSMR: An Iterative Algorithm for
+//! Sparse Least-Squares Problems. SIAM Journal on Scientific Computing 33(5), 2950–2971.
+//! <https://doi.org/10.1137/10079687X>
+//!
+//! \[2\] Åke Björck (1996). Numerical Methods for Least Squares Problems. SIAM.
+//! ISBN 0-89871-360-9.
+
+mod cgls;
+mod lsmr;
+mod lsqr;
+
+pub use cgls::CGLS;
+pub use lsmr::LSMR;
+pub use lsqr::LSQR;
+
+use crate::core::{ArgminFloat, Error, TerminationReason};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Stopping tests of Paige and Saunders shared by [`LSQR`] and [`LSMR`]
+///
+/// Terminates if either `||r|| <= btol * ||b|| + atol * ||A|| * ||x||` (the system is compatible),
+/// or `||A^T r|| <= atol * ||A|| * ||r||` (`x` is a least squares solution), or if the estimated
+/// condition number of `A` exceeds `conlim`. Here, `r = b - Ax` and all norms refer to the damped
+/// problem.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct StoppingCriteria<F> {
+    /// Relative tolerance on `A`
+    atol: F,
+    /// Relative tolerance on `b`
+    btol: F,
+    /// Limit on the condition number of `A`
+    conlim: F,
+}
+
+impl<F: ArgminFloat> StoppingCriteria<F> {
+    fn new() -> Self {
+        StoppingCriteria {
+            atol: F::epsilon().sqrt(),
+            btol: F::epsilon().sqrt(),
+            conlim: float!(1e8),
+        }
+    }
+
+    fn with_atol(mut self, solver: &str, atol: F) -> Result<Self, Error> {
+        if atol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!("`{}`: atol must be >= 0.", solver)
+            ));
+        }
+        self.atol = atol;
+        Ok(self)
+    }
+
+    fn with_btol(mut self, solver: &str, btol: F) -> Result<Self, Error> {
+        if btol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!("`{}`: btol must be >= 0.", solver)
+            ));
+        }
+        self.btol = btol;
+        Ok(self)
+    }
+
+    fn with_conlim(mut self, solver: &str, conlim: F) -> Result<Self, Error> {
+        if conlim <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!("`{}`: conlim must be > 0.", solver)
+            ));
+        }
+        self.conlim = conlim;
+        Ok(self)
+    }
+
+    /// Returns the reason for termination if one of the stopping tests is fulfilled
+    fn check(
+        &self,
+        norm_b: F,
+        norm_a: F,
+        cond_a: F,
+        norm_r: F,
+        norm_ar: F,
+        norm_x: F,
+    ) -> Option<TerminationReason> {
+        let one = float!(1.0);
+        let test1 = norm_r / norm_b;
+        let test2 = if norm_a * norm_r > float!(0.0) {
+            norm_ar / (norm_a * norm_r)
+        } else {
+            F::infinity()
+        };
+        let test3 = one / cond_a;
+        let t1 = test1 / (one + norm_a * norm_x / norm_b);
+        let rtol = self.btol + self.atol * norm_a * norm_x / norm_b;
+
+        // The tests in the first row check whether the tolerances are below machine precision.
+        if one + t1 <= one || one + test2 <= one || test1 <= rtol || test2 <= self.atol {
+            Some(TerminationReason::SolverConverged)
+        } else if one + test3 <= one || test3 <= one / self.conlim {
+            Some(TerminationReason::SolverExit(
+                "Estimated condition number of `A` exceeds `conlim`".to_string(),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Stable Givens rotation: returns `(c, s, r)` such that `[c s; -s c] [a; b] = [r; 0]`.
+pub(crate) fn sym_ortho<F: ArgminFloat>(a: F, b: F) -> (F, F, F) {
+    if b == float!(0.0) {
+        (a.signum(), float!(0.0), a.abs())
+    } else if a == float!(0.0) {
+        (float!(0.0), b.signum(), b.abs())
+    } else if b.abs() > a.abs() {
+        let tau = a / b;
+        let s = b.signum() / (float!(1.0) + tau * tau).sqrt();
+        let c = s * tau;
+        (c, s, b / s)
+    } else {
+        let tau = b / a;
+        let c = a.signum() / (float!(1.0) + tau * tau).sqrt();
+        let s = c * tau;
+        (c, s, a / c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{AdjointOperator, Operator};
+    use approx::assert_relative_eq;
+    use argmin_math::{ArgminDot, ArgminTranspose};
+
+    /// Dense linear least squares problem
+    #[derive(Clone)]
+    pub(super) struct Matrix {
+        pub a: Vec<Vec<f64>>,
+    }
+
+    impl Operator for Matrix {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self.a.dot(x))
+        }
+    }
+
+    impl AdjointOperator for Matrix {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply_adjoint(&self, y: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self.a.clone().t().dot(y))
+        }
+    }
+
+    /// Overdetermined, inconsistent problem
+    pub(super) fn problem() -> (Matrix, Vec<f64>) {
+        let a = vec![
+            vec![1.0, 2.0, 0.0, -1.0],
+            vec![0.5, -1.0, 3.0, 0.0],
+            vec![2.0, 0.0, 1.0, 1.0],
+            vec![-1.0, 1.0, 1.0, 2.0],
+            vec![0.0, 3.0, -2.0, 1.0],
+            vec![1.0, 1.0, 1.0, 1.0],
+        ];
+        let b = vec![1.0, -2.0, 0.5, 3.0, 1.0, -1.0];
+        (Matrix { a }, b)
+    }
+
+    /// Norm of the gradient `A^T (Ax - b) + damp^2 (x - x0)` of the damped least squares problem
+    pub(super) fn normal_equation_residual(
+        m: &Matrix,
+        b: &[f64],
+        damp: f64,
+        x0: &[f64],
+        x: &[f64],
+    ) -> f64 {
+        let r: Vec<f64> = m
+            .apply(&x.to_vec())
+            .unwrap()
+            .iter()
+            .zip(b.iter())
+            .map(|(ax, b)| ax - b)
+            .collect();
+        m.apply_adjoint(&r)
+            .unwrap()
+            .iter()
+            .zip(x.iter().zip(x0.iter()))
+            .map(|(g, (x, x0))| (g + damp * damp * (x - x0)).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    #[test]
+    fn test_sym_ortho() {
+        for (a, b) in [
+            (3.0f64, 4.0f64),
+            (-4.0, 3.0),
+            (1.0, -1e-3),
+            (0.0, -2.0),
+            (-2.0, 0.0),
+        ] {
+            let (c, s, r) = sym_ortho(a, b);
+            assert_relative_eq!(c * c + s * s, 1.0, epsilon = 1e-12);
+            assert_relative_eq!(c * a + s * b, r, epsilon = 1e-12);
+            assert_relative_eq!(-s * a + c * b, 0.0, epsilon = 1e-12);
+            assert_relative_eq!(r, (a * a + b * b).sqrt(), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_stopping_criteria() {
+        let sc: StoppingCriteria<f64> = StoppingCriteria::new();
+        // Compatible system
+        assert_eq!(
+            sc.check(1.0, 1.0, 1.0, 1e-10, 1.0, 1.0),
+            Some(TerminationReason::SolverConverged)
+        );
+        // Least squares solution
+        assert_eq!(
+            sc.check(1.0, 1.0, 1.0, 0.5, 1e-10, 1.0),
+            Some(TerminationReason::SolverConverged)
+        );
+        // Ill-conditioned
+        assert_eq!(
+            sc.check(1.0, 1.0, 1e9, 0.5, 0.5, 1.0),
+            Some(TerminationReason::SolverExit(
+                "Estimated condition number of `A` exceeds `conlim`".to_string()
+            ))
+        );
+        assert_eq!(sc.check(1.0, 1.0, 1.0, 0.5, 0.5, 1.0), None);
+    }
+}
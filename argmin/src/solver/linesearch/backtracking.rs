@@ -10,7 +10,7 @@ use crate::core::{
     Solver, State, TerminationReason, TerminationStatus, KV,
 };
 use crate::solver::linesearch::condition::*;
-use argmin_math::ArgminScaledAdd;
+use argmin_math::{ArgminDot, ArgminScaledAdd, ArgminScaledAddAssign};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +19,12 @@ use serde::{Deserialize, Serialize};
 /// The Backtracking line search is a method which finds a step length from a given point along a
 /// given direction, such that this step length obeys the Armijo (sufficient decrease) condition.
 ///
+/// By default, the step length is shrunk by a constant factor `rho` in each iteration. With
+/// [`with_interpolation`](`BacktrackingLineSearch::with_interpolation`), the next step length is
+/// instead chosen as the minimizer of a quadratic (first backtracking step) or cubic (subsequent
+/// steps) interpolation of the cost function along the search direction, safeguarded to lie
+/// within `[0.1, 0.5]` times the current step length (Nocedal & Wright, Section 3.5).
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
@@ -46,6 +52,10 @@ pub struct BacktrackingLineSearch<P, G, L, F> {
     condition: L,
     /// alpha
     alpha: F,
+    /// Choose step lengths by interpolation
+    interpolation: bool,
+    /// Previous step length and corresponding cost
+    prev_step: Option<(F, F)>,
 }
 
 impl<P, G, L, F> BacktrackingLineSearch<P, G, L, F>
@@ -72,6 +82,8 @@ where
             rho: float!(0.9),
             condition,
             alpha: float!(1.0),
+            interpolation: false,
+            prev_step: None,
         }
     }
 
@@ -102,6 +114,28 @@ where
         self.rho = rho;
         Ok(self)
     }
+
+    /// Choose the next step length by safeguarded quadratic/cubic interpolation
+    ///
+    /// If enabled, the contraction factor `rho` is only used if the interpolation fails, for
+    /// instance because the cost function returned NaN. Like the interpolated step length, the
+    /// contracted step length is kept within `[0.1, 0.5]` times the current step length.
+    /// Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::BacktrackingLineSearch;
+    /// # use argmin::solver::linesearch::condition::ArmijoCondition;
+    /// # let backtracking: BacktrackingLineSearch<Vec<f64>, Vec<f64>, _, f64> =
+    /// #     BacktrackingLineSearch::new(ArmijoCondition::new(0.0001f64));
+    /// let backtracking = backtracking.with_interpolation(true);
+    /// ```
+    #[must_use]
+    pub fn with_interpolation(mut self, interpolation: bool) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
 }
 
 impl<P, G, L, F> LineSearch<P, F> for BacktrackingLineSearch<P, G, L, F>
@@ -128,29 +162,23 @@ where
 
 impl<P, G, L, F> BacktrackingLineSearch<P, G, L, F>
 where
-    P: ArgminScaledAdd<P, F, P>,
+    P: Clone + ArgminScaledAddAssign<P, F>,
+    G: ArgminDot<P, F>,
     L: LineSearchCondition<P, G, F>,
     IterState<P, G, (), (), F>: State<Float = F>,
     F: ArgminFloat,
 {
-    /// Perform a single backtracking step
-    fn backtracking_step<O>(
-        &self,
-        problem: &mut Problem<O>,
-        state: IterState<P, G, (), (), F>,
-    ) -> Result<IterState<P, G, (), (), F>, Error>
-    where
-        O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
-    {
-        let new_param = self
-            .init_param
+    /// Compute the next step length from the cost function value `cost` at the current step
+    /// length by interpolation
+    fn interpolate(&self, cost: F) -> Result<F, Error> {
+        let dphi0 = self
+            .init_grad
             .as_ref()
             .ok_or_else(argmin_error_closure!(
                 PotentialBug,
-                "`BacktrackingLineSearch`: Initial parameter vector not set."
+                "`BacktrackingLineSearch`: Initial gradient not set."
             ))?
-            .scaled_add(
-                &self.alpha,
+            .dot(
                 self.search_direction
                     .as_ref()
                     .ok_or_else(argmin_error_closure!(
@@ -158,6 +186,63 @@ where
                         "`BacktrackingLineSearch`: Search direction not set."
                     ))?,
             );
+        let phi0 = self.init_cost;
+        let a1 = self.alpha;
+        let d1 = cost - phi0 - dphi0 * a1;
+        let alpha = match self.prev_step {
+            // Minimizer of the quadratic interpolating phi(0), phi'(0) and phi(a1)
+            None => -dphi0 * a1 * a1 / (float!(2.0) * d1),
+            // Minimizer of the cubic interpolating phi(0), phi'(0), phi(a0) and phi(a1)
+            Some((a0, cost0)) => {
+                let d0 = cost0 - phi0 - dphi0 * a0;
+                let denom = a0 * a0 * a1 * a1 * (a1 - a0);
+                let a = (a0 * a0 * d1 - a1 * a1 * d0) / denom;
+                let b = (a1 * a1 * a1 * d0 - a0 * a0 * a0 * d1) / denom;
+                if a == float!(0.0) {
+                    -dphi0 / (float!(2.0) * b)
+                } else {
+                    (-b + (b * b - float!(3.0) * a * dphi0).sqrt()) / (float!(3.0) * a)
+                }
+            }
+        };
+        let alpha = if alpha.is_finite() {
+            alpha
+        } else {
+            self.rho * a1
+        };
+        Ok(alpha.max(float!(0.1) * a1).min(float!(0.5) * a1))
+    }
+
+    /// Perform a single backtracking step
+    fn backtracking_step<O>(
+        &self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<IterState<P, G, (), (), F>, Error>
+    where
+        O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    {
+        let init_param = self.init_param.as_ref().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`BacktrackingLineSearch`: Initial parameter vector not set."
+        ))?;
+        let search_direction = self
+            .search_direction
+            .as_ref()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`BacktrackingLineSearch`: Search direction not set."
+            ))?;
+
+        // Reuse the buffer of the previous parameter vector (if available) for the new one
+        let mut new_param = match state.take_prev_param() {
+            Some(mut buf) => {
+                buf.clone_from(init_param);
+                buf
+            }
+            None => init_param.clone(),
+        };
+        new_param.scaled_add_assign(&self.alpha, search_direction);
 
         let cur_cost = problem.cost(&new_param)?;
 
@@ -176,8 +261,8 @@ where
 
 impl<O, P, G, L, F> Solver<O, IterState<P, G, (), (), F>> for BacktrackingLineSearch<P, G, L, F>
 where
-    P: Clone + SerializeAlias + ArgminScaledAdd<P, F, P>,
-    G: SerializeAlias + ArgminScaledAdd<P, F, P>,
+    P: Clone + SerializeAlias + ArgminScaledAddAssign<P, F>,
+    G: SerializeAlias + ArgminScaledAdd<P, F, P> + ArgminDot<P, F>,
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     L: LineSearchCondition<P, G, F> + SerializeAlias,
     F: ArgminFloat,
@@ -219,6 +304,7 @@ where
 
         self.init_param = Some(init_param);
         self.init_grad = Some(init_grad);
+        self.prev_step = None;
         let state = self.backtracking_step(problem, state)?;
         Ok((state, None))
     }
@@ -228,7 +314,14 @@ where
         problem: &mut Problem<O>,
         state: IterState<P, G, (), (), F>,
     ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
-        self.alpha = self.alpha * self.rho;
+        if self.interpolation {
+            let cost = state.get_cost();
+            let alpha = self.interpolate(cost)?;
+            self.prev_step = Some((self.alpha, cost));
+            self.alpha = alpha;
+        } else {
+            self.alpha = self.alpha * self.rho;
+        }
         let state = self.backtracking_step(problem, state)?;
         Ok((state, None))
     }
@@ -296,6 +389,8 @@ mod tests {
         assert_eq!(ls.search_direction, None);
         assert_eq!(ls.rho.to_ne_bytes(), 0.9f64.to_ne_bytes());
         assert_eq!(ls.alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(!ls.interpolation);
+        assert_eq!(ls.prev_step, None);
     }
 
     #[test]
@@ -708,4 +803,103 @@ mod tests {
         );
         assert!(data.get_gradient().is_none());
     }
+
+    #[test]
+    fn test_interpolate() {
+        let armijo = ArmijoCondition::new(0.01).unwrap();
+        let mut ls: BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64> =
+            BacktrackingLineSearch::new(armijo).with_interpolation(true);
+
+        // phi(a) = 1 - 3a + a^3 with minimum at a = 1
+        let phi = |a: f64| 1.0 - 3.0 * a + a.powi(3);
+        ls.init_cost = phi(0.0);
+        ls.init_grad = Some(vec![-3.0]);
+        ls.search_direction(vec![1.0]);
+
+        // Quadratic interpolation of phi(0), phi'(0) and phi(4): 3 * 16 / (2 * 64) = 0.375,
+        // safeguarded to 0.4
+        ls.alpha = 4.0;
+        assert_relative_eq!(
+            ls.interpolate(phi(4.0)).unwrap(),
+            0.4,
+            epsilon = f64::EPSILON
+        );
+
+        // Cubic interpolation is exact
+        ls.prev_step = Some((4.0, phi(4.0)));
+        ls.alpha = 2.0;
+        assert_relative_eq!(ls.interpolate(phi(2.0)).unwrap(), 1.0, epsilon = 1e-12);
+
+        // Infinite cost function values lead to the smallest allowed step length, NaN falls back
+        // to the contraction factor, which is safeguarded as well
+        ls.prev_step = None;
+        assert_relative_eq!(
+            ls.interpolate(f64::INFINITY).unwrap(),
+            0.2,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            ls.interpolate(f64::NAN).unwrap(),
+            0.5 * 2.0,
+            epsilon = f64::EPSILON
+        );
+        ls.rho = 0.01;
+        assert_relative_eq!(
+            ls.interpolate(f64::NAN).unwrap(),
+            0.1 * 2.0,
+            epsilon = f64::EPSILON
+        );
+        ls.rho = 0.3;
+        assert_relative_eq!(
+            ls.interpolate(f64::NAN).unwrap(),
+            0.3 * 2.0,
+            epsilon = f64::EPSILON
+        );
+
+        // A negative discriminant of the cubic falls back to the safeguarded contraction factor
+        ls.rho = 0.9;
+        ls.prev_step = Some((4.0, -50.0));
+        ls.alpha = 2.0;
+        assert_relative_eq!(
+            ls.interpolate(-10.0).unwrap(),
+            0.5 * 2.0,
+            epsilon = f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_executor_interpolation() {
+        fn run<L>(condition: L, interpolation: bool) -> IterState<Vec<f64>, Vec<f64>, (), (), f64>
+        where
+            L: LineSearchCondition<Vec<f64>, Vec<f64>, f64> + SerializeAlias + Clone,
+        {
+            let mut ls = BacktrackingLineSearch::new(condition).with_interpolation(interpolation);
+            ls.search_direction(vec![4.0f64, 0.0]);
+            Executor::new(BTTestProblem {}, ls)
+                .configure(|config| config.param(vec![-1.0, 0.0]).max_iters(100))
+                .run()
+                .unwrap()
+                .state
+        }
+
+        // The quadratic interpolation finds the exact minimizer `[0, 0]` along the search
+        // direction in a single backtracking step.
+        let states = [
+            run(ArmijoCondition::new(0.01).unwrap(), true),
+            run(WolfeCondition::new(0.01, 0.9).unwrap(), true),
+            run(StrongWolfeCondition::new(0.01, 0.9).unwrap(), true),
+            run(GoldsteinCondition::new(0.25).unwrap(), true),
+        ];
+        for state in states {
+            assert_eq!(state.get_param().unwrap(), &vec![0.0, 0.0]);
+            assert_eq!(state.get_iter(), 1);
+            assert_eq!(
+                state.termination_status,
+                TerminationStatus::Terminated(TerminationReason::SolverConverged)
+            );
+        }
+
+        let state = run(ArmijoCondition::new(0.01).unwrap(), false);
+        assert!(state.get_iter() > 1);
+    }
 }
@@ -0,0 +1,647 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, LineSearch, Problem, SerializeAlias, Solver,
+    State, TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::ArgminScaledAdd;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Golden ratio, used to expand the bracket
+const GOLDEN_RATIO: f64 = 1.618_033_988_749_895;
+
+/// Maximum number of contractions or expansions while bracketing the minimum
+const MAX_BRACKET_ITERS: usize = 50;
+
+/// Interface for one-dimensional minimizers which can be used by [`ExactLineSearch`]
+///
+/// The minimizer is given an interval which brackets a minimum of the function.
+pub trait BracketedMinimizer<F>: Sized {
+    /// Returns a new instance of the minimizer which searches the interval `[lower, upper]`
+    ///
+    /// All other settings (such as tolerances) are taken from `self`.
+    fn with_bracket(&self, lower: F, upper: F) -> Result<Self, Error>;
+}
+
+/// Restriction of a cost function to the line `t -> x + t * d`
+///
+/// This is the one-dimensional problem which [`ExactLineSearch`] hands to the minimizer.
+#[derive(Clone, Debug)]
+pub struct LineFunction<O, P> {
+    /// Cost function
+    pub problem: O,
+    /// Starting point `x`
+    pub param: P,
+    /// Search direction `d`
+    pub direction: P,
+}
+
+impl<O, P, F> CostFunction for LineFunction<O, P>
+where
+    O: CostFunction<Param = P, Output = F>,
+    P: ArgminScaledAdd<P, F, P>,
+    F: ArgminFloat,
+{
+    type Param = F;
+    type Output = F;
+
+    fn cost(&self, t: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem
+            .cost(&self.param.scaled_add(t, &self.direction))
+    }
+}
+
+/// # Exact line search
+///
+/// Line search which minimizes the cost function along the search direction using a
+/// one-dimensional minimizer such as [`BrentOpt`](`crate::solver::brent::BrentOpt`) or
+/// [`GoldenSectionSearch`](`crate::solver::goldensectionsearch::GoldenSectionSearch`).
+///
+/// In the initialization, a bracket `[a, c]` with an inner point `b` and `f(b) < f(a)`,
+/// `f(b) <= f(c)` is determined along the search direction, starting from the initial step length
+/// (default: 1). If the cost function does not decrease at the initial step length, the step is
+/// halved until it does; otherwise the bracket is expanded by the golden ratio. The minimizer
+/// then operates on `[a, c]` (using `b` as initial guess), with each iteration of this line
+/// search corresponding to one iteration of the minimizer. Therefore the number of iterations of
+/// the minimizer is available in the state returned by the line search.
+///
+/// Any minimizer implementing [`BracketedMinimizer`] can be used. The bounds passed to the
+/// minimizer on construction are ignored.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ExactLineSearch<P, S, F> {
+    /// Template of the one-dimensional minimizer
+    minimizer: S,
+    /// One-dimensional minimizer operating on the current bracket
+    solver: Option<S>,
+    /// State of the one-dimensional minimizer
+    solver_state: Option<IterState<F, (), (), (), F>>,
+    /// initial parameter vector
+    init_param: Option<P>,
+    /// Search direction
+    search_direction: Option<P>,
+    /// Initial step length
+    init_step: F,
+    /// Maximum number of iterations of the one-dimensional minimizer
+    max_iters: u64,
+}
+
+impl<P, S, F> ExactLineSearch<P, S, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `ExactLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::brent::BrentOpt;
+    /// # use argmin::solver::linesearch::ExactLineSearch;
+    /// let linesearch: ExactLineSearch<Vec<f64>, _, f64> =
+    ///     ExactLineSearch::new(BrentOpt::new(0.0, 1.0));
+    /// ```
+    pub fn new(minimizer: S) -> Self {
+        ExactLineSearch {
+            minimizer,
+            solver: None,
+            solver_state: None,
+            init_param: None,
+            search_direction: None,
+            init_step: float!(1.0),
+            max_iters: 100,
+        }
+    }
+
+    /// Set the maximum number of iterations of the one-dimensional minimizer
+    ///
+    /// Defaults to 100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::brent::BrentOpt;
+    /// # use argmin::solver::linesearch::ExactLineSearch;
+    /// # let linesearch: ExactLineSearch<Vec<f64>, _, f64> =
+    /// #     ExactLineSearch::new(BrentOpt::new(0.0, 1.0));
+    /// let linesearch = linesearch.with_max_iters(50);
+    /// ```
+    #[must_use]
+    pub fn with_max_iters(mut self, max_iters: u64) -> Self {
+        self.max_iters = max_iters;
+        self
+    }
+
+    /// Returns the current step length
+    fn step_length(&self) -> Result<F, Error> {
+        self.solver_state
+            .as_ref()
+            .and_then(|state| state.get_param().copied())
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`ExactLineSearch`: No step length returned by minimizer."
+            ))
+    }
+}
+
+impl<P, S, F> ExactLineSearch<P, S, F>
+where
+    P: ArgminScaledAdd<P, F, P>,
+    F: ArgminFloat,
+{
+    /// Determine a bracket `(a, b, c)` with `f(b) < f(a)` and `f(b) <= f(c)`
+    fn bracket<O>(&self, problem: &mut Problem<O>, cost: F) -> Result<(F, F, F), Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+    {
+        let (param, direction) = match (self.init_param.as_ref(), self.search_direction.as_ref()) {
+            (Some(param), Some(direction)) => (param, direction),
+            _ => {
+                return Err(argmin_error!(
+                    PotentialBug,
+                    "`ExactLineSearch`: Initial parameter vector or search direction not set."
+                ))
+            }
+        };
+        let mut phi = |t: F| problem.cost(&param.scaled_add(&t, direction));
+
+        let mut a = float!(0.0);
+        let mut b = self.init_step;
+        let mut fb = phi(b)?;
+        if fb.is_nan() || fb >= cost {
+            // Shrink until the cost function decreases
+            let mut c = b;
+            for _ in 0..MAX_BRACKET_ITERS {
+                b = float!(0.5) * c;
+                fb = phi(b)?;
+                if fb < cost {
+                    return Ok((a, b, c));
+                }
+                c = b;
+            }
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`ExactLineSearch`: No decrease of the cost function found along the search direction."
+            ));
+        }
+        // Expand until the cost function increases again
+        let golden = float!(GOLDEN_RATIO);
+        let mut c = b + golden * (b - a);
+        let mut fc = phi(c)?;
+        for _ in 0..MAX_BRACKET_ITERS {
+            if fc.is_nan() || fc >= fb {
+                return Ok((a, b, c));
+            }
+            a = b;
+            b = c;
+            fb = fc;
+            c = b + golden * (b - a);
+            fc = phi(c)?;
+        }
+        Err(argmin_error!(
+            ConditionViolated,
+            "`ExactLineSearch`: Cost function appears to be unbounded along the search direction."
+        ))
+    }
+
+    /// Run `func` on the one-dimensional problem along the search direction
+    fn with_line_problem<O, T>(
+        &mut self,
+        problem: &mut Problem<O>,
+        func: impl FnOnce(&mut S, &mut Problem<LineFunction<O, P>>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        let (param, direction) = match (self.init_param.take(), self.search_direction.take()) {
+            (Some(param), Some(direction)) => (param, direction),
+            _ => {
+                return Err(argmin_error!(
+                    PotentialBug,
+                    "`ExactLineSearch`: Initial parameter vector or search direction not set."
+                ))
+            }
+        };
+        let mut line_problem = Problem::new(LineFunction {
+            problem: problem.take_problem().ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`ExactLineSearch`: Failed to take `problem`."
+            ))?,
+            param,
+            direction,
+        });
+        let res = match self.solver.as_mut() {
+            Some(solver) => func(solver, &mut line_problem),
+            None => Err(argmin_error!(
+                PotentialBug,
+                "`ExactLineSearch`: Minimizer not initialized."
+            )),
+        };
+        // Hand back problem and take care of function evaluation counts
+        let LineFunction {
+            problem: inner,
+            param,
+            direction,
+        } = line_problem.take_problem().unwrap();
+        problem.problem = Some(inner);
+        problem.consume_func_counts(line_problem);
+        self.init_param = Some(param);
+        self.search_direction = Some(direction);
+        res
+    }
+
+    /// Returns the point `x + t * d` for the current step length `t`
+    fn current_param(&self) -> Result<P, Error> {
+        let t = self.step_length()?;
+        match (self.init_param.as_ref(), self.search_direction.as_ref()) {
+            (Some(param), Some(direction)) => Ok(param.scaled_add(&t, direction)),
+            _ => Err(argmin_error!(
+                PotentialBug,
+                "`ExactLineSearch`: Initial parameter vector or search direction not set."
+            )),
+        }
+    }
+}
+
+impl<P, S, F> LineSearch<P, F> for ExactLineSearch<P, S, F>
+where
+    F: ArgminFloat,
+{
+    /// Set search direction
+    fn search_direction(&mut self, search_direction: P) {
+        self.search_direction = Some(search_direction);
+    }
+
+    /// Set initial step length used for bracketing
+    fn initial_step_length(&mut self, alpha: F) -> Result<(), Error> {
+        if alpha <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "LineSearch: Initial alpha must be > 0."
+            ));
+        }
+        self.init_step = alpha;
+        Ok(())
+    }
+}
+
+impl<O, P, G, S, F> Solver<O, IterState<P, G, (), (), F>> for ExactLineSearch<P, S, F>
+where
+    O: CostFunction<Param = P, Output = F>,
+    P: Clone + SerializeAlias + ArgminScaledAdd<P, F, P>,
+    G: SerializeAlias,
+    S: Solver<LineFunction<O, P>, IterState<F, (), (), (), F>> + BracketedMinimizer<F> + Clone,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Exact line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        if self.search_direction.is_none() {
+            return Err(argmin_error!(
+                NotInitialized,
+                "ExactLineSearch: search_direction must be set."
+            ));
+        }
+
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ExactLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&init_param)?
+        } else {
+            cost
+        };
+
+        self.init_param = Some(init_param);
+        let (a, b, c) = self.bracket(problem, cost)?;
+
+        self.solver = Some(self.minimizer.with_bracket(a, c)?);
+        let (solver_state, _) = self.with_line_problem(problem, |solver, line_problem| {
+            solver.init(line_problem, IterState::new().param(b))
+        })?;
+        self.solver_state = Some(solver_state);
+
+        let cost = self.solver_state.as_ref().unwrap().get_cost();
+        Ok((
+            state.param(self.current_param()?).cost(cost),
+            Some(kv!("bracket_lower" => a; "bracket_upper" => c;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let solver_state = self.solver_state.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ExactLineSearch`: State of minimizer not set."
+        ))?;
+        let (mut solver_state, _) = self.with_line_problem(problem, |solver, line_problem| {
+            solver.next_iter(line_problem, solver_state)
+        })?;
+        solver_state.increment_iter();
+        let cost = solver_state.get_cost();
+        self.solver_state = Some(solver_state);
+
+        Ok((
+            state.param(self.current_param()?).cost(cost),
+            Some(kv!("step_length" => self.step_length()?;)),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        match (self.solver.as_mut(), self.solver_state.as_ref()) {
+            (Some(solver), Some(solver_state)) => {
+                let status = <S as Solver<LineFunction<O, P>, _>>::terminate(solver, solver_state);
+                if solver_state.terminated() || status.terminated() {
+                    TerminationStatus::Terminated(TerminationReason::SolverConverged)
+                } else if solver_state.get_iter() >= self.max_iters {
+                    TerminationStatus::Terminated(TerminationReason::MaxItersReached)
+                } else {
+                    TerminationStatus::NotTerminated
+                }
+            }
+            _ => TerminationStatus::NotTerminated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, Gradient};
+    use crate::solver::brent::BrentOpt;
+    use crate::solver::conjugategradient::{beta::FletcherReeves, NonlinearConjugateGradient};
+    use crate::solver::goldensectionsearch::GoldenSectionSearch;
+    use crate::solver::gradientdescent::SteepestDescent;
+    use approx::assert_relative_eq;
+
+    /// `f(x) = x_0^2 + 10 x_1^2`
+    #[derive(Clone)]
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p[0].powi(2) + 10.0 * p[1].powi(2))
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * p[0], 20.0 * p[1]])
+        }
+    }
+
+    /// `f(x) = (x_0 - m)^2`
+    #[derive(Clone)]
+    struct Shifted {
+        m: f64,
+    }
+
+    impl CostFunction for Shifted {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - self.m).powi(2))
+        }
+    }
+
+    /// `f(x) = -x_0`
+    #[derive(Clone)]
+    struct Linear {}
+
+    impl CostFunction for Linear {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(-p[0])
+        }
+    }
+
+    type LineSearchState = IterState<Vec<f64>, Vec<f64>, (), (), f64>;
+
+    test_trait_impl!(
+        exact_linesearch,
+        ExactLineSearch<Vec<f64>, BrentOpt<f64>, f64>
+    );
+
+    #[test]
+    fn test_new() {
+        let ls: ExactLineSearch<Vec<f64>, _, f64> = ExactLineSearch::new(BrentOpt::new(0.0, 1.0));
+        assert!(ls.solver.is_none());
+        assert!(ls.solver_state.is_none());
+        assert!(ls.init_param.is_none());
+        assert!(ls.search_direction.is_none());
+        assert_eq!(ls.init_step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(ls.max_iters, 100);
+        let ls = ls.with_max_iters(10);
+        assert_eq!(ls.max_iters, 10);
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let mut ls: ExactLineSearch<Vec<f64>, _, f64> =
+            ExactLineSearch::new(BrentOpt::new(0.0, 1.0));
+        assert_error!(
+            ls.initial_step_length(0.0),
+            ArgminError,
+            "Invalid parameter: \"LineSearch: Initial alpha must be > 0.\""
+        );
+        assert_error!(
+            Executor::new(Quadratic {}, ls.clone())
+                .configure(|config: LineSearchState| config.param(vec![1.0, 1.0]))
+                .run(),
+            ArgminError,
+            "Not initialized: \"ExactLineSearch: search_direction must be set.\""
+        );
+        ls.search_direction(vec![1.0, 1.0]);
+        let res: Result<(LineSearchState, _), _> =
+            ls.init(&mut Problem::new(Quadratic {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ExactLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_bracket() {
+        let mut ls: ExactLineSearch<Vec<f64>, _, f64> =
+            ExactLineSearch::new(BrentOpt::new(0.0, 1.0));
+        ls.init_param = Some(vec![0.0]);
+        ls.search_direction(vec![1.0]);
+
+        // Expansion
+        let (a, b, c) = ls
+            .bracket(&mut Problem::new(Shifted { m: 3.0 }), 9.0)
+            .unwrap();
+        assert_relative_eq!(a, 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(b, 1.0 + GOLDEN_RATIO, epsilon = f64::EPSILON);
+        assert_relative_eq!(
+            c,
+            1.0 + GOLDEN_RATIO * (1.0 + GOLDEN_RATIO),
+            epsilon = 1e-12
+        );
+
+        // Contraction
+        let (a, b, c) = ls
+            .bracket(&mut Problem::new(Shifted { m: 0.1 }), 0.01)
+            .unwrap();
+        assert_eq!(a.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(b.to_ne_bytes(), 0.125f64.to_ne_bytes());
+        assert_eq!(c.to_ne_bytes(), 0.25f64.to_ne_bytes());
+
+        // No decrease along an ascent direction
+        let res = ls.bracket(&mut Problem::new(Shifted { m: -1.0 }), 1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`ExactLineSearch`: No decrease of the cost function ",
+                "found along the search direction.\""
+            )
+        );
+
+        // No increase along a direction in which the cost function is unbounded
+        let res = ls.bracket(&mut Problem::new(Linear {}), 0.0);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`ExactLineSearch`: Cost function appears to be unbounded ",
+                "along the search direction.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_executor() {
+        // Exact minimizer along the steepest descent direction at `[1, 1]`
+        let t: f64 = 404.0 / 8008.0;
+        let expected = [1.0 - 2.0 * t, 1.0 - 20.0 * t];
+
+        let mut brent: ExactLineSearch<Vec<f64>, _, f64> =
+            ExactLineSearch::new(BrentOpt::new(0.0, 1.0).set_tolerance(1e-8, 1e-10));
+        brent.search_direction(vec![-2.0, -20.0]);
+        let res = Executor::new(Quadratic {}, brent)
+            .configure(|config: LineSearchState| config.param(vec![1.0, 1.0]))
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], expected[0], epsilon = 1e-8);
+        assert_relative_eq!(param[1], expected[1], epsilon = 1e-8);
+        assert!(res.state.get_iter() > 0);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+
+        let mut gss: ExactLineSearch<Vec<f64>, _, f64> = ExactLineSearch::new(
+            GoldenSectionSearch::new(0.0, 1.0)
+                .unwrap()
+                .with_tolerance(1e-8)
+                .unwrap(),
+        );
+        gss.search_direction(vec![-2.0, -20.0]);
+        gss.initial_step_length(0.01).unwrap();
+        let res = Executor::new(Quadratic {}, gss)
+            .configure(|config: LineSearchState| config.param(vec![1.0, 1.0]))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], expected[0], epsilon = 1e-6);
+        assert_relative_eq!(param[1], expected[1], epsilon = 1e-6);
+
+        // Limit the number of iterations of the minimizer
+        let mut brent: ExactLineSearch<Vec<f64>, _, f64> =
+            ExactLineSearch::new(BrentOpt::new(0.0, 1.0)).with_max_iters(2);
+        brent.search_direction(vec![-2.0, -20.0]);
+        let res = Executor::new(Quadratic {}, brent)
+            .configure(|config: LineSearchState| config.param(vec![1.0, 1.0]))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_iter(), 2);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+    }
+
+    #[test]
+    fn test_steepest_descent() {
+        let linesearch: ExactLineSearch<Vec<f64>, _, f64> =
+            ExactLineSearch::new(BrentOpt::new(0.0, 1.0));
+        let mut sd = SteepestDescent::new(linesearch);
+        let (_, kv) = sd
+            .next_iter(
+                &mut Problem::new(Quadratic {}),
+                IterState::new().param(vec![1.0, 1.0]),
+            )
+            .unwrap();
+        assert!(
+            kv.unwrap()
+                .get("linesearch_iters")
+                .unwrap()
+                .get_uint()
+                .unwrap()
+                > 0
+        );
+
+        let linesearch: ExactLineSearch<Vec<f64>, _, f64> =
+            ExactLineSearch::new(BrentOpt::new(0.0, 1.0).set_tolerance(1e-8, 1e-10));
+        let res = Executor::new(Quadratic {}, SteepestDescent::new(linesearch))
+            .configure(|config| config.param(vec![1.0, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert!(res.state.get_best_cost() < 1e-12);
+    }
+
+    #[test]
+    fn test_nonlinear_cg() {
+        // With an exact line search, the Fletcher-Reeves method minimizes a quadratic in `n`
+        // iterations.
+        let linesearch: ExactLineSearch<Vec<f64>, _, f64> =
+            ExactLineSearch::new(BrentOpt::new(0.0, 1.0).set_tolerance(1e-8, 1e-12));
+        let solver = NonlinearConjugateGradient::new(linesearch, FletcherReeves::new());
+        let res = Executor::new(Quadratic {}, solver)
+            .configure(|config| config.param(vec![1.0, 1.0]).max_iters(2))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 0.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], 0.0, epsilon = 1e-6);
+    }
+}
@@ -17,8 +17,10 @@
 //! ## Available line searches
 //!
 //! * [Backtracking line search](`BacktrackingLineSearch`)
+//! * [Exact line search](`ExactLineSearch`) using one-dimensional minimizers
 //! * [More-Thuente line search](`MoreThuenteLineSearch`)
 //! * [Hager-Zhang line search](`HagerZhangLineSearch`)
+//! * [Nonmonotone line search](`NonmonotoneLineSearch`)
 //!
 //! ## References
 //!
@@ -32,16 +34,28 @@
 //! \[2\] William W. Hager and Hongchao Zhang. "A new conjugate gradient method with guaranteed
 //! descent and an efficient line search." SIAM J. Optim. 16(1), 2006, 170-192.
 //! DOI: <https://doi.org/10.1137/030601880>
+//!
+//! \[3\] Luigi Grippo, Francesco Lampariello and Stefano Lucidi. "A Nonmonotone Line Search
+//! Technique for Newton's Method." SIAM J. Numer. Anal. 23(4), 1986, 707-716.
+//! DOI: <https://doi.org/10.1137/0723046>
+//!
+//! \[4\] Hongchao Zhang and William W. Hager. "A Nonmonotone Line Search Technique and Its
+//! Application to Unconstrained Optimization." SIAM J. Optim. 14(4), 2004, 1043-1056.
+//! DOI: <https://doi.org/10.1137/S1052623403428208>
 
 mod backtracking;
 /// Acceptance conditions
 pub mod condition;
+mod exact;
 mod hagerzhang;
 mod morethuente;
+mod nonmonotone;
 
 pub use self::backtracking::BacktrackingLineSearch;
+pub use self::exact::{BracketedMinimizer, ExactLineSearch, LineFunction};
 pub use self::hagerzhang::HagerZhangLineSearch;
 pub use self::morethuente::MoreThuenteLineSearch;
+pub use self::nonmonotone::{NonmonotoneLineSearch, NonmonotoneReference};
 
 /// # Line search trait
 ///
@@ -84,4 +98,11 @@ pub trait LineSearch<P, F> {
     ///
     /// This indicates the first step length which will be tried.
     fn initial_step_length(&mut self, step_length: F) -> Result<(), crate::core::Error>;
+
+    /// Record the cost function value at the starting point of the next line search
+    ///
+    /// Optimization methods call this before each line search. Nonmonotone line searches use it
+    /// to keep track of the history of cost function values; the default implementation does
+    /// nothing.
+    fn record_cost(&mut self, _cost: F) {}
 }
@@ -13,7 +13,7 @@ use crate::core::{
     ArgminFloat, CostFunction, Error, Gradient, IterState, LineSearch, Problem, SerializeAlias,
     Solver, State, TerminationReason, KV,
 };
-use argmin_math::{ArgminDot, ArgminScaledAdd};
+use argmin_math::{ArgminDot, ArgminScaledAddAssign};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -299,7 +299,7 @@ where
 impl<P, G, O, F> Solver<O, IterState<P, G, (), (), F>> for MoreThuenteLineSearch<P, G, F>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
-    P: Clone + SerializeAlias + ArgminDot<G, F> + ArgminScaledAdd<P, F, P>,
+    P: Clone + SerializeAlias + ArgminDot<G, F> + ArgminScaledAddAssign<P, F>,
     G: Clone + SerializeAlias + ArgminDot<P, F>,
     F: ArgminFloat,
 {
@@ -372,7 +372,7 @@ where
     fn next_iter(
         &mut self,
         problem: &mut Problem<O>,
-        state: IterState<P, G, (), (), F>,
+        mut state: IterState<P, G, (), (), F>,
     ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
         // set the minimum and maximum steps to correspond to the present interval of uncertainty
         let mut info = 0;
@@ -399,11 +399,16 @@ where
         }
 
         // Evaluate the function and gradient at new stp.x and compute the directional derivative
-        let new_param = self
-            .init_param
-            .as_ref()
-            .unwrap()
-            .scaled_add(&self.stp.x, self.search_direction.as_ref().unwrap());
+        let init_param = self.init_param.as_ref().unwrap();
+        // Reuse the buffer of the previous parameter vector (if available) for the new one
+        let mut new_param = match state.take_prev_param() {
+            Some(mut buf) => {
+                buf.clone_from(init_param);
+                buf
+            }
+            None => init_param.clone(),
+        };
+        new_param.scaled_add_assign(&self.stp.x, self.search_direction.as_ref().unwrap());
         self.f = problem.cost(&new_param)?;
         let new_grad = problem.gradient(&new_param)?;
         let cur_cost = self.f;
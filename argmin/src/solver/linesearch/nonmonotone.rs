@@ -0,0 +1,638 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, LineSearch, Problem, SerializeAlias,
+    Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::linesearch::condition::*;
+use argmin_math::{ArgminScaledAdd, ArgminScaledAddAssign};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Reference cost function value of a [`NonmonotoneLineSearch`]
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum NonmonotoneReference<F> {
+    /// Maximum of the last `memory` cost function values (Grippo, Lampariello and Lucidi)
+    MaxCost(usize),
+    /// Weighted average of all previous cost function values, where the weights decay by a
+    /// factor `eta` in `[0, 1]` (Zhang and Hager)
+    AverageCost(F),
+}
+
+/// # Nonmonotone line search
+///
+/// Backtracking line search which evaluates the acceptance condition with respect to a reference
+/// cost function value computed from the cost function values at the starting points of previous
+/// line searches instead of the current cost function value. This allows the cost function to
+/// increase temporarily, which often helps in narrow curved valleys.
+///
+/// Two reference values are available via [`NonmonotoneReference`]:
+///
+/// * [`MaxCost`](`NonmonotoneReference::MaxCost`): Maximum of the last `memory` cost function
+///   values (Grippo, Lampariello and Lucidi). This is the default with `memory = 10`.
+/// * [`AverageCost`](`NonmonotoneReference::AverageCost`): Weighted average `C_k` of all previous
+///   cost function values with `Q_{k+1} = eta * Q_k + 1` and
+///   `C_{k+1} = (eta * Q_k * C_k + f_{k+1}) / Q_{k+1}` (Zhang and Hager).
+///
+/// With `memory = 1` or `eta = 0` respectively, this is equivalent to a monotone
+/// [`BacktrackingLineSearch`](`crate::solver::linesearch::BacktrackingLineSearch`).
+///
+/// The history of cost function values is kept in the instance of the line search held by the
+/// optimization method, which reports the cost function value at each starting point via
+/// [`LineSearch::record_cost`]. If no cost function values were recorded, the cost function value
+/// at the starting point is used as reference.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## References
+///
+/// Luigi Grippo, Francesco Lampariello and Stefano Lucidi (1986). A Nonmonotone Line Search
+/// Technique for Newton's Method. SIAM Journal on Numerical Analysis 23(4), 707–716.
+/// <https://doi.org/10.1137/0723046>
+///
+/// Hongchao Zhang and William W. Hager (2004). A Nonmonotone Line Search Technique and Its
+/// Application to Unconstrained Optimization. SIAM Journal on Optimization 14(4), 1043–1056.
+/// <https://doi.org/10.1137/S1052623403428208>
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NonmonotoneLineSearch<P, G, L, F> {
+    /// initial parameter vector
+    init_param: Option<P>,
+    /// initial cost
+    init_cost: F,
+    /// initial gradient
+    init_grad: Option<G>,
+    /// reference cost
+    ref_cost: F,
+    /// Search direction
+    search_direction: Option<P>,
+    /// Contraction factor rho
+    rho: F,
+    /// Stopping condition
+    condition: L,
+    /// alpha
+    alpha: F,
+    /// Kind of reference cost
+    reference: NonmonotoneReference<F>,
+    /// Recorded cost function values (`MaxCost`)
+    costs: VecDeque<F>,
+    /// Weighted average of recorded cost function values (`AverageCost`)
+    avg_cost: F,
+    /// Sum of weights of recorded cost function values (`AverageCost`)
+    avg_weight: F,
+}
+
+impl<P, G, L, F> NonmonotoneLineSearch<P, G, L, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `NonmonotoneLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::NonmonotoneLineSearch;
+    /// # use argmin::solver::linesearch::condition::ArmijoCondition;
+    ///
+    /// let linesearch: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, _, f64> =
+    ///     NonmonotoneLineSearch::new(ArmijoCondition::new(0.0001f64));
+    /// ```
+    pub fn new(condition: L) -> Self {
+        NonmonotoneLineSearch {
+            init_param: None,
+            init_cost: F::infinity(),
+            init_grad: None,
+            ref_cost: F::infinity(),
+            search_direction: None,
+            rho: float!(0.5),
+            condition,
+            alpha: float!(1.0),
+            reference: NonmonotoneReference::MaxCost(10),
+            costs: VecDeque::new(),
+            avg_cost: F::infinity(),
+            avg_weight: float!(0.0),
+        }
+    }
+
+    /// Set contraction factor rho
+    ///
+    /// This factor must be in (0, 1) and defaults to 0.5.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NonmonotoneLineSearch;
+    /// # use argmin::solver::linesearch::condition::ArmijoCondition;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, _, f64> =
+    /// #     NonmonotoneLineSearch::new(ArmijoCondition::new(0.0001f64));
+    /// let linesearch = linesearch.rho(0.9)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rho(mut self, rho: F) -> Result<Self, Error> {
+        if rho <= float!(0.0) || rho >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "NonmonotoneLineSearch: Contraction factor rho must be in (0, 1)."
+            ));
+        }
+        self.rho = rho;
+        Ok(self)
+    }
+
+    /// Set the reference cost function value
+    ///
+    /// `memory` of [`MaxCost`](`NonmonotoneReference::MaxCost`) must be at least 1 and `eta` of
+    /// [`AverageCost`](`NonmonotoneReference::AverageCost`) must be in `[0, 1]`. Defaults to
+    /// `MaxCost(10)`. Discards all previously recorded cost function values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::{NonmonotoneLineSearch, NonmonotoneReference};
+    /// # use argmin::solver::linesearch::condition::ArmijoCondition;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, _, f64> =
+    /// #     NonmonotoneLineSearch::new(ArmijoCondition::new(0.0001f64));
+    /// let linesearch = linesearch.with_reference(NonmonotoneReference::AverageCost(0.85))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_reference(mut self, reference: NonmonotoneReference<F>) -> Result<Self, Error> {
+        match reference {
+            NonmonotoneReference::MaxCost(memory) if memory < 1 => {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "NonmonotoneLineSearch: memory must be >= 1."
+                ));
+            }
+            NonmonotoneReference::AverageCost(eta)
+                if eta.is_nan() || eta < float!(0.0) || eta > float!(1.0) =>
+            {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "NonmonotoneLineSearch: eta must be in [0, 1]."
+                ));
+            }
+            _ => {}
+        }
+        self.reference = reference;
+        self.costs.clear();
+        self.avg_cost = F::infinity();
+        self.avg_weight = float!(0.0);
+        Ok(self)
+    }
+
+    /// Returns the reference cost function value for a line search starting at `cost`
+    fn reference_cost(&self, cost: F) -> F {
+        let reference = match self.reference {
+            NonmonotoneReference::MaxCost(_) => self
+                .costs
+                .iter()
+                .copied()
+                .fold(F::neg_infinity(), |max, c| max.max(c)),
+            NonmonotoneReference::AverageCost(_) if self.avg_weight > float!(0.0) => self.avg_cost,
+            NonmonotoneReference::AverageCost(_) => F::neg_infinity(),
+        };
+        reference.max(cost)
+    }
+}
+
+impl<P, G, L, F> LineSearch<P, F> for NonmonotoneLineSearch<P, G, L, F>
+where
+    F: ArgminFloat,
+{
+    /// Set search direction
+    fn search_direction(&mut self, search_direction: P) {
+        self.search_direction = Some(search_direction);
+    }
+
+    /// Set initial step length
+    fn initial_step_length(&mut self, alpha: F) -> Result<(), Error> {
+        if alpha <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "LineSearch: Initial alpha must be > 0."
+            ));
+        }
+        self.alpha = alpha;
+        Ok(())
+    }
+
+    /// Record cost function value at the starting point of the next line search
+    fn record_cost(&mut self, cost: F) {
+        if !cost.is_finite() {
+            return;
+        }
+        match self.reference {
+            NonmonotoneReference::MaxCost(memory) => {
+                self.costs.push_back(cost);
+                while self.costs.len() > memory {
+                    self.costs.pop_front();
+                }
+            }
+            NonmonotoneReference::AverageCost(eta) => {
+                if self.avg_weight > float!(0.0) {
+                    let weight = eta * self.avg_weight;
+                    self.avg_weight = weight + float!(1.0);
+                    self.avg_cost = (weight * self.avg_cost + cost) / self.avg_weight;
+                } else {
+                    self.avg_weight = float!(1.0);
+                    self.avg_cost = cost;
+                }
+            }
+        }
+    }
+}
+
+impl<P, G, L, F> NonmonotoneLineSearch<P, G, L, F>
+where
+    P: Clone + ArgminScaledAddAssign<P, F>,
+    L: LineSearchCondition<P, G, F>,
+    IterState<P, G, (), (), F>: State<Float = F>,
+    F: ArgminFloat,
+{
+    /// Perform a single backtracking step
+    fn backtracking_step<O>(
+        &self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<IterState<P, G, (), (), F>, Error>
+    where
+        O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    {
+        let init_param = self.init_param.as_ref().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NonmonotoneLineSearch`: Initial parameter vector not set."
+        ))?;
+        let search_direction = self
+            .search_direction
+            .as_ref()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`NonmonotoneLineSearch`: Search direction not set."
+            ))?;
+
+        // Reuse the buffer of the previous parameter vector (if available) for the new one
+        let mut new_param = match state.take_prev_param() {
+            Some(mut buf) => {
+                buf.clone_from(init_param);
+                buf
+            }
+            None => init_param.clone(),
+        };
+        new_param.scaled_add_assign(&self.alpha, search_direction);
+
+        let cur_cost = problem.cost(&new_param)?;
+
+        let out = if self.condition.requires_current_gradient() {
+            state
+                .gradient(problem.gradient(&new_param)?)
+                .param(new_param)
+                .cost(cur_cost)
+        } else {
+            state.param(new_param).cost(cur_cost)
+        };
+
+        Ok(out)
+    }
+}
+
+impl<O, P, G, L, F> Solver<O, IterState<P, G, (), (), F>> for NonmonotoneLineSearch<P, G, L, F>
+where
+    P: Clone + SerializeAlias + ArgminScaledAddAssign<P, F>,
+    G: SerializeAlias + ArgminScaledAdd<P, F, P>,
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    L: LineSearchCondition<P, G, F> + SerializeAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Nonmonotone line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        if self.search_direction.is_none() {
+            return Err(argmin_error!(
+                NotInitialized,
+                "NonmonotoneLineSearch: search_direction must be set."
+            ));
+        }
+
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`NonmonotoneLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+
+        self.init_cost = if cost.is_infinite() {
+            problem.cost(&init_param)?
+        } else {
+            cost
+        };
+        self.ref_cost = self.reference_cost(self.init_cost);
+
+        let init_grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&init_param))?;
+
+        self.init_param = Some(init_param);
+        self.init_grad = Some(init_grad);
+        let state = self.backtracking_step(problem, state)?;
+        Ok((state, Some(kv!("reference_cost" => self.ref_cost;))))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        self.alpha = self.alpha * self.rho;
+        let state = self.backtracking_step(problem, state)?;
+        Ok((state, None))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        if self.condition.evaluate_condition(
+            state.cost,
+            state.get_gradient(),
+            self.ref_cost,
+            self.init_grad.as_ref().unwrap(),
+            self.search_direction.as_ref().unwrap(),
+            self.alpha,
+        ) {
+            TerminationStatus::Terminated(TerminationReason::SolverConverged)
+        } else {
+            TerminationStatus::NotTerminated
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor, State};
+    use crate::solver::quasinewton::LBFGS;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    type Armijo = NonmonotoneLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64>;
+
+    #[derive(Debug, Clone)]
+    struct NMTestProblem {}
+
+    impl CostFunction for NMTestProblem {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p[0].powi(2) + p[1].powi(2))
+        }
+    }
+
+    impl Gradient for NMTestProblem {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * p[0], 2.0 * p[1]])
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    test_trait_impl!(nonmonotonelinesearch,
+                    NonmonotoneLineSearch<TestProblem, Vec<f64>, ArmijoCondition<f64>, f64>);
+
+    #[test]
+    fn test_new() {
+        let ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.01).unwrap());
+
+        assert_eq!(ls.init_param, None);
+        assert!(ls.init_cost.is_infinite());
+        assert!(ls.ref_cost.is_infinite());
+        assert_eq!(ls.init_grad, None);
+        assert_eq!(ls.search_direction, None);
+        assert_eq!(ls.rho.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(ls.alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(ls.reference, NonmonotoneReference::MaxCost(10));
+        assert!(ls.costs.is_empty());
+        assert_eq!(ls.avg_weight.to_ne_bytes(), 0.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_rho() {
+        let ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.01).unwrap());
+        for rho in [0.0, 1.0, -1.0, 2.0] {
+            assert_error!(
+                ls.clone().rho(rho),
+                ArgminError,
+                "Invalid parameter: \"NonmonotoneLineSearch: Contraction factor rho must be in (0, 1).\""
+            );
+        }
+        let ls = ls.rho(0.9).unwrap();
+        assert_eq!(ls.rho.to_ne_bytes(), 0.9f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_reference() {
+        let ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.01).unwrap());
+        assert_error!(
+            ls.clone().with_reference(NonmonotoneReference::MaxCost(0)),
+            ArgminError,
+            "Invalid parameter: \"NonmonotoneLineSearch: memory must be >= 1.\""
+        );
+        for eta in [-0.1, 1.1, f64::NAN] {
+            assert_error!(
+                ls.clone()
+                    .with_reference(NonmonotoneReference::AverageCost(eta)),
+                ArgminError,
+                "Invalid parameter: \"NonmonotoneLineSearch: eta must be in [0, 1].\""
+            );
+        }
+        for reference in [
+            NonmonotoneReference::MaxCost(1),
+            NonmonotoneReference::AverageCost(0.0),
+            NonmonotoneReference::AverageCost(1.0),
+        ] {
+            let ls = ls.clone().with_reference(reference).unwrap();
+            assert_eq!(ls.reference, reference);
+        }
+    }
+
+    #[test]
+    fn test_record_cost_max() {
+        let mut ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.01).unwrap())
+            .with_reference(NonmonotoneReference::MaxCost(3))
+            .unwrap();
+        assert_eq!(ls.reference_cost(1.0).to_ne_bytes(), 1.0f64.to_ne_bytes());
+        for cost in [1.0, 5.0, f64::INFINITY, 2.0, f64::NAN, 3.0] {
+            ls.record_cost(cost);
+        }
+        assert_eq!(ls.costs, vec![5.0, 2.0, 3.0]);
+        assert_eq!(ls.reference_cost(3.0).to_ne_bytes(), 5.0f64.to_ne_bytes());
+        ls.record_cost(1.0);
+        assert_eq!(ls.reference_cost(1.0).to_ne_bytes(), 3.0f64.to_ne_bytes());
+        // Changing the reference discards the history
+        let ls = ls.with_reference(NonmonotoneReference::MaxCost(3)).unwrap();
+        assert!(ls.costs.is_empty());
+    }
+
+    #[test]
+    fn test_record_cost_average() {
+        let mut ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.01).unwrap())
+            .with_reference(NonmonotoneReference::AverageCost(0.5))
+            .unwrap();
+        assert_eq!(ls.reference_cost(1.0).to_ne_bytes(), 1.0f64.to_ne_bytes());
+        ls.record_cost(4.0);
+        assert_relative_eq!(ls.reference_cost(4.0), 4.0, epsilon = f64::EPSILON);
+        ls.record_cost(1.0);
+        assert_relative_eq!(ls.avg_weight, 1.5, epsilon = f64::EPSILON);
+        assert_relative_eq!(ls.reference_cost(1.0), 2.0, epsilon = f64::EPSILON);
+        ls.record_cost(0.5);
+        assert_relative_eq!(ls.avg_weight, 1.75, epsilon = f64::EPSILON);
+        assert_relative_eq!(ls.reference_cost(0.5), 2.0 / 1.75, epsilon = f64::EPSILON);
+
+        // eta = 0 only takes the last cost function value into account
+        let mut ls = ls
+            .with_reference(NonmonotoneReference::AverageCost(0.0))
+            .unwrap();
+        ls.record_cost(4.0);
+        ls.record_cost(1.0);
+        assert_relative_eq!(ls.reference_cost(1.0), 1.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.2).unwrap());
+        ls.search_direction(vec![1.0f64, 1.0]);
+        let res = ls.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`NonmonotoneLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_search_direction_not_set() {
+        let mut ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.2).unwrap());
+        assert_error!(
+            Executor::new(NMTestProblem {}, ls.clone())
+                .configure(|config| config.param(vec![-1.0, 0.0]))
+                .run(),
+            ArgminError,
+            "Not initialized: \"NonmonotoneLineSearch: search_direction must be set.\""
+        );
+        assert_error!(
+            ls.initial_step_length(0.0),
+            ArgminError,
+            "Invalid parameter: \"LineSearch: Initial alpha must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_executor() {
+        let mut ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(0.01).unwrap());
+        ls.search_direction(vec![3.0, 0.0]);
+
+        // Without history, the line search is monotone and the full step to `[2, 0]` (cost 4) is
+        // rejected.
+        let res = Executor::new(NMTestProblem {}, ls.clone())
+            .configure(|config| config.param(vec![-1.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_param().unwrap(), &vec![0.5, 0.0]);
+        assert_eq!(res.state.get_iter(), 1);
+
+        // With a cost function value of 5 in the history, the full step is accepted.
+        ls.record_cost(5.0);
+        ls.record_cost(1.0);
+        let res = Executor::new(NMTestProblem {}, ls.clone())
+            .configure(|config| config.param(vec![-1.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_param().unwrap(), &vec![2.0, 0.0]);
+        assert_eq!(res.state.get_iter(), 0);
+        assert_eq!(
+            res.state.termination_status,
+            TerminationStatus::Terminated(TerminationReason::SolverConverged)
+        );
+
+        // With a weighted average of 1, the full step is rejected again.
+        let mut ls = ls
+            .with_reference(NonmonotoneReference::AverageCost(0.85))
+            .unwrap();
+        ls.record_cost(1.0);
+        let res = Executor::new(NMTestProblem {}, ls)
+            .configure(|config| config.param(vec![-1.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_param().unwrap(), &vec![0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_lbfgs() {
+        // With a nonmonotone line search, L-BFGS converges to the minimum of the Rosenbrock
+        // function at `[1, 1]` for both reference values.
+        for reference in [
+            NonmonotoneReference::MaxCost(10),
+            NonmonotoneReference::AverageCost(0.85),
+        ] {
+            let ls: Armijo = NonmonotoneLineSearch::new(ArmijoCondition::new(1e-4).unwrap())
+                .with_reference(reference)
+                .unwrap();
+            let res = Executor::new(Rosenbrock {}, LBFGS::new(ls, 7))
+                .configure(|config| config.param(vec![-1.2, 1.0]).max_iters(200))
+                .run()
+                .unwrap();
+            let param = res.state.get_best_param().unwrap();
+            assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(param[1], 1.0, epsilon = 1e-6);
+            assert_eq!(
+                res.state.termination_status,
+                TerminationStatus::Terminated(TerminationReason::SolverConverged)
+            );
+        }
+    }
+}
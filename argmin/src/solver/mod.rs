@@ -5,16 +5,29 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+pub mod bayesian;
 pub mod brent;
 pub mod conjugategradient;
+pub mod curvature;
+pub mod direct;
+pub mod fixedpoint;
+pub mod frankwolfe;
 pub mod gaussnewton;
 pub mod goldensectionsearch;
 pub mod gradientdescent;
+pub mod krylov;
 pub mod landweber;
+pub mod linearleastsquares;
 pub mod linesearch;
 pub mod neldermead;
 pub mod newton;
+pub mod nnls;
 pub mod particleswarm;
+pub mod preconditioner;
+pub mod projectedgradient;
+pub mod projection;
 pub mod quasinewton;
 pub mod simulatedannealing;
 pub mod trustregion;
+
+pub(crate) mod util;
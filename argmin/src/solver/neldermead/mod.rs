@@ -867,4 +867,31 @@ mod tests {
         assert_relative_eq!(nm.params[2].0[1], 0.0f64, epsilon = f64::EPSILON);
         assert_relative_eq!(nm.params[2].1, 1.00f64, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_solver_array() {
+        use crate::core::Executor;
+
+        struct ArrayProblem {}
+
+        impl CostFunction for ArrayProblem {
+            type Param = [f64; 2];
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok((p[0] - 1.0).powi(2) + (p[1] + 2.0).powi(2))
+            }
+        }
+
+        let solver = NelderMead::new(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]])
+            .with_sd_tolerance(1e-12)
+            .unwrap();
+        let res = Executor::new(ArrayProblem {}, solver)
+            .configure(|state| state.max_iters(500))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], -2.0, epsilon = 1e-4);
+    }
 }
@@ -9,6 +9,7 @@
 //!
 //! * [`Newton`]
 //! * [`NewtonCG`]
+//! * [`NewtonLS`]
 //!
 //! # Reference
 //!
@@ -17,8 +18,11 @@
 
 /// Newton-CG method
 mod newton_cg;
+/// Newton's method with line search
+mod newton_linesearch;
 /// Newton's method
 mod newton_method;
 
 pub use self::newton_cg::NewtonCG;
+pub use self::newton_linesearch::{HessianModification, NewtonLS};
 pub use self::newton_method::Newton;
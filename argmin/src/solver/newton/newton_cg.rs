@@ -6,25 +6,41 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian, IterState, LineSearch,
-    Operator, OptimizationResult, Problem, SerializeAlias, Solver, State, TerminationReason,
+    ArgminFloat, DeserializeOwnedAlias, Error, Executor, Gradient, IterState, LineSearch, Operator,
+    OptimizationResult, Problem, SerializeAlias, Solver, State, TerminationReason,
     TerminationStatus, KV,
 };
 use crate::solver::conjugategradient::ConjugateGradient;
+use crate::solver::curvature::{Curvature, EvaluateCurvature, ExactHessian};
+use crate::solver::preconditioner::{IdentityPreconditioner, Preconditioner};
 use argmin_math::{
-    ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub, ArgminZeroLike,
+    ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminMulAssign, ArgminScaledAdd,
+    ArgminScaledAddAssign, ArgminSub, ArgminZeroLike,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 
 /// # Newton-Conjugate-Gradient (Newton-CG) method
 ///
 /// The Newton-CG method (also called truncated Newton method) uses a modified CG to approximately
 /// solve the Newton equations. After a search direction is found, a line search is performed.
 ///
+/// The CG iterations can be preconditioned via
+/// [`with_preconditioner`](`NewtonCG::with_preconditioner`). The preconditioner is kept fixed
+/// during the entire optimization, therefore it should approximate the Hessian in the region of
+/// interest, for instance by computing it from the Hessian at the initial guess.
+///
+/// By default, the Hessian is computed in every iteration. Since the CG iterations only require
+/// products of the Hessian with vectors, the Hessian can instead be replaced with
+/// Hessian-vector products or finite differences of gradients via
+/// [`with_curvature`](`NewtonCG::with_curvature`) (see [`curvature`](`crate::solver::curvature`)).
+///
 /// ## Requirements on the optimization problem
 ///
-/// The optimization problem is required to implement [`Gradient`] and [`Hessian`].
+/// The optimization problem is required to implement [`Gradient`] and, depending on the chosen
+/// [`curvature`](`crate::solver::curvature`), [`Hessian`](`crate::core::Hessian`) (default) or
+/// [`HessianVectorProduct`](`crate::core::HessianVectorProduct`).
 ///
 /// ## Reference
 ///
@@ -32,9 +48,13 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct NewtonCG<L, F> {
+pub struct NewtonCG<L, F, M = IdentityPreconditioner, C = ExactHessian> {
     /// line search
     linesearch: L,
+    /// preconditioner for the CG iterations
+    preconditioner: M,
+    /// computation of Hessian-vector products
+    curvature: C,
     /// curvature_threshold
     curvature_threshold: F,
     /// Tolerance for the stopping criterion based on cost difference
@@ -57,10 +77,72 @@ where
     pub fn new(linesearch: L) -> Self {
         NewtonCG {
             linesearch,
+            preconditioner: IdentityPreconditioner,
+            curvature: ExactHessian,
             curvature_threshold: float!(0.0),
             tol: F::epsilon(),
         }
     }
+}
+
+impl<L, F, M, C> NewtonCG<L, F, M, C>
+where
+    F: ArgminFloat,
+{
+    /// Set preconditioner for the CG iterations
+    ///
+    /// The preconditioner must be symmetric and positive definite. Defaults to
+    /// [`IdentityPreconditioner`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::NewtonCG;
+    /// # use argmin::solver::preconditioner::JacobiPreconditioner;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// # let hessian = vec![vec![4.0f64, 1.0], vec![1.0, 100.0]];
+    /// let preconditioner = JacobiPreconditioner::new(&hessian)?;
+    /// let ncg: NewtonCG<_, f64, _> = NewtonCG::new(linesearch).with_preconditioner(preconditioner);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_preconditioner<M2>(self, preconditioner: M2) -> NewtonCG<L, F, M2, C> {
+        NewtonCG {
+            linesearch: self.linesearch,
+            preconditioner,
+            curvature: self.curvature,
+            curvature_threshold: self.curvature_threshold,
+            tol: self.tol,
+        }
+    }
+
+    /// Set how products of the Hessian with vectors are computed
+    ///
+    /// Defaults to [`ExactHessian`]. See [`curvature`](`crate::solver::curvature`) for the
+    /// alternatives.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::NewtonCG;
+    /// # use argmin::solver::curvature::FiniteDiffHessianVectorProducts;
+    /// # let linesearch = ();
+    /// let fd: FiniteDiffHessianVectorProducts<f64> = FiniteDiffHessianVectorProducts::new();
+    /// let ncg: NewtonCG<_, f64, _, _> = NewtonCG::new(linesearch).with_curvature(fd);
+    /// ```
+    #[must_use]
+    pub fn with_curvature<C2>(self, curvature: C2) -> NewtonCG<L, F, M, C2> {
+        NewtonCG {
+            linesearch: self.linesearch,
+            preconditioner: self.preconditioner,
+            curvature,
+            curvature_threshold: self.curvature_threshold,
+            tol: self.tol,
+        }
+    }
 
     /// Set curvature threshold
     ///
@@ -106,21 +188,25 @@ where
     }
 }
 
-impl<O, L, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for NewtonCG<L, F>
+impl<O, L, P, G, H, F, M, C> Solver<O, IterState<P, G, (), H, F>> for NewtonCG<L, F, M, C>
 where
-    O: Gradient<Param = P, Gradient = G> + Hessian<Param = P, Hessian = H>,
+    O: Gradient<Param = P, Gradient = G>,
     P: Clone
         + SerializeAlias
         + DeserializeOwnedAlias
         + ArgminSub<P, P>
         + ArgminDot<P, F>
         + ArgminScaledAdd<P, F, P>
+        + ArgminScaledAddAssign<P, F>
         + ArgminMul<F, P>
+        + ArgminMulAssign<F>
         + ArgminConj
         + ArgminZeroLike,
     G: SerializeAlias + DeserializeOwnedAlias + ArgminL2Norm<F> + ArgminMul<F, P>,
-    H: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, P>,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
     L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), F>>,
+    M: Preconditioner<P>,
+    C: EvaluateCurvature<O, P, G, H>,
     F: ArgminFloat + ArgminL2Norm<F>,
 {
     const NAME: &'static str = "Newton-CG";
@@ -144,14 +230,15 @@ where
         let hessian = state
             .take_hessian()
             .map(Result::Ok)
-            .unwrap_or_else(|| problem.hessian(&param))?;
+            .unwrap_or_else(|| self.curvature.hessian(problem, &param, &grad))?;
 
         // Solve CG subproblem
-        let mut cg_problem = Problem::new(CGSubProblem::new(&hessian));
+        let mut cg_problem = Problem::new(CGSubProblem::new(problem, &self.curvature, &hessian));
 
         let mut x_p = param.zero_like();
         let mut x = param.zero_like();
-        let mut cg = ConjugateGradient::new(grad.mul(&(float!(-1.0))));
+        let mut cg = ConjugateGradient::new(grad.mul(&(float!(-1.0))))
+            .with_preconditioner(&self.preconditioner);
 
         let (mut cg_state, _) = cg.init(&mut cg_problem, IterState::new().param(x_p.clone()))?;
 
@@ -165,10 +252,12 @@ where
             x = cg_state.take_param().unwrap();
             let p = cg.get_prev_p()?;
 
-            let curvature = p.dot(&hessian.dot(p));
+            let curvature = p.dot(&cg_problem.apply(p)?);
             if curvature <= self.curvature_threshold {
                 if iter == 0 {
-                    x = grad.mul(&(float!(-1.0)));
+                    x = self
+                        .preconditioner
+                        .apply_inverse(&grad.mul(&(float!(-1.0))))?;
                 } else {
                     x = x_p;
                 }
@@ -188,6 +277,7 @@ where
         self.linesearch.search_direction(x);
 
         let line_cost = state.get_cost();
+        self.linesearch.record_cost(line_cost);
 
         // Run solver
         let OptimizationResult {
@@ -218,31 +308,37 @@ where
     }
 }
 
-#[derive(Clone)]
-struct CGSubProblem<'a, P, H> {
+/// Operator of the CG subproblem: Applies the Hessian via the outer problem such that evaluations
+/// are counted there.
+struct CGSubProblem<'a, O, P, C, H> {
+    problem: RefCell<&'a mut Problem<O>>,
+    curvature: &'a C,
     hessian: &'a H,
     phantom: std::marker::PhantomData<P>,
 }
 
-impl<'a, P, H> CGSubProblem<'a, P, H> {
+impl<'a, O, P, C, H> CGSubProblem<'a, O, P, C, H> {
     /// Constructor
-    fn new(hessian: &'a H) -> Self {
+    fn new(problem: &'a mut Problem<O>, curvature: &'a C, hessian: &'a H) -> Self {
         CGSubProblem {
+            problem: RefCell::new(problem),
+            curvature,
             hessian,
             phantom: std::marker::PhantomData,
         }
     }
 }
 
-impl<'a, P, H> Operator for CGSubProblem<'a, P, H>
+impl<'a, O, P, C, H> Operator for CGSubProblem<'a, O, P, C, H>
 where
-    H: ArgminDot<P, P>,
+    C: Curvature<O, P, H>,
 {
     type Param = P;
     type Output = P;
 
     fn apply(&self, p: &P) -> Result<P, Error> {
-        Ok(self.hessian.dot(p))
+        self.curvature
+            .hessian_vector_product(&mut self.problem.borrow_mut(), self.hessian, p)
     }
 }
 
@@ -251,7 +347,7 @@ mod tests {
     #![allow(clippy::let_unit_value)]
 
     use super::*;
-    use crate::core::{test_utils::TestProblem, ArgminError};
+    use crate::core::{test_utils::TestProblem, ArgminError, Hessian};
     use crate::solver::linesearch::MoreThuenteLineSearch;
     use crate::test_trait_impl;
 
@@ -260,8 +356,6 @@ mod tests {
         NewtonCG<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, f64>
     );
 
-    test_trait_impl!(cg_subproblem, CGSubProblem<Vec<f64>, Vec<Vec<f64>>>);
-
     #[test]
     fn test_tolerance() {
         let tol1: f64 = 1e-4;
@@ -285,6 +379,7 @@ mod tests {
             linesearch,
             curvature_threshold,
             tol,
+            ..
         } = ncg;
         assert_eq!(linesearch, ls);
         assert_eq!(curvature_threshold.to_ne_bytes(), 0.0f64.to_ne_bytes());
@@ -301,6 +396,7 @@ mod tests {
             linesearch,
             curvature_threshold,
             tol,
+            ..
         } = ncg;
         assert_eq!(linesearch, ls);
         assert_eq!(curvature_threshold.to_ne_bytes(), 1e-6f64.to_ne_bytes());
@@ -341,5 +437,175 @@ mod tests {
         );
     }
 
-    // TODO: Test next_iter.
+    #[test]
+    fn test_preconditioned() {
+        use crate::core::{CostFunction, Executor};
+        use crate::solver::preconditioner::JacobiPreconditioner;
+        use approx::assert_relative_eq;
+
+        /// Badly scaled quadratic `0.5 * x^T A x - b^T x`
+        struct Quadratic {
+            a: Vec<Vec<f64>>,
+            b: Vec<f64>,
+        }
+
+        impl CostFunction for Quadratic {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                let ax: Vec<f64> = self.a.dot(x);
+                let xax: f64 = x.dot(&ax);
+                let bx: f64 = self.b.dot(x);
+                Ok(0.5 * xax - bx)
+            }
+        }
+
+        impl Gradient for Quadratic {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+                let ax: Vec<f64> = self.a.dot(x);
+                Ok(ax.sub(&self.b))
+            }
+        }
+
+        impl Hessian for Quadratic {
+            type Param = Vec<f64>;
+            type Hessian = Vec<Vec<f64>>;
+
+            fn hessian(&self, _x: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(self.a.clone())
+            }
+        }
+
+        let a = vec![
+            vec![1e4, 1.0, 0.0],
+            vec![1.0, 1.0, 1e-2],
+            vec![0.0, 1e-2, 1e-2],
+        ];
+        let b = vec![1e4 + 1.0, 2.01, 2e-2];
+        let preconditioner = JacobiPreconditioner::new(&a).unwrap();
+        let linesearch = MoreThuenteLineSearch::new();
+        let solver = NewtonCG::new(linesearch).with_preconditioner(preconditioner);
+        let res = Executor::new(Quadratic { a, b }, solver)
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(20))
+            .run()
+            .unwrap();
+        let x = res.state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(x[1], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(x[2], 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_curvature() {
+        use crate::core::Executor;
+        use crate::solver::curvature::{
+            tests::Quartic, FiniteDiffHessianVectorProducts, HessianVectorProducts,
+        };
+        use std::collections::HashMap;
+
+        fn solve<C, H>(curvature: C) -> HashMap<&'static str, u64>
+        where
+            C: EvaluateCurvature<Quartic, Vec<f64>, Vec<f64>, H>,
+            H: Clone + SerializeAlias + DeserializeOwnedAlias,
+        {
+            let linesearch = MoreThuenteLineSearch::new();
+            let solver = NewtonCG::new(linesearch)
+                .with_curvature(curvature)
+                .with_tolerance(1e-12)
+                .unwrap();
+            let res = Executor::new(Quartic::new(), solver)
+                .configure(|state| state.param(vec![0.0; 3]).max_iters(50))
+                .run()
+                .unwrap();
+            let x = res.state.get_best_param().unwrap();
+            let grad = Quartic::new().gradient(x).unwrap();
+            assert!(grad.l2_norm() < 1e-6);
+            res.problem.counts
+        }
+
+        let counts = solve(ExactHessian);
+        assert!(counts["hessian_count"] > 0);
+
+        let counts = solve(HessianVectorProducts);
+        assert!(!counts.contains_key("hessian_count"));
+        assert!(counts["hessian_vector_product_count"] > 0);
+
+        let counts = solve(FiniteDiffHessianVectorProducts::new());
+        assert!(!counts.contains_key("hessian_count"));
+        assert!(!counts.contains_key("hessian_vector_product_count"));
+    }
+
+    #[cfg(feature = "_sprsl")]
+    #[test]
+    fn test_solver_sprs() {
+        use crate::core::{CostFunction, Executor};
+        use crate::solver::preconditioner::JacobiPreconditioner;
+        use approx::assert_relative_eq;
+        use sprs::{CsMat, TriMat};
+
+        /// Sparse quadratic `0.5 * x^T A x - b^T x`
+        struct Quadratic {
+            a: CsMat<f64>,
+            b: Vec<f64>,
+        }
+
+        impl CostFunction for Quadratic {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                let ax: Vec<f64> = self.a.dot(x);
+                let xax: f64 = x.dot(&ax);
+                let bx: f64 = self.b.dot(x);
+                Ok(0.5 * xax - bx)
+            }
+        }
+
+        impl Gradient for Quadratic {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+                let ax: Vec<f64> = self.a.dot(x);
+                Ok(ax.sub(&self.b))
+            }
+        }
+
+        impl Hessian for Quadratic {
+            type Param = Vec<f64>;
+            type Hessian = CsMat<f64>;
+
+            fn hessian(&self, _x: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(self.a.clone())
+            }
+        }
+
+        // Tridiagonal, badly scaled
+        let n = 50;
+        let mut a = TriMat::new((n, n));
+        for i in 0..n {
+            a.add_triplet(i, i, 2.0 * (i + 1) as f64);
+            if i + 1 < n {
+                a.add_triplet(i, i + 1, -1.0);
+                a.add_triplet(i + 1, i, -1.0);
+            }
+        }
+        let a: CsMat<f64> = a.to_csr();
+        let b: Vec<f64> = a.dot(&vec![1.0; n]);
+        let preconditioner = JacobiPreconditioner::new(&a).unwrap();
+        let linesearch = MoreThuenteLineSearch::new();
+        let solver = NewtonCG::new(linesearch).with_preconditioner(preconditioner);
+        let res = Executor::new(Quadratic { a, b }, solver)
+            .configure(|state| state.param(vec![0.0; n]).max_iters(20))
+            .run()
+            .unwrap();
+        let x = res.state.get_best_param().unwrap();
+        for xi in x {
+            assert_relative_eq!(*xi, 1.0, epsilon = 1e-6);
+        }
+    }
 }
@@ -0,0 +1,538 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian,
+    IterState, LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{
+    ArgminAdd, ArgminCholesky, ArgminCholeskySolve, ArgminEye, ArgminGet, ArgminL2Norm, ArgminLen,
+    ArgminModifiedCholesky, ArgminMul,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Modification of the Hessian used by [`NewtonLS`]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum HessianModification {
+    /// Gill-Murray modified Cholesky factorization. Diagonal elements are increased during the
+    /// factorization only where needed to obtain a sufficiently positive definite matrix with
+    /// bounded factors.
+    #[default]
+    ModifiedCholesky,
+    /// Levenberg-style diagonal shift. `tau * I` is added to the Hessian, where `tau` is
+    /// increased from `max(0, shift - min_i h_ii)` until a Cholesky factorization succeeds.
+    DiagonalShift,
+}
+
+/// # Newton's method with line search
+///
+/// Safeguarded Newton's method for nonconvex problems. In each iteration, the Hessian is modified
+/// according to [`HessianModification`] such that it becomes positive definite. The resulting
+/// search direction is therefore always a descent direction, even if the Hessian is singular or
+/// indefinite. An appropriate step length is obtained by a line search.
+///
+/// Where the Hessian is sufficiently positive definite, no modification is applied and the
+/// method reduces to Newton's method with line search.
+///
+/// The algorithm stops once the norm of the gradient falls below the
+/// [tolerance](`NewtonLS::with_tolerance_grad`).
+///
+/// Requires an initial parameter vector. The modified Hessian is factorized via
+/// `ArgminModifiedCholesky` or `ArgminCholesky` and the search direction is obtained from the
+/// factor via `ArgminCholeskySolve`.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`] and
+/// [`Hessian`].
+///
+/// ## References
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+///
+/// Philip E. Gill, Walter Murray and Margaret H. Wright (1981). Practical Optimization.
+/// Academic Press. ISBN 0-12-283952-8.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NewtonLS<L, F> {
+    /// line search
+    linesearch: L,
+    /// Hessian modification
+    modification: HessianModification,
+    /// Minimum shift of the diagonal shift modification
+    shift: F,
+    /// Tolerance on the norm of the gradient
+    tol_grad: F,
+}
+
+impl<L, F: ArgminFloat> NewtonLS<L, F> {
+    /// Construct a new instance of [`NewtonLS`]
+    ///
+    /// Requires a line search.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::NewtonLS;
+    /// # let linesearch = ();
+    /// let newton: NewtonLS<_, f64> = NewtonLS::new(linesearch);
+    /// ```
+    pub fn new(linesearch: L) -> Self {
+        NewtonLS {
+            linesearch,
+            modification: HessianModification::default(),
+            shift: float!(1e-3),
+            tol_grad: F::epsilon().sqrt(),
+        }
+    }
+
+    /// Set the Hessian modification
+    ///
+    /// Defaults to [`HessianModification::ModifiedCholesky`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::{HessianModification, NewtonLS};
+    /// # let linesearch = ();
+    /// let newton: NewtonLS<_, f64> =
+    ///     NewtonLS::new(linesearch).with_modification(HessianModification::DiagonalShift);
+    /// ```
+    #[must_use]
+    pub fn with_modification(mut self, modification: HessianModification) -> Self {
+        self.modification = modification;
+        self
+    }
+
+    /// Set the minimum shift of [`HessianModification::DiagonalShift`]
+    ///
+    /// Must be positive and defaults to `1e-3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::NewtonLS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let newton: NewtonLS<_, f64> = NewtonLS::new(linesearch).with_shift(1e-2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_shift(mut self, shift: F) -> Result<Self, Error> {
+        if shift <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NewtonLS`: shift must be > 0."
+            ));
+        }
+        self.shift = shift;
+        Ok(self)
+    }
+
+    /// Set tolerance on the norm of the gradient
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::NewtonLS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let linesearch = ();
+    /// let newton: NewtonLS<_, f64> = NewtonLS::new(linesearch).with_tolerance_grad(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_grad(mut self, tol_grad: F) -> Result<Self, Error> {
+        if tol_grad <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NewtonLS`: gradient tolerance must be > 0."
+            ));
+        }
+        self.tol_grad = tol_grad;
+        Ok(self)
+    }
+
+    /// Computes the Cholesky factor `L` of the modified Hessian `H + E = L L^T` as well as the
+    /// largest diagonal element of `E`.
+    fn factorize<H>(&self, hessian: &H, n: usize) -> Result<(H, F), Error>
+    where
+        H: ArgminGet<(usize, usize), F>
+            + ArgminModifiedCholesky<H, F>
+            + ArgminCholesky<H>
+            + ArgminEye
+            + ArgminAdd<H, H>
+            + ArgminMul<F, H>,
+    {
+        match self.modification {
+            HessianModification::ModifiedCholesky => hessian.modified_cholesky(),
+            HessianModification::DiagonalShift => {
+                let min_diag = (0..n)
+                    .map(|i| hessian.get_elem((i, i)))
+                    .fold(F::infinity(), |acc, h| acc.min(h));
+                let mut tau = if min_diag > float!(0.0) {
+                    float!(0.0)
+                } else {
+                    self.shift - min_diag
+                };
+                let eye = hessian.eye_like();
+                loop {
+                    if !tau.is_finite() {
+                        return Err(argmin_error!(
+                            ConditionViolated,
+                            "`NewtonLS`: Failed to shift the Hessian to positive definiteness."
+                        ));
+                    }
+                    let shifted = hessian.add(&eye.mul(&tau));
+                    if let Ok(l) = shifted.cholesky() {
+                        return Ok((l, tau));
+                    }
+                    tau = (float!(2.0) * tau).max(self.shift);
+                }
+            }
+        }
+    }
+}
+
+impl<O, L, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for NewtonLS<L, F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = G>
+        + Hessian<Param = P, Hessian = H>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminLen,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminMul<F, G> + ArgminL2Norm<F>,
+    H: ArgminGet<(usize, usize), F>
+        + ArgminModifiedCholesky<H, F>
+        + ArgminCholesky<H>
+        + ArgminEye
+        + ArgminAdd<H, H>
+        + ArgminMul<F, H>
+        + ArgminCholeskySolve<G, P>,
+    L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), F>>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Newton method with line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), H, F>,
+    ) -> Result<(IterState<P, G, (), H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`NewtonLS` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+        let grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&param))?;
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), H, F>,
+    ) -> Result<(IterState<P, G, (), H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NewtonLS`: Parameter vector in `state` not set"
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NewtonLS`: Gradient in `state` not set"
+        ))?;
+        let cost = state.get_cost();
+        let hessian = problem.hessian(&param)?;
+
+        // Solve `(H + E) p = L L^T p = -g` with the Cholesky factor of the modified Hessian
+        let (l, modification) = self.factorize(&hessian, param.len())?;
+        let neg_grad = grad.mul(&float!(-1.0));
+        let direction = l.cholesky_solve(&neg_grad)?;
+
+        self.linesearch.record_cost(cost);
+        self.linesearch.search_direction(direction);
+
+        // Run line search
+        let OptimizationResult {
+            problem: line_problem,
+            state: mut linesearch_state,
+            ..
+        } = Executor::new(
+            problem.take_problem().ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`NewtonLS`: Failed to take `problem` for line search"
+            ))?,
+            self.linesearch.clone(),
+        )
+        .configure(|config| config.param(param).gradient(grad).cost(cost))
+        .ctrlc(false)
+        .run()?;
+
+        // Get back problem and function evaluation counts
+        problem.consume_problem(line_problem);
+
+        let new_param = linesearch_state
+            .take_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`NewtonLS`: No `param` returned by line search"
+            ))?;
+        let new_grad = linesearch_state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&new_param))?;
+
+        Ok((
+            state
+                .param(new_param)
+                .cost(linesearch_state.get_cost())
+                .gradient(new_grad),
+            Some(kv!(
+                "modification" => modification;
+                "linesearch_iters" => linesearch_state.get_iter();
+            )),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, G, (), H, F>) -> TerminationStatus {
+        match state.get_gradient() {
+            Some(grad) if grad.l2_norm() <= self.tol_grad => {
+                TerminationStatus::Terminated(TerminationReason::SolverConverged)
+            }
+            _ => TerminationStatus::NotTerminated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::{
+        condition::ArmijoCondition, BacktrackingLineSearch, MoreThuenteLineSearch,
+    };
+    use approx::assert_relative_eq;
+    use argmin_math::{ArgminDot, ArgminTranspose};
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative, rosenbrock_2d_hessian};
+
+    type Linesearch = MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>;
+
+    test_trait_impl!(newton_ls, NewtonLS<Linesearch, f64>);
+
+    /// `f(x) = sum_i (x_i^4 / 4 - x_i^2 / 2)`, which has a local maximum at `0` and minima at
+    /// `x_i = +-1`.
+    struct DoubleWell {}
+
+    impl CostFunction for DoubleWell {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| x.powi(4) / 4.0 - x.powi(2) / 2.0).sum())
+        }
+    }
+
+    impl Gradient for DoubleWell {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(p.iter().map(|x| x.powi(3) - x).collect())
+        }
+    }
+
+    impl Hessian for DoubleWell {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+            let n = p.len();
+            Ok((0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            if i == j {
+                                3.0 * p[i].powi(2) - 1.0
+                            } else {
+                                0.0
+                            }
+                        })
+                        .collect()
+                })
+                .collect())
+        }
+    }
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    impl Hessian for Rosenbrock {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+            let h = rosenbrock_2d_hessian(p, 1.0, 100.0);
+            Ok(vec![vec![h[0], h[1]], vec![h[2], h[3]]])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let newton: NewtonLS<Linesearch, f64> = NewtonLS::new(MoreThuenteLineSearch::new());
+        let NewtonLS {
+            linesearch: _,
+            modification,
+            shift,
+            tol_grad,
+        } = newton;
+        assert_eq!(modification, HessianModification::ModifiedCholesky);
+        assert_eq!(shift.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+        assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_parameters() {
+        let newton: NewtonLS<Linesearch, f64> = NewtonLS::new(MoreThuenteLineSearch::new())
+            .with_modification(HessianModification::DiagonalShift)
+            .with_shift(1e-2)
+            .unwrap()
+            .with_tolerance_grad(1e-6)
+            .unwrap();
+        assert_eq!(newton.modification, HessianModification::DiagonalShift);
+        assert_eq!(newton.shift.to_ne_bytes(), 1e-2f64.to_ne_bytes());
+        assert_eq!(newton.tol_grad.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+
+        for value in [0.0, -1.0] {
+            assert_error!(
+                NewtonLS::<Linesearch, f64>::new(MoreThuenteLineSearch::new()).with_shift(value),
+                ArgminError,
+                "Invalid parameter: \"`NewtonLS`: shift must be > 0.\""
+            );
+            assert_error!(
+                NewtonLS::<Linesearch, f64>::new(MoreThuenteLineSearch::new())
+                    .with_tolerance_grad(value),
+                ArgminError,
+                "Invalid parameter: \"`NewtonLS`: gradient tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let mut newton: NewtonLS<Linesearch, f64> = NewtonLS::new(MoreThuenteLineSearch::new());
+        let res = newton.init(&mut Problem::new(DoubleWell {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`NewtonLS` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_diagonal_shift() {
+        let newton: NewtonLS<Linesearch, f64> = NewtonLS::new(MoreThuenteLineSearch::new())
+            .with_modification(HessianModification::DiagonalShift);
+
+        // Positive definite matrices are not shifted
+        let a = vec![vec![4.0, 2.0], vec![2.0, 5.0]];
+        let (_, tau) = newton.factorize(&a, 2).unwrap();
+        assert_relative_eq!(tau, 0.0);
+
+        // Eigenvalues of `a` are `-1` and `3`. The diagonal is positive, therefore the shift
+        // starts at `0` and is then doubled starting from `1e-3` until it exceeds `1`.
+        let a = vec![vec![1.0, 2.0], vec![2.0, 1.0]];
+        let (l, tau) = newton.factorize(&a, 2).unwrap();
+        assert_relative_eq!(tau, 1.024);
+        let lt: Vec<Vec<f64>> = l.dot(&l.clone().t());
+        assert_relative_eq!(lt[0][0], 1.0 + tau, epsilon = 1e-12);
+        assert_relative_eq!(lt[0][1], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(lt[1][1], 1.0 + tau, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_indefinite_hessian() {
+        // Newton's method would be attracted by the local maximum at `0`
+        for modification in [
+            HessianModification::ModifiedCholesky,
+            HessianModification::DiagonalShift,
+        ] {
+            let linesearch: BacktrackingLineSearch<_, _, _, f64> =
+                BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap());
+            let solver = NewtonLS::new(linesearch).with_modification(modification);
+            let res = Executor::new(DoubleWell {}, solver)
+                .configure(|state| state.param(vec![0.3, -0.2]).max_iters(100))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            assert_relative_eq!(x[0], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(x[1], -1.0, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_rosenbrock() {
+        for modification in [
+            HessianModification::ModifiedCholesky,
+            HessianModification::DiagonalShift,
+        ] {
+            let solver =
+                NewtonLS::new(MoreThuenteLineSearch::new()).with_modification(modification);
+            let res = Executor::new(Rosenbrock {}, solver)
+                .configure(|state| state.param(vec![0.0, 1.0]).max_iters(100))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            assert_relative_eq!(x[0], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(x[1], 1.0, epsilon = 1e-6);
+        }
+    }
+}
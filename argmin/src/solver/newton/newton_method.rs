@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{ArgminFloat, Error, Gradient, Hessian, IterState, Problem, Solver, KV};
-use argmin_math::{ArgminDot, ArgminInv, ArgminScaledSub};
+use argmin_math::{ArgminScaledSub, ArgminSolve};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -89,7 +89,7 @@ impl<O, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for Newton<F>
 where
     O: Gradient<Param = P, Gradient = G> + Hessian<Param = P, Hessian = H>,
     P: Clone + ArgminScaledSub<P, F, P>,
-    H: ArgminInv<H> + ArgminDot<G, P>,
+    H: ArgminSolve<G, P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Newton method";
@@ -108,7 +108,7 @@ where
         ))?;
         let grad = problem.gradient(&param)?;
         let hessian = problem.hessian(&param)?;
-        let new_param = param.scaled_sub(&self.gamma, &hessian.inv()?.dot(&grad));
+        let new_param = param.scaled_sub(&self.gamma, &hessian.solve(&grad)?);
         Ok((state.param(new_param), None))
     }
 }
@@ -117,11 +117,10 @@ where
 mod tests {
     use super::*;
     use crate::core::ArgminError;
-    #[cfg(feature = "_ndarrayl")]
-    use crate::core::Executor;
+    use crate::core::{DeserializeOwnedAlias, Executor, SerializeAlias};
     use crate::test_trait_impl;
-    #[cfg(feature = "_ndarrayl")]
     use approx::assert_relative_eq;
+    use argmin_math::ArgminGet;
 
     test_trait_impl!(newton_method, Newton<f64>);
 
@@ -285,4 +284,101 @@ mod tests {
         assert_relative_eq!(param[0], -1.0, epsilon = f64::EPSILON);
         assert_relative_eq!(param[1], -2.0, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_solver_scalar() {
+        use crate::core::State;
+        struct Problem {}
+
+        // f(x) = (x - 3)^2
+        impl Gradient for Problem {
+            type Param = f64;
+            type Gradient = f64;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(2.0 * (p - 3.0))
+            }
+        }
+
+        impl Hessian for Problem {
+            type Param = f64;
+            type Hessian = f64;
+
+            fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(2.0)
+            }
+        }
+
+        let param = *Executor::new(Problem {}, Newton::<f64>::new())
+            .configure(|config| config.param(0.0).max_iters(1))
+            .run()
+            .unwrap()
+            .state
+            .get_best_param()
+            .unwrap();
+        assert_relative_eq!(param, 3.0, epsilon = f64::EPSILON);
+    }
+
+    /// Problem with constant gradient and Hessian, used to run the same test on several backends
+    struct ConstantProblem<P, H> {
+        gradient: P,
+        hessian: H,
+    }
+
+    impl<P: Clone, H> Gradient for ConstantProblem<P, H> {
+        type Param = P;
+        type Gradient = P;
+
+        fn gradient(&self, _p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(self.gradient.clone())
+        }
+    }
+
+    impl<P, H: Clone> Hessian for ConstantProblem<P, H> {
+        type Param = P;
+        type Hessian = H;
+
+        fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+            Ok(self.hessian.clone())
+        }
+    }
+
+    /// Runs a single iteration (gamma = 1) from `init` on the problem with gradient `[1, 2]` and
+    /// Hessian `[[2, 1], [1, 3]]`, which must end up at `[-0.2, -0.6]`.
+    fn check_solver_backend<P, H>(init: P, gradient: P, hessian: H)
+    where
+        P: Clone
+            + ArgminScaledSub<P, f64, P>
+            + ArgminGet<usize, f64>
+            + SerializeAlias
+            + DeserializeOwnedAlias,
+        H: Clone + ArgminSolve<P, P> + SerializeAlias + DeserializeOwnedAlias,
+    {
+        use crate::core::State;
+
+        let param = Executor::new(ConstantProblem { gradient, hessian }, Newton::<f64>::new())
+            .configure(|config| config.param(init).max_iters(1))
+            .run()
+            .unwrap()
+            .state
+            .get_best_param()
+            .unwrap()
+            .clone();
+        assert_relative_eq!(param.get_elem(0), -0.2, epsilon = f64::EPSILON.sqrt());
+        assert_relative_eq!(param.get_elem(1), -0.6, epsilon = f64::EPSILON.sqrt());
+    }
+
+    #[test]
+    fn test_solver_vec() {
+        check_solver_backend(
+            vec![0.0, 0.0],
+            vec![1.0, 2.0],
+            vec![vec![2.0, 1.0], vec![1.0, 3.0]],
+        );
+    }
+
+    #[test]
+    fn test_solver_array() {
+        check_solver_backend([0.0, 0.0], [1.0, 2.0], [[2.0, 1.0], [1.0, 3.0]]);
+    }
 }
@@ -0,0 +1,256 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::{finalize, init_active_set, ActiveSet};
+use crate::core::{
+    ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+use argmin_math::{ArgminGet, ArgminL2Norm, ArgminLen, ArgminSet};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Bounded-variable least squares (BVLS)
+///
+/// Solves the linear least squares problem `min ||Ax - b||` subject to `l <= x <= u` with the
+/// active set method of Stark and Parker, a generalization of the Lawson-Hanson algorithm (see
+/// [`LawsonHanson`](`super::LawsonHanson`)) to lower and upper bounds. Bounds may be infinite,
+/// which allows for variables which are only bounded from one side or not at all.
+///
+/// Requires an initial parameter vector, which only determines the type and dimension of the
+/// solution. The initial parameter vector is not required to be feasible.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] and [`Jacobian`]. The operator
+/// returns the residuals `r(x) = Ax - b` and the Jacobian returns `A`. Since the problem is
+/// linear, both are only evaluated at the initial parameter vector. The least squares subproblems
+/// in the free variables are solved with a QR decomposition of the corresponding columns of `A`
+/// rather than via the normal equations.
+///
+/// ## Reference
+///
+/// Philip B. Stark and Robert L. Parker (1995). Bounded-Variable Least-Squares: an Algorithm and
+/// Applications. Computational Statistics 10, 129–141.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BVLS<P, F> {
+    /// Lower bounds
+    lower: P,
+    /// Upper bounds
+    upper: P,
+    /// Tolerance for the optimality conditions
+    tol: F,
+    /// Active set method
+    active_set: ActiveSet<F>,
+    /// Whether the optimality conditions are fulfilled
+    converged: bool,
+}
+
+impl<P, F: ArgminFloat> BVLS<P, F> {
+    /// Construct a new instance of [`BVLS`]
+    ///
+    /// Takes the lower and upper bounds, which must be of the same length as the parameter
+    /// vector. Infinite bounds are allowed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nnls::BVLS;
+    /// let solver: BVLS<_, f64> = BVLS::new(vec![0.0, f64::NEG_INFINITY], vec![1.0, 2.0]);
+    /// ```
+    pub fn new(lower: P, upper: P) -> Self {
+        BVLS {
+            lower,
+            upper,
+            tol: F::epsilon().sqrt(),
+            active_set: ActiveSet::default(),
+            converged: false,
+        }
+    }
+
+    /// Set relative tolerance for the optimality conditions.
+    ///
+    /// Tolerance must be larger than zero and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nnls::BVLS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let solver = BVLS::new(vec![0.0, 0.0], vec![1.0, 1.0]).with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BVLS`: tol must be positive."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, F, P, J, U> Solver<O, IterState<P, (), J, (), F>> for BVLS<P, F>
+where
+    O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
+    P: Clone + ArgminGet<usize, F> + ArgminSet<usize, F> + ArgminLen,
+    U: ArgminL2Norm<F> + ArgminGet<usize, F> + ArgminLen,
+    J: ArgminGet<(usize, usize), F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "BVLS";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), J, (), F>,
+    ) -> Result<(IterState<P, (), J, (), F>, Option<KV>), Error> {
+        let to_vec = |p: &P| (0..p.len()).map(|i| p.get_elem(i)).collect::<Vec<F>>();
+        let bounds = (to_vec(&self.lower), to_vec(&self.upper));
+        let (active_set, param) = init_active_set("BVLS", problem, &mut state, Some(bounds))?;
+        let (param, cost) = finalize(problem, param, &active_set.x)?;
+        self.active_set = active_set;
+        self.converged = false;
+        Ok((state.param(param).cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), J, (), F>,
+    ) -> Result<(IterState<P, (), J, (), F>, Option<KV>), Error> {
+        if !self.active_set.iterate(self.tol)? {
+            self.converged = true;
+        }
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`BVLS`: Parameter vector in state not set."
+        ))?;
+        let (param, cost) = finalize(problem, param, &self.active_set.x)?;
+        let kv = kv!("num_free" => self.active_set.num_free() as u64;);
+        Ok((state.param(param).cost(cost), Some(kv)))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, (), J, (), F>) -> TerminationStatus {
+        if self.converged {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::LinearLeastSquares;
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(bvls, BVLS<Vec<f64>, f64>);
+
+    #[test]
+    fn test_new() {
+        let BVLS {
+            lower,
+            upper,
+            tol,
+            converged,
+            ..
+        } = BVLS::<_, f64>::new(vec![0.0], vec![1.0]);
+        assert_eq!(lower, vec![0.0]);
+        assert_eq!(upper, vec![1.0]);
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(!converged);
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let BVLS { tol, .. } = BVLS::new(vec![0.0], vec![1.0])
+            .with_tolerance(1e-4f64)
+            .unwrap();
+        assert_eq!(tol.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_error!(
+            BVLS::new(vec![0.0], vec![1.0]).with_tolerance(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`BVLS`: tol must be positive.\""
+        );
+    }
+
+    #[test]
+    fn test_invalid_bounds() {
+        let problem = LinearLeastSquares {
+            a: vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+            b: vec![1.0, 1.0],
+        };
+        let res = Executor::new(problem.clone(), BVLS::new(vec![0.0], vec![1.0]))
+            .configure(|state| state.param(vec![0.0, 0.0]))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`BVLS`: Bounds and parameter vector must be of the same length.\""
+        );
+        let res = Executor::new(problem, BVLS::new(vec![0.0, 1.0], vec![1.0, 0.0]))
+            .configure(|state| state.param(vec![0.0, 0.0]))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`BVLS`: Lower bound must not be larger than upper bound.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        // Unconstrained solution is [1.5, -1, 2]
+        let problem = LinearLeastSquares {
+            a: vec![
+                vec![1.0, 0.0, 0.0],
+                vec![1.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0],
+                vec![0.0, 0.0, 1.0],
+            ],
+            b: vec![2.0, 1.0, -1.0, 2.0],
+        };
+        let solver = BVLS::new(
+            vec![f64::NEG_INFINITY, -0.5, 0.0],
+            vec![1.0, f64::INFINITY, 0.5],
+        );
+        let res = Executor::new(problem, solver)
+            .configure(|state| state.param(vec![0.0, 0.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(param[1], -0.5, epsilon = 1e-12);
+        assert_relative_eq!(param[2], 0.5, epsilon = 1e-12);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+
+        // Without active bounds, the unconstrained solution is found
+        let problem = LinearLeastSquares {
+            a: vec![vec![2.0, 1.0], vec![1.0, 3.0], vec![0.0, 1.0]],
+            b: vec![1.0, 2.0, 0.5],
+        };
+        let solver = BVLS::new(vec![-10.0, -10.0], vec![10.0, 10.0]);
+        let res = Executor::new(problem.clone(), solver)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        // Normal equations: [[5, 5], [5, 11]] x = [4, 7.5]
+        assert_relative_eq!(param[0], 0.2166666666666667, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 0.5833333333333333, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::{finalize, init_active_set, ActiveSet};
+use crate::core::{
+    ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+use argmin_math::{ArgminGet, ArgminL2Norm, ArgminLen, ArgminSet};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Lawson-Hanson nonnegative least squares
+///
+/// Solves the linear least squares problem `min ||Ax - b||` subject to `x >= 0` with the active
+/// set method of Lawson and Hanson. Each iteration releases the variable which violates the
+/// optimality conditions the most from its bound and solves the unconstrained least squares
+/// problem in the free variables, moving variables back to their bound if they become negative.
+/// The solver terminates once the Karush-Kuhn-Tucker conditions are fulfilled.
+///
+/// Requires an initial parameter vector, which only determines the type and dimension of the
+/// solution. The initial parameter vector is not required to be feasible.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] and [`Jacobian`]. The operator
+/// returns the residuals `r(x) = Ax - b` and the Jacobian returns `A`. Since the problem is
+/// linear, both are only evaluated at the initial parameter vector. The least squares subproblems
+/// in the free variables are solved with a QR decomposition of the corresponding columns of `A`
+/// rather than via the normal equations.
+///
+/// ## Reference
+///
+/// Charles L. Lawson and Richard J. Hanson (1995). Solving Least Squares Problems.
+/// SIAM. ISBN 0-89871-356-0. <https://doi.org/10.1137/1.9781611971217>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LawsonHanson<F> {
+    /// Tolerance for the optimality conditions
+    tol: F,
+    /// Active set method
+    active_set: ActiveSet<F>,
+    /// Whether the optimality conditions are fulfilled
+    converged: bool,
+}
+
+impl<F: ArgminFloat> LawsonHanson<F> {
+    /// Construct a new instance of [`LawsonHanson`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nnls::LawsonHanson;
+    /// let solver: LawsonHanson<f64> = LawsonHanson::new();
+    /// ```
+    pub fn new() -> Self {
+        LawsonHanson {
+            tol: F::epsilon().sqrt(),
+            active_set: ActiveSet::default(),
+            converged: false,
+        }
+    }
+
+    /// Set relative tolerance for the optimality conditions.
+    ///
+    /// Tolerance must be larger than zero and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nnls::LawsonHanson;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let solver = LawsonHanson::new().with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LawsonHanson`: tol must be positive."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<F: ArgminFloat> Default for LawsonHanson<F> {
+    fn default() -> LawsonHanson<F> {
+        LawsonHanson::new()
+    }
+}
+
+impl<O, F, P, J, U> Solver<O, IterState<P, (), J, (), F>> for LawsonHanson<F>
+where
+    O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
+    P: Clone + ArgminGet<usize, F> + ArgminSet<usize, F> + ArgminLen,
+    U: ArgminL2Norm<F> + ArgminGet<usize, F> + ArgminLen,
+    J: ArgminGet<(usize, usize), F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Lawson-Hanson";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), J, (), F>,
+    ) -> Result<(IterState<P, (), J, (), F>, Option<KV>), Error> {
+        let (active_set, param) = init_active_set("LawsonHanson", problem, &mut state, None)?;
+        let (param, cost) = finalize(problem, param, &active_set.x)?;
+        self.active_set = active_set;
+        self.converged = false;
+        Ok((state.param(param).cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), J, (), F>,
+    ) -> Result<(IterState<P, (), J, (), F>, Option<KV>), Error> {
+        if !self.active_set.iterate(self.tol)? {
+            self.converged = true;
+        }
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LawsonHanson`: Parameter vector in state not set."
+        ))?;
+        let (param, cost) = finalize(problem, param, &self.active_set.x)?;
+        let kv = kv!("num_free" => self.active_set.num_free() as u64;);
+        Ok((state.param(param).cost(cost), Some(kv)))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, (), J, (), F>) -> TerminationStatus {
+        if self.converged {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::LinearLeastSquares;
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(lawson_hanson, LawsonHanson<f64>);
+
+    #[test]
+    fn test_new() {
+        let LawsonHanson { tol, converged, .. } = LawsonHanson::<f64>::new();
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(!converged);
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let LawsonHanson { tol, .. } = LawsonHanson::new().with_tolerance(1e-4f64).unwrap();
+        assert_eq!(tol.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_error!(
+            LawsonHanson::new().with_tolerance(0.0f64),
+            ArgminError,
+            "Invalid parameter: \"`LawsonHanson`: tol must be positive.\""
+        );
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let problem = LinearLeastSquares {
+            a: vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+            b: vec![1.0, 1.0],
+        };
+        let res = LawsonHanson::new().init(&mut Problem::new(problem), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`LawsonHanson` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        // Unconstrained solution [1.5, 1] is feasible
+        let problem = LinearLeastSquares {
+            a: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]],
+            b: vec![2.0, 1.0, 1.0],
+        };
+        let res = Executor::new(problem, LawsonHanson::new())
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.5, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(res.state.get_best_cost(), 0.5f64.sqrt(), epsilon = 1e-12);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+
+        // Unconstrained solution [1.5, -1] is infeasible, starting from an infeasible point
+        let problem = LinearLeastSquares {
+            a: vec![vec![1.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]],
+            b: vec![2.0, 1.0, -1.0],
+        };
+        let res = Executor::new(problem, LawsonHanson::new())
+            .configure(|state| state.param(vec![-3.0, 7.0]).max_iters(10))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.5, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 0.0, epsilon = 1e-12);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+    }
+
+    #[cfg(feature = "_nalgebral")]
+    #[test]
+    fn test_solver_nalgebra() {
+        use nalgebra::{DMatrix, DVector};
+
+        struct Problem {
+            a: DMatrix<f64>,
+            b: DVector<f64>,
+        }
+
+        impl Operator for Problem {
+            type Param = DVector<f64>;
+            type Output = DVector<f64>;
+
+            fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(&self.a * x - &self.b)
+            }
+        }
+
+        impl Jacobian for Problem {
+            type Param = DVector<f64>;
+            type Jacobian = DMatrix<f64>;
+
+            fn jacobian(&self, _x: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(self.a.clone())
+            }
+        }
+
+        let problem = Problem {
+            a: DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 1.0, 0.0, 0.0, 1.0]),
+            b: DVector::from_vec(vec![2.0, 1.0, -1.0]),
+        };
+        let res = Executor::new(problem, LawsonHanson::new())
+            .configure(|state| state.param(DVector::zeros(2)).max_iters(10))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.5, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 0.0, epsilon = 1e-12);
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    #[test]
+    fn test_solver_ndarray() {
+        use ndarray::{array, Array1, Array2};
+
+        struct Problem {
+            a: Array2<f64>,
+            b: Array1<f64>,
+        }
+
+        impl Operator for Problem {
+            type Param = Array1<f64>;
+            type Output = Array1<f64>;
+
+            fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(self.a.dot(x) - &self.b)
+            }
+        }
+
+        impl Jacobian for Problem {
+            type Param = Array1<f64>;
+            type Jacobian = Array2<f64>;
+
+            fn jacobian(&self, _x: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(self.a.clone())
+            }
+        }
+
+        let problem = Problem {
+            a: array![[1.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            b: array![2.0, 1.0, -1.0],
+        };
+        let res = Executor::new(problem, LawsonHanson::new())
+            .configure(|state| state.param(Array1::zeros(2)).max_iters(10))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.5, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 0.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,588 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Bound-constrained linear least squares
+//!
+//! * [Nonnegative least squares (Lawson–Hanson)](`LawsonHanson`)
+//! * [Bounded-variable least squares (BVLS)](`BVLS`)
+//!
+//! ## References
+//!
+//! \[0\] Charles L. Lawson and Richard J. Hanson (1995). Solving Least Squares Problems.
+//! SIAM. ISBN 0-89871-356-0. <https://doi.org/10.1137/1.9781611971217>
+//!
+//! \[1\] Philip B. Stark and Robert L. Parker (1995). Bounded-Variable Least-Squares: an
+//! Algorithm and Applications. Computational Statistics 10, 129–141.
+
+mod bvls;
+mod lawsonhanson;
+
+pub use bvls::BVLS;
+pub use lawsonhanson::LawsonHanson;
+
+use crate::core::{ArgminFloat, Error, IterState, Jacobian, Operator, Problem};
+use crate::solver::util::dense::{dot, least_squares_qr};
+use argmin_math::{ArgminGet, ArgminL2Norm, ArgminLen, ArgminSet};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Status of a variable in the active set method
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+enum VarStatus {
+    /// Fixed at its lower bound
+    Lower,
+    /// Fixed at its upper bound
+    Upper,
+    /// Not constrained by its bounds
+    Free,
+}
+
+/// Active set method for `min ||Ax - b||` subject to `l <= x <= u`.
+///
+/// The least squares subproblems in the free variables are solved with a Householder QR
+/// decomposition of the corresponding columns of `A`. Contrary to the normal equations `A^T A`,
+/// this does not square the condition number of the subproblems.
+///
+/// With `l = 0` and `u = inf` this is the Lawson–Hanson algorithm, in general it is the BVLS
+/// algorithm of Stark and Parker.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct ActiveSet<F> {
+    /// Rows of `A`
+    a: Vec<Vec<F>>,
+    /// `b`
+    b: Vec<F>,
+    /// Lower bounds
+    lower: Vec<F>,
+    /// Upper bounds
+    upper: Vec<F>,
+    /// Current solution
+    x: Vec<F>,
+    /// Status of each variable
+    status: Vec<VarStatus>,
+}
+
+impl<F> Default for ActiveSet<F> {
+    fn default() -> Self {
+        ActiveSet {
+            a: vec![],
+            b: vec![],
+            lower: vec![],
+            upper: vec![],
+            x: vec![],
+            status: vec![],
+        }
+    }
+}
+
+impl<F: ArgminFloat> ActiveSet<F> {
+    /// Starts with all variables at one of their bounds. Variables without finite bounds are free
+    /// from the beginning.
+    fn new(a: Vec<Vec<F>>, b: Vec<F>, lower: Vec<F>, upper: Vec<F>) -> Result<Self, Error> {
+        let (x, status) = lower
+            .iter()
+            .zip(upper.iter())
+            .map(|(l, u)| {
+                if l.is_finite() {
+                    (*l, VarStatus::Lower)
+                } else if u.is_finite() {
+                    (*u, VarStatus::Upper)
+                } else {
+                    (float!(0.0), VarStatus::Free)
+                }
+            })
+            .unzip();
+        let mut active_set = ActiveSet {
+            a,
+            b,
+            lower,
+            upper,
+            x,
+            status,
+        };
+        if active_set.num_free() > 0 {
+            active_set.solve_subproblem(None)?;
+        }
+        Ok(active_set)
+    }
+
+    /// Number of free variables
+    fn num_free(&self) -> usize {
+        self.status
+            .iter()
+            .filter(|s| **s == VarStatus::Free)
+            .count()
+    }
+
+    /// Computes `A^T v`
+    fn transpose_apply(&self, v: &[F]) -> Vec<F> {
+        self.a
+            .iter()
+            .zip(v.iter())
+            .fold(vec![float!(0.0); self.x.len()], |mut acc, (row, vi)| {
+                for (accj, aij) in acc.iter_mut().zip(row.iter()) {
+                    *accj = *accj + *aij * *vi;
+                }
+                acc
+            })
+    }
+
+    /// Negative gradient `A^T (b - A x)` of `||Ax - b||^2 / 2`
+    fn negative_gradient(&self) -> Vec<F> {
+        let r: Vec<F> = self
+            .a
+            .iter()
+            .zip(self.b.iter())
+            .map(|(row, b)| *b - dot(row, &self.x))
+            .collect();
+        self.transpose_apply(&r)
+    }
+
+    /// Returns the fixed variable whose bound constraint is violated most by the KKT conditions
+    /// (relative to `tol`), ignoring the variables marked in `blocked`.
+    fn select(&self, tol: F, blocked: &[bool]) -> Option<usize> {
+        let w = self.negative_gradient();
+        let max_abs = |v: &[F]| v.iter().fold(float!(0.0), |acc: F, vi| acc.max(vi.abs()));
+        // Largest squared column norm of `A`, which bounds the elements of `A^T A`
+        let col_norm_max = (0..self.x.len())
+            .map(|j| {
+                self.a
+                    .iter()
+                    .fold(float!(0.0), |acc: F, row| acc + row[j] * row[j])
+            })
+            .fold(float!(0.0), |acc: F, v| acc.max(v));
+        let threshold = tol
+            * (max_abs(&self.transpose_apply(&self.b)) + col_norm_max * max_abs(&self.x))
+                .max(F::min_positive_value());
+        let mut best: Option<(usize, F)> = None;
+        for (j, wj) in w.iter().enumerate() {
+            let violation = match self.status[j] {
+                VarStatus::Lower => *wj,
+                VarStatus::Upper => -*wj,
+                VarStatus::Free => continue,
+            };
+            if blocked[j] || violation <= threshold || self.lower[j] == self.upper[j] {
+                continue;
+            }
+            match best {
+                Some((_, v)) if violation <= v => {}
+                _ => best = Some((j, violation)),
+            }
+        }
+        best.map(|(j, _)| j)
+    }
+
+    /// Performs one outer iteration: releases the variable violating the KKT conditions the most
+    /// from its bound and solves the resulting subproblem. Returns `false` if the KKT conditions
+    /// are fulfilled (with tolerance `tol`), i.e. if `x` is optimal.
+    fn iterate(&mut self, tol: F) -> Result<bool, Error> {
+        let mut blocked = vec![false; self.x.len()];
+        while let Some(t) = self.select(tol, &blocked) {
+            let previous = self.status[t];
+            self.status[t] = VarStatus::Free;
+            if self.solve_subproblem(Some((t, previous)))? {
+                return Ok(true);
+            }
+            // Numerically, the subproblem does not move `t` away from its bound.
+            self.status[t] = previous;
+            blocked[t] = true;
+        }
+        Ok(false)
+    }
+
+    /// Minimizes over the free variables while keeping all other variables fixed, moving variables
+    /// which hit their bounds out of the free set. If `released` is given (a variable and the bound
+    /// it was released from) and the first solution of the subproblem does not move this variable
+    /// away from its bound (or the subproblem is singular), `false` is returned.
+    fn solve_subproblem(&mut self, released: Option<(usize, VarStatus)>) -> Result<bool, Error> {
+        let n = self.x.len();
+        for iter in 0..(3 * n + 1) {
+            let z = match self.free_solution() {
+                Some(z) => z,
+                None if released.is_some() && iter == 0 => return Ok(false),
+                None => {
+                    return Err(argmin_error!(
+                        ConditionViolated,
+                        "Active set method: Subproblem is singular."
+                    ))
+                }
+            };
+            if let (Some((t, bound)), 0) = (released, iter) {
+                let wrong_direction = match bound {
+                    VarStatus::Lower => z[t] <= self.lower[t],
+                    VarStatus::Upper => z[t] >= self.upper[t],
+                    VarStatus::Free => false,
+                };
+                if wrong_direction {
+                    return Ok(false);
+                }
+            }
+
+            // Largest step towards `z` which keeps all free variables within their bounds
+            let mut alpha = float!(1.0);
+            let mut hit = None;
+            for (j, zj) in z.iter().enumerate() {
+                if self.status[j] != VarStatus::Free {
+                    continue;
+                }
+                let step = if *zj <= self.lower[j] {
+                    (self.x[j] - self.lower[j]) / (self.x[j] - *zj)
+                } else if *zj >= self.upper[j] {
+                    (self.upper[j] - self.x[j]) / (*zj - self.x[j])
+                } else {
+                    continue;
+                };
+                if step < alpha || hit.is_none() {
+                    alpha = step.min(alpha);
+                    hit = Some(j);
+                }
+            }
+
+            match hit {
+                None => {
+                    self.x = z;
+                    return Ok(true);
+                }
+                Some(h) => {
+                    let alpha = alpha.max(float!(0.0));
+                    for (j, zj) in z.iter().enumerate() {
+                        if self.status[j] != VarStatus::Free {
+                            continue;
+                        }
+                        self.x[j] = self.x[j] + alpha * (*zj - self.x[j]);
+                        if j == h || self.x[j] <= self.lower[j] {
+                            if self.x[j] <= self.lower[j] || *zj <= self.lower[j] {
+                                self.x[j] = self.lower[j];
+                                self.status[j] = VarStatus::Lower;
+                            } else {
+                                self.x[j] = self.upper[j];
+                                self.status[j] = VarStatus::Upper;
+                            }
+                        } else if self.x[j] >= self.upper[j] {
+                            self.x[j] = self.upper[j];
+                            self.status[j] = VarStatus::Upper;
+                        }
+                    }
+                }
+            }
+        }
+        Err(argmin_error!(
+            PotentialBug,
+            "Active set method: Subproblem did not converge."
+        ))
+    }
+
+    /// Solves the least squares problem in the free variables with all other variables fixed.
+    /// Returns `None` if the columns of `A` belonging to the free variables are linearly
+    /// dependent.
+    fn free_solution(&self) -> Option<Vec<F>> {
+        let free: Vec<usize> = (0..self.x.len())
+            .filter(|&j| self.status[j] == VarStatus::Free)
+            .collect();
+        let columns: Vec<Vec<F>> = free
+            .iter()
+            .map(|&j| self.a.iter().map(|row| row[j]).collect())
+            .collect();
+        let rhs: Vec<F> = self
+            .a
+            .iter()
+            .zip(self.b.iter())
+            .map(|(row, bi)| {
+                (0..self.x.len())
+                    .filter(|&k| self.status[k] != VarStatus::Free)
+                    .fold(*bi, |acc, k| acc - row[k] * self.x[k])
+            })
+            .collect();
+        let sol = least_squares_qr(&columns, &rhs)?;
+        let mut z = self.x.clone();
+        for (k, &j) in free.iter().enumerate() {
+            z[j] = sol[k];
+        }
+        Some(z)
+    }
+}
+
+/// Sets up the active set method for `min ||r(x)||` subject to the given bounds, where `r` is the
+/// (affine) operator of the problem with constant Jacobian `A`. Returns the active set method and
+/// the parameter vector provided to the solver, which is used as template for the solution.
+fn init_active_set<O, P, J, U, F>(
+    solver_name: &str,
+    problem: &mut Problem<O>,
+    state: &mut IterState<P, (), J, (), F>,
+    bounds: Option<(Vec<F>, Vec<F>)>,
+) -> Result<(ActiveSet<F>, P), Error>
+where
+    O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
+    P: Clone + ArgminGet<usize, F> + ArgminLen,
+    U: ArgminGet<usize, F> + ArgminLen,
+    J: ArgminGet<(usize, usize), F>,
+    F: ArgminFloat,
+{
+    let param = state.take_param().ok_or_else(argmin_error_closure!(
+        NotInitialized,
+        format!(
+            concat!(
+                "`{}` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            ),
+            solver_name
+        )
+    ))?;
+    let n = param.len();
+    let (lower, upper) = bounds.unwrap_or_else(|| (vec![float!(0.0); n], vec![F::infinity(); n]));
+    if lower.len() != n || upper.len() != n {
+        return Err(argmin_error!(
+            InvalidParameter,
+            format!(
+                "`{}`: Bounds and parameter vector must be of the same length.",
+                solver_name
+            )
+        ));
+    }
+    if lower.iter().zip(upper.iter()).any(|(l, u)| l > u) {
+        return Err(argmin_error!(
+            InvalidParameter,
+            format!(
+                "`{}`: Lower bound must not be larger than upper bound.",
+                solver_name
+            )
+        ));
+    }
+
+    let residuals = problem.apply(&param)?;
+    let jacobian = problem.jacobian(&param)?;
+
+    // The active set method selects columns of `A`, therefore it works on a dense copy.
+    let x0: Vec<F> = (0..n).map(|i| param.get_elem(i)).collect();
+    let a: Vec<Vec<F>> = (0..residuals.len())
+        .map(|i| (0..n).map(|j| jacobian.get_elem((i, j))).collect())
+        .collect();
+    // r(x0) = A x0 - b  =>  b = A x0 - r(x0)
+    let b: Vec<F> = a
+        .iter()
+        .enumerate()
+        .map(|(i, row)| dot(row, &x0) - residuals.get_elem(i))
+        .collect();
+
+    Ok((ActiveSet::new(a, b, lower, upper)?, param))
+}
+
+/// Writes `x` into `template` and computes the norm of the residuals
+fn finalize<O, P, U, F>(problem: &mut Problem<O>, mut template: P, x: &[F]) -> Result<(P, F), Error>
+where
+    O: Operator<Param = P, Output = U>,
+    P: ArgminSet<usize, F>,
+    U: ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    for (i, xi) in x.iter().enumerate() {
+        template.set_elem(i, *xi);
+    }
+    let cost = problem.apply(&template)?.l2_norm();
+    Ok((template, cost))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Error, State};
+    use approx::assert_relative_eq;
+
+    /// Linear least squares problem `min ||Ax - b||` with dense `Vec` matrices
+    #[derive(Clone)]
+    pub(super) struct LinearLeastSquares {
+        pub a: Vec<Vec<f64>>,
+        pub b: Vec<f64>,
+    }
+
+    impl Operator for LinearLeastSquares {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self
+                .a
+                .iter()
+                .zip(self.b.iter())
+                .map(|(row, b)| dot(row, x) - b)
+                .collect())
+        }
+    }
+
+    impl Jacobian for LinearLeastSquares {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn jacobian(&self, _x: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(self.a.clone())
+        }
+    }
+
+    /// A small random problem
+    fn random_problem(m: usize, n: usize, seed: u64) -> (Vec<Vec<f64>>, Vec<f64>) {
+        use rand::{Rng, SeedableRng};
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let a: Vec<Vec<f64>> = (0..m)
+            .map(|_| (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect())
+            .collect();
+        let b: Vec<f64> = (0..m).map(|_| rng.gen_range(-1.0..1.0)).collect();
+        (a, b)
+    }
+
+    /// Checks the KKT conditions of the bound-constrained problem
+    fn assert_kkt(active_set: &ActiveSet<f64>) {
+        let w = active_set.negative_gradient();
+        for (j, wj) in w.iter().enumerate() {
+            let (x, l, u) = (active_set.x[j], active_set.lower[j], active_set.upper[j]);
+            assert!(x >= l && x <= u);
+            if x > l {
+                assert!(*wj >= -1e-8);
+            }
+            if x < u {
+                assert!(*wj <= 1e-8);
+            }
+        }
+    }
+
+    #[test]
+    fn test_active_set_nonnegative() {
+        for seed in 0..20 {
+            let (a, b) = random_problem(8, 5, seed);
+            let mut active_set =
+                ActiveSet::new(a, b, vec![0.0; 5], vec![f64::INFINITY; 5]).unwrap();
+            let mut iters = 0;
+            while active_set.iterate(1e-12).unwrap() {
+                iters += 1;
+                assert!(iters < 100);
+            }
+            assert_kkt(&active_set);
+        }
+    }
+
+    #[test]
+    fn test_active_set_bounded() {
+        for seed in 0..20 {
+            let (a, b) = random_problem(8, 5, seed);
+            let lower = vec![-0.2, f64::NEG_INFINITY, 0.0, -1.0, 0.1];
+            let upper = vec![0.2, 0.1, f64::INFINITY, 1.0, 0.1];
+            let mut active_set = ActiveSet::new(a, b, lower, upper).unwrap();
+            let mut iters = 0;
+            while active_set.iterate(1e-12).unwrap() {
+                iters += 1;
+                assert!(iters < 100);
+            }
+            assert_kkt(&active_set);
+        }
+    }
+
+    #[test]
+    fn test_active_set_rank_deficient() {
+        // The second column duplicates the first one and the fourth column is the sum of the
+        // first and the third one.
+        let a = vec![
+            vec![1.0, 1.0, 0.0, 1.0],
+            vec![2.0, 2.0, 1.0, 3.0],
+            vec![0.0, 0.0, 1.0, 1.0],
+            vec![1.0, 1.0, -1.0, 0.0],
+        ];
+        let b = vec![1.0, 5.0, 3.0, -2.0];
+        let mut active_set = ActiveSet::new(a, b, vec![0.0; 4], vec![f64::INFINITY; 4]).unwrap();
+        let mut iters = 0;
+        while active_set.iterate(1e-12).unwrap() {
+            iters += 1;
+            assert!(iters < 100);
+        }
+        assert_kkt(&active_set);
+        // `A x = b` has the nonnegative solution `x = [0, 0, 2, 1]`, hence the residual vanishes.
+        for (row, bi) in active_set.a.iter().zip(active_set.b.iter()) {
+            assert_relative_eq!(dot(row, &active_set.x), *bi, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_active_set_ill_conditioned() {
+        // The columns of `A` are nearly linearly dependent and the condition number of `A` is
+        // about `1e8`, hence `A^T A` is singular in double precision. The solution of the
+        // unconstrained problem is `x = [-1 / delta, 1 / delta]`.
+        let delta = 1e-8;
+        let a = vec![
+            vec![1.0, 1.0],
+            vec![1.0, 1.0 + delta],
+            vec![1.0, 1.0 - delta],
+        ];
+        let b = vec![0.0, 1.0, -1.0];
+        let active_set =
+            ActiveSet::new(a, b, vec![f64::NEG_INFINITY; 2], vec![f64::INFINITY; 2]).unwrap();
+        assert_relative_eq!(active_set.x[0] * delta, -1.0, epsilon = 1e-6);
+        assert_relative_eq!(active_set.x[1] * delta, 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_init_active_set_errors() {
+        let mut problem = Problem::new(LinearLeastSquares {
+            a: vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+            b: vec![1.0, 1.0],
+        });
+        let res = init_active_set("Solver", &mut problem, &mut IterState::new(), None);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`Solver` requires an initial parameter vector. Please ",
+                "provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+        let res = init_active_set(
+            "Solver",
+            &mut problem,
+            &mut IterState::new().param(vec![0.0, 0.0]),
+            Some((vec![0.0], vec![1.0])),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Solver`: Bounds and parameter vector must be of the same length.\""
+        );
+        let res = init_active_set(
+            "Solver",
+            &mut problem,
+            &mut IterState::new().param(vec![0.0, 0.0]),
+            Some((vec![0.0, 2.0], vec![1.0, 1.0])),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Solver`: Lower bound must not be larger than upper bound.\""
+        );
+    }
+
+    #[test]
+    fn test_init_active_set_dense() {
+        let mut problem = Problem::new(LinearLeastSquares {
+            a: vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]],
+            b: vec![1.0, -1.0, 2.0],
+        });
+        // The initial parameter vector does not influence the least squares problem
+        let (active_set, _) = init_active_set(
+            "Solver",
+            &mut problem,
+            &mut IterState::new().param(vec![0.7, -3.0]),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            active_set.a,
+            vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]
+        );
+        assert_relative_eq!(active_set.b[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(active_set.b[1], -1.0, epsilon = 1e-12);
+        assert_relative_eq!(active_set.b[2], 2.0, epsilon = 1e-12);
+        assert_eq!(active_set.x, vec![0.0, 0.0]);
+        assert_eq!(active_set.status, vec![VarStatus::Lower; 2]);
+    }
+}
@@ -25,6 +25,8 @@ use crate::core::{
     KV,
 };
 use argmin_math::{ArgminAdd, ArgminMinMax, ArgminMul, ArgminRandom, ArgminSub, ArgminZeroLike};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -41,6 +43,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// The optimization problem is required to implement [`CostFunction`].
 ///
+/// The random number generator is part of the solver and is therefore stored in checkpoints
+/// (given that it implements `serde::Serialize`). A run is reproducible if the solver is
+/// constructed via [`ParticleSwarm::new_with_rng`] with a seeded random number generator.
+///
 /// ## References
 ///
 /// \[0\] Zambrano-Bigiarini, M. et.al. (2013): Standard Particle Swarm Optimisation 2011 at
@@ -50,7 +56,7 @@ use serde::{Deserialize, Serialize};
 /// \[1\] <https://en.wikipedia.org/wiki/Particle_swarm_optimization>
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct ParticleSwarm<P, F> {
+pub struct ParticleSwarm<P, F, R = Xoshiro256PlusPlus> {
     /// Inertia weight
     weight_inertia: F,
     /// Cognitive acceleration coefficient
@@ -61,6 +67,8 @@ pub struct ParticleSwarm<P, F> {
     bounds: (P, P),
     /// Number of particles
     num_particles: usize,
+    /// Random number generator
+    rng: R,
 }
 
 impl<P, F> ParticleSwarm<P, F>
@@ -85,6 +93,9 @@ where
     /// * cognitive: `0.5 + ln(2)`
     /// * social: `0.5 + ln(2)`
     ///
+    /// Uses the `Xoshiro256PlusPlus` RNG seeded from system entropy. For use of another (or a
+    /// seeded) RNG, consider using [`ParticleSwarm::new_with_rng`].
+    ///
     /// # Example
     ///
     /// ```
@@ -94,12 +105,42 @@ where
     /// let pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40);
     /// ```
     pub fn new(bounds: (P, P), num_particles: usize) -> Self {
+        ParticleSwarm::new_with_rng(bounds, num_particles, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<P, F, R> ParticleSwarm<P, F, R>
+where
+    P: Clone + SyncAlias + ArgminSub<P, P> + ArgminMul<F, P> + ArgminRandom + ArgminZeroLike,
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Construct a new instance of `ParticleSwarm` with a given random number generator
+    ///
+    /// Same as [`ParticleSwarm::new`], but requires a RNG which must implement `rand::Rng` (and
+    /// `serde::Serialize` if the `serde1` feature is enabled). Using a seeded RNG makes runs
+    /// reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// use rand::SeedableRng;
+    /// use rand_xoshiro::Xoshiro256PlusPlus;
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let pso: ParticleSwarm<_, f64, _> =
+    ///     ParticleSwarm::new_with_rng((lower_bound, upper_bound), 40, rng);
+    /// ```
+    pub fn new_with_rng(bounds: (P, P), num_particles: usize, rng: R) -> Self {
         ParticleSwarm {
             weight_inertia: float!(1.0f64 / (2.0 * 2.0f64.ln())),
             weight_cognitive: float!(0.5 + 2.0f64.ln()),
             weight_social: float!(0.5 + 2.0f64.ln()),
             bounds,
             num_particles,
+            rng,
         }
     }
 
@@ -214,23 +255,23 @@ where
     }
 
     /// Initializes positions and velocities for all particles
-    fn initialize_positions_and_velocities(&self) -> (Vec<P>, Vec<P>) {
+    fn initialize_positions_and_velocities(&mut self) -> (Vec<P>, Vec<P>) {
         let (min, max) = &self.bounds;
         let delta = max.sub(min);
         let delta_neg = delta.mul(&float!(-1.0));
 
         (
             (0..self.num_particles)
-                .map(|_| P::rand_from_range(min, max))
+                .map(|_| P::rand_from_range(min, max, &mut self.rng))
                 .collect(),
             (0..self.num_particles)
-                .map(|_| P::rand_from_range(&delta_neg, &delta))
+                .map(|_| P::rand_from_range(&delta_neg, &delta, &mut self.rng))
                 .collect(),
         )
     }
 }
 
-impl<O, P, F> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F>
+impl<O, P, F, R> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F, R>
 where
     O: CostFunction<Param = P, Output = F> + SyncAlias,
     P: SerializeAlias
@@ -243,6 +284,7 @@ where
         + ArgminRandom
         + ArgminMinMax,
     F: ArgminFloat,
+    R: Rng + SerializeAlias,
 {
     const NAME: &'static str = "Particle Swarm Optimization";
 
@@ -315,13 +357,14 @@ where
 
                 // ad 2)
                 let to_optimum = p.best_position.sub(&p.position);
-                let pull_to_optimum = P::rand_from_range(&zero, &to_optimum);
+                let pull_to_optimum = P::rand_from_range(&zero, &to_optimum, &mut self.rng);
                 let pull_to_optimum = pull_to_optimum.mul(&self.weight_cognitive);
 
                 // ad 3)
                 let to_global_optimum = best_particle.position.sub(&p.position);
                 let pull_to_global_optimum =
-                    P::rand_from_range(&zero, &to_global_optimum).mul(&self.weight_social);
+                    P::rand_from_range(&zero, &to_global_optimum, &mut self.rng)
+                        .mul(&self.weight_social);
 
                 p.velocity = momentum.add(&pull_to_optimum).add(&pull_to_global_optimum);
                 let new_position = p.position.add(&p.velocity);
@@ -422,6 +465,7 @@ mod tests {
             weight_social,
             bounds,
             num_particles,
+            rng: _rng,
         } = pso;
 
         assert_relative_eq!(
@@ -538,7 +582,7 @@ mod tests {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
         let num_particles = 100;
-        let pso: ParticleSwarm<_, f64> =
+        let mut pso: ParticleSwarm<_, f64> =
             ParticleSwarm::new((lower_bound, upper_bound), num_particles);
 
         let (positions, velocities) = pso.initialize_positions_and_velocities();
@@ -727,4 +771,50 @@ mod tests {
             assert_eq!(state.get_cost().to_ne_bytes(), (-3.0f64).to_ne_bytes());
         }
     }
+
+    #[test]
+    fn test_new_with_rng_reproducible() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+        let run = || {
+            let mut problem = Problem::new(TestProblem::new());
+            let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new_with_rng(
+                (lower_bound.clone(), upper_bound.clone()),
+                10,
+                Xoshiro256PlusPlus::seed_from_u64(42),
+            );
+            let (mut state, _) = pso.init(&mut problem, PopulationState::new()).unwrap();
+            for _ in 0..5 {
+                (state, _) = pso.next_iter(&mut problem, state).unwrap();
+            }
+            state
+                .take_population()
+                .unwrap()
+                .into_iter()
+                .map(|p| p.position)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(run(), run());
+    }
+
+    #[cfg(feature = "serde1")]
+    #[test]
+    fn test_serialized_rng_continues_random_stream() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new_with_rng(
+            (lower_bound, upper_bound),
+            10,
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        );
+        pso.initialize_positions_and_velocities();
+
+        let serialized = serde_json::to_string(&pso).unwrap();
+        let mut restored: ParticleSwarm<Vec<f64>, f64> = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(
+            pso.initialize_positions_and_velocities(),
+            restored.initialize_positions_and_velocities()
+        );
+    }
 }
@@ -0,0 +1,254 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Preconditioner;
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{ArgminDiagonal, ArgminGet, ArgminLen, ArgminSet};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of attempts with increasing diagonal shifts
+const MAX_ATTEMPTS: usize = 30;
+
+/// # Incomplete Cholesky preconditioner
+///
+/// Computes the incomplete Cholesky factorization `L * L^T ≈ A` without fill-in (IC(0)) of a
+/// symmetric positive definite matrix `A`, i.e. `L` is only allowed to be nonzero where the lower
+/// triangle of `A` is nonzero. Only the lower triangle of `A` is accessed.
+///
+/// The incomplete factorization may break down even for positive definite matrices. In this case
+/// it is repeated for `A + alpha * diag(A)` with increasing shifts `alpha` (Manteuffel, 1980).
+/// The final shift is available via [`get_shift`](`IncompleteCholesky::get_shift`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::Error;
+/// use argmin::solver::preconditioner::{IncompleteCholesky, Preconditioner};
+///
+/// # fn main() -> Result<(), Error> {
+/// let a = vec![
+///     vec![4.0f64, 1.0, 0.0],
+///     vec![1.0, 4.0, 1.0],
+///     vec![0.0, 1.0, 4.0],
+/// ];
+/// let ic = IncompleteCholesky::new(&a)?;
+/// let z = ic.apply_inverse(&vec![1.0, 1.0, 1.0])?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Reference
+///
+/// Thomas A. Manteuffel (1980). An Incomplete Factorization Technique for Positive Definite
+/// Linear Systems. Mathematics of Computation 34(150), 473–497.
+/// <https://doi.org/10.2307/2006097>
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct IncompleteCholesky<H, F> {
+    /// Lower triangular factor (the strict upper triangle is not used)
+    l: H,
+    /// Dimension of the matrix
+    n: usize,
+    /// Diagonal shift
+    shift: F,
+}
+
+impl<H, F> IncompleteCholesky<H, F>
+where
+    H: Clone + ArgminGet<(usize, usize), F> + ArgminSet<(usize, usize), F>,
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`IncompleteCholesky`] by factorizing `A`
+    ///
+    /// Returns an error if the diagonal of `A` is not positive or if the factorization fails for
+    /// all attempted shifts.
+    pub fn new<P>(a: &H) -> Result<Self, Error>
+    where
+        H: ArgminDiagonal<P>,
+        P: ArgminGet<usize, F> + ArgminLen,
+    {
+        let diag = a.diagonal();
+        let n = diag.len();
+        let diag: Vec<F> = (0..n).map(|i| diag.get_elem(i)).collect();
+        if diag.iter().any(|&d| d <= float!(0.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`IncompleteCholesky`: Diagonal of the matrix must be positive."
+            ));
+        }
+
+        let mut shift = float!(0.0);
+        for _ in 0..MAX_ATTEMPTS {
+            if let Some(l) = Self::factorize(a, &diag, shift) {
+                return Ok(IncompleteCholesky { l, n, shift });
+            }
+            shift = if shift == float!(0.0) {
+                float!(1e-3)
+            } else {
+                shift * float!(2.0)
+            };
+        }
+        Err(argmin_error!(
+            ConditionViolated,
+            "`IncompleteCholesky`: Factorization failed. Is the matrix symmetric positive definite?"
+        ))
+    }
+
+    /// Returns the diagonal shift `alpha` which was necessary to compute the factorization of
+    /// `A + alpha * diag(A)`
+    pub fn get_shift(&self) -> F {
+        self.shift
+    }
+
+    /// IC(0) factorization of `A + shift * diag(A)`. Returns `None` on breakdown.
+    fn factorize(a: &H, diag: &[F], shift: F) -> Option<H> {
+        let n = diag.len();
+        let mut l = a.clone();
+        for (k, &dk) in diag.iter().enumerate() {
+            let pivot = (0..k).fold(dk * (float!(1.0) + shift), |acc, j| {
+                acc - l.get_elem((k, j)).powi(2)
+            });
+            if pivot <= float!(0.0) || !pivot.is_finite() {
+                return None;
+            }
+            let lkk = pivot.sqrt();
+            l.set_elem((k, k), lkk);
+            for i in (k + 1)..n {
+                let aik = a.get_elem((i, k));
+                let lik = if aik == float!(0.0) {
+                    aik
+                } else {
+                    (0..k).fold(aik, |acc, j| acc - l.get_elem((i, j)) * l.get_elem((k, j))) / lkk
+                };
+                l.set_elem((i, k), lik);
+            }
+        }
+        Some(l)
+    }
+}
+
+impl<P, H, F> Preconditioner<P> for IncompleteCholesky<H, F>
+where
+    P: Clone + ArgminGet<usize, F> + ArgminSet<usize, F>,
+    H: ArgminGet<(usize, usize), F>,
+    F: ArgminFloat,
+{
+    fn apply_inverse(&self, r: &P) -> Result<P, Error> {
+        let n = self.n;
+        let mut z = r.clone();
+        // Solve `L * y = r`
+        for i in 0..n {
+            let sum = (0..i).fold(z.get_elem(i), |acc, j| {
+                acc - self.l.get_elem((i, j)) * z.get_elem(j)
+            });
+            z.set_elem(i, sum / self.l.get_elem((i, i)));
+        }
+        // Solve `L^T * z = y`
+        for i in (0..n).rev() {
+            let sum = ((i + 1)..n).fold(z.get_elem(i), |acc, j| {
+                acc - self.l.get_elem((j, i)) * z.get_elem(j)
+            });
+            z.set_elem(i, sum / self.l.get_elem((i, i)));
+        }
+        Ok(z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+    use argmin_math::ArgminDot;
+
+    #[test]
+    fn test_tridiagonal() {
+        // The Cholesky factor of a tridiagonal matrix has no fill-in, therefore IC(0) is exact.
+        let a = vec![
+            vec![4.0f64, 1.0, 0.0, 0.0],
+            vec![1.0, 4.0, 1.0, 0.0],
+            vec![0.0, 1.0, 4.0, 1.0],
+            vec![0.0, 0.0, 1.0, 4.0],
+        ];
+        let ic = IncompleteCholesky::new(&a).unwrap();
+        assert_eq!(ic.get_shift().to_ne_bytes(), 0.0f64.to_ne_bytes());
+        let r = vec![1.0, -2.0, 0.5, 3.0];
+        let z = ic.apply_inverse(&r).unwrap();
+        let az = a.dot(&z);
+        for i in 0..4 {
+            assert_relative_eq!(az[i], r[i], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_pattern() {
+        // Zeros in the lower triangle of `A` remain zeros in `L`
+        let a = vec![
+            vec![4.0f64, 1.0, 1.0],
+            vec![1.0, 4.0, 0.0],
+            vec![1.0, 0.0, 4.0],
+        ];
+        let ic = IncompleteCholesky::new(&a).unwrap();
+        assert_eq!(ic.l[2][1].to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_relative_eq!(ic.l[0][0], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(ic.l[1][0], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(ic.l[2][0], 0.5, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_shift() {
+        // Positive definite, but IC(0) breaks down without a shift
+        let a = vec![
+            vec![6.0f64, 3.0, 0.0, 4.0],
+            vec![3.0, 6.0, -3.0, 0.0],
+            vec![0.0, -3.0, 4.0, 4.0],
+            vec![4.0, 0.0, 4.0, 9.0],
+        ];
+        assert!(IncompleteCholesky::factorize(&a, &[6.0, 6.0, 4.0, 9.0], 0.0).is_none());
+        let ic = IncompleteCholesky::new(&a).unwrap();
+        assert!(ic.get_shift() > 0.0);
+    }
+
+    #[test]
+    fn test_invalid_diagonal() {
+        let a = vec![vec![1.0f64, 0.0], vec![0.0, -1.0]];
+        assert_error!(
+            IncompleteCholesky::new(&a),
+            ArgminError,
+            "Invalid parameter: \"`IncompleteCholesky`: Diagonal of the matrix must be positive.\""
+        );
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    #[test]
+    fn test_ndarray() {
+        use ndarray::array;
+
+        let a = array![[4.0f64, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]];
+        let ic = IncompleteCholesky::new(&a).unwrap();
+        let r = array![1.0, -2.0, 0.5];
+        let az = a.dot(&ic.apply_inverse(&r).unwrap());
+        for i in 0..3 {
+            assert_relative_eq!(az[i], r[i], epsilon = 1e-12);
+        }
+    }
+
+    #[cfg(feature = "_nalgebral")]
+    #[test]
+    fn test_nalgebra() {
+        use nalgebra::{DMatrix, DVector};
+
+        let a = DMatrix::from_row_slice(3, 3, &[4.0f64, 1.0, 0.0, 1.0, 4.0, 1.0, 0.0, 1.0, 4.0]);
+        let ic = IncompleteCholesky::new(&a).unwrap();
+        let r = DVector::from_vec(vec![1.0, -2.0, 0.5]);
+        let az = &a * ic.apply_inverse(&r).unwrap();
+        for i in 0..3 {
+            assert_relative_eq!(az[i], r[i], epsilon = 1e-12);
+        }
+    }
+}
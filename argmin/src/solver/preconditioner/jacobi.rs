@@ -0,0 +1,140 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Preconditioner;
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{ArgminDiagonal, ArgminDiv, ArgminGet, ArgminLen};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Jacobi preconditioner
+///
+/// Uses the diagonal of the matrix `A` as preconditioner (`M = diag(A)`). This compensates for
+/// badly scaled variables at negligible cost.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::Error;
+/// use argmin::solver::preconditioner::{JacobiPreconditioner, Preconditioner};
+///
+/// # fn main() -> Result<(), Error> {
+/// let a = vec![vec![4.0f64, 1.0], vec![1.0, 100.0]];
+/// let jacobi = JacobiPreconditioner::new(&a)?;
+/// let z = jacobi.apply_inverse(&vec![2.0, 50.0])?;
+/// # assert_eq!(z, vec![0.5, 0.5]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct JacobiPreconditioner<P> {
+    /// Diagonal of `A`
+    diag: P,
+}
+
+impl<P> JacobiPreconditioner<P> {
+    /// Construct a new instance of [`JacobiPreconditioner`] from a square matrix `A`
+    ///
+    /// Returns an error if the diagonal of `A` contains zeros.
+    pub fn new<H, F>(a: &H) -> Result<Self, Error>
+    where
+        H: ArgminDiagonal<P>,
+        P: ArgminGet<usize, F> + ArgminLen,
+        F: ArgminFloat,
+    {
+        Self::from_diagonal(a.diagonal())
+    }
+
+    /// Construct a new instance of [`JacobiPreconditioner`] from the diagonal of `A`
+    ///
+    /// Returns an error if `diag` contains zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::preconditioner::JacobiPreconditioner;
+    /// # fn main() -> Result<(), Error> {
+    /// let jacobi = JacobiPreconditioner::from_diagonal(vec![4.0f64, 100.0])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_diagonal<F>(diag: P) -> Result<Self, Error>
+    where
+        P: ArgminGet<usize, F> + ArgminLen,
+        F: ArgminFloat,
+    {
+        if (0..diag.len()).any(|i| diag.get_elem(i) == float!(0.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`JacobiPreconditioner`: Diagonal must not contain zeros."
+            ));
+        }
+        Ok(JacobiPreconditioner { diag })
+    }
+}
+
+impl<P: ArgminDiv<P, P>> Preconditioner<P> for JacobiPreconditioner<P> {
+    fn apply_inverse(&self, r: &P) -> Result<P, Error> {
+        Ok(r.div(&self.diag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+
+    #[test]
+    fn test_new() {
+        let a = vec![
+            vec![4.0f64, 1.0, 0.0],
+            vec![1.0, -2.0, 3.0],
+            vec![0.0, 3.0, 8.0],
+        ];
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        assert_eq!(jacobi.diag, vec![4.0, -2.0, 8.0]);
+        assert_eq!(
+            jacobi.apply_inverse(&vec![1.0, 1.0, 2.0]).unwrap(),
+            vec![0.25, -0.5, 0.25]
+        );
+    }
+
+    #[test]
+    fn test_zero_diagonal() {
+        assert_error!(
+            JacobiPreconditioner::from_diagonal(vec![1.0f64, 0.0]),
+            ArgminError,
+            "Invalid parameter: \"`JacobiPreconditioner`: Diagonal must not contain zeros.\""
+        );
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    #[test]
+    fn test_ndarray() {
+        use ndarray::array;
+
+        let a = array![[4.0f64, 1.0], [1.0, 100.0]];
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        let z = jacobi.apply_inverse(&array![2.0, 50.0]).unwrap();
+        assert_eq!(z, array![0.5, 0.5]);
+    }
+
+    #[cfg(feature = "_nalgebral")]
+    #[test]
+    fn test_nalgebra() {
+        use nalgebra::{DMatrix, DVector};
+
+        let a = DMatrix::from_row_slice(2, 2, &[4.0f64, 1.0, 1.0, 100.0]);
+        let jacobi = JacobiPreconditioner::new(&a).unwrap();
+        let z = jacobi
+            .apply_inverse(&DVector::from_vec(vec![2.0, 50.0]))
+            .unwrap();
+        assert_eq!(z, DVector::from_vec(vec![0.5, 0.5]));
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Preconditioners
+//!
+//! A preconditioner `M` approximates the matrix `A` of a linear system `A * x = b` such that
+//! `M^-1 * A` is better conditioned than `A` while `M^-1 * r` is cheap to compute. Iterative
+//! solvers which support preconditioning accept any type implementing [`Preconditioner`].
+//!
+//! * [`IdentityPreconditioner`]: No preconditioning (`M = I`)
+//! * [`JacobiPreconditioner`]: Diagonal of `A`
+//! * [`IncompleteCholesky`]: Incomplete Cholesky factorization `L * L^T` of a symmetric positive
+//!   definite `A` which has the same sparsity pattern as `A`
+//!
+//! The latter two work with all matrix types which implement the required traits of
+//! `argmin-math`, which includes `Vec<Vec<_>>` as well as the ndarray and nalgebra backends.
+
+mod incompletecholesky;
+mod jacobi;
+
+pub use incompletecholesky::IncompleteCholesky;
+pub use jacobi::JacobiPreconditioner;
+
+use crate::core::Error;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface for preconditioners
+///
+/// Applies the inverse `M^-1` of the preconditioner to a vector.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::preconditioner::Preconditioner;
+///
+/// /// Scales every element of the vector by a fixed factor
+/// struct Scaling {
+///     factor: f64,
+/// }
+///
+/// impl Preconditioner<Vec<f64>> for Scaling {
+///     fn apply_inverse(&self, r: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         Ok(r.iter().map(|ri| ri / self.factor).collect())
+///     }
+/// }
+/// ```
+pub trait Preconditioner<P> {
+    /// Compute `M^-1 * r`
+    fn apply_inverse(&self, r: &P) -> Result<P, Error>;
+}
+
+/// Identity preconditioner (`M = I`)
+///
+/// Default preconditioner of all solvers which support preconditioning.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct IdentityPreconditioner;
+
+impl<P: Clone> Preconditioner<P> for IdentityPreconditioner {
+    fn apply_inverse(&self, r: &P) -> Result<P, Error> {
+        Ok(r.clone())
+    }
+}
+
+impl<P, M: Preconditioner<P>> Preconditioner<P> for &M {
+    fn apply_inverse(&self, r: &P) -> Result<P, Error> {
+        (*self).apply_inverse(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity() {
+        let r = vec![1.0f64, -2.0, 3.0];
+        assert_eq!(IdentityPreconditioner.apply_inverse(&r).unwrap(), r);
+    }
+
+    #[test]
+    fn test_reference() {
+        let r = vec![1.0f64, -2.0, 3.0];
+        let m = &IdentityPreconditioner;
+        assert_eq!(
+            Preconditioner::<Vec<f64>>::apply_inverse(&m, &r).unwrap(),
+            r
+        );
+    }
+}
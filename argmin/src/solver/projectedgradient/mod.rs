@@ -0,0 +1,123 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Projected gradient methods
+//!
+//! Minimization of smooth functions over closed convex sets onto which the Euclidean projection
+//! is cheap to compute, such as boxes, simplices or balls. The feasible set is provided via the
+//! [`Projection`](`crate::solver::projection::Projection`) trait.
+//!
+//! * [`ProjectedGradient`]: Projected gradient method with Armijo line search along the
+//!   projection arc
+//! * [`SPG`]: Spectral projected gradient method with Barzilai-Borwein steps and a nonmonotone
+//!   line search
+//!
+//! Both methods stop once the norm of the projected gradient `P(x - g) - x` falls below a given
+//! tolerance.
+//!
+//! ## References
+//!
+//! \[0\] Dimitri P. Bertsekas (1976). On the Goldstein-Levitin-Polyak gradient projection method.
+//! IEEE Transactions on Automatic Control 21(2), 174–184.
+//! <https://doi.org/10.1109/TAC.1976.1101194>
+//!
+//! \[1\] Ernesto G. Birgin, José Mario Martínez and Marcos Raydan (2000). Nonmonotone Spectral
+//! Projected Gradient Methods on Convex Sets. SIAM Journal on Optimization 10(4), 1196–1211.
+//! <https://doi.org/10.1137/S1052623497330963>
+
+mod projectedgradient_method;
+mod spg;
+
+pub use projectedgradient_method::ProjectedGradient;
+pub use spg::SPG;
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{CostFunction, Error, Gradient};
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    /// Rosenbrock function. Within the box `[-1.5, 0.8] x [-1.5, 2.0]`, the minimum is attained
+    /// at `[0.8, 0.64]`.
+    #[derive(Clone)]
+    pub(super) struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(x, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(x, 1.0, 100.0))
+        }
+    }
+
+    /// Cost function which is NaN if any component is negative
+    #[derive(Clone)]
+    pub(super) struct NanIfNegative {}
+
+    impl CostFunction for NanIfNegative {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            if x.iter().all(|xi| *xi >= 0.0) {
+                Ok(x.iter().sum())
+            } else {
+                Ok(f64::NAN)
+            }
+        }
+    }
+
+    impl Gradient for NanIfNegative {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![1.0; x.len()])
+        }
+    }
+
+    /// `f(x) = 1/2 sum_i d_i (x_i - c_i)^2` with `d = [1, 10, 100]` and `c = [2, -0.5, 0.5]`
+    #[derive(Clone)]
+    pub(super) struct ScaledQuadratic {}
+
+    const D: [f64; 3] = [1.0, 10.0, 100.0];
+    const C: [f64; 3] = [2.0, -0.5, 0.5];
+
+    impl CostFunction for ScaledQuadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(0.5
+                * x.iter()
+                    .zip(D.iter().zip(C.iter()))
+                    .map(|(xi, (di, ci))| di * (xi - ci).powi(2))
+                    .sum::<f64>())
+        }
+    }
+
+    impl Gradient for ScaledQuadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(x.iter()
+                .zip(D.iter().zip(C.iter()))
+                .map(|(xi, (di, ci))| di * (xi - ci))
+                .collect())
+        }
+    }
+}
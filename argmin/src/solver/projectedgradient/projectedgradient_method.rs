@@ -0,0 +1,447 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+use crate::solver::projection::Projection;
+use crate::solver::util::nonmonotone::MAX_BACKTRACKING_ITERS;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledSub, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Projected gradient method
+///
+/// Minimizes a smooth function over a closed convex set given via a [`Projection`] `P`. The
+/// iterates are obtained by searching along the projection arc
+///
+/// `x(alpha) = P(x_k - alpha * g_k)`
+///
+/// with `alpha = s * beta^m`, where `m` is the smallest nonnegative integer such that the Armijo
+/// condition
+///
+/// `f(x(alpha)) <= f(x_k) + sigma * <g_k, x(alpha) - x_k>`
+///
+/// holds (Bertsekas, 1976). The [initial step](`ProjectedGradient::with_initial_step`) `s`
+/// defaults to `1`, the [shrink factor](`ProjectedGradient::with_shrink_factor`) `beta` to `0.5`
+/// and the [sufficient decrease parameter](`ProjectedGradient::with_sufficient_decrease`)
+/// `sigma` to `1e-4`.
+///
+/// The norm of the projected gradient `P(x_k - g_k) - x_k` vanishes exactly at stationary points.
+/// It is reported as `pg_norm` in the KV and the algorithm stops once it falls below the
+/// [tolerance](`ProjectedGradient::with_tolerance`).
+///
+/// Requires an initial parameter vector, which is projected onto the feasible set first.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## Reference
+///
+/// Dimitri P. Bertsekas (1976). On the Goldstein-Levitin-Polyak gradient projection method.
+/// IEEE Transactions on Automatic Control 21(2), 174–184.
+/// <https://doi.org/10.1109/TAC.1976.1101194>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ProjectedGradient<C, F> {
+    /// Projection onto the feasible set
+    projection: C,
+    /// Initial step length of the line search
+    init_step: F,
+    /// Factor by which the step length is reduced
+    shrink: F,
+    /// Sufficient decrease parameter
+    sufficient_decrease: F,
+    /// Tolerance on the norm of the projected gradient
+    tol: F,
+    /// Norm of the projected gradient of the current iterate
+    pg_norm: F,
+}
+
+impl<C, F: ArgminFloat> ProjectedGradient<C, F> {
+    /// Construct a new instance of [`ProjectedGradient`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::ProjectedGradient;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> = ProjectedGradient::new(bounds);
+    /// ```
+    pub fn new(projection: C) -> Self {
+        ProjectedGradient {
+            projection,
+            init_step: float!(1.0),
+            shrink: float!(0.5),
+            sufficient_decrease: float!(1e-4),
+            tol: F::epsilon().sqrt(),
+            pg_norm: F::infinity(),
+        }
+    }
+
+    /// Set initial step length `s` of the line search
+    ///
+    /// Must be positive and defaults to `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::ProjectedGradient;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> = ProjectedGradient::new(bounds).with_initial_step(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_initial_step(mut self, step: F) -> Result<Self, Error> {
+        if step <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: initial step must be > 0."
+            ));
+        }
+        self.init_step = step;
+        Ok(self)
+    }
+
+    /// Set shrink factor `beta` of the line search
+    ///
+    /// Must be in `(0, 1)` and defaults to `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::ProjectedGradient;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> = ProjectedGradient::new(bounds).with_shrink_factor(0.3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_shrink_factor(mut self, shrink: F) -> Result<Self, Error> {
+        if shrink <= float!(0.0) || shrink >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: shrink factor must be in (0, 1)."
+            ));
+        }
+        self.shrink = shrink;
+        Ok(self)
+    }
+
+    /// Set sufficient decrease parameter `sigma` of the Armijo condition
+    ///
+    /// Must be in `(0, 1)` and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::ProjectedGradient;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> =
+    ///     ProjectedGradient::new(bounds).with_sufficient_decrease(1e-3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sufficient_decrease(mut self, sigma: F) -> Result<Self, Error> {
+        if sigma <= float!(0.0) || sigma >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: sufficient decrease parameter must be in (0, 1)."
+            ));
+        }
+        self.sufficient_decrease = sigma;
+        Ok(self)
+    }
+
+    /// Set tolerance on the norm of the projected gradient
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`. Close to the solution, the line search
+    /// is limited by the rounding errors of the cost function, which may prevent reaching very
+    /// small tolerances on ill-conditioned problems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::ProjectedGradient;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> = ProjectedGradient::new(bounds).with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: tol must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, C, P, G, F> Solver<O, IterState<P, G, (), (), F>> for ProjectedGradient<C, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    C: Projection<P>,
+    P: Clone + ArgminSub<P, P> + ArgminScaledSub<G, F, P> + ArgminDot<G, F> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Projected Gradient";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x0 = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ProjectedGradient` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let x0 = self.projection.project(&x0)?;
+        let cost = problem.cost(&x0)?;
+        let grad = problem.gradient(&x0)?;
+        self.pg_norm = self
+            .projection
+            .project(&x0.scaled_sub(&float!(1.0), &grad))?
+            .sub(&x0)
+            .l2_norm();
+        let pg_norm = self.pg_norm;
+        Ok((
+            state.param(x0).cost(cost).gradient(grad),
+            Some(kv!("pg_norm" => pg_norm;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ProjectedGradient`: Parameter vector in `state` not set"
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ProjectedGradient`: Gradient in `state` not set"
+        ))?;
+        let cost = state.get_cost();
+        if !cost.is_finite() {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`ProjectedGradient`: Cost function value is not finite."
+            ));
+        }
+
+        // Armijo line search along the projection arc
+        let mut alpha = self.init_step;
+        let mut accepted = None;
+        for _ in 0..MAX_BACKTRACKING_ITERS {
+            let x_new = self.projection.project(&x.scaled_sub(&alpha, &grad))?;
+            let cost_new = problem.cost(&x_new)?;
+            let decrease = x_new.sub(&x).dot(&grad);
+            if cost_new <= cost + self.sufficient_decrease * decrease {
+                accepted = Some((x_new, cost_new));
+                break;
+            }
+            alpha = alpha * self.shrink;
+        }
+        let (x_new, cost_new) = accepted.ok_or_else(argmin_error_closure!(
+            ConditionViolated,
+            "`ProjectedGradient`: Line search did not find a sufficient decrease."
+        ))?;
+
+        let grad_new = problem.gradient(&x_new)?;
+        self.pg_norm = self
+            .projection
+            .project(&x_new.scaled_sub(&float!(1.0), &grad_new))?
+            .sub(&x_new)
+            .l2_norm();
+
+        let pg_norm = self.pg_norm;
+        Ok((
+            state.param(x_new).cost(cost_new).gradient(grad_new),
+            Some(kv!("step_length" => alpha; "pg_norm" => pg_norm;)),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        if self.pg_norm <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{NanIfNegative, Rosenbrock, ScaledQuadratic};
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::solver::projection::BoxProjection;
+    use approx::assert_relative_eq;
+
+    type Bounds = BoxProjection<Vec<f64>>;
+
+    fn bounds() -> Bounds {
+        BoxProjection::new(vec![-1.0, -1.0, -1.0], vec![1.0, 1.0, 1.0])
+    }
+
+    test_trait_impl!(projected_gradient, ProjectedGradient<Bounds, f64>);
+
+    #[test]
+    fn test_new() {
+        let pg: ProjectedGradient<Bounds, f64> = ProjectedGradient::new(bounds());
+        let ProjectedGradient {
+            projection,
+            init_step,
+            shrink,
+            sufficient_decrease,
+            tol,
+            pg_norm,
+        } = pg;
+        assert_eq!(projection, bounds());
+        assert_eq!(init_step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(shrink.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(sufficient_decrease.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(pg_norm.is_infinite());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let pg = || ProjectedGradient::<Bounds, f64>::new(bounds());
+        for value in [0.0, -1.0] {
+            assert_error!(
+                pg().with_initial_step(value),
+                ArgminError,
+                "Invalid parameter: \"`ProjectedGradient`: initial step must be > 0.\""
+            );
+            assert_error!(
+                pg().with_tolerance(value),
+                ArgminError,
+                "Invalid parameter: \"`ProjectedGradient`: tol must be > 0.\""
+            );
+        }
+        for value in [0.0, -1.0, 1.0, 2.0] {
+            assert_error!(
+                pg().with_shrink_factor(value),
+                ArgminError,
+                "Invalid parameter: \"`ProjectedGradient`: shrink factor must be in (0, 1).\""
+            );
+            assert_error!(
+                pg().with_sufficient_decrease(value),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ProjectedGradient`: ",
+                    "sufficient decrease parameter must be in (0, 1).\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let mut pg: ProjectedGradient<Bounds, f64> = ProjectedGradient::new(bounds());
+        let res = pg.init(&mut Problem::new(ScaledQuadratic {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ProjectedGradient` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_line_search_fails() {
+        // All trial points have a NaN cost
+        let res = Executor::new(NanIfNegative {}, ProjectedGradient::new(bounds()))
+            .configure(|state| state.param(vec![0.0, 0.0, 0.0]).max_iters(10))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`ProjectedGradient`: Line search did not find a ",
+                "sufficient decrease.\""
+            )
+        );
+
+        // The cost at the initial point is NaN
+        let res = Executor::new(NanIfNegative {}, ProjectedGradient::new(bounds()))
+            .configure(|state| state.param(vec![-0.5, 0.5, 0.5]).max_iters(10))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`ProjectedGradient`: Cost function value is not finite.\""
+        );
+    }
+
+    #[test]
+    fn test_init_projects() {
+        let mut pg: ProjectedGradient<Bounds, f64> = ProjectedGradient::new(bounds());
+        let state = IterState::new().param(vec![5.0, -5.0, 0.0]);
+        let (state, _) = pg
+            .init(&mut Problem::new(ScaledQuadratic {}), state)
+            .unwrap();
+        assert_eq!(state.get_param().unwrap(), &vec![1.0, -1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_solver() {
+        // Separable problem: the solution is the projection of `c` onto the box
+        let solver = ProjectedGradient::new(bounds())
+            .with_tolerance(1e-6)
+            .unwrap();
+        let res = Executor::new(ScaledQuadratic {}, solver)
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        for (xi, expected) in x.iter().zip([1.0, -0.5, 0.5]) {
+            assert_relative_eq!(*xi, expected, epsilon = 1e-6);
+        }
+
+        let bounds = BoxProjection::new(vec![-1.5, -1.5], vec![0.8, 2.0]);
+        let res = Executor::new(Rosenbrock {}, ProjectedGradient::new(bounds))
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(20000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 0.8, epsilon = 1e-6);
+        assert_relative_eq!(x[1], 0.64, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,525 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+use crate::solver::projection::Projection;
+use crate::solver::util::nonmonotone::nonmonotone_backtracking;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledAdd, ArgminScaledSub, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// # Spectral projected gradient method (SPG)
+///
+/// Minimizes a smooth function over a closed convex set given via a [`Projection`] `P`. In each
+/// iteration, the search direction
+///
+/// `d_k = P(x_k - lambda_k * g_k) - x_k`
+///
+/// is computed with the Barzilai-Borwein (spectral) step length
+/// `lambda_k = <s, s> / <s, y>`, where `s = x_k - x_{k-1}` and `y = g_k - g_{k-1}`, safeguarded
+/// by the [step bounds](`SPG::with_step_bounds`). The step `x_{k+1} = x_k + alpha * d_k` is
+/// determined by a nonmonotone line search (Grippo, Lampariello and Lucidi, 1986) which only
+/// requires a sufficient decrease compared to the largest of the last `M` cost function values:
+///
+/// `f(x_k + alpha * d_k) <= max_{0 <= j < M} f(x_{k-j}) + gamma * alpha * <g_k, d_k>`
+///
+/// Rejected step lengths are reduced by safeguarded quadratic interpolation. This is algorithm
+/// SPG2 of Birgin, Martínez and Raydan (2000).
+///
+/// The [memory](`SPG::with_memory`) `M` defaults to `10` (`M = 1` gives a monotone line search)
+/// and the [sufficient decrease parameter](`SPG::with_sufficient_decrease`) `gamma` to `1e-4`.
+///
+/// The norm of the projected gradient `P(x_k - g_k) - x_k` is reported as `pg_norm` in the KV
+/// and the algorithm stops once it falls below the [tolerance](`SPG::with_tolerance`).
+///
+/// Requires an initial parameter vector, which is projected onto the feasible set first.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## References
+///
+/// Ernesto G. Birgin, José Mario Martínez and Marcos Raydan (2000). Nonmonotone Spectral
+/// Projected Gradient Methods on Convex Sets. SIAM Journal on Optimization 10(4), 1196–1211.
+/// <https://doi.org/10.1137/S1052623497330963>
+///
+/// Luigi Grippo, Francesco Lampariello and Stefano Lucidi (1986). A Nonmonotone Line Search
+/// Technique for Newton's Method. SIAM Journal on Numerical Analysis 23(4), 707–716.
+/// <https://doi.org/10.1137/0723046>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SPG<C, F> {
+    /// Projection onto the feasible set
+    projection: C,
+    /// Number of previous cost function values considered by the line search
+    memory: usize,
+    /// Sufficient decrease parameter
+    sufficient_decrease: F,
+    /// Lower bound on the spectral step length
+    step_min: F,
+    /// Upper bound on the spectral step length
+    step_max: F,
+    /// Tolerance on the norm of the projected gradient
+    tol: F,
+    /// Current spectral step length
+    spectral_step: F,
+    /// Previous cost function values
+    costs: VecDeque<F>,
+    /// Norm of the projected gradient of the current iterate
+    pg_norm: F,
+}
+
+impl<C, F: ArgminFloat> SPG<C, F> {
+    /// Construct a new instance of [`SPG`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::SPG;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let spg: SPG<_, f64> = SPG::new(bounds);
+    /// ```
+    pub fn new(projection: C) -> Self {
+        SPG {
+            projection,
+            memory: 10,
+            sufficient_decrease: float!(1e-4),
+            step_min: float!(1e-30),
+            step_max: float!(1e30),
+            tol: F::epsilon().sqrt(),
+            spectral_step: float!(1.0),
+            costs: VecDeque::new(),
+            pg_norm: F::infinity(),
+        }
+    }
+
+    /// Set memory `M` of the nonmonotone line search
+    ///
+    /// Must be positive and defaults to `10`. With `M = 1`, the line search is monotone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::SPG;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let spg: SPG<_, f64> = SPG::new(bounds).with_memory(5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_memory(mut self, memory: usize) -> Result<Self, Error> {
+        if memory < 1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SPG`: memory must be >= 1."
+            ));
+        }
+        self.memory = memory;
+        Ok(self)
+    }
+
+    /// Set sufficient decrease parameter `gamma` of the line search
+    ///
+    /// Must be in `(0, 1)` and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::SPG;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let spg: SPG<_, f64> = SPG::new(bounds).with_sufficient_decrease(1e-3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sufficient_decrease(mut self, gamma: F) -> Result<Self, Error> {
+        if gamma <= float!(0.0) || gamma >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SPG`: sufficient decrease parameter must be in (0, 1)."
+            ));
+        }
+        self.sufficient_decrease = gamma;
+        Ok(self)
+    }
+
+    /// Set lower and upper bound on the spectral step length
+    ///
+    /// Must satisfy `0 < min < max` and default to `1e-30` and `1e30`, respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::SPG;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let spg: SPG<_, f64> = SPG::new(bounds).with_step_bounds(1e-10, 1e10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_bounds(mut self, min: F, max: F) -> Result<Self, Error> {
+        if min <= float!(0.0) || max <= min {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SPG`: step bounds must satisfy 0 < min < max."
+            ));
+        }
+        self.step_min = min;
+        self.step_max = max;
+        Ok(self)
+    }
+
+    /// Set tolerance on the norm of the projected gradient
+    ///
+    /// Must be positive and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::SPG;
+    /// # use argmin::solver::projection::BoxProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = BoxProjection::new(vec![-1.0, -1.0], vec![1.0, 1.0]);
+    /// let spg: SPG<_, f64> = SPG::new(bounds).with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(InvalidParameter, "`SPG`: tol must be > 0."));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Clamps the step length to the step bounds
+    fn safeguard(&self, step: F) -> F {
+        if step.is_nan() {
+            self.step_max
+        } else {
+            step.max(self.step_min).min(self.step_max)
+        }
+    }
+}
+
+impl<O, C, P, G, F> Solver<O, IterState<P, G, (), (), F>> for SPG<C, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    C: Projection<P>,
+    P: Clone
+        + ArgminSub<P, P>
+        + ArgminScaledSub<G, F, P>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminDot<G, F>
+        + ArgminL2Norm<F>,
+    G: ArgminSub<G, G>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Spectral Projected Gradient";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x0 = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`SPG` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let x0 = self.projection.project(&x0)?;
+        let cost = problem.cost(&x0)?;
+        let grad = problem.gradient(&x0)?;
+        self.pg_norm = self
+            .projection
+            .project(&x0.scaled_sub(&float!(1.0), &grad))?
+            .sub(&x0)
+            .l2_norm();
+        self.spectral_step = self.safeguard(float!(1.0) / self.pg_norm);
+        self.costs.clear();
+        self.costs.push_back(cost);
+        let pg_norm = self.pg_norm;
+        Ok((
+            state.param(x0).cost(cost).gradient(grad),
+            Some(kv!("pg_norm" => pg_norm;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SPG`: Parameter vector in `state` not set"
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SPG`: Gradient in `state` not set"
+        ))?;
+        let cost = state.get_cost();
+
+        let direction = self
+            .projection
+            .project(&x.scaled_sub(&self.spectral_step, &grad))?
+            .sub(&x);
+        let slope = direction.dot(&grad);
+        let cost_max = self.costs.iter().fold(cost, |acc, c| acc.max(*c));
+
+        // Nonmonotone line search with safeguarded quadratic interpolation
+        let (x_new, cost_new, alpha) = nonmonotone_backtracking(
+            "SPG",
+            cost,
+            cost_max,
+            slope,
+            self.sufficient_decrease,
+            |alpha| {
+                let x_new = x.scaled_add(&alpha, &direction);
+                let cost_new = problem.cost(&x_new)?;
+                Ok((x_new, cost_new))
+            },
+        )?;
+
+        let grad_new = problem.gradient(&x_new)?;
+
+        // Barzilai-Borwein step length
+        let s = x_new.sub(&x);
+        let sty = s.dot(&grad_new.sub(&grad));
+        self.spectral_step = if sty <= float!(0.0) {
+            self.step_max
+        } else {
+            self.safeguard(s.l2_norm().powi(2) / sty)
+        };
+
+        self.costs.push_back(cost_new);
+        if self.costs.len() > self.memory {
+            self.costs.pop_front();
+        }
+
+        self.pg_norm = self
+            .projection
+            .project(&x_new.scaled_sub(&float!(1.0), &grad_new))?
+            .sub(&x_new)
+            .l2_norm();
+
+        let pg_norm = self.pg_norm;
+        let spectral_step = self.spectral_step;
+        Ok((
+            state.param(x_new).cost(cost_new).gradient(grad_new),
+            Some(kv!(
+                "step_length" => alpha;
+                "spectral_step" => spectral_step;
+                "pg_norm" => pg_norm;
+            )),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        if self.pg_norm <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{NanIfNegative, Rosenbrock, ScaledQuadratic};
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::solver::projectedgradient::ProjectedGradient;
+    use crate::solver::projection::{BoxProjection, SimplexProjection};
+    use approx::assert_relative_eq;
+
+    type Bounds = BoxProjection<Vec<f64>>;
+
+    fn bounds() -> Bounds {
+        BoxProjection::new(vec![-1.0, -1.0, -1.0], vec![1.0, 1.0, 1.0])
+    }
+
+    test_trait_impl!(spg, SPG<Bounds, f64>);
+
+    #[test]
+    fn test_new() {
+        let spg: SPG<Bounds, f64> = SPG::new(bounds());
+        let SPG {
+            projection,
+            memory,
+            sufficient_decrease,
+            step_min,
+            step_max,
+            tol,
+            spectral_step,
+            costs,
+            pg_norm,
+        } = spg;
+        assert_eq!(projection, bounds());
+        assert_eq!(memory, 10);
+        assert_eq!(sufficient_decrease.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(step_min.to_ne_bytes(), 1e-30f64.to_ne_bytes());
+        assert_eq!(step_max.to_ne_bytes(), 1e30f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(spectral_step.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(costs.is_empty());
+        assert!(pg_norm.is_infinite());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let spg = || SPG::<Bounds, f64>::new(bounds());
+        assert_error!(
+            spg().with_memory(0),
+            ArgminError,
+            "Invalid parameter: \"`SPG`: memory must be >= 1.\""
+        );
+        for value in [0.0, -1.0, 1.0, 2.0] {
+            assert_error!(
+                spg().with_sufficient_decrease(value),
+                ArgminError,
+                "Invalid parameter: \"`SPG`: sufficient decrease parameter must be in (0, 1).\""
+            );
+        }
+        for (min, max) in [(0.0, 1.0), (-1.0, 1.0), (1.0, 1.0), (2.0, 1.0)] {
+            assert_error!(
+                spg().with_step_bounds(min, max),
+                ArgminError,
+                "Invalid parameter: \"`SPG`: step bounds must satisfy 0 < min < max.\""
+            );
+        }
+        for value in [0.0, -1.0] {
+            assert_error!(
+                spg().with_tolerance(value),
+                ArgminError,
+                "Invalid parameter: \"`SPG`: tol must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_param_not_initialized() {
+        let mut spg: SPG<Bounds, f64> = SPG::new(bounds());
+        let res = spg.init(&mut Problem::new(ScaledQuadratic {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`SPG` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_memory() {
+        let bounds = BoxProjection::new(vec![-1.5, -1.5], vec![0.8, 2.0]);
+        let mut spg: SPG<Bounds, f64> = SPG::new(bounds).with_memory(2).unwrap();
+        let mut problem = Problem::new(Rosenbrock {});
+        let state = IterState::new().param(vec![-1.0, 1.0]);
+        let (mut state, _) = spg.init(&mut problem, state).unwrap();
+        assert_eq!(spg.costs.len(), 1);
+        for expected in [2, 2, 2] {
+            (state, _) = spg.next_iter(&mut problem, state).unwrap();
+            assert_eq!(spg.costs.len(), expected);
+            assert_relative_eq!(*spg.costs.back().unwrap(), state.get_cost());
+        }
+    }
+
+    #[test]
+    fn test_line_search_fails() {
+        // All trial points have a NaN cost
+        let res = Executor::new(NanIfNegative {}, SPG::new(bounds()))
+            .configure(|state| state.param(vec![0.0, 0.0, 0.0]).max_iters(10))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`SPG`: Line search did not find a sufficient decrease.\""
+        );
+
+        // The cost at the initial point is NaN
+        let res = Executor::new(NanIfNegative {}, SPG::new(bounds()))
+            .configure(|state| state.param(vec![-0.5, 0.5, 0.5]).max_iters(10))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`SPG`: Cost function value is not finite.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let res = Executor::new(ScaledQuadratic {}, SPG::new(bounds()))
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        for (xi, expected) in x.iter().zip([1.0, -0.5, 0.5]) {
+            assert_relative_eq!(*xi, expected, epsilon = 1e-6);
+        }
+
+        // On the simplex, the KKT conditions give `x_i = max(c_i - mu / d_i, 0)` with
+        // `mu = 1.5 / 1.01`
+        let simplex = SimplexProjection::new(1.0);
+        let res = Executor::new(ScaledQuadratic {}, SPG::new(simplex))
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        let mu = 1.5 / 1.01;
+        for (xi, expected) in x.iter().zip([2.0 - mu, 0.0, 0.5 - mu / 100.0]) {
+            assert_relative_eq!(*xi, expected, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_faster_than_projected_gradient() {
+        let bounds = BoxProjection::new(vec![-1.5, -1.5], vec![0.8, 2.0]);
+        let res = Executor::new(Rosenbrock {}, SPG::new(bounds.clone()))
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 0.8, epsilon = 1e-6);
+        assert_relative_eq!(x[1], 0.64, epsilon = 1e-6);
+        let spg_iters = res.state.get_iter();
+
+        let res = Executor::new(Rosenbrock {}, ProjectedGradient::new(bounds))
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(20000))
+            .run()
+            .unwrap();
+        assert!(spg_iters < res.state.get_iter());
+    }
+}
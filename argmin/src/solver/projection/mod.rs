@@ -0,0 +1,235 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Projections onto convex sets
+//!
+//! Solvers for constrained problems such as
+//! [`ProjectedGradient`](`crate::solver::projectedgradient::ProjectedGradient`) and
+//! [`SPG`](`crate::solver::projectedgradient::SPG`) access the feasible set via the Euclidean
+//! projection onto it, which is provided by types implementing [`Projection`].
+//!
+//! * [`BoxProjection`]: Box `{x : l <= x <= u}` (elementwise)
+//! * [`SimplexProjection`]: Simplex `{x : x_i >= 0, sum_i x_i = r}`
+//! * [`L2BallProjection`]: Euclidean ball `{x : ||x||_2 <= r}`
+//!
+//! ## References
+//!
+//! John Duchi, Shai Shalev-Shwartz, Yoram Singer and Tushar Chandra (2008). Efficient
+//! Projections onto the l1-Ball for Learning in High Dimensions. Proceedings of the 25th
+//! International Conference on Machine Learning, 272–279.
+//! <https://doi.org/10.1145/1390156.1390191>
+
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{ArgminGet, ArgminL2Norm, ArgminLen, ArgminMinMax, ArgminMul, ArgminSet};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface for projections onto convex sets
+///
+/// Returns the point of the feasible set which is closest to `x` in the Euclidean norm.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::projection::Projection;
+///
+/// /// Nonnegative orthant `{x : x >= 0}`
+/// struct Nonnegative {}
+///
+/// impl Projection<Vec<f64>> for Nonnegative {
+///     fn project(&self, x: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         Ok(x.iter().map(|xi| xi.max(0.0)).collect())
+///     }
+/// }
+/// ```
+pub trait Projection<P> {
+    /// Project `x` onto the feasible set
+    fn project(&self, x: &P) -> Result<P, Error>;
+}
+
+impl<P, C: Projection<P>> Projection<P> for &C {
+    fn project(&self, x: &P) -> Result<P, Error> {
+        (*self).project(x)
+    }
+}
+
+/// Projection onto the box `{x : l <= x <= u}`
+///
+/// The bounds are given elementwise and must satisfy `l <= u`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BoxProjection<P> {
+    lower: P,
+    upper: P,
+}
+
+impl<P> BoxProjection<P> {
+    /// Construct a new instance of [`BoxProjection`] from the lower and upper bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projection::BoxProjection;
+    /// let bounds = BoxProjection::new(vec![0.0f64, -1.0], vec![1.0, 1.0]);
+    /// ```
+    pub fn new(lower: P, upper: P) -> Self {
+        BoxProjection { lower, upper }
+    }
+}
+
+impl<P: ArgminMinMax> Projection<P> for BoxProjection<P> {
+    fn project(&self, x: &P) -> Result<P, Error> {
+        Ok(P::max(&P::min(x, &self.upper), &self.lower))
+    }
+}
+
+/// Projection onto the simplex `{x : x_i >= 0, sum_i x_i = r}` with radius `r > 0`
+///
+/// Uses the sorting based algorithm of Duchi et al. (2008) with complexity `O(n log n)`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SimplexProjection<F> {
+    radius: F,
+}
+
+impl<F> SimplexProjection<F> {
+    /// Construct a new instance of [`SimplexProjection`] with radius `r`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projection::SimplexProjection;
+    /// let simplex = SimplexProjection::new(1.0f64);
+    /// ```
+    pub fn new(radius: F) -> Self {
+        SimplexProjection { radius }
+    }
+}
+
+impl<P, F> Projection<P> for SimplexProjection<F>
+where
+    P: Clone + ArgminGet<usize, F> + ArgminSet<usize, F> + ArgminLen,
+    F: ArgminFloat,
+{
+    fn project(&self, x: &P) -> Result<P, Error> {
+        let n = x.len();
+        let mut u: Vec<F> = (0..n).map(|i| x.get_elem(i)).collect();
+        if u.iter().any(|ui| ui.is_nan()) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SimplexProjection`: vector must not contain NaN."
+            ));
+        }
+        u.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        let mut sum = float!(0.0);
+        let mut theta = float!(0.0);
+        for (j, uj) in u.iter().enumerate() {
+            sum = sum + *uj;
+            let candidate = (sum - self.radius) / F::from_usize(j + 1).unwrap();
+            if *uj > candidate {
+                theta = candidate;
+            }
+        }
+        let mut p = x.clone();
+        for i in 0..n {
+            p.set_elem(i, (x.get_elem(i) - theta).max(float!(0.0)));
+        }
+        Ok(p)
+    }
+}
+
+/// Projection onto the Euclidean ball `{x : ||x||_2 <= r}` with radius `r > 0`
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct L2BallProjection<F> {
+    radius: F,
+}
+
+impl<F> L2BallProjection<F> {
+    /// Construct a new instance of [`L2BallProjection`] with radius `r`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projection::L2BallProjection;
+    /// let ball = L2BallProjection::new(1.0f64);
+    /// ```
+    pub fn new(radius: F) -> Self {
+        L2BallProjection { radius }
+    }
+}
+
+impl<P, F> Projection<P> for L2BallProjection<F>
+where
+    P: Clone + ArgminL2Norm<F> + ArgminMul<F, P>,
+    F: ArgminFloat,
+{
+    fn project(&self, x: &P) -> Result<P, Error> {
+        let norm = x.l2_norm();
+        if norm <= self.radius {
+            Ok(x.clone())
+        } else {
+            Ok(x.mul(&(self.radius / norm)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(box_projection, BoxProjection<Vec<f64>>);
+    test_trait_impl!(simplex_projection, SimplexProjection<f64>);
+    test_trait_impl!(l2_ball_projection, L2BallProjection<f64>);
+
+    #[test]
+    fn test_box() {
+        let bounds = BoxProjection::new(vec![0.0f64, -1.0, -1.0], vec![1.0, 1.0, 1.0]);
+        let p = bounds.project(&vec![-0.5, 0.5, 2.0]).unwrap();
+        assert_eq!(p, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_simplex() {
+        let simplex = SimplexProjection::new(1.0f64);
+        for (x, expected) in [
+            (vec![0.8, 0.5, -0.3], vec![0.65, 0.35, 0.0]),
+            (vec![0.2, 0.3, 0.5], vec![0.2, 0.3, 0.5]),
+            (vec![0.0, 0.0, 0.0], vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0]),
+            (vec![5.0, -1.0, 0.0], vec![1.0, 0.0, 0.0]),
+        ] {
+            let p: Vec<f64> = simplex.project(&x).unwrap();
+            for (pi, ei) in p.iter().zip(expected.iter()) {
+                assert_relative_eq!(*pi, *ei, epsilon = 1e-12);
+            }
+        }
+        let simplex = SimplexProjection::new(2.0f64);
+        let p: Vec<f64> = simplex.project(&vec![1.0, 1.0, 1.0, 1.0]).unwrap();
+        for pi in p {
+            assert_relative_eq!(pi, 0.5, epsilon = 1e-12);
+        }
+        let res: Result<Vec<f64>, Error> = simplex.project(&vec![1.0, f64::NAN]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`SimplexProjection`: vector must not contain NaN.\""
+        );
+    }
+
+    #[test]
+    fn test_l2_ball() {
+        let ball = L2BallProjection::new(1.0f64);
+        let p: Vec<f64> = ball.project(&vec![3.0, 4.0]).unwrap();
+        assert_relative_eq!(p[0], 0.6, epsilon = 1e-12);
+        assert_relative_eq!(p[1], 0.8, epsilon = 1e-12);
+        let p: Vec<f64> = ball.project(&vec![0.3, 0.4]).unwrap();
+        assert_eq!(p, vec![0.3, 0.4]);
+    }
+}
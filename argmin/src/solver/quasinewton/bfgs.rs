@@ -228,6 +228,7 @@ where
 
         let p = inv_hessian.dot(&prev_grad).mul(&float!(-1.0));
 
+        self.linesearch.record_cost(cur_cost);
         self.linesearch.search_direction(p);
 
         // Run solver
@@ -507,4 +508,93 @@ mod tests {
             assert_eq!(s.to_ne_bytes(), g.to_ne_bytes());
         }
     }
+
+    #[test]
+    fn test_solver_array() {
+        use crate::core::Executor;
+        use approx::assert_relative_eq;
+        use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = [f64; 2];
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(p, 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = [f64; 2];
+            type Gradient = [f64; 2];
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                let g = rosenbrock_2d_derivative(p, 1.0, 100.0);
+                Ok([g[0], g[1]])
+            }
+        }
+
+        let linesearch = MoreThuenteLineSearch::new();
+        let solver: BFGS<_, f64> = BFGS::new(linesearch);
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| {
+                state
+                    .param([-1.2, 1.0])
+                    .inv_hessian([[1.0, 0.0], [0.0, 1.0]])
+                    .max_iters(100)
+            })
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+    }
+
+    #[cfg(feature = "_faerl")]
+    #[test]
+    fn test_solver_faer() {
+        use crate::core::Executor;
+        use approx::assert_relative_eq;
+        use argmin_math::FaerCol;
+        use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+        use faer::{col, Col};
+
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = FaerCol<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(&[p[0], p[1]], 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = FaerCol<f64>;
+            type Gradient = FaerCol<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                let g = rosenbrock_2d_derivative(&[p[0], p[1]], 1.0, 100.0);
+                Ok(FaerCol(Col::from_fn(2, |i| g[i])))
+            }
+        }
+
+        let linesearch = MoreThuenteLineSearch::new();
+        let solver: BFGS<_, f64> = BFGS::new(linesearch);
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| {
+                state
+                    .param(FaerCol(col![-1.2, 1.0]))
+                    .inv_hessian(faer::Mat::identity(2, 2))
+                    .max_iters(100)
+            })
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+    }
 }
@@ -190,6 +190,7 @@ where
 
         let p = inv_hessian.dot(&prev_grad).mul(&float!(-1.0));
 
+        self.linesearch.record_cost(cost);
         self.linesearch.search_direction(p);
 
         let OptimizationResult {
@@ -11,8 +11,8 @@ use crate::core::{
     TerminationStatus, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul, ArgminSignum,
-    ArgminSub, ArgminZeroLike,
+    ArgminAdd, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul, ArgminMulAssign,
+    ArgminScaledAddAssign, ArgminScaledSubAssign, ArgminSignum, ArgminSub, ArgminZeroLike,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -317,6 +317,8 @@ where
         + ArgminMul<F, P>
         + ArgminMul<P, P>
         + ArgminMul<G, P>
+        + ArgminMulAssign<F>
+        + ArgminScaledAddAssign<P, F>
         + ArgminL1Norm<F>
         + ArgminSignum
         + ArgminZeroLike
@@ -331,8 +333,8 @@ where
         + ArgminAdd<P, G>
         + ArgminDot<G, F>
         + ArgminDot<P, F>
-        + ArgminMul<F, G>
         + ArgminMul<F, P>
+        + ArgminScaledSubAssign<G, F>
         + ArgminZeroLike
         + ArgminMinMax,
     L: Clone + LineSearch<P, F> + Solver<LineSearchProblem<O, P, G, F>, IterState<P, G, (), (), F>>,
@@ -412,7 +414,7 @@ where
             let rho_t = float!(1.0) / yksk;
             let skq: F = sk.dot(&q);
             let alpha_t = skq.mul(rho_t);
-            q = q.sub(&yk.mul(&alpha_t));
+            q.scaled_sub_assign(&alpha_t, yk);
             rho[cur_m - i - 1] = rho_t;
             alpha[cur_m - i - 1] = alpha_t;
         }
@@ -420,7 +422,7 @@ where
         for (i, (sk, yk)) in self.s.iter().zip(self.y.iter()).enumerate() {
             let beta: F = yk.dot(&r);
             let beta = beta.mul(rho[i]);
-            r = r.add(&sk.mul(&(alpha[i] - beta)));
+            r.scaled_add_assign(&(alpha[i] - beta), sk);
         }
 
         let mut line_problem = LineSearchProblem::new(problem.take_problem().unwrap());
@@ -434,9 +436,11 @@ where
             .mul(&r)
             .mul(&float!(-1.0))
         } else {
-            r.mul(&float!(-1.0))
+            r.mul_assign(&float!(-1.0));
+            r
         };
 
+        self.linesearch.record_cost(cur_cost);
         self.linesearch.search_direction(d);
 
         // Run solver
@@ -733,4 +737,45 @@ mod tests {
             assert!((result_param[3]).abs() < 1e-6);
         }
     }
+
+    #[cfg(feature = "_faerl")]
+    #[test]
+    fn test_solver_faer() {
+        use crate::core::Executor;
+        use approx::assert_relative_eq;
+        use argmin_math::FaerCol;
+        use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+        use faer::{col, Col};
+
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = FaerCol<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(&[p[0], p[1]], 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = FaerCol<f64>;
+            type Gradient = FaerCol<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                let g = rosenbrock_2d_derivative(&[p[0], p[1]], 1.0, 100.0);
+                Ok(FaerCol(Col::from_fn(2, |i| g[i])))
+            }
+        }
+
+        let linesearch = MoreThuenteLineSearch::new();
+        let solver: LBFGS<_, FaerCol<f64>, FaerCol<f64>, f64> = LBFGS::new(linesearch, 7);
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(FaerCol(col![-1.2, 1.0])).max_iters(100))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+    }
 }
@@ -10,7 +10,7 @@ use crate::core::{
     TerminationStatus, TrustRegionRadius, KV,
 };
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminSub, ArgminWeightedDot,
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSolve, ArgminSub, ArgminWeightedDot,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -62,7 +62,7 @@ where
         + ArgminDot<P, F>
         + ArgminAdd<P, P>
         + ArgminSub<P, P>,
-    H: ArgminInv<H> + ArgminDot<P, P>,
+    H: ArgminSolve<P, P> + ArgminDot<P, P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Dogleg";
@@ -93,7 +93,7 @@ where
         let pstar;
 
         // pb = -H^-1g
-        let pb = (h.inv()?).dot(&g).mul(&float!(-1.0));
+        let pb = h.solve(&g)?.mul(&float!(-1.0));
 
         if pb.l2_norm() <= self.radius {
             pstar = pb;
@@ -180,6 +180,44 @@ mod tests {
         assert_eq!(radius.to_ne_bytes(), f64::NAN.to_ne_bytes());
     }
 
+    #[test]
+    fn test_next_iter_scalar() {
+        use approx::assert_relative_eq;
+
+        struct TestProblem {}
+
+        impl Gradient for TestProblem {
+            type Param = f64;
+            type Gradient = f64;
+
+            fn gradient(&self, _p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(2.0)
+            }
+        }
+
+        impl Hessian for TestProblem {
+            type Param = f64;
+            type Hessian = f64;
+
+            fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(4.0)
+            }
+        }
+
+        // The Newton step `-g / h` lies within the trust region
+        let mut dl: Dogleg<f64> = Dogleg::new();
+        dl.set_radius(1.0);
+        let state: IterState<f64, f64, (), f64, f64> = IterState::new().param(1.0);
+        let (mut state_out, _) = dl
+            .next_iter(&mut Problem::new(TestProblem {}), state)
+            .unwrap();
+        assert_relative_eq!(
+            state_out.take_param().unwrap(),
+            -0.5,
+            epsilon = f64::EPSILON
+        );
+    }
+
     #[cfg(feature = "_ndarrayl")]
     #[test]
     fn test_next_iter() {
@@ -9,9 +9,9 @@ use crate::core::{
     ArgminFloat, Error, IterState, Problem, SerializeAlias, Solver, State, TerminationReason,
     TerminationStatus, TrustRegionRadius, KV,
 };
-use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminWeightedDot, ArgminZeroLike,
-};
+use crate::solver::curvature::{Curvature, ExactHessian};
+use crate::solver::preconditioner::{IdentityPreconditioner, Preconditioner};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminZeroLike};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -20,23 +20,42 @@ use serde::{Deserialize, Serialize};
 /// The Steihaug method is a conjugate gradients based approach for finding an approximate solution
 /// to the second order approximation of the cost function within the trust region.
 ///
+/// A symmetric positive definite preconditioner `M` can be provided via
+/// [`with_preconditioner`](`Steihaug::with_preconditioner`). In this case, the trust region is
+/// measured in the norm `||p||_M = sqrt(p^T M p)`, which effectively rescales the variables
+/// (Steihaug-Toint method).
+///
+/// Only products of the Hessian with vectors are required. By default, the Hessian in the state
+/// is multiplied with vectors; other sources of Hessian-vector products can be chosen via
+/// [`with_curvature`](`Steihaug::with_curvature`) (see [`curvature`](`crate::solver::curvature`)).
+///
 /// ## Reference
 ///
 /// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone, Default)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct Steihaug<P, F> {
+pub struct Steihaug<P, F, M = IdentityPreconditioner, C = ExactHessian> {
     /// Radius
     radius: F,
+    /// Preconditioner
+    preconditioner: M,
+    /// Computation of Hessian-vector products
+    curvature: C,
     /// epsilon
     epsilon: F,
     /// p
     p: Option<P>,
     /// residual
     r: Option<P>,
-    /// r^Tr
+    /// r^T M^-1 r
     rtr: F,
+    /// p^T M p
+    pmp: F,
+    /// p^T M d
+    pmd: F,
+    /// d^T M d
+    dmd: F,
     /// initial residual
     r_0_norm: F,
     /// direction
@@ -61,15 +80,95 @@ where
     pub fn new() -> Self {
         Steihaug {
             radius: F::nan(),
+            preconditioner: IdentityPreconditioner,
+            curvature: ExactHessian,
             epsilon: float!(10e-10),
             p: None,
             r: None,
             rtr: F::nan(),
+            pmp: F::nan(),
+            pmd: F::nan(),
+            dmd: F::nan(),
             r_0_norm: F::nan(),
             d: None,
             max_iters: std::u64::MAX,
         }
     }
+}
+
+impl<P, F, M, C> Steihaug<P, F, M, C>
+where
+    P: ArgminMul<F, P> + ArgminDot<P, F> + ArgminAdd<P, P>,
+    F: ArgminFloat,
+{
+    /// Set preconditioner
+    ///
+    /// The preconditioner must be symmetric and positive definite. Defaults to
+    /// [`IdentityPreconditioner`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::Steihaug;
+    /// # use argmin::solver::preconditioner::JacobiPreconditioner;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let hessian = vec![vec![4.0f64, 1.0], vec![1.0, 100.0]];
+    /// let preconditioner = JacobiPreconditioner::new(&hessian)?;
+    /// let sh: Steihaug<Vec<f64>, f64, _> = Steihaug::new().with_preconditioner(preconditioner);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_preconditioner<M2>(self, preconditioner: M2) -> Steihaug<P, F, M2, C> {
+        Steihaug {
+            radius: self.radius,
+            preconditioner,
+            curvature: self.curvature,
+            epsilon: self.epsilon,
+            p: self.p,
+            r: self.r,
+            rtr: self.rtr,
+            pmp: self.pmp,
+            pmd: self.pmd,
+            dmd: self.dmd,
+            r_0_norm: self.r_0_norm,
+            d: self.d,
+            max_iters: self.max_iters,
+        }
+    }
+
+    /// Set how products of the Hessian with vectors are computed
+    ///
+    /// Defaults to [`ExactHessian`]. See [`curvature`](`crate::solver::curvature`) for the
+    /// alternatives.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::Steihaug;
+    /// # use argmin::solver::curvature::HessianVectorProducts;
+    /// let sh: Steihaug<Vec<f64>, f64, _, _> =
+    ///     Steihaug::new().with_curvature(HessianVectorProducts);
+    /// ```
+    #[must_use]
+    pub fn with_curvature<C2>(self, curvature: C2) -> Steihaug<P, F, M, C2> {
+        Steihaug {
+            radius: self.radius,
+            preconditioner: self.preconditioner,
+            curvature,
+            epsilon: self.epsilon,
+            p: self.p,
+            r: self.r,
+            rtr: self.rtr,
+            pmp: self.pmp,
+            pmd: self.pmd,
+            dmd: self.dmd,
+            r_0_norm: self.r_0_norm,
+            d: self.d,
+            max_iters: self.max_iters,
+        }
+    }
 
     /// Set epsilon
     ///
@@ -117,26 +216,19 @@ where
         self
     }
 
-    /// evaluate m(p) (without considering f_init because it is not available)
-    fn eval_m<H>(&self, p: &P, g: &P, h: &H) -> F
-    where
-        P: ArgminWeightedDot<P, F, H>,
-    {
-        g.dot(p) + float!(0.5) * p.weighted_dot(h, p)
-    }
-
     /// calculate all possible step lengths
+    ///
+    /// If `model` is provided, the step length which minimizes `model(tau) = m(p + tau * d)`
+    /// (without considering f_init because it is not available) is returned, otherwise the largest
+    /// step length.
     #[allow(clippy::many_single_char_names)]
-    fn tau<G, H>(&self, filter_func: G, eval: bool, g: &P, h: &H) -> F
+    fn tau<G>(&self, filter_func: G, model: Option<&dyn Fn(F) -> F>) -> F
     where
         G: Fn(F) -> bool,
-        P: ArgminWeightedDot<P, F, H>,
     {
-        let p = self.p.as_ref().unwrap();
-        let d = self.d.as_ref().unwrap();
-        let a = p.dot(p);
-        let b = d.dot(d);
-        let c = p.dot(d);
+        let a = self.pmp;
+        let b = self.dmd;
+        let c = self.pmd;
         let delta = self.radius.powi(2);
         let t1 = (-a * b + b * delta + c.powi(2)).sqrt();
         let tau1 = -(t1 + c) / b;
@@ -147,7 +239,7 @@ where
             let tau3 = (delta - a) / (float!(2.0) * c);
             t.push(tau3);
         }
-        let v = if eval {
+        let v = if let Some(model) = model {
             // remove NAN taus and calculate m (without f_init) for all taus, then sort them based
             // on their result and return the tau which corresponds to the lowest m
             let mut v = t
@@ -155,10 +247,7 @@ where
                 .cloned()
                 .enumerate()
                 .filter(|(_, tau)| (!tau.is_nan() || !tau.is_infinite()) && filter_func(*tau))
-                .map(|(i, tau)| {
-                    let p_local = p.add(&d.mul(&tau));
-                    (i, self.eval_m(&p_local, g, h))
-                })
+                .map(|(i, tau)| (i, model(tau)))
                 .filter(|(_, m)| !m.is_nan() || !m.is_infinite())
                 .collect::<Vec<(usize, F)>>();
             v.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
@@ -178,7 +267,7 @@ where
     }
 }
 
-impl<P, O, F, H> Solver<O, IterState<P, P, (), H, F>> for Steihaug<P, F>
+impl<P, O, F, H, M, C> Solver<O, IterState<P, P, (), H, F>> for Steihaug<P, F, M, C>
 where
     P: Clone
         + SerializeAlias
@@ -187,7 +276,8 @@ where
         + ArgminDot<P, F>
         + ArgminAdd<P, P>
         + ArgminZeroLike,
-    H: ArgminDot<P, P>,
+    M: Preconditioner<P>,
+    C: Curvature<O, P, H>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Steihaug";
@@ -219,8 +309,12 @@ where
         }
 
         self.r_0_norm = r.l2_norm();
-        self.rtr = r.dot(&r);
-        self.d = Some(r.mul(&float!(-1.0)));
+        let z = self.preconditioner.apply_inverse(&r)?;
+        self.rtr = r.dot(&z);
+        self.pmp = float!(0.0);
+        self.pmd = float!(0.0);
+        self.dmd = self.rtr;
+        self.d = Some(z.mul(&float!(-1.0)));
         let p = r.zero_like();
         self.p = Some(p.clone());
 
@@ -231,7 +325,7 @@ where
 
     fn next_iter(
         &mut self,
-        _problem: &mut Problem<O>,
+        problem: &mut Problem<O>,
         mut state: IterState<P, P, (), H, F>,
     ) -> Result<(IterState<P, P, (), H, F>, Option<KV>), Error> {
         let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
@@ -245,12 +339,18 @@ where
         ))?;
 
         let d = self.d.as_ref().unwrap();
-        let dhd = d.weighted_dot(&h, d);
+        let hd = self.curvature.hessian_vector_product(problem, &h, d)?;
+        let dhd = d.dot(&hd);
 
         // Current search direction d is a direction of zero curvature or negative curvature
         let p = self.p.as_ref().unwrap();
         if dhd <= float!(0.0) {
-            let tau = self.tau(|_| true, true, &grad, &h);
+            let hp = self.curvature.hessian_vector_product(problem, &h, p)?;
+            let (gp, gd, php, phd) = (grad.dot(p), grad.dot(d), p.dot(&hp), p.dot(&hd));
+            let model = |tau: F| {
+                gp + tau * gd + float!(0.5) * (php + float!(2.0) * tau * phd + tau.powi(2) * dhd)
+            };
+            let tau = self.tau(|_| true, Some(&model));
             return Ok((
                 state
                     .param(p.add(&d.mul(&tau)))
@@ -261,10 +361,11 @@ where
 
         let alpha = self.rtr / dhd;
         let p_n = p.add(&d.mul(&alpha));
+        let pmp_n = self.pmp + float!(2.0) * alpha * self.pmd + alpha.powi(2) * self.dmd;
 
         // new p violates trust region bound
-        if p_n.l2_norm() >= self.radius {
-            let tau = self.tau(|x| x >= float!(0.0), false, &grad, &h);
+        if pmp_n.sqrt() >= self.radius {
+            let tau = self.tau(|x| x >= float!(0.0), None);
             return Ok((
                 state
                     .param(p.add(&d.mul(&tau)))
@@ -274,7 +375,7 @@ where
         }
 
         let r = self.r.as_ref().unwrap();
-        let r_n = r.add(&h.dot(d).mul(&alpha));
+        let r_n = r.add(&hd.mul(&alpha));
 
         if r_n.l2_norm() < self.epsilon * self.r_0_norm {
             return Ok((
@@ -285,9 +386,13 @@ where
             ));
         }
 
-        let rjtrj = r_n.dot(&r_n);
+        let z_n = self.preconditioner.apply_inverse(&r_n)?;
+        let rjtrj = r_n.dot(&z_n);
         let beta = rjtrj / self.rtr;
-        self.d = Some(r_n.mul(&float!(-1.0)).add(&d.mul(&beta)));
+        self.pmd = beta * (self.pmd + alpha * self.dmd);
+        self.dmd = rjtrj + beta.powi(2) * self.dmd;
+        self.pmp = pmp_n;
+        self.d = Some(z_n.mul(&float!(-1.0)).add(&d.mul(&beta)));
         self.r = Some(r_n);
         self.p = Some(p_n.clone());
         self.rtr = rjtrj;
@@ -309,7 +414,7 @@ where
     }
 }
 
-impl<P, F: ArgminFloat> TrustRegionRadius<F> for Steihaug<P, F> {
+impl<P, F: ArgminFloat, M, C> TrustRegionRadius<F> for Steihaug<P, F, M, C> {
     /// Set current radius.
     ///
     /// Needed by [`TrustRegion`](`crate::solver::trustregion::TrustRegion`).
@@ -345,13 +450,23 @@ mod tests {
             epsilon,
             p,
             r,
+            preconditioner,
+            curvature,
             rtr,
+            pmp,
+            pmd,
+            dmd,
             r_0_norm,
             d,
             max_iters,
         } = sh;
 
         assert_eq!(radius.to_ne_bytes(), f64::NAN.to_ne_bytes());
+        assert_eq!(preconditioner, IdentityPreconditioner);
+        assert_eq!(curvature, ExactHessian);
+        assert_eq!(pmp.to_ne_bytes(), f64::NAN.to_ne_bytes());
+        assert_eq!(pmd.to_ne_bytes(), f64::NAN.to_ne_bytes());
+        assert_eq!(dmd.to_ne_bytes(), f64::NAN.to_ne_bytes());
         assert_eq!(epsilon.to_ne_bytes(), 10e-10f64.to_ne_bytes());
         assert!(p.is_none());
         assert!(r.is_none());
@@ -449,12 +564,19 @@ mod tests {
             p,
             r,
             rtr,
+            pmp,
+            pmd,
+            dmd,
             r_0_norm,
             d,
             max_iters,
+            ..
         } = sh;
 
         assert_eq!(radius.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(pmp.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(pmd.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(dmd.to_ne_bytes(), 5.0f64.to_ne_bytes());
         assert_eq!(epsilon.to_ne_bytes(), 10e-10f64.to_ne_bytes());
         assert_relative_eq!(p.as_ref().unwrap()[0], 0.0f64, epsilon = f64::EPSILON);
         assert_relative_eq!(p.as_ref().unwrap()[1], 0.0f64, epsilon = f64::EPSILON);
@@ -466,4 +588,49 @@ mod tests {
         assert_relative_eq!(d.as_ref().unwrap()[1], -grad[1], epsilon = f64::EPSILON);
         assert_eq!(max_iters, u64::MAX);
     }
+
+    #[test]
+    fn test_preconditioned() {
+        use crate::core::Executor;
+        use crate::solver::preconditioner::JacobiPreconditioner;
+
+        // Badly scaled, positive definite Hessian. The Newton step is [-1, -1, -1].
+        let hessian: Vec<Vec<f64>> = vec![
+            vec![1e4, 1.0, 0.0],
+            vec![1.0, 1.0, 1e-2],
+            vec![0.0, 1e-2, 1e-2],
+        ];
+        let grad: Vec<f64> = vec![1e4 + 1.0, 2.01, 2e-2];
+
+        // Large radius: interior solution
+        let sh = Steihaug::new().with_preconditioner(JacobiPreconditioner::new(&hessian).unwrap());
+        let mut sh: Steihaug<Vec<f64>, f64, _> = sh;
+        sh.set_radius(1e3);
+        let res = Executor::new(TestProblem::new(), sh)
+            .configure(|state| state.gradient(grad.clone()).hessian(hessian.clone()))
+            .run()
+            .unwrap();
+        let p = res.state.get_param().unwrap();
+        for pi in p {
+            assert_relative_eq!(*pi, -1.0, epsilon = 1e-8);
+        }
+
+        // Small radius: solution is on the boundary of the trust region in the M-norm
+        let radius = 1.0;
+        let sh = Steihaug::new().with_preconditioner(JacobiPreconditioner::new(&hessian).unwrap());
+        let mut sh: Steihaug<Vec<f64>, f64, _> = sh;
+        sh.set_radius(radius);
+        let res = Executor::new(TestProblem::new(), sh)
+            .configure(|state| state.gradient(grad.clone()).hessian(hessian.clone()))
+            .run()
+            .unwrap();
+        let p = res.state.get_param().unwrap();
+        let norm_m: f64 = p
+            .iter()
+            .zip(hessian.iter().enumerate())
+            .map(|(pi, (i, row))| pi * pi * row[i])
+            .sum::<f64>()
+            .sqrt();
+        assert_relative_eq!(norm_m, radius, epsilon = 1e-10);
+    }
 }
@@ -6,12 +6,12 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian,
-    IterState, OptimizationResult, Problem, SerializeAlias, Solver, TerminationStatus,
-    TrustRegionRadius, KV,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
+    OptimizationResult, Problem, SerializeAlias, Solver, TerminationStatus, TrustRegionRadius, KV,
 };
+use crate::solver::curvature::{EvaluateCurvature, ExactHessian};
 use crate::solver::trustregion::reduction_ratio;
-use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminWeightedDot};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -28,10 +28,18 @@ use serde::{Deserialize, Serialize};
 /// * [Dogleg method](`crate::solver::trustregion::Dogleg`)
 /// * [Steihaug method](`crate::solver::trustregion::Steihaug`)
 ///
+/// By default, the Hessian is computed in every iteration. Instead, Hessian-vector products or
+/// finite differences of gradients can be used via
+/// [`with_curvature`](`TrustRegion::with_curvature`) (see
+/// [`curvature`](`crate::solver::curvature`)). The same choice must be made for the subproblem
+/// solver, which is only supported by the [Steihaug method](`crate::solver::trustregion::Steihaug`).
+///
 /// ## Requirements on the optimization problem
 ///
-/// The optimization problem is required to implement [`CostFunction`], [`Gradient`] and
-/// [`Hessian`].
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`] and,
+/// depending on the chosen [`curvature`](`crate::solver::curvature`),
+/// [`Hessian`](`crate::core::Hessian`) (default) or
+/// [`HessianVectorProduct`](`crate::core::HessianVectorProduct`).
 ///
 /// ## Reference
 ///
@@ -39,7 +47,7 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct TrustRegion<R, F> {
+pub struct TrustRegion<R, F, C = ExactHessian> {
     /// Radius
     radius: F,
     /// Maximum radius
@@ -48,6 +56,8 @@ pub struct TrustRegion<R, F> {
     eta: F,
     /// subproblem (must implement [`crate::solver::trustregion::TrustRegionRadius`])
     subproblem: R,
+    /// computation of Hessian-vector products
+    curvature: C,
     /// f(xk)
     fxk: F,
     /// mk(0)
@@ -73,10 +83,43 @@ where
             max_radius: float!(100.0),
             eta: float!(0.125),
             subproblem,
+            curvature: ExactHessian,
             fxk: F::nan(),
             mk0: F::nan(),
         }
     }
+}
+
+impl<R, F, C> TrustRegion<R, F, C>
+where
+    F: ArgminFloat,
+{
+    /// Set how products of the Hessian with vectors are computed
+    ///
+    /// Defaults to [`ExactHessian`]. See [`curvature`](`crate::solver::curvature`) for the
+    /// alternatives. The subproblem solver must be configured accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::{Steihaug, TrustRegion};
+    /// # use argmin::solver::curvature::HessianVectorProducts;
+    /// let sh: Steihaug<Vec<f64>, f64, _, _> =
+    ///     Steihaug::new().with_curvature(HessianVectorProducts);
+    /// let tr: TrustRegion<_, f64, _> = TrustRegion::new(sh).with_curvature(HessianVectorProducts);
+    /// ```
+    #[must_use]
+    pub fn with_curvature<C2>(self, curvature: C2) -> TrustRegion<R, F, C2> {
+        TrustRegion {
+            radius: self.radius,
+            max_radius: self.max_radius,
+            eta: self.eta,
+            subproblem: self.subproblem,
+            curvature,
+            fxk: self.fxk,
+            mk0: self.mk0,
+        }
+    }
 
     /// Set radius
     ///
@@ -157,11 +200,9 @@ where
     }
 }
 
-impl<O, R, F, P, G, H> Solver<O, IterState<P, G, (), H, F>> for TrustRegion<R, F>
+impl<O, R, F, P, G, H, C> Solver<O, IterState<P, G, (), H, F>> for TrustRegion<R, F, C>
 where
-    O: CostFunction<Param = P, Output = F>
-        + Gradient<Param = P, Gradient = G>
-        + Hessian<Param = P, Hessian = H>,
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone
         + std::fmt::Debug
         + SerializeAlias
@@ -171,8 +212,9 @@ where
         + ArgminDot<G, F>
         + ArgminAdd<P, P>,
     G: Clone + SerializeAlias + DeserializeOwnedAlias,
-    H: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, P>,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
     R: Clone + TrustRegionRadius<F> + Solver<O, IterState<P, G, (), H, F>>,
+    C: EvaluateCurvature<O, P, G, H>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Trust region";
@@ -198,7 +240,7 @@ where
         let hessian = state
             .take_hessian()
             .map(Result::Ok)
-            .unwrap_or_else(|| problem.hessian(&param))?;
+            .unwrap_or_else(|| self.curvature.hessian(problem, &param, &grad))?;
 
         let cost = state.get_cost();
         self.fxk = if cost.is_infinite() && cost.is_sign_positive() {
@@ -261,7 +303,10 @@ where
 
         let new_param = pk.add(&param);
         let fxkpk = problem.cost(&new_param)?;
-        let mkpk = self.fxk + pk.dot(&grad) + float!(0.5) * pk.weighted_dot(&hessian, &pk);
+        let hpk = self
+            .curvature
+            .hessian_vector_product(problem, &hessian, &pk)?;
+        let mkpk = self.fxk + pk.dot(&grad) + float!(0.5) * pk.dot(&hpk);
 
         let rho = reduction_ratio(self.fxk, fxkpk, self.mk0, mkpk);
 
@@ -283,7 +328,7 @@ where
                 self.fxk = fxkpk;
                 self.mk0 = fxkpk;
                 let grad = problem.gradient(&new_param)?;
-                let hessian = problem.hessian(&new_param)?;
+                let hessian = self.curvature.hessian(problem, &new_param, &grad)?;
                 state
                     .param(new_param)
                     .cost(fxkpk)
@@ -325,10 +370,13 @@ mod tests {
             max_radius,
             eta,
             subproblem: _,
+            curvature,
             fxk,
             mk0,
         } = tr;
 
+        assert_eq!(curvature, ExactHessian);
+
         assert_eq!(radius.to_ne_bytes(), 1.0f64.to_ne_bytes());
         assert_eq!(max_radius.to_ne_bytes(), 100.0f64.to_ne_bytes());
         assert_eq!(eta.to_ne_bytes(), 0.125f64.to_ne_bytes());
@@ -432,14 +480,54 @@ mod tests {
             max_radius,
             eta,
             subproblem: _,
+            curvature,
             fxk,
             mk0,
         } = tr;
 
+        assert_eq!(curvature, ExactHessian);
+
         assert_eq!(radius.to_ne_bytes(), 1.0f64.to_ne_bytes());
         assert_eq!(max_radius.to_ne_bytes(), 100.0f64.to_ne_bytes());
         assert_eq!(eta.to_ne_bytes(), 0.125f64.to_ne_bytes());
         assert_eq!(fxk.to_ne_bytes(), 1.0f64.sqrt().to_ne_bytes());
         assert_eq!(mk0.to_ne_bytes(), 1.0f64.to_ne_bytes());
     }
+
+    #[test]
+    fn test_curvature() {
+        use crate::solver::curvature::{
+            tests::Quartic, FiniteDiffHessianVectorProducts, HessianVectorProducts,
+        };
+        use std::collections::HashMap;
+
+        fn solve<C, H>(curvature: C) -> HashMap<&'static str, u64>
+        where
+            C: Clone + EvaluateCurvature<Quartic, Vec<f64>, Vec<f64>, H>,
+            H: Clone + SerializeAlias + DeserializeOwnedAlias,
+        {
+            let subproblem: Steihaug<Vec<f64>, f64, _, _> =
+                Steihaug::new().with_curvature(curvature.clone());
+            let solver = TrustRegion::new(subproblem).with_curvature(curvature);
+            let res = Executor::new(Quartic::new(), solver)
+                .configure(|state| state.param(vec![0.0; 3]).max_iters(50))
+                .run()
+                .unwrap();
+            let x = res.state.get_best_param().unwrap();
+            let grad = Quartic::new().gradient(x).unwrap();
+            assert!(grad.l2_norm() < 1e-6);
+            res.problem.counts
+        }
+
+        let counts = solve(ExactHessian);
+        assert!(counts["hessian_count"] > 0);
+
+        let counts = solve(HessianVectorProducts);
+        assert!(!counts.contains_key("hessian_count"));
+        assert!(counts["hessian_vector_product_count"] > 0);
+
+        let counts = solve(FiniteDiffHessianVectorProducts::new());
+        assert!(!counts.contains_key("hessian_count"));
+        assert!(!counts.contains_key("hessian_vector_product_count"));
+    }
 }
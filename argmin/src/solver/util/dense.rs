@@ -0,0 +1,158 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Dense linear algebra on small `Vec<Vec<F>>` systems which solvers assemble internally (Gram
+//! matrices, kernel matrices, least squares subproblems), independent of the math backend of the
+//! problem.
+
+use crate::core::ArgminFloat;
+
+/// Dot product of two slices
+pub(crate) fn dot<F: ArgminFloat>(a: &[F], b: &[F]) -> F {
+    a.iter()
+        .zip(b.iter())
+        .fold(float!(0.0), |acc, (x, y)| acc + *x * *y)
+}
+
+/// Cholesky decomposition `A = L L^T`; returns `None` if `A` is not positive definite.
+pub(crate) fn cholesky<F: ArgminFloat>(a: &[Vec<F>]) -> Option<Vec<Vec<F>>> {
+    let n = a.len();
+    let mut l = vec![vec![float!(0.0); n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let s = a[i][j] - dot(&l[i][..j], &l[j][..j]);
+            if i == j {
+                if s <= float!(0.0) || s.is_nan() {
+                    return None;
+                }
+                l[i][i] = s.sqrt();
+            } else {
+                l[i][j] = s / l[j][j];
+            }
+        }
+    }
+    Some(l)
+}
+
+/// Solves `L x = b` by forward substitution
+pub(crate) fn solve_lower<F: ArgminFloat>(l: &[Vec<F>], b: &[F]) -> Vec<F> {
+    let mut x: Vec<F> = Vec::with_capacity(b.len());
+    for i in 0..b.len() {
+        let xi = (b[i] - dot(&l[i][..i], &x)) / l[i][i];
+        x.push(xi);
+    }
+    x
+}
+
+/// Solves `L^T x = b` by backward substitution
+pub(crate) fn solve_upper_transposed<F: ArgminFloat>(l: &[Vec<F>], b: &[F]) -> Vec<F> {
+    let n = b.len();
+    let mut x = vec![float!(0.0); n];
+    for i in (0..n).rev() {
+        let s = ((i + 1)..n).fold(float!(0.0), |acc, k| acc + l[k][i] * x[k]);
+        x[i] = (b[i] - s) / l[i][i];
+    }
+    x
+}
+
+/// Solves the linear least squares problem `min ||A x - b||` via a Householder QR decomposition of
+/// `A`, which is given by its columns. Returns `None` if `A` does not have full column rank (with
+/// respect to machine precision).
+pub(crate) fn least_squares_qr<F: ArgminFloat>(columns: &[Vec<F>], b: &[F]) -> Option<Vec<F>> {
+    let m = b.len();
+    let n = columns.len();
+    if n > m {
+        return None;
+    }
+    let mut r = columns.to_vec();
+    let mut qtb = b.to_vec();
+    let scale = r
+        .iter()
+        .fold(float!(0.0), |acc: F, col| acc.max(dot(col, col).sqrt()));
+    let tol = float!(m as f64) * F::epsilon() * scale;
+    for j in 0..n {
+        let norm = dot(&r[j][j..], &r[j][j..]).sqrt();
+        if norm <= tol || norm.is_nan() {
+            return None;
+        }
+        // Householder reflection `I - 2 v v^T / (v^T v)` which maps `r[j][j..]` onto
+        // `alpha * e_1`
+        let alpha = if r[j][j] > float!(0.0) { -norm } else { norm };
+        let mut v = r[j][j..].to_vec();
+        v[0] = v[0] - alpha;
+        let vtv = dot(&v, &v);
+        let (_, rest) = r.split_at_mut(j + 1);
+        for col in rest
+            .iter_mut()
+            .map(|col| &mut col[j..])
+            .chain(Some(&mut qtb[j..]))
+        {
+            let f = float!(2.0) * dot(&v, col) / vtv;
+            for (ci, vi) in col.iter_mut().zip(v.iter()) {
+                *ci = *ci - f * *vi;
+            }
+        }
+        r[j][j] = alpha;
+    }
+    // Back substitution `R x = Q^T b`
+    let mut x = vec![float!(0.0); n];
+    for i in (0..n).rev() {
+        let s = ((i + 1)..n).fold(float!(0.0), |acc, k| acc + r[k][i] * x[k]);
+        x[i] = (qtb[i] - s) / r[i][i];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_cholesky() {
+        let a = vec![
+            vec![4.0f64, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ];
+        let l = cholesky(&a).unwrap();
+        let expected = [[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]];
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(l[i][j], expected[i][j], epsilon = 1e-12);
+            }
+        }
+        let b = [1.0, 2.0, 3.0];
+        let x = solve_upper_transposed(&l, &solve_lower(&l, &b));
+        for i in 0..3 {
+            assert_relative_eq!(dot(&a[i], &x), b[i], epsilon = 1e-9);
+        }
+        assert!(cholesky(&[vec![1.0f64, 2.0], vec![2.0, 1.0]]).is_none());
+    }
+
+    #[test]
+    fn test_least_squares_qr() {
+        // Columns of `A = [[1, 1], [1, 2], [1, 3]]`, the solution of `min ||Ax - b||` is the
+        // regression line through `(1, 1), (2, 2), (3, 2)`
+        let a = vec![vec![1.0f64, 1.0, 1.0], vec![1.0, 2.0, 3.0]];
+        let x = least_squares_qr(&a, &[1.0, 2.0, 2.0]).unwrap();
+        assert_relative_eq!(x[0], 2.0 / 3.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 0.5, epsilon = 1e-12);
+
+        // Consistent system with negative diagonal elements
+        let a = vec![vec![-2.0f64, 0.0], vec![1.0, -3.0]];
+        let x = least_squares_qr(&a, &[-1.0, -6.0]).unwrap();
+        assert_relative_eq!(x[0], 1.5, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-12);
+
+        // Rank deficient
+        let a = vec![vec![1.0f64, 2.0, 3.0], vec![2.0, 4.0, 6.0]];
+        assert!(least_squares_qr(&a, &[1.0, 2.0, 2.0]).is_none());
+        let a = vec![vec![1.0f64], vec![2.0]];
+        assert!(least_squares_qr(&a, &[1.0]).is_none());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2018-2023 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Nonmonotone backtracking with safeguarded quadratic interpolation as used by the spectral
+//! gradient methods ([`SPG`](`crate::solver::projectedgradient::SPG`) and
+//! [`BarzilaiBorwein`](`crate::solver::gradientdescent::BarzilaiBorwein`)).
+
+use crate::core::{ArgminFloat, Error};
+
+/// Maximum number of backtracking steps per iteration
+pub(crate) const MAX_BACKTRACKING_ITERS: usize = 100;
+
+/// Nonmonotone backtracking along `step -> trial(step)`, starting from `step = 1`.
+///
+/// A step is accepted once `cost(step) <= cost_max + sufficient_decrease * step * slope`, where
+/// `cost_max` is the maximum over the recent cost function values (including `cost`) and `slope`
+/// is the (negative) directional derivative at `step = 0`. Rejected steps are replaced by the
+/// minimizer of the quadratic interpolating `cost`, `slope` and `cost(step)`, safeguarded to
+/// `[0.1, 0.9] * step`, otherwise halved.
+///
+/// Returns the accepted point, its cost function value and the step. Fails with a
+/// `ConditionViolated` error (prefixed with `solver`) if `cost` or `cost_max` is not finite or if
+/// no step is accepted within [`MAX_BACKTRACKING_ITERS`] trials.
+pub(crate) fn nonmonotone_backtracking<P, F, T>(
+    solver: &str,
+    cost: F,
+    cost_max: F,
+    slope: F,
+    sufficient_decrease: F,
+    mut trial: T,
+) -> Result<(P, F, F), Error>
+where
+    F: ArgminFloat,
+    T: FnMut(F) -> Result<(P, F), Error>,
+{
+    if !cost.is_finite() || !cost_max.is_finite() {
+        return Err(argmin_error!(
+            ConditionViolated,
+            format!("`{solver}`: Cost function value is not finite.")
+        ));
+    }
+    let mut step = float!(1.0);
+    for _ in 0..MAX_BACKTRACKING_ITERS {
+        let (x_new, cost_new) = trial(step)?;
+        if cost_new <= cost_max + sufficient_decrease * step * slope {
+            return Ok((x_new, cost_new, step));
+        }
+        let step_tmp = float!(-0.5) * step.powi(2) * slope / (cost_new - cost - step * slope);
+        step = if step_tmp >= float!(0.1) * step && step_tmp <= float!(0.9) * step {
+            step_tmp
+        } else {
+            step * float!(0.5)
+        };
+    }
+    Err(argmin_error!(
+        ConditionViolated,
+        format!("`{solver}`: Line search did not find a sufficient decrease.")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_nonmonotone_backtracking() {
+        // f(t) = (t - 0.25)^2, f(0) = 0.0625, f'(0) = -0.5
+        let f = |t: f64| (t - 0.25).powi(2);
+        let mut evals = 0;
+        let (x, cost, step) = nonmonotone_backtracking("Test", f(0.0), f(0.0), -0.5, 1e-4, |t| {
+            evals += 1;
+            Ok((t, f(t)))
+        })
+        .unwrap();
+        // The quadratic interpolation recovers the exact minimizer after one rejected step
+        assert_eq!(evals, 2);
+        assert_relative_eq!(x, 0.25);
+        assert_relative_eq!(step, 0.25);
+        assert_relative_eq!(cost, 0.0);
+
+        // Nonmonotone: the first step is accepted because of a larger previous cost
+        let (_, _, step) =
+            nonmonotone_backtracking("Test", f(0.0), 1.0, -0.5, 1e-4, |t| Ok((t, f(t)))).unwrap();
+        assert_relative_eq!(step, 1.0);
+    }
+
+    #[test]
+    fn test_nonmonotone_backtracking_fails() {
+        let res = nonmonotone_backtracking("Test", 1.0, 1.0, -1.0, 1e-4, |t| Ok((t, f64::NAN)));
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`Test`: Line search did not find a sufficient decrease.\""
+        );
+
+        let res = nonmonotone_backtracking("Test", f64::NAN, 1.0, -1.0, 1e-4, |t| Ok((t, 0.0)));
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`Test`: Cost function value is not finite.\""
+        );
+
+        let res =
+            nonmonotone_backtracking("Test", 1.0, f64::INFINITY, -1.0, 1e-4, |t| Ok((t, 0.0)));
+        assert_error!(
+            res,
+            ArgminError,
+            "Condition violated: \"`Test`: Cost function value is not finite.\""
+        );
+    }
+}